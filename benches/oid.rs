@@ -0,0 +1,49 @@
+//! Benchmarking the decoding of object identifiers
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rasn::prelude::*;
+use rasn::{ber, oer, uper};
+
+/// A thousand identifiers of the lengths typically found in certificates
+/// and SNMP varbind lists, most short enough to be stored inline.
+fn build_oids() -> Vec<ObjectIdentifier> {
+    let templates: [&[u128]; 5] = [
+        &[2, 5, 4, 3],
+        &[1, 2, 840, 113_549, 1, 1, 11],
+        &[1, 3, 6, 1, 2, 1, 2, 2, 1, 10],
+        &[1, 3, 6, 1, 4, 1, 311, 21, 20],
+        &[1, 3, 6, 1, 4, 1, 2021, 10, 1, 3, 1, 0],
+    ];
+
+    (0..1000)
+        .map(|index| {
+            let mut arcs = templates[index % templates.len()].to_vec();
+            *arcs.last_mut().unwrap() += index as u128;
+            ObjectIdentifier::new(arcs).unwrap()
+        })
+        .collect()
+}
+
+macro_rules! rasn_dec_oid_fn {
+    ($fn_name:ident, $codec:ident) => {
+        fn $fn_name(c: &mut Criterion) {
+            let items = build_oids();
+            let encoded = $codec::encode(&items).unwrap();
+            c.bench_function(
+                &format!(
+                    "RASN/decode {} - 1000 object identifiers",
+                    stringify!($codec).to_uppercase()
+                ),
+                |b| {
+                    b.iter(|| black_box($codec::decode::<Vec<ObjectIdentifier>>(&encoded).unwrap()))
+                },
+            );
+        }
+    };
+}
+
+rasn_dec_oid_fn!(uper_oid, uper);
+rasn_dec_oid_fn!(oer_oid, oer);
+rasn_dec_oid_fn!(ber_oid, ber);
+
+criterion_group!(benches, uper_oid, oer_oid, ber_oid);
+criterion_main!(benches);
@@ -15,5 +15,9 @@ macro_rules! fuzz_type {
 }
 
 pub fn fuzz(data: &[u8]) {
-    fuzz_type!(data, rasn_pkix::Certificate);
+    fuzz_type!(data, rasn_pkix::Certificate, rasn::types::ObjectIdentifier);
+
+    // The BER decoder must return an error rather than panic on any input.
+    let _ = rasn::ber::decode::<rasn::types::ObjectIdentifier>(data);
+    let _ = rasn::ber::decode::<rasn::types::Open>(data);
 }
@@ -1,6 +1,4 @@
-use itertools::Itertools;
-
-use crate::config::{Config, FieldConfig};
+use crate::config::{root_field_count, Config, FieldConfig};
 
 #[allow(clippy::too_many_lines)]
 pub fn derive_struct_impl(
@@ -19,14 +17,21 @@ pub fn derive_struct_impl(
         .map(|(i, f)| FieldConfig::new(f, config, i))
         .collect::<Result<Vec<_>, _>>()?;
 
-    let field_metadata = field_configs
+    if let Some(field) = field_configs
         .iter()
-        .filter(|field| field.is_not_extension())
-        .map(|field| {
-            let metadata = field.to_field_metadata();
-            quote!(#metadata)
-        })
-        .collect::<Vec<_>>();
+        .rev()
+        .skip(1)
+        .find(|field| field.remainder)
+    {
+        return Err(syn::Error::new_spanned(
+            field.field,
+            "`remainder` can only be used on the last field",
+        ));
+    }
+
+    let root_fields = root_fields(&field_configs, crate_root);
+    let root_field_count = root_field_count(&field_configs);
+    let has_flatten = field_configs.iter().any(|field| field.flatten);
 
     let extension_metadata = field_configs
         .iter()
@@ -38,27 +43,26 @@ pub fn derive_struct_impl(
         .collect::<Vec<_>>();
     // TODO use partition above?
 
-    let all_optional_tags_are_unique: Vec<_> = field_configs
+    let ambiguous_groups = ambiguous_tag_groups(&field_configs);
+    check_distinct_field_tags(name, &ambiguous_groups)?;
+
+    let error_message = format!(
+        "{name}'s fields is not a valid \
+                order of ASN.1 tags, ensure that your field's tags and \
+                OPTIONALs are correct.",
+    );
+    let all_optional_tags_are_unique: Vec<_> = ambiguous_groups
         .iter()
-        .chunk_by(|config| config.is_option_or_default_type())
-        .into_iter()
-        .filter_map(|(key, fields)| key.then_some(fields))
         .map(|fields| {
-            let error_message = format!(
-                "{name}'s fields is not a valid \
-                        order of ASN.1 tags, ensure that your field's tags and \
-                        OPTIONALs are correct.",
-            );
-
-            let tag_tree = fields.map(|f| f.tag_tree()).collect::<Vec<_>>();
+            let tag_tree = fields.iter().map(|f| f.tag_tree()).collect::<Vec<_>>();
 
-            Ok::<_, syn::Error>(quote!({
+            quote!({
                 const LIST: &'static [#crate_root::types::TagTree] = &[#(#tag_tree),*];
                 const TAG_TREE: #crate_root::types::TagTree = #crate_root::types::TagTree::Choice(LIST);
                 const _: () = assert!(TAG_TREE.is_unique(), #error_message);
-            }))
+            })
         })
-        .collect::<Result<Vec<_>, _>>()?;
+        .collect::<Vec<_>>();
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -71,22 +75,49 @@ pub fn derive_struct_impl(
     } else {
         quote!(None)
     };
-    let root_field_count = field_metadata.len();
     let extension_field_count = extension_metadata.len();
     let extensible = config.constraints.extensible;
 
+    // The components of flattened fields are only known once their types
+    // are, so the tags across them are checked in const evaluation. That is
+    // done in a free constant where possible, so the check doesn't depend on
+    // `TAG` being used, and otherwise when evaluating `TAG`.
+    let flattened_tags_check = has_flatten.then(|| {
+        quote! {
+            assert!(
+                <#name #ty_generics as #crate_root::types::Constructed<#root_field_count, #extension_field_count>>::FIELDS
+                    .has_unambiguous_tags(),
+                #error_message
+            );
+        }
+    });
+    let (flattened_tags_are_unambiguous, flattened_tags_item) = if generics.params.is_empty() {
+        (
+            None,
+            flattened_tags_check.map(|check| quote!(const _: () = { #check };)),
+        )
+    } else {
+        (flattened_tags_check, None)
+    };
+
     let constructed_impl = (!config.delegate).then(|| {
         quote! {
             #[automatically_derived]
             impl #impl_generics  #crate_root::types::Constructed<#root_field_count, #extension_field_count> for #name #ty_generics #where_clause {
-                const FIELDS: #crate_root::types::fields::Fields<#root_field_count> = #crate_root::types::fields::Fields::from_static([
-                    #(#field_metadata),*
-                ]);
+                const FIELDS: #crate_root::types::fields::Fields<#root_field_count> = #crate_root::types::fields::Fields::from_static(#root_fields);
                 const IS_EXTENSIBLE: bool = #extensible;
                 const EXTENDED_FIELDS: Option<#crate_root::types::fields::Fields<#extension_field_count>> = #extended_fields_def;
             }
         }
     });
+    let components_impl = config.has_components(&field_configs).then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_root::types::Components for #name #ty_generics #where_clause {
+                const COMPONENTS: &'static [#crate_root::types::fields::Field] = &#root_fields;
+            }
+        }
+    });
     let constraints = config
         .constraints
         .const_expr(crate_root)
@@ -117,10 +148,15 @@ pub fn derive_struct_impl(
     Ok(quote! {
         #constructed_impl
 
+        #components_impl
+
+        #flattened_tags_item
+
         #[automatically_derived]
         impl #impl_generics  #crate_root::AsnType for #name #ty_generics #where_clause {
             const TAG: #crate_root::types::Tag = {
                 #(#all_optional_tags_are_unique)*
+                #flattened_tags_are_unambiguous
 
                 #tag
             };
@@ -129,3 +165,104 @@ pub fn derive_struct_impl(
         }
     })
 }
+
+/// The fields of the root component list as an array, with the components
+/// of each `flatten` field in its place.
+fn root_fields(
+    field_configs: &[FieldConfig<'_>],
+    crate_root: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let fields = field_configs
+        .iter()
+        .filter(|field| field.is_not_extension() && !field.remainder);
+
+    if !field_configs.iter().any(|field| field.flatten) {
+        let metadata = fields.map(FieldConfig::to_field_metadata);
+        return quote!([#(#metadata),*]);
+    }
+
+    let mut parts = Vec::new();
+    let mut run = Vec::new();
+    for field in fields {
+        if field.flatten {
+            if !run.is_empty() {
+                parts.push(quote!(&[#(#run),*]));
+                run.clear();
+            }
+            parts.push(field.components());
+        } else {
+            run.push(field.to_field_metadata());
+        }
+    }
+    if !run.is_empty() {
+        parts.push(quote!(&[#(#run),*]));
+    }
+
+    let count = root_field_count(field_configs);
+    quote!(#crate_root::types::fields::concat::<#count>(&[#(#parts),*]))
+}
+
+/// Groups the fields whose tags must be distinct for the value to be
+/// decodable: each run of `OPTIONAL` or `DEFAULT` fields together with the
+/// field that immediately follows it, as X.680 requires.
+fn ambiguous_tag_groups<'config, 'a>(
+    field_configs: &'config [FieldConfig<'a>],
+) -> Vec<Vec<&'config FieldConfig<'a>>> {
+    let mut groups = Vec::new();
+    let mut run = Vec::new();
+
+    for field in field_configs.iter().filter(|field| !field.remainder) {
+        // The tags of flattened components are checked with
+        // `Fields::has_unambiguous_tags` instead.
+        if field.flatten {
+            if run.len() > 1 {
+                groups.push(core::mem::take(&mut run));
+            }
+            run.clear();
+            continue;
+        }
+
+        run.push(field);
+
+        if field.is_not_option_or_default_type() {
+            if run.len() > 1 {
+                groups.push(core::mem::take(&mut run));
+            }
+            run.clear();
+        }
+    }
+
+    if run.len() > 1 {
+        groups.push(run);
+    }
+
+    groups
+}
+
+/// Reports fields in the same group whose tags are known to clash without
+/// evaluating their types, so the error can name them. Clashes that depend on
+/// the fields' types are caught by the `TAG_TREE` assertions instead.
+fn check_distinct_field_tags(
+    name: &syn::Ident,
+    groups: &[Vec<&FieldConfig<'_>>],
+) -> syn::Result<()> {
+    for fields in groups {
+        for (i, field) in fields.iter().enumerate() {
+            let key = field.tag_key();
+
+            if let Some(first) = fields[..i].iter().find(|other| other.tag_key() == key) {
+                return Err(syn::Error::new_spanned(
+                    field.field,
+                    format!(
+                        "`{name}.{first}` and `{name}.{second}` have the same tag, so `{name}` \
+                        cannot be decoded; give one of them a distinct tag with `#[rasn(tag(..))]`",
+                        first = first.field_name(),
+                        second = field.field_name(),
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
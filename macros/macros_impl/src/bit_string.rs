@@ -0,0 +1,152 @@
+use syn::spanned::Spanned;
+
+use crate::config::Config;
+
+/// A struct of `bool` fields marked `#[rasn(bit_string)]`, representing a
+/// `BIT STRING` with a named bit list where each field is one named bit.
+pub struct BitString<'a> {
+    pub name: &'a syn::Ident,
+    pub generics: &'a syn::Generics,
+    pub bits: Vec<(&'a syn::Ident, usize)>,
+    pub config: &'a Config,
+}
+
+impl<'a> BitString<'a> {
+    pub fn new(
+        name: &'a syn::Ident,
+        generics: &'a syn::Generics,
+        container: &'a syn::DataStruct,
+        config: &'a Config,
+    ) -> syn::Result<Self> {
+        let mut bits: Vec<(&syn::Ident, usize)> = Vec::new();
+
+        for (i, field) in container.fields.iter().enumerate() {
+            let mut bit = None;
+
+            for attr in &field.attrs {
+                if !attr.path().is_ident(crate::CRATE_NAME) {
+                    continue;
+                }
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("bit") {
+                        let value: syn::LitInt = meta.value()?.parse()?;
+                        bit = Some(value.base10_parse()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("only `#[rasn(bit = n)]` is available on named bits"))
+                    }
+                })?;
+            }
+
+            let ident = field.ident.as_ref().unwrap();
+            let bit = bit.unwrap_or(i);
+
+            if let Some((other, _)) = bits.iter().find(|(_, other)| *other == bit) {
+                return Err(syn::Error::new(
+                    field.span(),
+                    format!("`{ident}` and `{other}` are both bit {bit}"),
+                ));
+            }
+
+            bits.push((ident, bit));
+        }
+
+        Ok(Self {
+            name,
+            generics,
+            bits,
+            config,
+        })
+    }
+
+    pub fn impl_asntype(&self) -> proc_macro2::TokenStream {
+        let crate_root = &self.config.crate_root;
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let tag = self
+            .config
+            .tag
+            .as_ref()
+            .map_or(quote!(#crate_root::types::Tag::BIT_STRING), |tag| {
+                tag.to_tokens(crate_root)
+            });
+        let identifier = self
+            .config
+            .identifier
+            .clone()
+            .unwrap_or_else(|| syn::LitStr::new(&name.to_string(), name.span()));
+        let constraints_def = self.config.constraints.const_static_def(crate_root);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_root::AsnType for #name #ty_generics #where_clause {
+                const TAG: #crate_root::types::Tag = #tag;
+                const IDENTIFIER: #crate_root::types::Identifier = #crate_root::types::Identifier(Some(#identifier));
+                #constraints_def
+            }
+        }
+    }
+
+    pub fn impl_encode(&self) -> proc_macro2::TokenStream {
+        let crate_root = &self.config.crate_root;
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let len = self.bits.iter().map(|(_, bit)| bit + 1).max().unwrap_or(0);
+        let set_bits = self
+            .bits
+            .iter()
+            .map(|(ident, bit)| quote!(bits.set(#bit, self.#ident);));
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_root::Encode for #name #ty_generics #where_clause {
+                fn encode_with_tag_and_constraints<'encoder, E: #crate_root::Encoder<'encoder>>(
+                    &self,
+                    encoder: &mut E,
+                    tag: #crate_root::types::Tag,
+                    constraints: #crate_root::types::Constraints,
+                    identifier: #crate_root::types::Identifier,
+                ) -> core::result::Result<(), E::Error> {
+                    let mut bits = #crate_root::types::BitString::repeat(false, #len);
+                    #(#set_bits)*
+                    // X.680 22.7 allows trailing zero bits of a named bit list
+                    // to be removed, and X.690 11.2.2 requires it for DER.
+                    bits.truncate(bits.last_one().map_or(0, |last| last + 1));
+
+                    encoder
+                        .encode_bit_string(tag, constraints, &bits, identifier.or(Self::IDENTIFIER))
+                        .map(drop)
+                }
+            }
+        }
+    }
+
+    pub fn impl_decode(&self) -> proc_macro2::TokenStream {
+        let crate_root = &self.config.crate_root;
+        let name = self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let fields = self
+            .bits
+            .iter()
+            .map(|(ident, bit)| quote!(#ident: bits.get(#bit).is_some_and(|bit| *bit)));
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_root::Decode for #name #ty_generics #where_clause {
+                fn decode_with_tag_and_constraints<D: #crate_root::Decoder>(
+                    decoder: &mut D,
+                    tag: #crate_root::types::Tag,
+                    constraints: #crate_root::types::Constraints,
+                ) -> core::result::Result<Self, D::Error> {
+                    // Bits past the end of the value are `false`, and bits
+                    // without a name are ignored.
+                    let bits = decoder.decode_bit_string(tag, constraints)?;
+
+                    Ok(Self {
+                        #(#fields),*
+                    })
+                }
+            }
+        }
+    }
+}
@@ -234,6 +234,7 @@ pub struct Config {
     pub set: bool,
     pub automatic_tags: bool,
     pub delegate: bool,
+    pub bit_string: bool,
     pub tag: Option<Tag>,
     pub constraints: Constraints,
 }
@@ -251,6 +252,7 @@ impl Config {
         let mut size = None;
         let mut value = None;
         let mut delegate = false;
+        let mut bit_string = false;
         let mut extensible = false;
 
         for attr in &input.attrs {
@@ -265,11 +267,11 @@ impl Config {
 
                             let s: LitStr = value.parse()?;
                             let root: Path = s.parse()?;
-                            crate_root = Some(root);
+                            set_once(&meta, &mut crate_root, root)?;
                         }
                     } else if path.is_ident("identifier") {
                         let value = meta.value()?;
-                        identifier = Some(value.parse()?);
+                        set_once(&meta, &mut identifier, value.parse()?)?;
                     } else if path.is_ident("enumerated") {
                         enumerated = true;
                     } else if path.is_ident("choice") {
@@ -279,20 +281,38 @@ impl Config {
                     } else if path.is_ident("automatic_tags") {
                         automatic_tags = true;
                     } else if path.is_ident("tag") {
-                        tag = Some(Tag::from_meta(&meta)?);
+                        set_once(&meta, &mut tag, Tag::from_meta(&meta)?)?;
                     } else if path.is_ident("delegate") {
                         delegate = true;
+                    } else if path.is_ident("bit_string") {
+                        bit_string = true;
+                    } else if path.is_ident("extensible") {
+                        extensible = true;
                     } else if path.is_ident("from") {
-                        from = Some(StringValue::from_meta(&meta)?);
+                        set_once(&meta, &mut from, StringValue::from_meta(&meta)?)?;
                     } else if path.is_ident("size") {
-                        size = Some(Value::from_meta(&meta)?);
+                        set_once(&meta, &mut size, Value::from_meta(&meta)?)?;
                     } else if path.is_ident("value") {
-                        value = Some(Value::from_meta(&meta)?);
+                        set_once(&meta, &mut value, Value::from_meta(&meta)?)?;
                     } else {
-                        return Err(meta.error(format!(
-                            "unknown input provided: {}",
-                            path.to_token_stream()
-                        )));
+                        return Err(unknown_attribute(
+                            &meta,
+                            &[
+                                "automatic_tags",
+                                "bit_string",
+                                "choice",
+                                "crate_root",
+                                "delegate",
+                                "enumerated",
+                                "extensible",
+                                "from",
+                                "identifier",
+                                "set",
+                                "size",
+                                "tag",
+                                "value",
+                            ],
+                        ));
                     }
                     Ok(())
                 })?
@@ -318,6 +338,29 @@ impl Config {
             ));
         }
 
+        if bit_string {
+            let has_named_fields = matches!(
+                &input.data,
+                syn::Data::Struct(syn::DataStruct {
+                    fields: syn::Fields::Named(_),
+                    ..
+                })
+            );
+
+            if !has_named_fields || set || delegate {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "`#[rasn(bit_string)]` is only valid on structs with named `bool` fields.",
+                ));
+            } else if tag.as_ref().is_some_and(Tag::is_explicit) {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "`#[rasn(bit_string)]` types can't have an explicit tag, tag the field \
+                    containing them instead.",
+                ));
+            }
+        }
+
         let mut invalid_delegate = false;
 
         if is_enum && delegate {
@@ -360,6 +403,7 @@ impl Config {
 
         Ok(Self {
             automatic_tags,
+            bit_string,
             choice,
             delegate,
             enumerated,
@@ -409,8 +453,43 @@ impl Config {
                 (fields == &syn::Fields::Unit).then(|| quote!(#crate_root::types::Tag::NULL))
             })
             .or_else(|| self.set.then(|| quote!(#crate_root::types::Tag::SET)))
+            .or_else(|| {
+                self.bit_string
+                    .then(|| quote!(#crate_root::types::Tag::BIT_STRING))
+            })
             .unwrap_or(quote!(#crate_root::types::Tag::SEQUENCE))
     }
+
+    /// Whether the struct is a `SEQUENCE` whose components can be included
+    /// in another with `#[rasn(flatten)]`, which excludes those with
+    /// extension additions or a `remainder` field.
+    pub fn has_components(&self, field_configs: &[FieldConfig<'_>]) -> bool {
+        !self.delegate
+            && !self.set
+            && field_configs
+                .iter()
+                .all(|field| field.is_not_extension() && !field.remainder)
+    }
+}
+
+/// The number of fields in the root component list of a `SEQUENCE`, where a
+/// `flatten` field counts as each of the components it includes.
+pub fn root_field_count(field_configs: &[FieldConfig<'_>]) -> proc_macro2::TokenStream {
+    let count = field_configs
+        .iter()
+        .filter(|field| field.is_not_extension() && !field.remainder && !field.flatten)
+        .count();
+    let flattened = field_configs
+        .iter()
+        .filter(|field| field.flatten)
+        .map(FieldConfig::components)
+        .collect::<Vec<_>>();
+
+    if flattened.is_empty() {
+        quote!(#count)
+    } else {
+        quote!({ #count #(+ #flattened.len())* })
+    }
 }
 
 pub(crate) fn is_option_type(ty: &syn::Type) -> bool {
@@ -482,26 +561,49 @@ impl<'config> VariantConfig<'config> {
             attr.parse_nested_meta(|meta| {
                 let path = &meta.path;
                 if path.is_ident("tag") {
-                    tag = Some(Tag::from_meta(&meta)?);
+                    set_once(&meta, &mut tag, Tag::from_meta(&meta)?)?;
                 } else if path.is_ident("identifier") {
                     let value = meta.value()?;
-                    identifier = Some(value.parse()?);
+                    set_once(&meta, &mut identifier, value.parse()?)?;
                 } else if path.is_ident("size") {
-                    size = Some(Value::from_meta(&meta)?);
+                    set_once(&meta, &mut size, Value::from_meta(&meta)?)?;
                 } else if path.is_ident("value") {
-                    value = Some(Value::from_meta(&meta)?);
+                    set_once(&meta, &mut value, Value::from_meta(&meta)?)?;
                 } else if path.is_ident("from") {
-                    from = Some(StringValue::from_meta(&meta)?);
+                    set_once(&meta, &mut from, StringValue::from_meta(&meta)?)?;
                 } else if path.is_ident("extensible") {
                     extensible = true;
                 } else if path.is_ident("extension_addition") {
                     extension_addition = true;
+                } else if !path.is_ident("unknown") {
+                    // `unknown` is handled by `split_unknown_variant`.
+                    return Err(unknown_attribute(
+                        &meta,
+                        &[
+                            "extensible",
+                            "extension_addition",
+                            "from",
+                            "identifier",
+                            "size",
+                            "tag",
+                            "unknown",
+                            "value",
+                        ],
+                    ));
                 }
 
                 Ok(())
             })?;
         }
 
+        if container_config.enumerated && tag.is_some() {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "variants of `#[rasn(enumerated)]` enums can't be tagged, only `CHOICE` \
+                variants can",
+            ));
+        }
+
         let fields = &variant.fields;
 
         if matches!(fields, syn::Fields::Unnamed(_)) && fields.len() != 1 {
@@ -726,6 +828,21 @@ impl<'config> VariantConfig<'config> {
             })
         }
     }
+
+    /// A representation of the variant's tag that is equal for two variants
+    /// whenever their tags are known to be equal without evaluating their
+    /// types, e.g. when both have the same tag attribute or the same type.
+    pub fn tag_key(&self) -> syn::Result<String> {
+        let is_sequence = self.tag.is_none()
+            && !self.container_config.automatic_tags
+            && matches!(self.variant.fields, syn::Fields::Named(_));
+
+        if is_sequence {
+            Ok(String::from("SEQUENCE"))
+        } else {
+            Ok(self.tag_tree()?.to_string())
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -739,6 +856,11 @@ pub struct FieldConfig<'a> {
     pub extension_addition_group: bool,
     pub constraints: Constraints,
     pub context: usize,
+    pub decode_with: Option<syn::Path>,
+    pub encode_with: Option<syn::Path>,
+    pub defined_by: Option<syn::Ident>,
+    pub remainder: bool,
+    pub flatten: bool,
 }
 
 pub enum FieldType {
@@ -762,6 +884,11 @@ impl<'a> FieldConfig<'a> {
         let mut extensible = false;
         let mut extension_addition = false;
         let mut extension_addition_group = false;
+        let mut decode_with = None;
+        let mut encode_with = None;
+        let mut defined_by = None;
+        let mut remainder = false;
+        let mut flatten = false;
         /*if !field.attrs.is_empty() {
             panic!("{:?}", field)
         }*/
@@ -778,35 +905,63 @@ impl<'a> FieldConfig<'a> {
                             "You can't use the `#[rasn(tag)]` with `#[rasn(automatic_tags)]`",
                         ));
                     }
-                    tag = Some(Tag::from_meta(&meta)?);
+                    set_once(&meta, &mut tag, Tag::from_meta(&meta)?)?;
                 } else if path.is_ident("default") {
                     if meta.input.is_empty() || meta.input.peek(Token![,]) {
-                        default = Some(None);
+                        set_once(&meta, &mut default, None)?;
                     } else {
                         let value = meta.value()?;
                         let s: syn::LitStr = value.parse()?;
-                        default = Some(Some(s.parse()?));
+                        set_once(&meta, &mut default, Some(s.parse()?))?;
                     }
                 } else if path.is_ident("identifier") {
                     let value = meta.value()?;
-                    identifier = Some(value.parse()?);
+                    set_once(&meta, &mut identifier, value.parse()?)?;
                 } else if path.is_ident("size") {
-                    size = Some(Value::from_meta(&meta)?);
+                    set_once(&meta, &mut size, Value::from_meta(&meta)?)?;
                 } else if path.is_ident("value") {
-                    value = Some(Value::from_meta(&meta)?);
+                    set_once(&meta, &mut value, Value::from_meta(&meta)?)?;
                 } else if path.is_ident("from") {
-                    from = Some(StringValue::from_meta(&meta)?);
+                    set_once(&meta, &mut from, StringValue::from_meta(&meta)?)?;
                 } else if path.is_ident("extensible") {
                     extensible = true;
                 } else if path.is_ident("extension_addition") {
                     extension_addition = true;
                 } else if path.is_ident("extension_addition_group") {
                     extension_addition_group = true;
+                } else if path.is_ident("decode_with") {
+                    let s: syn::LitStr = meta.value()?.parse()?;
+                    set_once(&meta, &mut decode_with, s.parse()?)?;
+                } else if path.is_ident("encode_with") {
+                    let s: syn::LitStr = meta.value()?.parse()?;
+                    set_once(&meta, &mut encode_with, s.parse()?)?;
+                } else if path.is_ident("defined_by") {
+                    let s: syn::LitStr = meta.value()?.parse()?;
+                    set_once(&meta, &mut defined_by, s.parse()?)?;
+                } else if path.is_ident("remainder") {
+                    remainder = true;
+                } else if path.is_ident("flatten") {
+                    flatten = true;
                 } else {
-                    return Err(meta.error(format!(
-                        "unknown field tag {:?}",
-                        path.get_ident().map(ToString::to_string)
-                    )));
+                    return Err(unknown_attribute(
+                        &meta,
+                        &[
+                            "decode_with",
+                            "default",
+                            "defined_by",
+                            "encode_with",
+                            "extensible",
+                            "extension_addition",
+                            "extension_addition_group",
+                            "flatten",
+                            "from",
+                            "identifier",
+                            "remainder",
+                            "size",
+                            "tag",
+                            "value",
+                        ],
+                    ));
                 }
                 Ok(())
             })?;
@@ -816,6 +971,107 @@ impl<'a> FieldConfig<'a> {
             return Err(syn::Error::new(field.span(), "field cannot be both `extension_addition` and `extension_addition_group`, choose one"));
         }
 
+        if decode_with.is_some() || encode_with.is_some() {
+            if extension_addition || extension_addition_group {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`decode_with` and `encode_with` can't be used on extension additions",
+                ));
+            }
+            if container_config.set || container_config.choice || container_config.delegate {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`decode_with` and `encode_with` can only be used on `SEQUENCE` fields",
+                ));
+            }
+        }
+
+        if defined_by.is_some() {
+            if tag.is_some()
+                || default.is_some()
+                || extension_addition
+                || extension_addition_group
+                || decode_with.is_some()
+                || encode_with.is_some()
+                || remainder
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`defined_by` can't be combined with other field attributes",
+                ));
+            }
+            if container_config.set
+                || container_config.choice
+                || container_config.delegate
+                || container_config.automatic_tags
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`defined_by` can only be used on `SEQUENCE` fields without automatic tags",
+                ));
+            }
+        }
+
+        if remainder {
+            if tag.is_some()
+                || default.is_some()
+                || extension_addition
+                || extension_addition_group
+                || decode_with.is_some()
+                || encode_with.is_some()
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`remainder` can't be combined with other field attributes",
+                ));
+            }
+            if container_config.set || container_config.choice || container_config.delegate {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`remainder` can only be used on `SEQUENCE` fields",
+                ));
+            }
+        }
+
+        if flatten {
+            if tag.is_some()
+                || default.is_some()
+                || identifier.is_some()
+                || size.is_some()
+                || value.is_some()
+                || from.is_some()
+                || extensible
+                || extension_addition
+                || extension_addition_group
+                || decode_with.is_some()
+                || encode_with.is_some()
+                || defined_by.is_some()
+                || remainder
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`flatten` can't be combined with other field attributes",
+                ));
+            }
+            if container_config.set
+                || container_config.choice
+                || container_config.delegate
+                || container_config.automatic_tags
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`flatten` can only be used on `SEQUENCE` fields without automatic tags",
+                ));
+            }
+            if is_option_type(&field.ty) {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`flatten` fields can't be `OPTIONAL`, make the components of the \
+                    flattened type optional instead",
+                ));
+            }
+        }
+
         Ok(Self {
             container_config,
             default,
@@ -831,6 +1087,11 @@ impl<'a> FieldConfig<'a> {
                 value,
             },
             context,
+            decode_with,
+            encode_with,
+            defined_by,
+            remainder,
+            flatten,
         })
     }
 
@@ -893,7 +1154,20 @@ impl<'a> FieldConfig<'a> {
             }
         };
 
-        let encode = if self.tag.is_some() || self.container_config.automatic_tags {
+        let encode = if self.flatten {
+            quote!(#crate_root::types::EncodeComponents::encode_components(&#this #field, encoder)?;)
+        } else if self.remainder {
+            quote!(encoder.encode_remaining_components(&#this #field)?;)
+        } else if let Some(encode_with) = &self.encode_with {
+            quote!(#encode_with(&#this #field, encoder, #tag)?;)
+        } else if self.defined_by.is_some() {
+            let any = if self.is_option_type() {
+                quote!(#this #field.as_ref().map(#crate_root::types::DefinedBy::encode_as_any).transpose()?)
+            } else {
+                quote!(#crate_root::types::DefinedBy::encode_as_any(&#this #field)?)
+            };
+            quote!(#crate_root::Encode::encode(&#any, encoder)?;)
+        } else if self.tag.is_some() || self.container_config.automatic_tags {
             if self.tag.as_ref().is_some_and(|tag| tag.is_explicit()) {
                 if self.default.is_some() {
                     // Note: encoder must be aware if the field is optional and present, so we should not do the presence check on this level
@@ -1060,7 +1334,25 @@ impl<'a> FieldConfig<'a> {
             quote!()
         };
 
-        let decode = if self.extension_addition_group {
+        let decode = if self.flatten {
+            quote!(<#ty as #crate_root::types::DecodeComponents>::decode_components(decoder) #or_else)
+        } else if self.remainder {
+            quote!(decoder.decode_remaining_components() #or_else)
+        } else if let Some(decode_with) = &self.decode_with {
+            quote!(#decode_with(decoder, #tag) #or_else)
+        } else if let Some(defined_by) = &self.defined_by {
+            let oid = format_ident!("__rasn_field_{}", defined_by);
+            if self.is_option_type() {
+                quote!(<Option<#crate_root::types::Any> as #crate_root::Decode>::decode(decoder)?
+                    .map(|any| #crate_root::types::DefinedBy::decode_by_oid(&#oid, &any))
+                    .transpose() #or_else)
+            } else {
+                quote!(#crate_root::types::DefinedBy::decode_by_oid(
+                    &#oid,
+                    &<#crate_root::types::Any as #crate_root::Decode>::decode(decoder)?,
+                ) #or_else)
+            }
+        } else if self.extension_addition_group {
             quote!(decoder.decode_extension_addition_group() #or_else)
         } else {
             match (
@@ -1240,6 +1532,42 @@ impl<'a> FieldConfig<'a> {
         })
     }
 
+    /// Decodes a field that borrows from the input with `DecodeBorrowed`, for
+    /// structs whose fields borrow from their lifetime parameter.
+    pub fn decode_borrowed(&self, name: &syn::Ident) -> syn::Result<proc_macro2::TokenStream> {
+        let crate_root = &self.container_config.crate_root;
+        let unsupported = [
+            (self.default.is_some(), "default"),
+            (self.extension_addition, "extension_addition"),
+            (self.extension_addition_group, "extension_addition_group"),
+            (self.constraints.has_constraints(), "size, value and from"),
+            (self.decode_with.is_some(), "decode_with"),
+            (self.defined_by.is_some(), "defined_by"),
+            (self.remainder, "remainder"),
+            (self.flatten, "flatten"),
+        ];
+
+        if let Some((_, attribute)) = unsupported.iter().find(|(used, _)| *used) {
+            return Err(syn::Error::new_spanned(
+                self.field,
+                format!("`{attribute}` can't be used on fields that borrow from the input"),
+            ));
+        }
+
+        let ident = format!("{}.{}", name, self.field_name());
+        let or_else = quote!(.map_err(|error| #crate_root::de::Error::field_error(#ident, error.into(), decoder.codec()))?);
+        let tag = self.tag();
+        let is_explicit = self.tag.as_ref().is_some_and(|tag| tag.is_explicit());
+        let decode = match (is_explicit, self.is_option_type()) {
+            (true, true) => quote!(decoder.decode_optional_explicit_prefix_borrowed(#tag)),
+            (true, false) => quote!(decoder.decode_explicit_prefix_borrowed(#tag)),
+            (false, true) => quote!(decoder.decode_optional_borrowed_with_tag(#tag)),
+            (false, false) => quote!(decoder.decode_borrowed_with_tag(#tag)),
+        };
+
+        Ok(quote!(#decode #or_else))
+    }
+
     pub fn default_fn(&self) -> Option<proc_macro2::TokenStream> {
         let ty = &self.field.ty;
         self.default.as_ref().map(|default_fn| match default_fn {
@@ -1286,6 +1614,34 @@ impl<'a> FieldConfig<'a> {
         }
     }
 
+    /// A representation of the field's tag that is equal for two fields
+    /// whenever their tags are known to be equal without evaluating their
+    /// types, e.g. when both have the same tag attribute or the same type.
+    pub fn tag_key(&self) -> String {
+        if self.tag.is_some() || self.container_config.automatic_tags {
+            self.tag_tree().to_string()
+        } else {
+            let ty = map_to_inner_type(&self.field.ty).unwrap_or(&self.field.ty);
+            self.container_config.tag_tree_for_ty(ty).to_string()
+        }
+    }
+
+    /// The components a `flatten` field includes in the `SEQUENCE`.
+    pub fn components(&self) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let mut ty = self.field.ty.clone();
+        ty.strip_lifetimes();
+        quote!(<#ty as #crate_root::types::Components>::COMPONENTS)
+    }
+
+    /// The field's name, or its index for tuple structs.
+    pub fn field_name(&self) -> String {
+        self.field
+            .ident
+            .as_ref()
+            .map_or_else(|| self.context.to_string(), ToString::to_string)
+    }
+
     pub fn to_field_metadata(&self) -> proc_macro2::TokenStream {
         let context = self.context;
         let crate_root = &self.container_config.crate_root;
@@ -1551,13 +1907,49 @@ impl Value {
             skip_comma(&content);
         }
 
+        let Some(constraint) = constraint else {
+            return Err(item.error("expected a constraint before `extensible`, e.g. `1..=4`"));
+        };
+
         Ok(Constraint {
-            constraint: constraint.unwrap(),
+            constraint,
             extensible,
         })
     }
 }
 
+/// Stores the value of an attribute, rejecting attributes that are given more
+/// than once.
+fn set_once<T>(
+    meta: &syn::meta::ParseNestedMeta,
+    slot: &mut Option<T>,
+    value: T,
+) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error(format!(
+            "duplicate `{}` attribute",
+            meta.path.to_token_stream()
+        )));
+    }
+
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Creates the error for an attribute that isn't one of `expected`.
+fn unknown_attribute(meta: &syn::meta::ParseNestedMeta, expected: &[&str]) -> syn::Error {
+    let expected = expected
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    meta.error(format!(
+        "unknown `rasn` attribute `{}`, expected one of: {expected}",
+        meta.path.to_token_stream()
+    ))
+}
+
 fn skip_comma(content: &syn::parse::ParseBuffer) {
     if content.peek(Token![,]) {
         let _: Token![,] = content.parse().unwrap();
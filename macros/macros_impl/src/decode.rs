@@ -1,7 +1,8 @@
 use quote::ToTokens;
 use syn::Fields;
 
-use crate::config::{map_to_inner_type, Config, FieldConfig};
+use crate::config::{map_to_inner_type, root_field_count, Config, FieldConfig};
+use crate::ext::TypeExt;
 
 #[allow(clippy::too_many_lines)]
 pub fn derive_struct_impl(
@@ -11,6 +12,7 @@ pub fn derive_struct_impl(
     config: &Config,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let mut list = vec![];
+    let mut decode_components_impl = None;
     let crate_root = &config.crate_root;
     let crate_root_literal = crate_root.to_token_stream().to_string();
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -22,6 +24,17 @@ pub fn derive_struct_impl(
         .map(|(i, field)| FieldConfig::new(field, config, i))
         .collect::<Result<Vec<_>, _>>()?;
 
+    let lifetimes = generics
+        .lifetimes()
+        .map(|param| &param.lifetime.ident)
+        .collect::<Vec<_>>();
+    if field_configs
+        .iter()
+        .any(|config| config.field.ty.mentions_lifetime(&lifetimes))
+    {
+        return derive_borrowed_struct_impl(name, &generics, &container, config, &field_configs);
+    }
+
     let decode_impl = if config.delegate {
         let ty = &container.fields.iter().next().unwrap().ty;
         let field_count = field_configs.len();
@@ -193,32 +206,72 @@ pub fn derive_struct_impl(
         }
     } else {
         let mut all_fields_optional_or_default = true;
-        let mut count_root_fields: usize = 0;
+        let count_root_fields = root_field_count(&field_configs);
         let mut count_extended_fields: usize = 0;
+        let type_params: Vec<_> = generics
+            .params
+            .iter()
+            .filter_map(|param| {
+                if let syn::GenericParam::Type(type_param) = param {
+                    Some(type_param.ident.clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
 
         for field_config in &field_configs {
-            if !field_config.is_option_or_default_type() {
+            if !field_config.is_option_or_default_type() && !field_config.remainder {
                 all_fields_optional_or_default = false;
             }
             if field_config.extension_addition || field_config.extension_addition_group {
                 count_extended_fields += 1;
-            } else {
-                count_root_fields += 1;
             }
 
-            let type_params: Vec<_> = generics
-                .params
+            list.push(field_config.decode_field_def(name, &type_params)?);
+        }
+
+        // A field that is `defined_by` another needs that field's decoded
+        // value, so every field is decoded into a local before `Self` is built.
+        let defined_by = field_configs
+            .iter()
+            .filter_map(|config| config.defined_by.as_ref().map(|oid| (config, oid)))
+            .collect::<Vec<_>>();
+        let locals = if defined_by.is_empty() {
+            quote!()
+        } else {
+            for (config, oid) in defined_by {
+                let precedes = field_configs[..config.context]
+                    .iter()
+                    .any(|other| other.field.ident.as_ref() == Some(oid));
+                if !precedes {
+                    return Err(syn::Error::new_spanned(
+                        config.field,
+                        format!(
+                            "`defined_by` must name a field that precedes this one, found `{oid}`"
+                        ),
+                    ));
+                }
+            }
+
+            let (names, ops): (Vec<_>, Vec<_>) = field_configs
                 .iter()
-                .filter_map(|param| {
-                    if let syn::GenericParam::Type(type_param) = param {
-                        Some(type_param.ident.clone())
-                    } else {
-                        None
-                    }
+                .map(|config| {
+                    let ident = config.field.ident.as_ref().unwrap();
+                    let name = quote::format_ident!("__rasn_field_{}", ident);
+                    let op = config.decode(name, &type_params)?;
+                    Ok(((ident, name), op))
                 })
+                .collect::<syn::Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            list = names
+                .iter()
+                .map(|(ident, name)| quote!(#ident: #name))
                 .collect();
-            list.push(field_config.decode_field_def(name, &type_params)?);
-        }
+            let names = names.iter().map(|(_, name)| name);
+            quote!(#(let #names = #ops;)*)
+        };
 
         let fields = match container.fields {
             Fields::Named(_) => quote!({ #(#list),* }),
@@ -255,8 +308,21 @@ pub fn derive_struct_impl(
             quote!(None::<fn() -> Self>)
         };
 
+        if config.has_components(&field_configs) {
+            decode_components_impl = Some(quote! {
+                #[automatically_derived]
+                impl #impl_generics #crate_root::types::DecodeComponents for #name #ty_generics #where_clause {
+                    fn decode_components<D: #crate_root::Decoder>(decoder: &mut D) -> core::result::Result<Self, D::Error> {
+                        #locals
+                        Ok(Self #fields)
+                    }
+                }
+            });
+        }
+
         quote! {
             decoder.decode_sequence::<#count_root_fields, #count_extended_fields, _, _, _>(tag, #initializer_fn, |decoder| {
+                #locals
                 Ok(Self #fields)
             })
         }
@@ -285,6 +351,87 @@ pub fn derive_struct_impl(
                 #decode_impl
             }
         }
+
+        #decode_components_impl
+    })
+}
+
+/// Implements `DecodeBorrowed` for a `SEQUENCE` with fields that borrow
+/// from the struct's lifetime parameter, such as `&'a str`. Borrowed fields
+/// are decoded with `DecodeBorrowed`, and the remaining fields as usual.
+fn derive_borrowed_struct_impl(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    container: &syn::DataStruct,
+    config: &Config,
+    field_configs: &[FieldConfig<'_>],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut lifetimes = generics.lifetimes();
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(param), None) => &param.lifetime,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                generics,
+                "structs with borrowed fields must have exactly one lifetime parameter",
+            ))
+        }
+    };
+
+    if config.delegate || config.set || config.has_explicit_tag() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "structs with borrowed fields can't be a `delegate`, a `set` or explicitly tagged",
+        ));
+    }
+
+    let lifetimes = [&lifetime.ident];
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let fields = field_configs
+        .iter()
+        .map(|field| {
+            let lhs = field.field.ident.as_ref().map(|i| quote!(#i :));
+            let decode_op = if field.field.ty.mentions_lifetime(&lifetimes) {
+                field.decode_borrowed(name)?
+            } else if let Some(defined_by) = &field.defined_by {
+                return Err(syn::Error::new_spanned(
+                    defined_by,
+                    "`defined_by` can't be used in structs with borrowed fields",
+                ));
+            } else {
+                field.decode(name, &type_params)?
+            };
+            Ok(quote!(#lhs #decode_op))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let fields = match container.fields {
+        Fields::Named(_) => quote!({ #(#fields),* }),
+        Fields::Unnamed(_) => quote!(( #(#fields),* )),
+        Fields::Unit => quote!(),
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #crate_root::ber::de::DecodeBorrowed<#lifetime> for #name #ty_generics #where_clause {
+            fn decode_borrowed(decoder: &mut #crate_root::ber::de::Decoder<#lifetime>) -> core::result::Result<Self, #crate_root::error::DecodeError> {
+                Self::decode_borrowed_with_tag(decoder, <Self as #crate_root::AsnType>::TAG)
+            }
+
+            fn decode_borrowed_with_tag(decoder: &mut #crate_root::ber::de::Decoder<#lifetime>, tag: #crate_root::types::Tag) -> core::result::Result<Self, #crate_root::error::DecodeError> {
+                #[allow(unused_imports)]
+                use #crate_root::Decoder as _;
+
+                decoder.decode_constructed_borrowed(tag, |decoder| {
+                    Ok(Self #fields)
+                })
+            }
+        }
     })
 }
 
@@ -1,6 +1,6 @@
 use syn::LitStr;
 
-use crate::config::{Config, FieldConfig};
+use crate::config::{root_field_count, Config, FieldConfig};
 
 pub fn derive_struct_impl(
     name: &syn::Ident,
@@ -10,8 +10,8 @@ pub fn derive_struct_impl(
 ) -> syn::Result<proc_macro2::TokenStream> {
     let crate_root = &config.crate_root;
 
+    let mut field_configs = Vec::with_capacity(container.fields.len());
     let mut field_encodings = Vec::with_capacity(container.fields.len());
-    let mut number_root_fields: usize = 0;
     let mut number_extended_fields: usize = 0;
     let type_params: Vec<_> = generics
         .params
@@ -32,14 +32,14 @@ pub fn derive_struct_impl(
 
         if field_config.is_extension() {
             number_extended_fields += 1;
-        } else {
-            number_root_fields += 1;
         }
 
+        field_configs.push(field_config);
         field_encodings.push(quote! {
             #field_encoding
         });
     }
+    let number_root_fields = root_field_count(&field_configs);
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -104,7 +104,21 @@ pub fn derive_struct_impl(
         }
     };
 
-    let vars = fields_as_vars(&container.fields);
+    let vars = fields_as_vars(&container.fields).collect::<Vec<_>>();
+    let encode_components_impl = config.has_components(&field_configs).then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_root::types::EncodeComponents for #name #ty_generics #where_clause {
+                fn encode_components<'encoder, EN: #crate_root::Encoder<'encoder>>(&self, encoder: &mut EN) -> core::result::Result<(), EN::Error> {
+                    #(#vars)*
+
+                    #(#field_encodings)*
+                    Ok(())
+                }
+            }
+        }
+    });
+
     Ok(quote! {
         #[allow(clippy::mutable_key_type)]
         impl #impl_generics  #crate_root::Encode for #name #ty_generics #where_clause {
@@ -114,6 +128,8 @@ pub fn derive_struct_impl(
                 #encode_impl
             }
         }
+
+        #encode_components_impl
     })
 }
 
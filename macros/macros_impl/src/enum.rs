@@ -9,10 +9,101 @@ pub struct Enum<'a> {
     pub name: &'a syn::Ident,
     pub generics: &'a syn::Generics,
     pub variants: &'a syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+    /// The variant marked `#[rasn(unknown)]`, which isn't part of `variants`.
+    pub unknown: Option<&'a syn::Variant>,
     pub config: &'a Config,
 }
 
+type Variants = syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>;
+
+fn is_unknown_variant(variant: &syn::Variant) -> bool {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(crate::CRATE_NAME))
+        .any(|attr| {
+            attr.meta.require_list().is_ok_and(|list| {
+                list.tokens.clone().into_iter().any(
+                    |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "unknown"),
+                )
+            })
+        })
+}
+
+/// Separates the variant marked `#[rasn(unknown)]` from the rest. It captures
+/// the alternatives of a `CHOICE` that are unknown to this definition as
+/// `Any`, so it takes no part in the generated tag handling.
+pub fn split_unknown_variant(
+    variants: &Variants,
+    config: &Config,
+) -> syn::Result<(Variants, Option<syn::Variant>)> {
+    let (unknown, known): (Vec<_>, Vec<_>) = variants.iter().cloned().partition(is_unknown_variant);
+
+    let mut unknown = unknown.into_iter();
+    let Some(variant) = unknown.next() else {
+        return Ok((variants.clone(), None));
+    };
+
+    if let Some(second) = unknown.next() {
+        return Err(syn::Error::new_spanned(
+            second.ident,
+            "only one variant can be marked `#[rasn(unknown)]`",
+        ));
+    }
+
+    if !config.choice {
+        return Err(syn::Error::new_spanned(
+            variant.ident,
+            "`#[rasn(unknown)]` is only available in choices",
+        ));
+    }
+
+    if config.has_explicit_tag() {
+        return Err(syn::Error::new_spanned(
+            variant.ident,
+            "`#[rasn(unknown)]` can't be used in explicitly tagged choices",
+        ));
+    }
+
+    if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1) {
+        return Err(syn::Error::new_spanned(
+            variant.ident,
+            "`#[rasn(unknown)]` variants must hold a single `Any`, e.g. `Unknown(Any)`",
+        ));
+    }
+
+    Ok((known.into_iter().collect(), Some(variant)))
+}
+
 impl Enum<'_> {
+    /// Rejects `CHOICE`s where two variants have the same tag, as the
+    /// variant to decode couldn't be determined from the tag. Only tags that
+    /// are visible to the macro are checked here, the remaining cases are
+    /// caught by the uniqueness assertion on the `TAG_TREE`.
+    fn check_distinct_variant_tags(&self, variant_configs: &[VariantConfig]) -> syn::Result<()> {
+        let mut seen: Vec<(String, &syn::Ident)> = Vec::new();
+
+        for config in variant_configs {
+            let key = config.tag_key()?;
+            let ident = &config.variant.ident;
+
+            if let Some((_, first)) = seen.iter().find(|(seen_key, _)| *seen_key == key) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!(
+                        "`{name}::{first}` and `{name}::{ident}` have the same tag, so `{name}` \
+                        cannot be decoded; give one of them a distinct tag with `#[rasn(tag(..))]`",
+                        name = self.name,
+                    ),
+                ));
+            }
+
+            seen.push((key, ident));
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_lines)]
     pub fn impl_asntype(&self) -> syn::Result<proc_macro2::TokenStream> {
         let crate_root = &self.config.crate_root;
@@ -40,6 +131,10 @@ impl Enum<'_> {
             .map(|(i, v)| VariantConfig::new(v, self.generics, self.config, i))
             .collect::<Result<Vec<_>, _>>()?;
 
+        if self.config.choice {
+            self.check_distinct_variant_tags(&variant_configs)?;
+        }
+
         let field_tags = if self.config.choice {
             variant_configs
                 .iter()
@@ -308,10 +403,24 @@ impl Enum<'_> {
                 .collect::<Result<Result<Vec<_>, _>, _>>()??;
 
             let str_name = syn::LitStr::new(&self.name.to_string(), proc_macro2::Span::call_site());
+            let fallback = if let Some(unknown) = self.unknown {
+                let ident = &unknown.ident;
+                // Only codecs that identify alternatives by their tag can
+                // delimit an unknown one.
+                quote! {
+                    if matches!(decoder.codec(), #crate_root::Codec::Ber | #crate_root::Codec::Cer | #crate_root::Codec::Der) {
+                        <#crate_root::types::Any as #crate_root::Decode>::decode(decoder).map(Self::#ident)
+                    } else {
+                        Err(#crate_root::de::Error::no_valid_choice(#str_name, decoder.codec()))
+                    }
+                }
+            } else {
+                quote!(Err(#crate_root::de::Error::no_valid_choice(#str_name, decoder.codec())))
+            };
             let from_tag = quote! {
                 #(#decode_ops)*
 
-                Err(#crate_root::de::Error::no_valid_choice(#str_name, decoder.codec()))
+                #fallback
             };
             Some(quote! {
                 #[automatically_derived]
@@ -472,14 +581,28 @@ impl Enum<'_> {
             }
         });
 
+        let (unknown_tag, unknown_variant) = self
+            .unknown
+            .map(|unknown| {
+                let name = &self.name;
+                let ident = &unknown.ident;
+                let tag = quote!(value.tag().unwrap_or(#crate_root::types::Tag::EOC));
+                (
+                    quote!(#name::#ident(value) => #tag,),
+                    quote!(#name::#ident(value) => #crate_root::Encode::encode(value, encoder).map(|_| #tag),),
+                )
+            })
+            .unzip();
         let tag_match = &quote! {
                 match self {
-                    #(#tags),*
+                    #(#tags,)*
+                    #unknown_tag
                 }
         };
         let encoder_closure_match = &quote! {
                 |encoder| match self {
-                    #(#variants),*
+                    #(#variants,)*
+                    #unknown_variant
                 }
         };
         let encode_variants = quote! {
@@ -1,5 +1,6 @@
 pub trait TypeExt {
     fn strip_lifetimes(&mut self);
+    fn mentions_lifetime(&self, lifetimes: &[&syn::Ident]) -> bool;
 }
 
 impl TypeExt for syn::Type {
@@ -8,6 +9,34 @@ impl TypeExt for syn::Type {
             reference.lifetime = None;
         }
     }
+
+    /// Whether any of `lifetimes` appears anywhere in the type, e.g. `'a` in
+    /// `Option<&'a str>`.
+    fn mentions_lifetime(&self, lifetimes: &[&syn::Ident]) -> bool {
+        fn mentions(tokens: proc_macro2::TokenStream, lifetimes: &[&syn::Ident]) -> bool {
+            let mut tokens = tokens.into_iter().peekable();
+            while let Some(token) = tokens.next() {
+                match token {
+                    proc_macro2::TokenTree::Group(group) => {
+                        if mentions(group.stream(), lifetimes) {
+                            return true;
+                        }
+                    }
+                    proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                        if let Some(proc_macro2::TokenTree::Ident(ident)) = tokens.peek() {
+                            if lifetimes.contains(&ident) {
+                                return true;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            false
+        }
+
+        mentions(quote::ToTokens::to_token_stream(self), lifetimes)
+    }
 }
 
 pub trait GenericsExt {
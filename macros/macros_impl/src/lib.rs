@@ -2,6 +2,7 @@
 extern crate quote;
 
 mod asn_type;
+mod bit_string;
 mod config;
 mod decode;
 mod encode;
@@ -38,14 +39,21 @@ pub fn decode_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Token
                 }
             }
         }),
+        syn::Data::Struct(v) if config.bit_string => {
+            Ok(bit_string::BitString::new(name, &generics, &v, &config)?.impl_decode())
+        }
         syn::Data::Struct(v) => decode::derive_struct_impl(name, generics, v, &config),
-        syn::Data::Enum(syn::DataEnum { variants, .. }) => r#enum::Enum {
-            name,
-            generics: &generics,
-            variants: &variants,
-            config: &config,
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let (variants, unknown) = r#enum::split_unknown_variant(&variants, &config)?;
+            r#enum::Enum {
+                name,
+                generics: &generics,
+                variants: &variants,
+                unknown: unknown.as_ref(),
+                config: &config,
+            }
+            .impl_decode()
         }
-        .impl_decode(),
         _ => Err(syn::Error::new(
             name.span(),
             "Union types are not supported.",
@@ -78,14 +86,21 @@ pub fn encode_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Token
                 }
             }
         },
+        syn::Data::Struct(v) if config.bit_string => {
+            bit_string::BitString::new(name, &generics, &v, &config)?.impl_encode()
+        }
         syn::Data::Struct(v) => encode::derive_struct_impl(name, generics, v, &config)?,
-        syn::Data::Enum(syn::DataEnum { variants, .. }) => r#enum::Enum {
-            name,
-            generics: &generics,
-            variants: &variants,
-            config: &config,
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let (variants, unknown) = r#enum::split_unknown_variant(&variants, &config)?;
+            r#enum::Enum {
+                name,
+                generics: &generics,
+                variants: &variants,
+                unknown: unknown.as_ref(),
+                config: &config,
+            }
+            .impl_encode()?
         }
-        .impl_encode()?,
         _ => {
             return Err(syn::Error::new(
                 name.span(),
@@ -109,14 +124,21 @@ pub fn asn_type_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Tok
     }
 
     Ok(match input.data {
+        syn::Data::Struct(v) if config.bit_string => {
+            bit_string::BitString::new(name, &generics, &v, &config)?.impl_asntype()
+        }
         syn::Data::Struct(v) => asn_type::derive_struct_impl(name, generics, v, &config)?,
-        syn::Data::Enum(syn::DataEnum { variants, .. }) => r#enum::Enum {
-            name,
-            generics: &generics,
-            variants: &variants,
-            config: &config,
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let (variants, unknown) = r#enum::split_unknown_variant(&variants, &config)?;
+            r#enum::Enum {
+                name,
+                generics: &generics,
+                variants: &variants,
+                unknown: unknown.as_ref(),
+                config: &config,
+            }
+            .impl_asntype()?
         }
-        .impl_asntype()?,
         _ => {
             return Err(syn::Error::new(
                 name.span(),
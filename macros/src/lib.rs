@@ -11,6 +11,11 @@ fn __print_stream(stream: proc_macro2::TokenStream) -> proc_macro::TokenStream {
 /// Will automatically generate a decode implementation using the your
 /// container's definition. See [`AsnType`](`asn_type_derive`) for information
 /// on available attributes.
+///
+/// Structs with a lifetime parameter can have fields that borrow from the
+/// input, such as `&'a str` or `OctetSlice<'a>`. These implement
+/// `ber::de::DecodeBorrowed` instead of `Decode`, and are decoded with
+/// `ber::decode_borrowed` or `der::decode_borrowed`.
 #[proc_macro_derive(Decode, attributes(rasn))]
 pub fn decode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -52,6 +57,41 @@ pub fn encode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// - `enumerated/choice` Use either `#[rasn(choice)]` or `#[rasn(enumerated)]`
 /// - `delegate` Only available for newtype wrappers (e.g. `struct Delegate(T)`);
 ///   uses the inner `T` type for implementing the trait. Tuple-struct can have more than one field if other fields are `PhantomData` types.
+/// - `extensible` Marks the type as extensible, the same as `#[non_exhaustive]`.
+/// - `bit_string` Only available on structs of named `bool` fields; encodes
+///   the struct as a `BIT STRING` with a named bit list, where each field is
+///   the bit numbered by its position or its `bit` attribute. Trailing zero
+///   bits are never transmitted, and missing or unnamed bits decode as unset.
+///
+/// ##### Variant Attributes
+/// - `unknown` Only available in choices; marks a `Variant(Any)`
+///   that captures alternatives with tags no other variant matches, so they
+///   can be decoded and re-encoded unchanged. Only codecs that identify
+///   alternatives by tag, i.e. BER, CER and DER, use it.
+///
+/// ##### Field Attributes
+/// - `decode_with = "path"` / `encode_with = "path"` Only available on
+///   `SEQUENCE` fields; replaces the field's generated decoding or encoding
+///   with a call to `fn<D: Decoder>(&mut D, Tag) -> Result<T, D::Error>` or
+///   `fn<'e, E: Encoder<'e>>(&T, &mut E, Tag) -> Result<(), E::Error>`
+///   respectively, which receive the field's effective tag.
+/// - `bit = n` Only available in `bit_string` structs; sets the field's bit
+///   number.
+/// - `remainder` Only available on the last field of a `SEQUENCE`, which must
+///   be a `SequenceOf<Any>`; collects every component after the known fields
+///   on decode and re-emits them unchanged on encode. Only codecs that
+///   delimit components by tag and length, i.e. BER, CER and DER, fill it;
+///   the others decode it as empty and skip it when encoding.
+/// - `defined_by = "field"` Only available on `SEQUENCE` fields; the field's
+///   type (or the `T` of an `Option<T>`) must implement `DefinedBy`, e.g.
+///   through `rasn::defined_by!`. The field is decoded as an `ANY`, which is
+///   then interpreted using the value of the named `OBJECT IDENTIFIER` field,
+///   which must precede it.
+/// - `flatten` Only available on `SEQUENCE` fields whose type is itself a
+///   derived `SEQUENCE` without extension additions; includes that type's
+///   components in place of the field, like `COMPONENTS OF`, rather than
+///   nesting it. Its components must have tags that are distinct from the
+///   neighbouring fields' wherever either is `OPTIONAL` or `DEFAULT`.
 #[proc_macro_derive(AsnType, attributes(rasn))]
 pub fn asn_type_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -1,18 +1,37 @@
 //! # Basic Encoding Rules
 
 pub mod de;
+mod dump;
 pub mod enc;
 mod identifier;
 mod rules;
+mod tlv;
 
+pub use de::DecoderOptions;
+pub use dump::dump;
 pub use identifier::Identifier;
 pub(crate) use rules::EncodingRules;
+pub use tlv::{
+    iter, peek_header, total_length, validate, validate_with_stats, ContentLength, Header, Iter,
+    Stats, Tlv,
+};
 
 /// Attempts to decode `T` from `input` using BER.
 /// # Errors
-/// Returns error specific to BER decoder if decoding is not possible.
+/// Returns error specific to BER decoder if decoding is not possible, or if
+/// `input` contains data after the value. Use [`decode_with_remainder`] to
+/// decode a value from the front of a buffer.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut de::Decoder::new(input, de::DecoderOptions::ber()))
+    let (value, remainder) = decode_with_remainder(input)?;
+
+    if remainder.is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            remainder.len(),
+            crate::Codec::Ber,
+        ))
+    }
 }
 
 /// Attempts to decode `T` from `input` using BER. Returns both `T` and reference to the remainder of the input.
@@ -23,10 +42,208 @@ pub fn decode_with_remainder<T: crate::Decode>(
     input: &[u8],
 ) -> Result<(T, &[u8]), crate::error::DecodeError> {
     let decoder = &mut de::Decoder::new(input, de::DecoderOptions::ber());
-    let decoded_instance = T::decode(decoder)?;
+    let decoded_instance = T::decode(decoder).map_err(|error| decoder.locate_error(error))?;
     Ok((decoded_instance, decoder.remaining()))
 }
 
+/// Decodes consecutive BER encoded values of `T` from `input`, such as a file
+/// of concatenated certificates.
+///
+/// Iteration ends at the end of `input`, or after the first error, whose
+/// offset refers to `input`.
+/// ```
+/// let data = &[0x02, 0x01, 0x05, 0x02, 0x01, 0x07];
+/// let values = rasn::ber::decode_all::<u8>(data).collect::<Result<Vec<_>, _>>();
+///
+/// assert_eq!(vec![5, 7], values.unwrap());
+/// ```
+pub fn decode_all<T: crate::Decode>(
+    input: &[u8],
+) -> impl Iterator<Item = Result<T, crate::error::DecodeError>> + '_ {
+    decode_all_with_options(de::DecoderOptions::ber(), input)
+}
+
+pub(crate) fn decode_all_with_options<T: crate::Decode>(
+    options: DecoderOptions,
+    input: &[u8],
+) -> impl Iterator<Item = Result<T, crate::error::DecodeError>> + '_ {
+    let mut decoder = Some(de::Decoder::new(input, options));
+
+    core::iter::from_fn(move || {
+        let inner = decoder.as_mut()?;
+        if inner.remaining().is_empty() {
+            return None;
+        }
+
+        let result = T::decode(inner).map_err(|error| inner.locate_error(error));
+        if result.is_err() {
+            decoder = None;
+        }
+        Some(result)
+    })
+}
+
+/// Attempts to decode `T` from `input` with `options`, e.g. to decode BER
+/// with lower resource limits than the defaults, failing if any input remains
+/// after the value. The options apply to every value nested inside `T`.
+/// ```
+/// use rasn::ber::DecoderOptions;
+///
+/// // A `SEQUENCE OF SEQUENCE OF BOOLEAN` nested two levels deep.
+/// let data = &[0x30, 0x05, 0x30, 0x03, 0x01, 0x01, 0xFF];
+/// let options = DecoderOptions::ber().with_max_depth(1);
+///
+/// assert!(rasn::ber::decode::<Vec<Vec<bool>>>(data).is_ok());
+/// assert!(rasn::ber::decode_with_options::<Vec<Vec<bool>>>(options, data).is_err());
+/// ```
+/// # Errors
+/// Returns `DecodeError` if `input` is not a valid encoding of `T` under
+/// `options`, or if `input` contains data after the value.
+pub fn decode_with_options<T: crate::Decode>(
+    options: DecoderOptions,
+    input: &[u8],
+) -> Result<T, crate::error::DecodeError> {
+    let decoder = &mut de::Decoder::new(input, options);
+    let value = T::decode(decoder).map_err(|error| decoder.locate_error(error))?;
+
+    if decoder.remaining().is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            decoder.remaining().len(),
+            options.current_codec(),
+        ))
+    }
+}
+
+/// Attempts to decode `T` from shared `input` using BER, failing if any input
+/// remains after the value. Primitive `OCTET STRING`s decoded as
+/// [`OctetString`][crate::types::OctetString] are views of `input` instead of
+/// copies, constructed ones are still joined into a new buffer.
+/// ```
+/// use rasn::types::OctetString;
+///
+/// let input = bytes::Bytes::from_static(&[0x04, 0x03, 0x01, 0x02, 0x03]);
+/// let value = rasn::ber::decode_bytes::<OctetString>(input.clone()).unwrap();
+///
+/// assert_eq!(&[1, 2, 3], &*value);
+/// assert_eq!(input[2..].as_ptr(), value.as_ptr());
+/// ```
+/// # Errors
+/// Returns error specific to BER decoder if decoding is not possible, or if
+/// `input` contains data after the value.
+#[cfg(not(feature = "arc-slice"))]
+pub fn decode_bytes<T: crate::Decode>(input: bytes::Bytes) -> Result<T, crate::error::DecodeError> {
+    let decoder = &mut de::Decoder::from_bytes(&input, de::DecoderOptions::ber());
+    let value = T::decode(decoder).map_err(|error| decoder.locate_error(error))?;
+
+    if decoder.remaining().is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            decoder.remaining().len(),
+            crate::Codec::Ber,
+        ))
+    }
+}
+
+/// Decodes a BER encoded `BIT STRING` from `input` into the start of
+/// `buffer`, returning its length in bits, without allocating when the value
+/// is in primitive form. Useful when the maximum size is known up front.
+/// ```
+/// use bitvec::prelude::*;
+///
+/// let mut buffer = BitArray::<[u8; 4], Msb0>::ZERO;
+/// let length = rasn::ber::decode_bit_string_into(&[0x03, 0x02, 0x04, 0xB0], &mut buffer).unwrap();
+///
+/// assert_eq!(bits![u8, Msb0; 1, 0, 1, 1], &buffer[..length]);
+/// assert!(rasn::ber::decode_bit_string_into(&[0x03, 0x02, 0x04, 0xB0], &mut buffer[..3]).is_err());
+/// ```
+/// # Errors
+/// Returns `DecodeError` if `input` isn't a valid `BIT STRING`, is longer than
+/// `buffer`, or contains data after the value.
+pub fn decode_bit_string_into(
+    input: &[u8],
+    buffer: &mut crate::types::BitStr,
+) -> Result<usize, crate::error::DecodeError> {
+    let decoder = &mut de::Decoder::new(input, de::DecoderOptions::ber());
+    let length = decoder
+        .decode_bit_string_into(crate::types::Tag::BIT_STRING, buffer)
+        .map_err(|error| decoder.locate_error(error))?;
+
+    if decoder.remaining().is_empty() {
+        Ok(length)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            decoder.remaining().len(),
+            crate::Codec::Ber,
+        ))
+    }
+}
+
+/// Attempts to decode `T` from `input` using BER, borrowing string and
+/// octet string contents from `input` instead of copying them.
+/// # Errors
+/// Returns error specific to BER decoder if decoding is not possible.
+pub fn decode_borrowed<'input, T: de::DecodeBorrowed<'input>>(
+    input: &'input [u8],
+) -> Result<T, crate::error::DecodeError> {
+    let decoder = &mut de::Decoder::new(input, de::DecoderOptions::ber());
+    let value = decoder
+        .decode_borrowed()
+        .map_err(|error| decoder.locate_error(error))?;
+
+    if decoder.remaining().is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            decoder.remaining().len(),
+            crate::Codec::Ber,
+        ))
+    }
+}
+
+/// Attempts to decode `T` from a single BER encoded value read from `reader`.
+///
+/// Only the bytes belonging to the value are read, so multiple values can be
+/// decoded one after another from the same stream.
+/// # Errors
+/// Returns `DecodeError` if reading from `reader` fails, or the value read is
+/// not a valid BER encoding of `T`.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode, R: std::io::Read>(
+    reader: &mut R,
+) -> Result<T, crate::error::DecodeError> {
+    let mut buffer = alloc::vec::Vec::new();
+    de::reader::read_value(reader, de::DecoderOptions::ber(), &mut buffer)?;
+    decode(&buffer)
+}
+
+/// Attempts to decode `T` from a single BER encoded value read
+/// asynchronously from `reader`, such as a socket.
+///
+/// The identifier and length octets are read first, followed by exactly the
+/// contents of the value, including any nested values of an indefinite
+/// length. The complete value is then decoded in memory, so multiple values
+/// can be decoded one after another from the same stream.
+///
+/// # Cancel safety
+/// This function is not cancel safe. If the future is dropped before it
+/// completes, the octets read so far are lost and the stream is left part way
+/// through a value.
+/// # Errors
+/// Returns `DecodeError` with [`DecodeErrorKind::Io`][crate::error::DecodeErrorKind::Io]
+/// if reading from `reader` fails, or another kind if the value read is not a
+/// valid BER encoding of `T`.
+#[cfg(feature = "tokio")]
+pub async fn decode_async<T: crate::Decode, R: tokio::io::AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<T, crate::error::DecodeError> {
+    let mut buffer = alloc::vec::Vec::new();
+    de::async_reader::read_value(reader, de::DecoderOptions::ber(), &mut buffer).await?;
+    decode(&buffer)
+}
+
 /// Attempts to encode `value` to BER.
 /// # Errors
 /// Returns error specific to BER encoder if encoding is not possible.
@@ -40,6 +257,105 @@ pub fn encode<T: crate::Encode>(
     Ok(enc.output())
 }
 
+/// Attempts to encode `value` to BER, appending the encoding to `buffer`.
+///
+/// Reusing the same `buffer` across calls avoids allocating a new output
+/// buffer for every value. `buffer` is left unchanged if encoding fails.
+/// # Errors
+/// Returns error specific to BER encoder if encoding is not possible.
+pub fn encode_buf<T: crate::Encode>(
+    value: &T,
+    buffer: &mut alloc::vec::Vec<u8>,
+) -> Result<(), crate::error::EncodeError> {
+    let start = buffer.len();
+    let mut enc = enc::Encoder::from_buffer(enc::EncoderOptions::ber(), core::mem::take(buffer));
+
+    let result = value.encode(&mut enc);
+    *buffer = enc.output();
+
+    if result.is_err() {
+        buffer.truncate(start);
+    }
+
+    result
+}
+
+/// Attempts to encode `value` to BER, writing the encoding to the start of
+/// `buffer` and returning the number of octets written.
+///
+/// Only definite lengths are used, so [`Encode::encoded_len`] gives the
+/// size `buffer` needs to be. The value is encoded twice: first to count its
+/// length and the lengths of the constructed values in it, and then straight
+/// into `buffer` without an intermediate buffer. `BOOLEAN`, primitive integer,
+/// `NULL` and `OCTET STRING` values with tag numbers below 31 are encoded
+/// without allocating, and constructed values only need their lengths kept.
+/// ```
+/// let mut buffer = [0; 8];
+/// let length = rasn::ber::encode_into(&true, &mut buffer).unwrap();
+/// assert_eq!([0x01, 0x01, 0xFF], buffer[..length]);
+/// ```
+/// # Errors
+/// Returns error specific to BER encoder if encoding is not possible, or
+/// [`EncodeErrorKind::BufferTooSmall`] with the number of octets needed if
+/// the encoding doesn't fit in `buffer`, which is then left unchanged.
+///
+/// [`Encode::encoded_len`]: crate::Encode::encoded_len
+/// [`EncodeErrorKind::BufferTooSmall`]: crate::error::EncodeErrorKind::BufferTooSmall
+pub fn encode_into<T: crate::Encode>(
+    value: &T,
+    buffer: &mut [u8],
+) -> Result<usize, crate::error::EncodeError> {
+    let options = enc::EncoderOptions::ber();
+    let counted = enc::count(options, value)?;
+    let needed = counted.0;
+
+    if needed > buffer.len() {
+        return Err(crate::error::EncodeError::from_kind(
+            crate::error::EncodeErrorKind::BufferTooSmall { needed },
+            crate::Codec::Ber,
+        ));
+    }
+
+    let sink = enc::SliceSink {
+        buffer,
+        position: 0,
+    };
+    enc::write(options, value, sink, counted)?;
+
+    Ok(needed)
+}
+
+/// Attempts to encode `value` to BER, writing the encoding to `writer`.
+///
+/// The value is encoded twice: first to count the lengths of the constructed
+/// values in it, and then straight to `writer` without buffering the
+/// encoding. Each identifier, length and contents is written separately, so
+/// `writer` should be buffered, e.g. with [`std::io::BufWriter`].
+/// # Errors
+/// Returns error specific to BER encoder if encoding is not possible, or if
+/// writing to `writer` fails, in which case part of the encoding may have
+/// been written.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: &mut W,
+) -> Result<(), crate::error::EncodeError> {
+    let options = enc::EncoderOptions::ber();
+    let counted = enc::count(options, value)?;
+    let sink = enc::IoSink {
+        writer,
+        error: None,
+    };
+
+    match enc::write(options, value, sink, counted)?.error {
+        Some(error) => Err(crate::error::EncodeError::from_kind(
+            crate::error::EncodeErrorKind::Io { error },
+            crate::Codec::Ber,
+        )),
+        None => Ok(()),
+    }
+}
+
 /// Creates a new BER encoder that can be used to encode any value.
 /// # Errors
 /// Returns error specific to BER encoder if encoding is not possible.
@@ -60,6 +376,7 @@ mod tests {
     use alloc::vec;
     use alloc::vec::Vec;
     use bitvec::order::Msb0;
+    #[cfg(feature = "chrono")]
     use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 
     use crate::{
@@ -73,21 +390,159 @@ mod tests {
         const TAG: Tag = Tag::new(Class::Context, 0);
     }
 
+    #[test]
+    fn decode_all() {
+        // Three `INTEGER`s, where the second has an `OCTET STRING` tag.
+        let data = &[0x02, 0x01, 0x05, 0x04, 0x01, 0x07, 0x02, 0x01, 0x09];
+        let mut values = super::decode_all::<Integer>(data);
+
+        assert_eq!(Integer::from(5), values.next().unwrap().unwrap());
+        assert_eq!(Some(3), values.next().unwrap().unwrap_err().offset);
+        assert!(values.next().is_none());
+
+        assert_eq!(0, super::decode_all::<Integer>(&[]).count());
+    }
+
+    /// A `SEQUENCE` of a primitive `OCTET STRING`, an `OCTET STRING` in
+    /// constructed form with two segments, and an `INTEGER`.
+    #[test]
+    #[cfg(not(feature = "arc-slice"))]
+    fn decode_bytes() {
+        let input = bytes::Bytes::from(vec![
+            0x30, 0x12, 0x04, 0x03, 0x01, 0x02, 0x03, 0x24, 0x08, 0x04, 0x02, 0xAA, 0xBB, 0x04,
+            0x02, 0xCC, 0xDD, 0x02, 0x01, 0x05,
+        ]);
+        let within_input = |value: &OctetString| {
+            input.as_ptr_range().contains(&value.as_ptr())
+                && input
+                    .as_ptr_range()
+                    .contains(&value[value.len() - 1..].as_ptr())
+        };
+
+        let (primitive, constructed, integer): (OctetString, OctetString, Integer) =
+            super::decode_bytes(input.clone()).unwrap();
+        assert_eq!(&[1, 2, 3], &*primitive);
+        assert_eq!(&[0xAA, 0xBB, 0xCC, 0xDD], &*constructed);
+        assert_eq!(Integer::from(5), integer);
+        assert_eq!(input[4..].as_ptr(), primitive.as_ptr());
+        assert!(!within_input(&constructed));
+
+        let copied: (OctetString, OctetString, Integer) = decode(&input).unwrap();
+        assert_eq!((primitive, constructed, integer), copied);
+        assert!(!within_input(&copied.0));
+
+        let mut trailing = input.to_vec();
+        trailing.push(0);
+        assert!(
+            super::decode_bytes::<(OctetString, OctetString, Integer)>(trailing.into()).is_err()
+        );
+    }
+
+    #[test]
+    fn decoder_options() {
+        use super::{decode_with_options, DecoderOptions};
+
+        // An indefinite length `SEQUENCE OF BOOLEAN` with a `TRUE` of 0x01.
+        let data = &[0x30, 0x80, 0x01, 0x01, 0x01, 0x00, 0x00];
+        assert_eq!(
+            vec![true],
+            decode_with_options::<Vec<bool>>(DecoderOptions::ber(), data).unwrap()
+        );
+        assert!(decode_with_options::<Vec<bool>>(DecoderOptions::der(), data).is_err());
+
+        type Depth4 = Vec<Vec<Vec<Vec<bool>>>>;
+        type Depth5 = Vec<Depth4>;
+        let depth_4 = &[0x30, 0x06, 0x30, 0x04, 0x30, 0x02, 0x30, 0x00];
+        let depth_5 = &[0x30, 0x08, 0x30, 0x06, 0x30, 0x04, 0x30, 0x02, 0x30, 0x00];
+        let options = DecoderOptions::ber().with_max_depth(4);
+
+        assert!(decode_with_options::<Depth4>(options, depth_4).is_ok());
+        assert!(matches!(
+            &*decode_with_options::<Depth5>(options, depth_5)
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(
+                    crate::error::BerDecodeErrorKind::NestingTooDeep { limit: 4 }
+                )
+            }
+        ));
+        assert!(decode::<Depth5>(depth_5).is_ok());
+        // Indefinite lengths are still accepted, as in the BER preset.
+        assert_eq!(
+            vec![true],
+            decode_with_options::<Vec<bool>>(options, data).unwrap()
+        );
+    }
+
     #[test]
     fn oversized_integer() {
         const DATA: &[u8] = &[0x02, 0x06, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
 
         assert!(matches!(
             &*decode::<u32>(DATA).unwrap_err().kind,
-            DecodeErrorKind::IntegerOverflow { max_width: 32 }
+            DecodeErrorKind::IntegerOutOfRange { target: "u32", value }
+                if *value == 0x1122_3344_5566_i64.into()
         ));
 
         assert!(matches!(
             &*decode::<i32>(DATA).unwrap_err().kind,
-            DecodeErrorKind::IntegerOverflow { max_width: 32 }
+            DecodeErrorKind::IntegerOutOfRange { target: "i32", value }
+                if *value == 0x1122_3344_5566_i64.into()
         ));
     }
 
+    #[test]
+    fn integer_out_of_range() {
+        use num_bigint::BigInt;
+
+        fn out_of_range<T: crate::Decode + core::fmt::Debug>(
+            data: &[u8],
+        ) -> (&'static str, BigInt) {
+            match *decode::<T>(data).unwrap_err().kind {
+                DecodeErrorKind::IntegerOutOfRange { target, value } => (target, value),
+                kind => panic!("unexpected error: {kind}"),
+            }
+        }
+
+        assert_eq!(
+            ("u8", 256.into()),
+            out_of_range::<u8>(&[0x02, 0x02, 0x01, 0x00])
+        );
+        assert_eq!(("u8", (-1).into()), out_of_range::<u8>(&[0x02, 0x01, 0xFF]));
+        assert_eq!(
+            ("u128", (-1).into()),
+            out_of_range::<u128>(&[0x02, 0x01, 0xFF])
+        );
+        assert_eq!(
+            ("usize", (-1).into()),
+            out_of_range::<usize>(&[0x02, 0x01, 0xFF])
+        );
+
+        macro_rules! boundaries {
+            ($($ty:ty),+ $(,)?) => {
+                $(
+                    let max = BigInt::from(<$ty>::MAX);
+                    let min = BigInt::from(<$ty>::MIN);
+                    let encode_integer =
+                        |value: &BigInt| encode(&Integer::from(value.clone())).unwrap();
+
+                    assert_eq!(<$ty>::MAX, decode::<$ty>(&encode_integer(&max)).unwrap());
+                    assert_eq!(<$ty>::MIN, decode::<$ty>(&encode_integer(&min)).unwrap());
+
+                    for value in [&max + 1, &min - 1] {
+                        assert_eq!(
+                            (stringify!($ty), value.clone()),
+                            out_of_range::<$ty>(&encode_integer(&value))
+                        );
+                    }
+                )+
+            };
+        }
+
+        boundaries!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    }
+
     #[test]
     fn leading_integer_bytes() {
         const DATA: &[u8] = &[0x02, 0x06, 0x00, 0x00, 0x33, 0x44, 0x55, 0x66];
@@ -97,6 +552,66 @@ mod tests {
         assert_eq!(decode::<i32>(SIGNED_DATA).unwrap(), -2_092_673_690);
     }
 
+    #[test]
+    fn constructed_primitive_types() {
+        use crate as rasn;
+        use crate::error::{BerDecodeErrorKind, CodecDecodeError, DecodeError};
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+        #[rasn(enumerated)]
+        enum Colour {
+            Red = 5,
+        }
+
+        fn is_constructed_error(error: DecodeError) -> bool {
+            matches!(
+                *error.kind,
+                DecodeErrorKind::CodecSpecific {
+                    inner: CodecDecodeError::Ber(BerDecodeErrorKind::InvalidConstructedIdentifier),
+                }
+            )
+        }
+
+        macro_rules! assert_rejected {
+            ($($ty:ty: $primitive:expr),+ $(,)?) => {$({
+                let primitive: &[u8] = $primitive;
+                assert!(decode::<$ty>(primitive).is_ok());
+
+                // The same octets with the constructed bit set, and the value
+                // wrapped inside a constructed value with the same tag.
+                let mut flagged = primitive.to_vec();
+                flagged[0] |= 0x20;
+                let mut wrapped = vec![primitive[0] | 0x20, primitive.len() as u8];
+                wrapped.extend_from_slice(primitive);
+                let mut indefinite = vec![primitive[0] | 0x20, 0x80];
+                indefinite.extend_from_slice(primitive);
+                indefinite.extend_from_slice(&[0x00, 0x00]);
+
+                for data in [&flagged, &wrapped, &indefinite] {
+                    assert!(is_constructed_error(decode::<$ty>(data).unwrap_err()), "{data:02X?}");
+                    assert!(crate::cer::decode::<$ty>(data).is_err());
+                    assert!(crate::der::decode::<$ty>(data).is_err());
+                }
+            })+};
+        }
+
+        assert_rejected!(
+            bool: &[0x01, 0x01, 0xFF],
+            Integer: &[0x02, 0x01, 0x05],
+            i32: &[0x02, 0x01, 0x05],
+            Colour: &[0x0A, 0x01, 0x05],
+            (): &[0x05, 0x00],
+            ObjectIdentifier: &[0x06, 0x03, 0x2A, 0x03, 0x04],
+        );
+        #[cfg(feature = "f64")]
+        assert_rejected!(f64: &[0x09, 0x01, 0x40]);
+
+        let error = decode::<Integer>(&[0x22, 0x03, 0x02, 0x01, 0x05]).unwrap_err();
+        assert_eq!(Some(0), error.offset);
+        assert!(is_constructed_error(error));
+    }
+
     #[test]
     fn bit_string() {
         const DATA: &[u8] = &[0, 0xD0];
@@ -116,6 +631,50 @@ mod tests {
         assert_eq!(trailing_expected, encode(&trailing_test).unwrap());
     }
 
+    #[test]
+    fn bit_slices() {
+        // The slices start part way into the first octet of `source`, so none
+        // of them begin on an octet boundary.
+        let source = BitString::from_vec(vec![0xA5, 0x3C, 0xF0, 0x0F, 0x96]);
+
+        for length in [0, 1, 8, 9, 23] {
+            let slice = &source[3..3 + length];
+            let aligned: BitString = slice.iter().by_vals().collect();
+            let encoded = encode(&slice).unwrap();
+
+            assert_eq!(encode(&aligned).unwrap(), encoded);
+            assert_eq!(3 + length.div_ceil(8), encoded.len());
+            assert_eq!(aligned, decode::<BitString>(&encoded).unwrap());
+
+            let mut buffer = bitvec::array::BitArray::<[u8; 3], Msb0>::ZERO;
+            let decoded = super::decode_bit_string_into(&encoded, &mut buffer).unwrap();
+            assert_eq!(length, decoded);
+            assert_eq!(slice, &buffer[..decoded]);
+
+            assert_eq!(
+                crate::uper::encode(&aligned).unwrap(),
+                crate::uper::encode(&slice).unwrap()
+            );
+            assert_eq!(
+                crate::oer::encode(&aligned).unwrap(),
+                crate::oer::encode(&slice).unwrap()
+            );
+            assert_eq!(
+                crate::jer::encode(&aligned).unwrap(),
+                crate::jer::encode(&slice).unwrap()
+            );
+        }
+
+        // Constructed values are joined before being copied into the buffer.
+        let constructed = &[
+            0x23, 0x80, 0x03, 0x03, 0x00, 0x0A, 0x3B, 0x03, 0x05, 0x04, 0x5F, 0x29, 0x1C, 0xD0,
+            0x00, 0x00,
+        ];
+        let mut buffer = bitvec::array::BitArray::<[u8; 6], Msb0>::ZERO;
+        let decoded = super::decode_bit_string_into(constructed, &mut buffer).unwrap();
+        assert_eq!(decode::<BitString>(constructed).unwrap(), buffer[..decoded]);
+    }
+
     #[test]
     fn implicit_prefix() {
         type MyInteger = Implicit<C0, u64>;
@@ -123,6 +682,25 @@ mod tests {
         let new_int = MyInteger::new(5);
 
         assert_eq!(new_int, decode(&encode(&new_int).unwrap()).unwrap());
+
+        struct C1;
+        impl AsnType for C1 {
+            const TAG: Tag = Tag::new(Class::Context, 1);
+        }
+
+        // The implicit tag replaces the universal tag rather than being
+        // checked against it.
+        let data = &[0x81, 0x01, 0x2A][..];
+        assert_eq!(
+            Integer::from(42),
+            *decode::<Implicit<C1, Integer>>(data).unwrap()
+        );
+        assert!(decode::<Implicit<C1, Integer>>(&[0x02, 0x01, 0x2A]).is_err());
+
+        let data = &[0x80, 0x02, 0xAB, 0xCD][..];
+        let octets = decode::<Implicit<C0, OctetString>>(data).unwrap();
+        assert_eq!(&[0xAB, 0xCD], &**octets);
+        assert_eq!(data, &*encode(&octets).unwrap());
     }
 
     #[test]
@@ -204,6 +782,8 @@ mod tests {
             crate::ber::decode::<Set>(&age_then_name).unwrap(),
             crate::ber::decode::<Set>(&name_then_age).unwrap()
         );
+        assert!(crate::der::decode::<Set>(&age_then_name).is_ok());
+        assert!(crate::der::decode::<Set>(&name_then_age).is_err());
 
         impl crate::Decode for Set {
             fn decode_with_tag_and_constraints<D: crate::Decoder>(
@@ -271,6 +851,7 @@ mod tests {
         }
     }
     #[test]
+    #[cfg(feature = "chrono")]
     fn test_generalized_time() {
         // "20801009130005.342Z"
         let offset = chrono::FixedOffset::east_opt(0).unwrap();
@@ -372,7 +953,167 @@ mod tests {
         let result = crate::ber::decode::<crate::types::GeneralizedTime>(&data);
         assert!(result.is_err());
     }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn canonical_generalized_time() {
+        use crate::Encode;
+
+        let time = |offset: i32, milli: u32| {
+            NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_milli_opt(14, 0, 0, milli)
+                .unwrap()
+                .and_local_timezone(chrono::FixedOffset::east_opt(offset).unwrap())
+                .unwrap()
+        };
+        let encode_with_offset = |value: &GeneralizedTime| {
+            let options = crate::ber::enc::EncoderOptions::ber()
+                .with_generalized_time_offset(true)
+                .unwrap();
+            let mut encoder = crate::ber::enc::Encoder::new(options);
+            value.encode(&mut encoder).unwrap();
+            encoder.output()
+        };
+
+        let value = time(0, 120);
+        let encoded = crate::der::encode(&value).unwrap();
+        assert_eq!(b"20240101140000.12Z", &encoded[2..]);
+        assert_eq!(
+            value,
+            crate::der::decode::<GeneralizedTime>(&encoded).unwrap()
+        );
+
+        // Offsets are converted to UTC, unless asked to keep them in BER.
+        let value = time(2 * 3600, 0);
+        let encoded = crate::der::encode(&value).unwrap();
+        assert_eq!(b"20240101120000Z", &encoded[2..]);
+        assert_eq!(encoded, encode(&value).unwrap());
+        assert_eq!(value, decode::<GeneralizedTime>(&encoded).unwrap());
+
+        let encoded = encode_with_offset(&value);
+        assert_eq!(b"20240101140000+0200", &encoded[2..]);
+        assert_eq!(value, decode::<GeneralizedTime>(&encoded).unwrap());
+        assert_eq!(
+            b"20240101140000.12Z",
+            &encode_with_offset(&time(0, 120))[2..]
+        );
+
+        assert!(crate::ber::enc::EncoderOptions::der()
+            .with_generalized_time_offset(true)
+            .is_err());
+        assert!(crate::ber::enc::EncoderOptions::cer()
+            .with_generalized_time_offset(true)
+            .is_err());
+    }
+
+    #[test]
+    fn built_in_date_time() {
+        let value = crate::types::DateTime {
+            year: 2024,
+            month: 2,
+            day: 29,
+            hour: 23,
+            minute: 30,
+            second: 5,
+            nanos: 250_000_000,
+            offset_minutes: -60,
+        };
+        let time = GeneralizedTime::try_from(value).unwrap();
+
+        let encoded = encode(&time).unwrap();
+        assert_eq!(b"20240301003005.25Z", &encoded[2..]);
+        let decoded = decode::<GeneralizedTime>(&encoded).unwrap();
+        assert_eq!(
+            value.to_utc(),
+            crate::types::DateTime::from(decoded).to_utc()
+        );
+        assert_eq!(
+            encoded,
+            crate::der::encode(&crate::der::decode::<GeneralizedTime>(&encoded).unwrap()).unwrap()
+        );
+
+        let time = UtcTime::try_from(value).unwrap();
+        let encoded = encode(&time).unwrap();
+        assert_eq!(b"240301003005Z", &encoded[2..]);
+        assert_eq!(
+            crate::types::DateTime {
+                nanos: 0,
+                ..value.to_utc()
+            },
+            crate::types::DateTime::from(decode::<UtcTime>(&encoded).unwrap())
+        );
+    }
+
+    #[test]
+    fn raw_times() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        struct Validity {
+            not_before: RawUtcTime,
+            not_after: RawGeneralizedTime,
+        }
+
+        let fields = |time: crate::types::DateTime| {
+            (
+                time.year,
+                time.month,
+                time.day,
+                time.hour,
+                time.minute,
+                time.second,
+                time.nanos,
+                time.offset_minutes,
+            )
+        };
+
+        let mut encoded = vec![0x18, 0x16];
+        encoded.extend_from_slice(b"20170102030405,50+0100");
+        let time: RawGeneralizedTime = decode(&encoded).unwrap();
+        assert_eq!("20170102030405,50+0100", time.as_str());
+        assert_eq!(
+            (2017, 1, 2, 2, 4, 5, 500_000_000, 0),
+            fields(crate::types::DateTime::from(time.time()).to_utc())
+        );
+        assert_eq!(encoded, encode(&time).unwrap());
+        assert_eq!(encoded, crate::der::encode(&time).unwrap());
+        // The parsed time is still normalised, and DER only accepts it in
+        // the canonical form.
+        let canonical = encode(&time.time()).unwrap();
+        assert_eq!(b"20170102020405.5Z", &canonical[2..]);
+        assert!(crate::der::decode::<RawGeneralizedTime>(&encoded).is_err());
+        let time = crate::der::decode::<RawGeneralizedTime>(&canonical).unwrap();
+        assert_eq!(RawGeneralizedTime::new(time.time()), time);
+        assert_eq!(canonical, crate::der::encode(&time).unwrap());
+
+        let mut encoded = vec![0x17, 0x0F];
+        encoded.extend_from_slice(b"1701020304+0100");
+        let time: RawUtcTime = decode(&encoded).unwrap();
+        assert_eq!("1701020304+0100", time.as_str());
+        assert_eq!(
+            (2017, 1, 2, 2, 4, 0, 0, 0),
+            fields(crate::types::DateTime::from(time.time()))
+        );
+        assert_eq!(encoded, encode(&time).unwrap());
+        assert_eq!(
+            b"170102020400Z",
+            RawUtcTime::new(time.time()).as_str().as_bytes()
+        );
+
+        let mut encoded = vec![0x30, 0x29, 0x17, 0x0F];
+        encoded.extend_from_slice(b"1701020304+0100");
+        encoded.extend_from_slice(&[0x18, 0x16]);
+        encoded.extend_from_slice(b"20170102030405,50+0100");
+        let validity: Validity = decode(&encoded).unwrap();
+        assert_eq!("1701020304+0100", validity.not_before.as_str());
+        assert_eq!("20170102030405,50+0100", validity.not_after.as_str());
+        assert_eq!(encoded, encode(&validity).unwrap());
+    }
+
     #[test]
+    #[cfg(feature = "chrono")]
     fn test_utc_time() {
         // "180122132900Z"
         round_trip!(
@@ -418,6 +1159,43 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
+    fn utc_time_year_range() {
+        let utc_time = |year| -> UtcTime {
+            NaiveDate::from_ymd_opt(year, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+
+        assert_eq!(&encode(&utc_time(1950)).unwrap()[2..4], b"50");
+        assert_eq!(&encode(&utc_time(2049)).unwrap()[2..4], b"49");
+        assert_eq!(&crate::der::encode(&utc_time(2049)).unwrap()[2..4], b"49");
+
+        // 2051 must not be silently encoded as "51", which reads back as 1951.
+        for year in [1949, 2050, 2051] {
+            let error = encode(&utc_time(year)).unwrap_err();
+            assert!(matches!(
+                *error.kind,
+                crate::error::EncodeErrorKind::UtcTimeYearOutOfRange { year: y } if y == year
+            ));
+        }
+        assert!(crate::jer::encode(&utc_time(2051)).is_err());
+
+        // Two digit years from 50 are in the 20th century.
+        for year in [1950, 1969, 1970, 2000, 2049] {
+            let encoded = encode(&utc_time(year)).unwrap();
+            assert_eq!(utc_time(year), decode::<UtcTime>(&encoded).unwrap());
+            assert_eq!(
+                utc_time(year),
+                crate::der::decode::<UtcTime>(&encoded).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
     fn test_date() {
         round_trip!(
             ber,
@@ -460,4 +1238,1238 @@ mod tests {
             &[0x30, 0x05, 0xA5, 0x03, 0x02, 0x01, 0x2A]
         );
     }
+
+    #[test]
+    fn open_constructed() {
+        let value = Open::Sequence(vec![
+            Open::Integer(5.into()),
+            Open::Set(vec![Open::Bool(true), Open::Null]),
+            Open::Sequence(vec![]),
+        ]);
+        round_trip!(
+            ber,
+            Open,
+            value.clone(),
+            &[0x30, 0x0C, 0x02, 0x01, 0x05, 0x31, 0x05, 0x01, 0x01, 0xFF, 0x05, 0x00, 0x30, 0x00]
+        );
+
+        // Indefinite length children are accepted by BER.
+        let indefinite = &[
+            0x30, 0x80, 0x02, 0x01, 0x05, 0x31, 0x80, 0x01, 0x01, 0xFF, 0x05, 0x00, 0x00, 0x00,
+            0x30, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(value, decode::<Open>(indefinite).unwrap());
+    }
+
+    #[test]
+    fn open_utf8_string() {
+        round_trip!(
+            ber,
+            Open,
+            Open::Utf8String("rasn".into()),
+            &[0x0C, 0x04, b'r', b'a', b's', b'n']
+        );
+    }
+
+    #[test]
+    fn open_object_identifier() {
+        let encoded = &[0x06, 0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D];
+        let oid = ObjectIdentifier::new(vec![1, 2, 840, 113_549]).unwrap();
+        assert_eq!(
+            Open::ObjectIdentifier(oid.clone()),
+            decode::<Open>(encoded).unwrap()
+        );
+        round_trip!(ber, Open, Open::ObjectIdentifier(oid), encoded);
+    }
+
+    #[test]
+    fn open_unknown() {
+        let cases: &[&[u8]] = &[
+            // A constructed context-specific value.
+            &[0xA5, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF],
+            // The same with an indefinite length.
+            &[0xA5, 0x80, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF, 0x00, 0x00],
+            // A primitive application value.
+            &[0x45, 0x01, 0x07],
+        ];
+
+        for encoded in cases {
+            let value = decode::<Open>(encoded).unwrap();
+            assert_eq!(value, Open::Unknown(Any::new(encoded.to_vec())));
+            assert_eq!(*encoded, &*encode(&value).unwrap());
+        }
+
+        let value = decode::<Open>(cases[0]).unwrap();
+        assert_eq!(value.tag(), Tag::new(Class::Context, 5));
+        assert_eq!(value.as_unknown().unwrap().is_constructed(), Some(true));
+
+        let encoded = &[0x30, 0x08, 0x02, 0x01, 0x01, 0xA5, 0x03, 0x02, 0x01, 0x05];
+        let value = decode::<Open>(encoded).unwrap();
+        assert_eq!(
+            value.as_sequence().unwrap()[1],
+            Open::Unknown(Any::new(encoded[5..].to_vec()))
+        );
+        assert_eq!(encoded, &*encode(&value).unwrap());
+    }
+
+    #[test]
+    fn high_tag_number() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(tag(context, 1000))]
+        struct HighTag {
+            value: Integer,
+        }
+
+        let encoded = &[0xBF, 0x87, 0x68, 0x03, 0x02, 0x01, 0x05];
+        round_trip!(ber, HighTag, HighTag { value: 5.into() }, encoded);
+
+        let any = decode::<Any>(encoded).unwrap();
+        assert_eq!(any.as_bytes(), encoded);
+        assert_eq!(encode(&any).unwrap(), encoded);
+    }
+
+    #[test]
+    fn redundant_leading_septets() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(delegate, tag(context, 65))]
+        struct HighTag(Integer);
+
+        // BER accepts leading zero septets and re-encodes them minimally,
+        // while the canonical encoding rules reject them.
+        let padded = &[0x9F, 0x80, 0x41, 0x01, 0x05];
+        let value = decode::<HighTag>(padded).unwrap();
+        assert_eq!(HighTag(5.into()), value);
+        assert_eq!(&[0x9F, 0x41, 0x01, 0x05][..], encode(&value).unwrap());
+        assert!(crate::cer::decode::<HighTag>(padded).is_err());
+        assert!(crate::der::decode::<HighTag>(padded).is_err());
+
+        let padded = &[0x06, 0x04, 0x2A, 0x80, 0x81, 0x34];
+        let oid = decode::<ObjectIdentifier>(padded).unwrap();
+        assert_eq!(ObjectIdentifier::new(vec![1, 2, 180]).unwrap(), oid);
+        assert_eq!(&[0x06, 0x03, 0x2A, 0x81, 0x34][..], encode(&oid).unwrap());
+        assert!(crate::cer::decode::<ObjectIdentifier>(padded).is_err());
+        assert!(crate::der::decode::<ObjectIdentifier>(padded).is_err());
+        assert!(crate::coer::decode::<ObjectIdentifier>(&[0x04, 0x2A, 0x80, 0x81, 0x34]).is_err());
+        assert_eq!(
+            oid,
+            crate::oer::decode::<ObjectIdentifier>(&[0x04, 0x2A, 0x80, 0x81, 0x34]).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_object_identifier() {
+        for arcs in [
+            vec![],
+            vec![1],
+            vec![3, 1],
+            vec![1, 40, 1],
+            vec![2, u128::MAX],
+        ] {
+            let oid = ObjectIdentifier::new_unchecked(arcs.into());
+            assert!(encode(&oid).is_err(), "{oid:?}");
+        }
+    }
+
+    #[test]
+    fn joint_iso_itu_t_object_identifiers() {
+        // Under the root arc 2 the second arc isn't limited to 0..40, so the
+        // first sub-identifier is 80 plus the second arc.
+        for (arcs, encoded) in [
+            (vec![1, 39], &[0x06, 0x01, 0x4F][..]),
+            (vec![2, 0], &[0x06, 0x01, 0x50]),
+            (vec![2, 39], &[0x06, 0x01, 0x77]),
+            (vec![2, 40], &[0x06, 0x01, 0x78]),
+            (vec![2, 25, 1], &[0x06, 0x02, 0x69, 0x01]),
+            (vec![2, 999], &[0x06, 0x02, 0x88, 0x37]),
+        ] {
+            let oid = ObjectIdentifier::new(arcs).unwrap();
+            assert_eq!(encoded, encode(&oid).unwrap(), "{oid}");
+            assert_eq!(encoded, crate::der::encode(&oid).unwrap(), "{oid}");
+            assert_eq!(oid, decode::<ObjectIdentifier>(encoded).unwrap());
+            assert_eq!(
+                oid,
+                crate::der::decode::<ObjectIdentifier>(encoded).unwrap()
+            );
+
+            // The other codecs share the contents octets.
+            let encoded = crate::oer::encode(&oid).unwrap();
+            assert_eq!(
+                oid,
+                crate::oer::decode::<ObjectIdentifier>(&encoded).unwrap()
+            );
+            let encoded = crate::uper::encode(&oid).unwrap();
+            assert_eq!(
+                oid,
+                crate::uper::decode::<ObjectIdentifier>(&encoded).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn indefinite_length_sequence() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        struct Message {
+            id: Integer,
+            payload: OctetString,
+            flags: SequenceOf<bool>,
+        }
+
+        let expected = Message {
+            id: 5.into(),
+            payload: OctetString::from_static(&[0xAA, 0xBB]),
+            flags: vec![true],
+        };
+
+        // Indefinite length sequence containing an indefinite length octet
+        // string, which itself contains an indefinite length segment.
+        let data = &[
+            0x30, 0x80, 0x02, 0x01, 0x05, 0x24, 0x80, 0x04, 0x01, 0xAA, 0x24, 0x80, 0x04, 0x01,
+            0xBB, 0x00, 0x00, 0x00, 0x00, 0x30, 0x03, 0x01, 0x01, 0xFF, 0x00, 0x00,
+        ];
+        assert_eq!(expected, decode::<Message>(data).unwrap());
+
+        // Definite length sequence containing indefinite length values.
+        let data = &[
+            0x30, 0x12, 0x02, 0x01, 0x05, 0x24, 0x80, 0x04, 0x02, 0xAA, 0xBB, 0x00, 0x00, 0x30,
+            0x80, 0x01, 0x01, 0xFF, 0x00, 0x00,
+        ];
+        assert_eq!(expected, decode::<Message>(data).unwrap());
+
+        // Missing end-of-contents.
+        let data = &[
+            0x30, 0x80, 0x02, 0x01, 0x05, 0x04, 0x02, 0xAA, 0xBB, 0x30, 0x03, 0x01, 0x01, 0xFF,
+        ];
+        assert!(matches!(
+            &*decode::<Message>(data).unwrap_err().kind,
+            DecodeErrorKind::Incomplete { .. }
+        ));
+
+        // DER doesn't allow indefinite lengths.
+        assert!(crate::der::decode::<Message>(&[
+            0x30, 0x80, 0x02, 0x01, 0x05, 0x04, 0x02, 0xAA, 0xBB, 0x30, 0x03, 0x01, 0x01, 0xFF,
+            0x00, 0x00,
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn indefinite_length_encoding() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        struct Message {
+            id: Integer,
+            #[rasn(tag(explicit(0)))]
+            payload: OctetString,
+            flags: SequenceOf<bool>,
+        }
+
+        let value = Message {
+            id: 5.into(),
+            payload: OctetString::from_static(&[0xAA, 0xBB]),
+            flags: vec![true],
+        };
+
+        let options = crate::ber::enc::EncoderOptions::ber()
+            .with_indefinite_length(true)
+            .unwrap();
+        let mut encoder = crate::ber::enc::Encoder::new(options);
+        value.encode(&mut encoder).unwrap();
+        let output = encoder.output();
+
+        // Constructed values are indefinite, primitive values stay definite.
+        assert_eq!(
+            &[
+                0x30, 0x80, 0x02, 0x01, 0x05, 0xA0, 0x80, 0x04, 0x02, 0xAA, 0xBB, 0x00, 0x00, 0x30,
+                0x80, 0x01, 0x01, 0xFF, 0x00, 0x00, 0x00, 0x00,
+            ][..],
+            &output[..]
+        );
+        assert_eq!(value, decode::<Message>(&output).unwrap());
+
+        let options = crate::ber::enc::EncoderOptions::ber()
+            .with_indefinite_length(false)
+            .unwrap();
+        let mut encoder = crate::ber::enc::Encoder::new(options);
+        value.encode(&mut encoder).unwrap();
+        assert_eq!(encode(&value).unwrap(), encoder.output());
+
+        assert!(matches!(
+            &*crate::ber::enc::EncoderOptions::der()
+                .with_indefinite_length(true)
+                .unwrap_err()
+                .kind,
+            crate::error::EncodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecEncodeError::Der(
+                    crate::error::DerEncodeErrorKind::IndefiniteLengthNotAllowed
+                )
+            }
+        ));
+        assert!(crate::ber::enc::EncoderOptions::der()
+            .with_indefinite_length(false)
+            .is_ok());
+    }
+
+    #[test]
+    fn constrained_integer() {
+        type Octet = ConstrainedInteger<0, 255>;
+
+        for value in [0, 1, 255] {
+            let integer = Octet::try_from(value).unwrap();
+            assert_eq!(
+                integer,
+                decode::<Octet>(&encode(&integer).unwrap()).unwrap()
+            );
+        }
+        assert!(Octet::try_from(-1).is_err());
+        assert!(Octet::try_from(Integer::from(256)).is_err());
+
+        let error = decode::<Octet>(&encode(&256).unwrap()).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::ValueConstraintNotSatisfied { .. }
+        ));
+        assert!(error.to_string().contains("expected: 0..255; actual: 256"));
+    }
+
+    #[test]
+    fn unsigned_integer() {
+        assert_eq!(
+            &[0x02, 0x02, 0x00, 0x80],
+            &*encode(&Unsigned::from(128u8)).unwrap()
+        );
+
+        let error = decode::<Unsigned>(&[0x02, 0x01, 0xFF]).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::ValueConstraintNotSatisfied { .. }
+        ));
+        assert!(Unsigned::try_from(Integer::from(-1)).is_err());
+
+        // A 16 octet serial number with its top bit set needs a leading zero
+        // octet, making 17 contents octets.
+        let serial = Unsigned::from_be_bytes(&[0xFF; 16]);
+        let encoded = encode(&serial).unwrap();
+        assert_eq!(&[0x02, 0x11, 0x00], &encoded[..3]);
+        assert_eq!(19, encoded.len());
+        assert_eq!(serial, decode::<Unsigned>(&encoded).unwrap());
+        assert_eq!(
+            num_bigint::BigUint::from_bytes_be(&[0xFF; 16]),
+            serial.to_biguint()
+        );
+
+        // PER and OER encode it like an unconstrained INTEGER.
+        let value = Unsigned::from(70_000u32);
+        let encoded = crate::uper::encode(&value).unwrap();
+        assert_eq!(
+            crate::uper::encode(&Integer::from(70_000)).unwrap(),
+            encoded
+        );
+        assert_eq!(value, crate::uper::decode::<Unsigned>(&encoded).unwrap());
+        assert_eq!(
+            crate::oer::encode(&Integer::from(70_000)).unwrap(),
+            crate::oer::encode(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn size_constrained() {
+        type Nonce = SizeConstrained<OctetString, 8, 8>;
+        type Names = SizeConstrained<SequenceOf<Ia5String>, 1, 10>;
+
+        let nonce = Nonce::new(OctetString::from_static(&[0xAB; 8])).unwrap();
+        let encoded = encode(&nonce).unwrap();
+        assert_eq!(&[0x04, 0x08], &encoded[..2]);
+        assert_eq!(nonce, decode::<Nonce>(&encoded).unwrap());
+
+        let error = decode::<Nonce>(&[0x04, 0x02, 0xAB, 0xAB]).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::SizeConstraintNotSatisfied { size: Some(2), .. }
+        ));
+        assert!(error.to_string().contains("expected: 8..=8"));
+
+        let name = Ia5String::from_iso646_bytes(b"rasn").unwrap();
+        let names = Names::new(vec![name.clone(); 10]).unwrap();
+        assert_eq!(names, decode::<Names>(&encode(&names).unwrap()).unwrap());
+
+        let error = Names::new(vec![name.clone(); 11]).unwrap_err();
+        assert_eq!((error.size, error.min, error.max), (11, 1, 10));
+        assert!(decode::<Names>(&encode(&vec![name; 11]).unwrap()).is_err());
+        assert!(decode::<Names>(&[0x30, 0x00]).is_err());
+
+        type Label = SizeConstrained<UniversalString, 1, 4>;
+        let label = Label::new(UniversalString::new("ab€".into())).unwrap();
+        assert_eq!(label, decode::<Label>(&encode(&label).unwrap()).unwrap());
+        assert!(Label::new(UniversalString::new("abcde".into())).is_err());
+    }
+
+    #[test]
+    fn trimmed_bit_strings() {
+        use crate::Encode;
+
+        fn encode_trimmed(value: &BitString) -> Vec<u8> {
+            let options = crate::ber::enc::EncoderOptions::der().with_trimmed_bit_strings(true);
+            let mut encoder = crate::ber::enc::Encoder::new(options);
+            value.encode(&mut encoder).unwrap();
+            encoder.output()
+        }
+
+        let cases: &[(BitString, &[u8], &[u8])] = &[
+            (
+                bitvec::bitvec![u8, Msb0; 1, 0, 0, 0, 0, 1, 0, 0, 0],
+                &[0x03, 0x03, 0x07, 0x84, 0x00],
+                &[0x03, 0x02, 0x02, 0x84],
+            ),
+            (
+                bitvec::bitvec![u8, Msb0; 0; 8],
+                &[0x03, 0x02, 0x00, 0x00],
+                &[0x03, 0x01, 0x00],
+            ),
+            (
+                bitvec::bitvec![u8, Msb0; 1, 0, 0, 0, 0, 0, 0, 1],
+                &[0x03, 0x02, 0x00, 0x81],
+                &[0x03, 0x02, 0x00, 0x81],
+            ),
+            (BitString::new(), &[0x03, 0x01, 0x00], &[0x03, 0x01, 0x00]),
+        ];
+
+        for (value, untrimmed, trimmed) in cases {
+            assert_eq!(*untrimmed, &*crate::der::encode(value).unwrap());
+            assert_eq!(*trimmed, &*encode_trimmed(value));
+            assert_eq!(*value, decode::<BitString>(untrimmed).unwrap());
+
+            let decoded = decode::<BitString>(trimmed).unwrap();
+            assert_eq!(value.last_one(), decoded.last_one());
+            assert!(value.starts_with(&decoded));
+        }
+    }
+
+    #[test]
+    fn real() {
+        use crate::Encode;
+
+        fn encode_decimal(value: f64) -> Vec<u8> {
+            let options = crate::ber::enc::EncoderOptions::ber().with_decimal_real(true);
+            let mut encoder = crate::ber::enc::Encoder::new(options);
+            value.encode(&mut encoder).unwrap();
+            encoder.output()
+        }
+
+        let binary: &[(f64, &[u8])] = &[
+            (0.0, &[0x09, 0x00]),
+            (-0.0, &[0x09, 0x01, 0x43]),
+            (f64::INFINITY, &[0x09, 0x01, 0x40]),
+            (f64::NEG_INFINITY, &[0x09, 0x01, 0x41]),
+            (1.0, &[0x09, 0x03, 0x80, 0x00, 0x01]),
+            (0.5, &[0x09, 0x03, 0x80, 0xFF, 0x01]),
+            (-12.5, &[0x09, 0x03, 0xC0, 0xFF, 0x19]),
+            (2.0f64.powi(300), &[0x09, 0x04, 0x81, 0x01, 0x2C, 0x01]),
+        ];
+        for (value, encoded) in binary {
+            assert_eq!(*encoded, &*encode(value).unwrap());
+            assert_eq!(*encoded, &*crate::der::encode(value).unwrap());
+            let decoded = decode::<f64>(encoded).unwrap();
+            assert_eq!(*value, decoded);
+            assert_eq!(value.is_sign_negative(), decoded.is_sign_negative());
+        }
+        assert_eq!(&[0x09, 0x01, 0x42][..], encode(&f64::NAN).unwrap());
+        assert!(decode::<f64>(&[0x09, 0x01, 0x42]).unwrap().is_nan());
+
+        // Base 8, and base 16 with a scaling factor of one.
+        assert_eq!(8.0, decode::<f64>(&[0x09, 0x03, 0x90, 0x01, 0x01]).unwrap());
+        assert_eq!(
+            96.0,
+            decode::<f64>(&[0x09, 0x03, 0xA4, 0x01, 0x03]).unwrap()
+        );
+
+        // NR1 with a trailing decimal mark, NR1 with leading spaces, NR2 with
+        // a comma and NR3.
+        assert_eq!(3.0, decode::<f64>(&[0x09, 0x03, 0x01, 0x33, 0x2E]).unwrap());
+        assert_eq!(
+            12.0,
+            decode::<f64>(&[0x09, 0x05, 0x01, 0x20, 0x20, 0x31, 0x32]).unwrap()
+        );
+        assert_eq!(
+            1.5,
+            decode::<f64>(&[0x09, 0x04, 0x02, 0x31, 0x2C, 0x35]).unwrap()
+        );
+        let mut nr3 = vec![0x09, 0x09, 0x03];
+        nr3.extend_from_slice(b"-0.56E+4");
+        assert_eq!(-5600.0, decode::<f64>(&nr3).unwrap());
+        assert_eq!(-5600.0, crate::der::decode::<f64>(&nr3).unwrap());
+
+        let error = decode::<f64>(&[0x09, 0x05, 0x03, 0x31, 0x2E, 0x32, 0x2E]).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(
+                    crate::error::BerDecodeErrorKind::InvalidDecimalReal { text }
+                )
+            } if text == "1.2."
+        ));
+        // CER and DER only allow the NR3 form.
+        assert!(crate::der::decode::<f64>(&[0x09, 0x03, 0x01, 0x33, 0x2E]).is_err());
+
+        // Binary forms that BER accepts, but CER and DER don't: base 8, a
+        // scaling factor, an even mantissa, a leading zero mantissa octet and
+        // a redundant exponent octet.
+        let non_canonical: &[(f64, &[u8], &str)] = &[
+            (8.0, &[0x09, 0x03, 0x90, 0x01, 0x01], "base"),
+            (6.0, &[0x09, 0x03, 0x84, 0x00, 0x03], "scaling factor"),
+            (1.0, &[0x09, 0x03, 0x80, 0xFF, 0x02], "odd"),
+            (1.0, &[0x09, 0x04, 0x80, 0x00, 0x00, 0x01], "leading zero"),
+            (1.0, &[0x09, 0x04, 0x81, 0x00, 0x00, 0x01], "fewest octets"),
+            (0.5, &[0x09, 0x04, 0x81, 0xFF, 0xFF, 0x01], "fewest octets"),
+            (1.0, &[0x09, 0x04, 0x83, 0x01, 0x00, 0x01], "fewest octets"),
+        ];
+        for (value, encoded, rule) in non_canonical {
+            assert_eq!(*value, decode::<f64>(encoded).unwrap());
+
+            let error = crate::der::decode::<f64>(encoded).unwrap_err();
+            assert!(
+                matches!(
+                    &*error.kind,
+                    DecodeErrorKind::CodecSpecific {
+                        inner: crate::error::CodecDecodeError::Der(
+                            crate::error::DerDecodeErrorKind::NonCanonicalReal { rule: broken }
+                        )
+                    } if broken.contains(rule)
+                ),
+                "{error}"
+            );
+        }
+
+        let decimal: &[(f64, &[u8])] = &[
+            (1.0, b"1.E+0"),
+            (12.5, b"125.E-1"),
+            (-5600.0, b"-56.E2"),
+            (0.1, b"1.E-1"),
+        ];
+        for (value, text) in decimal {
+            let encoded = encode_decimal(*value);
+            assert_eq!(&[0x09, text.len() as u8 + 1, 0x03][..], &encoded[..3]);
+            assert_eq!(*text, &encoded[3..]);
+            assert_eq!(*value, crate::der::decode::<f64>(&encoded).unwrap());
+        }
+        assert_eq!(&[0x09, 0x00][..], encode_decimal(0.0));
+    }
+
+    #[test]
+    fn fixed_size_arrays() {
+        let mut encoded = vec![0x04, 0x14];
+        encoded.extend(0..20);
+        let expected: [u8; 20] = core::array::from_fn(|i| i as u8);
+        assert_eq!(
+            FixedOctetString::new(expected),
+            decode::<FixedOctetString<20>>(&encoded).unwrap()
+        );
+        assert_eq!(encoded, encode(&FixedOctetString::new(expected)).unwrap());
+
+        encoded[1] = 0x13;
+        encoded.pop();
+        assert!(matches!(
+            &*decode::<FixedOctetString<20>>(&encoded).unwrap_err().kind,
+            DecodeErrorKind::FixedStringConversionFailed {
+                actual: 19,
+                expected: 20,
+                ..
+            }
+        ));
+
+        let encoded = &[
+            0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03,
+        ];
+        let integers: [Integer; 3] = [1.into(), 2.into(), 3.into()];
+        assert_eq!(integers, decode::<[Integer; 3]>(encoded).unwrap());
+        assert_eq!(&encoded[..], encode(&integers).unwrap());
+
+        assert!(matches!(
+            &*decode::<[Integer; 4]>(encoded).unwrap_err().kind,
+            DecodeErrorKind::IncorrectItemNumberInSequence {
+                expected: 4,
+                actual: 3,
+            }
+        ));
+    }
+
+    #[test]
+    fn structured_errors() {
+        use crate::error::{BerDecodeErrorKind, CodecDecodeError};
+
+        let ber_kind = |data: &[u8]| match *decode::<ObjectIdentifier>(data).unwrap_err().kind {
+            DecodeErrorKind::CodecSpecific {
+                inner: CodecDecodeError::Ber(kind),
+            } => kind,
+            kind => panic!("unexpected error: {kind:?}"),
+        };
+
+        assert!(matches!(
+            ber_kind(&[0x02, 0x01, 0x01]),
+            BerDecodeErrorKind::MismatchedTag {
+                expected: Tag::OBJECT_IDENTIFIER,
+                actual: Tag::INTEGER,
+            }
+        ));
+        assert!(matches!(
+            ber_kind(&[0x00, 0x00]),
+            BerDecodeErrorKind::UnexpectedEndOfContents
+        ));
+        // Truncated arcs.
+        assert!(matches!(
+            ber_kind(&[0x06, 0x02, 0x2A, 0x86]),
+            BerDecodeErrorKind::InvalidObjectIdentifierArc
+        ));
+        assert!(matches!(
+            ber_kind(&[0x06, 0x02, 0x2A, 0x80]),
+            BerDecodeErrorKind::InvalidObjectIdentifierArc
+        ));
+
+        assert!(matches!(
+            *decode::<bool>(&[0x01, 0x02, 0xFF, 0xFF]).unwrap_err().kind,
+            DecodeErrorKind::MismatchedLength {
+                expected: 1,
+                actual: 2
+            }
+        ));
+        assert!(matches!(
+            *decode::<bool>(&[0x01, 0x02, 0xFF]).unwrap_err().kind,
+            DecodeErrorKind::Incomplete { .. }
+        ));
+        assert!(matches!(
+            *crate::der::decode::<bool>(&[0x01, 0x01, 0x01])
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::InvalidBool { value: 1 }
+        ));
+    }
+
+    #[test]
+    fn incomplete_input() {
+        use crate::error::{BerDecodeErrorKind, CodecDecodeError};
+        use nom::Needed;
+
+        let kind = |error: crate::error::DecodeError| *error.kind;
+        let needed = |error| match kind(error) {
+            DecodeErrorKind::Incomplete { needed } => needed,
+            kind => panic!("unexpected error: {kind:?}"),
+        };
+        let ber_kind = |error| match kind(error) {
+            DecodeErrorKind::CodecSpecific {
+                inner: CodecDecodeError::Ber(kind),
+            } => kind,
+            kind => panic!("unexpected error: {kind:?}"),
+        };
+
+        // Values missing their last bytes, or their length octets.
+        let data = &[0x04, 0x03, 0x01, 0x02, 0x03];
+        assert_eq!(
+            Needed::new(1),
+            needed(decode::<OctetString>(&data[..4]).unwrap_err())
+        );
+        assert_eq!(
+            Needed::new(1),
+            needed(decode::<OctetString>(&data[..1]).unwrap_err())
+        );
+        let data = &[0x30, 0x06, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00];
+        assert_eq!(
+            Needed::new(2),
+            needed(decode::<(bool, bool)>(&data[..6]).unwrap_err())
+        );
+        assert_eq!(
+            Needed::new(2),
+            needed(super::decode_with_remainder::<(bool, bool)>(&data[..6]).unwrap_err())
+        );
+
+        // Indefinite length values can't tell how much input is missing.
+        let data = &[0x30, 0x80, 0x01, 0x01, 0xFF, 0x00, 0x00];
+        for length in 2..data.len() {
+            needed(decode::<Vec<bool>>(&data[..length]).unwrap_err());
+        }
+
+        // A wrong tag is an error however short the input is.
+        assert!(matches!(
+            ber_kind(decode::<bool>(&[0x02, 0x01]).unwrap_err()),
+            BerDecodeErrorKind::MismatchedTag { .. }
+        ));
+
+        // Values that extend past the value containing them are malformed.
+        assert!(matches!(
+            ber_kind(decode::<(bool,)>(&[0x30, 0x03, 0x01, 0x05, 0xFF]).unwrap_err()),
+            BerDecodeErrorKind::LengthExceedsContainer
+        ));
+        assert!(matches!(
+            ber_kind(decode::<OctetString>(&[0x24, 0x03, 0x04, 0x05, 0xAA]).unwrap_err()),
+            BerDecodeErrorKind::LengthExceedsContainer
+        ));
+        assert!(matches!(
+            ber_kind(decode::<BitString>(&[0x23, 0x03, 0x03, 0x05, 0x00]).unwrap_err()),
+            BerDecodeErrorKind::LengthExceedsContainer
+        ));
+    }
+
+    #[test]
+    fn incomplete_or_malformed_fields() {
+        use crate::error::{BerDecodeErrorKind, CodecDecodeError};
+
+        #[derive(AsnType, Debug, Decode, PartialEq)]
+        struct Inner {
+            value: OctetString,
+        }
+
+        #[derive(AsnType, Debug, Decode, PartialEq)]
+        struct Outer {
+            id: Integer,
+            inner: Inner,
+        }
+
+        let data = &[
+            0x30, 0x0A, 0x02, 0x01, 0x05, 0x30, 0x05, 0x04, 0x03, 0xAA, 0xBB, 0xCC,
+        ];
+        assert!(decode::<Outer>(data).is_ok());
+
+        // Truncated anywhere, more input could complete the value.
+        for length in 0..data.len() {
+            assert!(
+                decode::<Outer>(&data[..length])
+                    .unwrap_err()
+                    .is_incomplete(),
+                "truncated to {length} bytes"
+            );
+        }
+
+        // The outer SEQUENCE is complete, but the OCTET STRING claims more
+        // octets than the inner SEQUENCE contains.
+        let data = &[
+            0x30, 0x09, 0x02, 0x01, 0x05, 0x30, 0x04, 0x04, 0x03, 0xAA, 0xBB,
+        ];
+        let error = decode::<Outer>(data).unwrap_err();
+        assert!(!error.is_incomplete());
+        assert_eq!(Some("Outer.inner.value".into()), error.field_path());
+        assert!(matches!(
+            &*error.root_cause().kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: CodecDecodeError::Ber(BerDecodeErrorKind::LengthExceedsContainer),
+            }
+        ));
+    }
+
+    #[test]
+    fn tuples() {
+        let pair: (Integer, OctetString) = (5.into(), OctetString::from_static(b"ab"));
+        let encoded = &[0x30, 0x07, 0x02, 0x01, 0x05, 0x04, 0x02, b'a', b'b'][..];
+        assert_eq!(encoded, encode(&pair).unwrap());
+        assert_eq!(pair, decode::<(Integer, OctetString)>(encoded).unwrap());
+
+        let mut quad: (bool, Utf8String, Option<Integer>, OctetString) =
+            (true, "hi".into(), None, OctetString::default());
+        let encoded = &[
+            0x30, 0x09, 0x01, 0x01, 0xFF, 0x0C, 0x02, b'h', b'i', 0x04, 0x00,
+        ][..];
+        assert_eq!(encoded, encode(&quad).unwrap());
+        assert_eq!(quad, decode(encoded).unwrap());
+
+        quad.2 = Some(3.into());
+        let encoded = &[
+            0x30, 0x0C, 0x01, 0x01, 0xFF, 0x0C, 0x02, b'h', b'i', 0x02, 0x01, 0x03, 0x04, 0x00,
+        ][..];
+        assert_eq!(encoded, encode(&quad).unwrap());
+        assert_eq!(quad, decode(encoded).unwrap());
+
+        // Elements left over after the last one are an error.
+        let encoded = &[
+            0x30, 0x09, 0x02, 0x01, 0x05, 0x04, 0x02, b'a', b'b', 0x05, 0x00,
+        ][..];
+        assert!(matches!(
+            &*decode::<(Integer, OctetString)>(encoded).unwrap_err().kind,
+            DecodeErrorKind::UnexpectedExtraData { .. }
+        ));
+    }
+
+    #[test]
+    fn non_minimal_length() {
+        for data in [&[0x02, 0x01, 0x05][..], &[0x02, 0x82, 0x00, 0x01, 0x05]] {
+            assert_eq!(5, decode::<u8>(data).unwrap());
+        }
+        assert_eq!(5, crate::der::decode::<u8>(&[0x02, 0x01, 0x05]).unwrap());
+        assert!(matches!(
+            &*crate::der::decode::<u8>(&[0x02, 0x82, 0x00, 0x01, 0x05])
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Der(
+                    crate::error::DerDecodeErrorKind::NonMinimalLength
+                )
+            }
+        ));
+
+        let minimal = &[0x30, 0x03, 0x02, 0x01, 0x05];
+        let redundant = &[0x30, 0x81, 0x03, 0x02, 0x01, 0x05];
+        assert_eq!(vec![5u8], decode::<Vec<u8>>(minimal).unwrap());
+        assert_eq!(vec![5u8], decode::<Vec<u8>>(redundant).unwrap());
+        assert_eq!(vec![5u8], crate::der::decode::<Vec<u8>>(minimal).unwrap());
+        assert!(crate::der::decode::<Vec<u8>>(redundant).is_err());
+    }
+
+    #[test]
+    fn trailing_data() {
+        let data = &[0x02, 0x01, 0x05, 0x02, 0x02, 0x01, 0x00];
+
+        let (first, remainder) = crate::ber::decode_with_remainder::<u16>(data).unwrap();
+        assert_eq!(5, first);
+        assert_eq!(&data[3..], remainder);
+        let (second, remainder) = crate::ber::decode_with_remainder::<u16>(remainder).unwrap();
+        assert_eq!(256, second);
+        assert!(remainder.is_empty());
+
+        for error in [
+            decode::<u16>(data).unwrap_err(),
+            crate::der::decode::<u16>(data).unwrap_err(),
+            crate::cer::decode::<u16>(data).unwrap_err(),
+        ] {
+            assert!(matches!(
+                &*error.kind,
+                DecodeErrorKind::UnexpectedExtraData { length: 4 }
+            ));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decode_from_reader() {
+        let mut reader = std::io::Cursor::new(vec![
+            0x02, 0x01, 0x05, // INTEGER 5
+            0x24, 0x80, 0x04, 0x01, 0xAA, 0x04, 0x01, 0xBB, 0x00, 0x00, // OCTET STRING
+            0x9F, 0x87, 0x68, 0x81, 0x01, 0xFF, // [1000] IMPLICIT BOOLEAN
+        ]);
+
+        assert_eq!(
+            5,
+            crate::ber::decode_from_reader::<u8, _>(&mut reader).unwrap()
+        );
+        assert_eq!(3, reader.position());
+        assert_eq!(
+            OctetString::from_static(&[0xAA, 0xBB]),
+            crate::ber::decode_from_reader::<OctetString, _>(&mut reader).unwrap()
+        );
+        assert_eq!(13, reader.position());
+        assert_eq!(
+            &[0x9F, 0x87, 0x68, 0x81, 0x01, 0xFF][..],
+            crate::ber::decode_from_reader::<Any, _>(&mut reader)
+                .unwrap()
+                .as_bytes()
+        );
+
+        assert!(matches!(
+            &*crate::ber::decode_from_reader::<u8, _>(&mut reader)
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::Io { .. }
+        ));
+
+        // A tag number with more continuation octets than fit in a `u32` is
+        // rejected without reading any further.
+        let mut reader = std::io::Cursor::new(vec![0x1F, 0x81, 0x81, 0x81, 0x81, 0x81, 0x01]);
+        assert!(matches!(
+            &*crate::ber::decode_from_reader::<u8, _>(&mut reader)
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::IntegerOverflow { max_width: 32 }
+        ));
+        assert_eq!(6, reader.position());
+    }
+
+    #[test]
+    fn encode_buf_reuses_buffer() {
+        let values: Vec<u32> = (0..100_000).collect();
+        let expected = encode(&values).unwrap();
+
+        let mut buffer = Vec::with_capacity(expected.len());
+        crate::ber::encode_buf(&values, &mut buffer).unwrap();
+        assert_eq!(expected, buffer);
+        // The encoding was written straight into the supplied buffer.
+        assert_eq!(expected.len(), buffer.capacity());
+
+        let pointer = buffer.as_ptr();
+        buffer.clear();
+        crate::ber::encode_buf(&values, &mut buffer).unwrap();
+        assert_eq!(expected, buffer);
+        assert_eq!(pointer, buffer.as_ptr());
+        assert_eq!(expected.len(), buffer.capacity());
+
+        // Appends to existing contents, and leaves them alone on failure.
+        let mut buffer = vec![0xAA];
+        crate::ber::encode_buf(&true, &mut buffer).unwrap();
+        assert_eq!(vec![0xAA, 0x01, 0x01, 0xFF], buffer);
+        let invalid = ObjectIdentifier::new_unchecked(vec![3, 1].into());
+        assert!(crate::ber::encode_buf(&vec![invalid], &mut buffer).is_err());
+        assert_eq!(vec![0xAA, 0x01, 0x01, 0xFF], buffer);
+    }
+
+    #[test]
+    fn encode_into_slice() {
+        use crate as rasn;
+        use rasn::prelude::*;
+        #[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+        struct Reading {
+            sensor: u8,
+            value: Integer,
+            valid: bool,
+        }
+
+        let reading = Reading {
+            sensor: 7,
+            value: Integer::from(-300),
+            valid: true,
+        };
+        let expected = [
+            0x30, 0x0A, 0x02, 0x01, 0x07, 0x02, 0x02, 0xFE, 0xD4, 0x01, 0x01, 0xFF,
+        ];
+        assert_eq!(expected.len(), reading.encoded_len().unwrap());
+
+        let mut buffer = [0; 12];
+        assert_eq!(12, encode_into(&reading, &mut buffer).unwrap());
+        assert_eq!(expected, buffer);
+
+        let mut buffer = [0xAA; 11];
+        let error = encode_into(&reading, &mut buffer).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            crate::error::EncodeErrorKind::BufferTooSmall { needed: 12 }
+        ));
+        assert_eq!([0xAA; 11], buffer);
+
+        // Anything after the encoding is left alone.
+        let mut buffer = [0xAA; 16];
+        assert_eq!(12, encode_into(&reading, &mut buffer).unwrap());
+        assert_eq!(expected, buffer[..12]);
+        assert_eq!([0xAA; 4], buffer[12..]);
+    }
+
+    #[test]
+    fn sequence_of_iter() {
+        use crate as rasn;
+        use rasn::prelude::*;
+        #[derive(AsnType, Clone, Decode, Encode, Debug, PartialEq)]
+        struct Record {
+            id: u32,
+            message: Utf8String,
+        }
+
+        let records = (0..10_000).map(|id| Record {
+            id,
+            message: alloc::format!("record {id}"),
+        });
+        let expected = records.clone().collect::<SequenceOf<_>>();
+        let records = SequenceOfIter(records);
+
+        for encoded in [
+            encode(&records).unwrap(),
+            crate::cer::encode(&records).unwrap(),
+            crate::der::encode(&records).unwrap(),
+        ] {
+            assert_eq!(expected, decode::<SequenceOf<Record>>(&encoded).unwrap());
+        }
+        assert_eq!(encode(&expected).unwrap(), encode(&records).unwrap());
+        assert_eq!(
+            crate::cer::encode(&expected).unwrap(),
+            crate::cer::encode(&records).unwrap()
+        );
+
+        // Other codecs collect the elements first.
+        let encoded = crate::uper::encode(&records).unwrap();
+        assert_eq!(crate::uper::encode(&expected).unwrap(), encoded);
+        assert_eq!(
+            expected,
+            crate::uper::decode::<SequenceOf<Record>>(&encoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn decode_sequence_of_with() {
+        use crate::{Decode, Decoder as _};
+        use core::ops::ControlFlow;
+
+        fn new_decoder(encoded: &[u8]) -> crate::ber::de::Decoder<'_> {
+            crate::ber::de::Decoder::new(encoded, crate::ber::de::DecoderOptions::ber())
+        }
+
+        // Elements are passed on as they are decoded, without collecting them.
+        let encoded = encode(&SequenceOfIter(0..10_000u32)).unwrap();
+        let mut decoder = new_decoder(&encoded);
+        let (mut count, mut sum) = (0, 0u64);
+        decoder
+            .decode_sequence_of_with(Tag::SEQUENCE, Constraints::default(), |value: u32| {
+                count += 1;
+                sum += u64::from(value);
+                Ok(ControlFlow::Continue(()))
+            })
+            .unwrap();
+        assert_eq!((10_000, 49_995_000), (count, sum));
+        assert!(decoder.remaining().is_empty());
+
+        // Stopping early skips the rest of the value, whether its length is
+        // definite or indefinite.
+        let values = SequenceOfIter(0..10u32);
+        for mut encoded in [
+            encode(&values).unwrap(),
+            crate::cer::encode(&values).unwrap(),
+        ] {
+            encoded.extend_from_slice(&[0x01, 0x01, 0xFF]);
+            let mut decoder = new_decoder(&encoded);
+            let mut seen = Vec::new();
+            decoder
+                .decode_sequence_of_with(Tag::SEQUENCE, Constraints::default(), |value: u32| {
+                    seen.push(value);
+                    Ok(if seen.len() == 3 {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    })
+                })
+                .unwrap();
+            assert_eq!(vec![0, 1, 2], seen);
+            assert_eq!(&[0x01, 0x01, 0xFF], decoder.remaining());
+            assert!(bool::decode(&mut decoder).unwrap());
+        }
+
+        let encoded = crate::der::encode(&SetOf::from_vec(vec![3u8, 1, 2])).unwrap();
+        let mut decoder = new_decoder(&encoded);
+        let mut values = alloc::collections::BTreeSet::new();
+        decoder
+            .decode_set_of_with(Tag::SET, Constraints::default(), |value: u8| {
+                values.insert(value);
+                Ok(ControlFlow::Continue(()))
+            })
+            .unwrap();
+        assert_eq!(alloc::collections::BTreeSet::from([1, 2, 3]), values);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encode_to_writer() {
+        let mut output = Vec::new();
+        crate::ber::encode_to_writer(&5u8, &mut output).unwrap();
+        crate::ber::encode_to_writer(&true, &mut output).unwrap();
+        assert_eq!(vec![0x02, 0x01, 0x05, 0x01, 0x01, 0xFF], output);
+
+        // The first error writing is returned.
+        let mut output = [0; 4];
+        let error =
+            crate::ber::encode_to_writer(&vec![true, false], &mut &mut output[..]).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            crate::error::EncodeErrorKind::Io { .. }
+        ));
+        assert_eq!([0x30, 0x06, 0x01, 0x01], output);
+    }
+
+    /// Values written front to back, after their lengths are counted, are
+    /// the same as ones built up in a buffer.
+    #[test]
+    fn streamed_encodings() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Encode)]
+        #[rasn(set)]
+        struct Flags {
+            #[rasn(tag(1))]
+            second: bool,
+            #[rasn(tag(0))]
+            first: bool,
+        }
+
+        #[derive(AsnType, Encode)]
+        struct Record {
+            #[rasn(tag(explicit(context, 40)))]
+            name: Utf8String,
+            payloads: SequenceOf<SequenceOf<OctetString>>,
+            flags: Flags,
+            any: Any,
+        }
+
+        let record = Record {
+            name: "long".repeat(40),
+            payloads: vec![
+                vec![OctetString::from(vec![1; 300]), OctetString::default()],
+                Vec::new(),
+            ],
+            flags: Flags {
+                second: true,
+                first: false,
+            },
+            any: Any::new(vec![0x05, 0x00]),
+        };
+        let expected = encode(&record).unwrap();
+        assert_eq!(expected.len(), record.encoded_len().unwrap());
+
+        let mut buffer = vec![0xAA; expected.len() + 1];
+        assert_eq!(
+            expected.len(),
+            super::encode_into(&record, &mut buffer).unwrap()
+        );
+        assert_eq!(expected, buffer[..expected.len()]);
+        assert_eq!(0xAA, buffer[expected.len()]);
+
+        #[cfg(feature = "std")]
+        {
+            let mut output = Vec::new();
+            super::encode_to_writer(&record, &mut output).unwrap();
+            assert_eq!(expected, output);
+        }
+    }
+
+    #[test]
+    fn error_offset() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, PartialEq)]
+        struct Triple {
+            first: u8,
+            second: bool,
+            third: u8,
+        }
+
+        #[derive(AsnType, Debug, Decode, PartialEq)]
+        struct Wrapper {
+            #[rasn(tag(explicit(0)))]
+            triple: Triple,
+        }
+
+        // Invalid contents in the third element.
+        let data = [0x30, 0x08, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x02, 0x00];
+        assert_eq!(Some(8), decode::<Triple>(&data).unwrap_err().offset);
+        // Unexpected tag for the third element.
+        let data = [0x30, 0x08, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x04, 0x00];
+        assert_eq!(Some(8), decode::<Triple>(&data).unwrap_err().offset);
+        // Truncated third element.
+        let data = [0x30, 0x08, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x02, 0x03];
+        assert_eq!(Some(8), decode::<Triple>(&data).unwrap_err().offset);
+
+        // Offsets stay relative to the original input inside explicit tags.
+        let data = [
+            0x30, 0x0E, 0xA0, 0x0C, 0x30, 0x0A, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x02, 0x02,
+            0x00, 0x01,
+        ];
+        assert_eq!(
+            Some(12),
+            crate::der::decode::<Wrapper>(&data).unwrap_err().offset
+        );
+        assert!(decode::<Wrapper>(&data).is_ok());
+    }
+
+    #[test]
+    fn derived_set_in_any_order() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(set)]
+        struct Record {
+            #[rasn(tag(0))]
+            id: u8,
+            #[rasn(tag(1))]
+            note: Option<Utf8String>,
+            #[rasn(tag(2))]
+            flag: bool,
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(set)]
+        #[non_exhaustive]
+        struct ExtensibleRecord {
+            #[rasn(tag(0))]
+            id: u8,
+        }
+
+        let expected = Record {
+            id: 7,
+            note: None,
+            flag: true,
+        };
+        let ordered = [0x31, 0x06, 0x80, 0x01, 0x07, 0x82, 0x01, 0xFF];
+        let reversed = [0x31, 0x06, 0x82, 0x01, 0xFF, 0x80, 0x01, 0x07];
+
+        assert_eq!(ordered, &*encode(&expected).unwrap());
+        assert_eq!(expected, decode::<Record>(&ordered).unwrap());
+        assert_eq!(expected, decode::<Record>(&reversed).unwrap());
+        assert_eq!(expected, crate::der::decode::<Record>(&ordered).unwrap());
+        assert!(matches!(
+            &*crate::der::decode::<Record>(&reversed).unwrap_err().kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(
+                    crate::error::BerDecodeErrorKind::UnorderedSetComponent { .. }
+                )
+            }
+        ));
+
+        // Duplicate components.
+        let duplicate = [
+            0x31, 0x09, 0x80, 0x01, 0x07, 0x82, 0x01, 0xFF, 0x80, 0x01, 0x08,
+        ];
+        assert!(decode::<Record>(&duplicate).is_err());
+        // Missing required component.
+        assert!(decode::<Record>(&[0x31, 0x03, 0x82, 0x01, 0xFF]).is_err());
+        // Unknown components are only skipped when the set is extensible.
+        let unknown = [0x31, 0x06, 0x83, 0x01, 0x00, 0x80, 0x01, 0x07];
+        assert!(decode::<Record>(&unknown).is_err());
+        assert_eq!(
+            ExtensibleRecord { id: 7 },
+            decode::<ExtensibleRecord>(&unknown).unwrap()
+        );
+    }
+
+    #[test]
+    fn der_set_of_sorted_by_encoding() {
+        // Ordered by value these are -129, -1, 1, 128, which differs from the
+        // order of their encodings.
+        let values = SetOf::from_vec(vec![128i32, -1, -129, 1]);
+        let expected = [
+            0x31, 0x0E, 0x02, 0x01, 0x01, 0x02, 0x01, 0xFF, 0x02, 0x02, 0x00, 0x80, 0x02, 0x02,
+            0xFF, 0x7F,
+        ];
+
+        assert_eq!(&expected[..], crate::der::encode(&values).unwrap());
+        assert_eq!(
+            &expected[..],
+            crate::der::encode(&SetOf::from_vec(vec![1i32, -129, 128, -1])).unwrap()
+        );
+        assert_eq!(values, crate::der::decode::<SetOf<i32>>(&expected).unwrap());
+    }
+
+    #[test]
+    fn set_vec_keeps_order_and_duplicates() {
+        let values = SetVec::from(vec![128i32, -1, 128, 1]);
+        let received = [
+            0x31, 0x0E, 0x02, 0x02, 0x00, 0x80, 0x02, 0x01, 0xFF, 0x02, 0x02, 0x00, 0x80, 0x02,
+            0x01, 0x01,
+        ];
+
+        assert_eq!(&received[..], encode(&values).unwrap());
+        let decoded = decode::<SetVec<i32>>(&received).unwrap();
+        assert_eq!(values, decoded);
+        assert_eq!(&received[..], encode(&decoded).unwrap());
+
+        let sorted = [
+            0x31, 0x0E, 0x02, 0x01, 0x01, 0x02, 0x01, 0xFF, 0x02, 0x02, 0x00, 0x80, 0x02, 0x02,
+            0x00, 0x80,
+        ];
+        assert_eq!(&sorted[..], crate::der::encode(&values).unwrap());
+        assert_eq!(
+            crate::der::encode(&SetOf::from_vec(vec![128i32, -1, 128, 1])).unwrap(),
+            crate::der::encode(&values).unwrap()
+        );
+    }
 }
@@ -1,7 +1,11 @@
 //! # Decoding BER
 
+#[cfg(feature = "tokio")]
+pub(crate) mod async_reader;
 mod config;
-pub(super) mod parser;
+pub(crate) mod parser;
+#[cfg(feature = "std")]
+pub(crate) mod reader;
 
 use super::identifier::Identifier;
 use crate::{
@@ -13,7 +17,7 @@ use crate::{
     Decode,
 };
 use alloc::{borrow::Cow, borrow::ToOwned, string::ToString, vec::Vec};
-use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use core::ops::ControlFlow;
 use parser::ParseNumberError;
 
 pub use self::config::DecoderOptions;
@@ -29,6 +33,14 @@ pub struct Decoder<'input> {
     input: &'input [u8],
     config: DecoderOptions,
     initial_len: usize,
+    depth: usize,
+    /// Offset of `input` from the start of the original input.
+    offset: usize,
+    /// Offset of the value most recently parsed by this decoder.
+    value_offset: usize,
+    /// The buffer `input` is a view of, when decoding from shared bytes.
+    #[cfg(not(feature = "arc-slice"))]
+    source: Option<bytes::Bytes>,
 }
 
 impl<'input> Decoder<'input> {
@@ -49,15 +61,96 @@ impl<'input> Decoder<'input> {
             input,
             config,
             initial_len: input.len(),
+            depth: 0,
+            offset: 0,
+            value_offset: 0,
+            #[cfg(not(feature = "arc-slice"))]
+            source: None,
         }
     }
 
+    /// Create a new [`Decoder`] from the given shared `input` and `config`.
+    /// Primitive `OCTET STRING`s decoded as [`types::OctetString`] are views
+    /// of `input` rather than copies.
+    #[cfg(not(feature = "arc-slice"))]
+    #[must_use]
+    pub fn from_bytes(input: &'input bytes::Bytes, config: DecoderOptions) -> Self {
+        Self {
+            source: Some(input.clone()),
+            ..Self::new(input, config)
+        }
+    }
+
+    /// Checks that another element can be added to a collection already
+    /// holding `count` elements.
+    fn check_element_count(&self, count: usize) -> Result<()> {
+        match self.config.max_elements {
+            Some(limit) if count >= limit => {
+                Err(BerDecodeErrorKind::TooManyElements { limit }.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether another element of a `SEQUENCE OF` or `SET OF` follows,
+    /// rather than the end of the contents or an end-of-contents marker,
+    /// whose first octet is never the start of a value.
+    fn has_element(&self) -> bool {
+        self.input.first().is_some_and(|&octet| octet != 0)
+    }
+
+    /// Creates a decoder for the contents of a constructed value nested
+    /// inside the current one, starting at `offset` in the original input.
+    fn nested(&self, input: &'input [u8], offset: usize) -> Result<Self> {
+        if self.depth >= self.config.max_depth {
+            return Err(BerDecodeErrorKind::NestingTooDeep {
+                limit: self.config.max_depth,
+            }
+            .into());
+        }
+
+        Ok(Self {
+            depth: self.depth + 1,
+            offset,
+            value_offset: offset,
+            #[cfg(not(feature = "arc-slice"))]
+            source: self.source.clone(),
+            ..Self::new(input, self.config)
+        })
+    }
+
+    /// Returns the identifier of the next value without consuming any input.
+    pub fn peek_identifier(&self) -> Result<Identifier> {
+        parser::parse_identifier_octet(self.config, self.input)
+            .map(|(_, identifier)| identifier)
+            .map_err(|e| e.into_decode_error(self.codec()))
+            .map_err(|error| error.with_offset(self.position()))
+    }
+
+    /// Returns the tag of the next value without consuming any input, so
+    /// that callers can choose how to decode it.
+    pub fn peek_tag(&self) -> Result<Tag> {
+        self.peek_identifier().map(|identifier| identifier.tag)
+    }
+
     /// Return a number of the decoded bytes by this decoder
     #[must_use]
     pub fn decoded_len(&self) -> usize {
         self.initial_len - self.input.len()
     }
 
+    /// Returns the offset of the remaining input from the start of the
+    /// original input.
+    fn position(&self) -> usize {
+        self.offset + self.decoded_len()
+    }
+
+    /// Records the offset of the value that was being decoded in `error`,
+    /// if no more precise offset is known.
+    pub(crate) fn locate_error(&self, error: DecodeError) -> DecodeError {
+        error.with_offset(self.value_offset)
+    }
+
     fn parse_eoc(&mut self) -> Result<()> {
         let (i, _) = nom::bytes::streaming::tag(EOC)(self.input)
             .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
@@ -66,19 +159,27 @@ impl<'input> Decoder<'input> {
     }
 
     pub(crate) fn parse_value(&mut self, tag: Tag) -> Result<(Identifier, Option<&'input [u8]>)> {
+        self.value_offset = self.position();
         let (input, (identifier, contents)) =
-            self::parser::parse_value(self.config, self.input, Some(tag))?;
+            self::parser::parse_value(self.config, self.input, Some(tag))
+                .map_err(|error| self.locate_error(error))?;
         self.input = input;
         Ok((identifier, contents))
     }
 
-    pub(crate) fn parse_primitive_value(&mut self, tag: Tag) -> Result<(Identifier, &'input [u8])> {
-        let (input, (identifier, contents)) =
-            self::parser::parse_value(self.config, self.input, Some(tag))?;
-        self.input = input;
-        match contents {
-            Some(contents) => Ok((identifier, contents)),
-            None => Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into()),
+    /// Parses a value that must be encoded in primitive form, returning its
+    /// contents. X.690 requires this of e.g. `BOOLEAN`, `INTEGER` and `NULL`
+    /// under every encoding rule set, and borrowed strings can't be
+    /// assembled from constructed segments.
+    pub(crate) fn parse_primitive_value(&mut self, tag: Tag) -> Result<&'input [u8]> {
+        match self.parse_value(tag)? {
+            (identifier, _) if identifier.is_constructed() => {
+                Err(self.locate_error(BerDecodeErrorKind::InvalidConstructedIdentifier.into()))
+            }
+            (_, Some(contents)) => Ok(contents),
+            (_, None) => {
+                Err(self.locate_error(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into()))
+            }
         }
     }
 
@@ -107,36 +208,207 @@ impl<'input> Decoder<'input> {
             None => (true, self.input),
         };
 
-        let mut inner = Self::new(contents, self.config);
+        let offset = self.position() - if streaming { 0 } else { contents.len() };
+        let mut inner = self
+            .nested(contents, offset)
+            .map_err(|error| self.locate_error(error))?;
 
-        let result = (decode_fn)(&mut inner)?;
+        let result = (decode_fn)(&mut inner).map_err(|error| {
+            let error = inner.locate_error(error);
+            if streaming {
+                error
+            } else {
+                parser::within_definite_length(error)
+            }
+        })?;
 
         if streaming {
             self.input = inner.input;
-            self.parse_eoc()?;
+            self.parse_eoc()
+                .map_err(|error| error.with_offset(self.position()))?;
         } else if !inner.input.is_empty() {
-            return Err(DecodeError::unexpected_extra_data(
-                inner.input.len(),
-                self.codec(),
-            ));
+            return Err(
+                DecodeError::unexpected_extra_data(inner.input.len(), self.codec())
+                    .with_offset(inner.position()),
+            );
         }
 
         Ok(result)
     }
+
+    /// Decodes the contents of a `REAL` value in any of its binary, decimal
+    /// or special value forms.
+    fn decode_real_from_bytes(&self, contents: &[u8]) -> Result<f64> {
+        let invalid = || DecodeError::from_kind(DecodeErrorKind::InvalidRealEncoding, self.codec());
+
+        let Some((&first, rest)) = contents.split_first() else {
+            return Ok(0.0);
+        };
+
+        match first & 0xC0 {
+            0x00 => self.decode_decimal_real(first, rest),
+            0x40 => match (first, rest.is_empty()) {
+                (0x40, true) => Ok(f64::INFINITY),
+                (0x41, true) => Ok(f64::NEG_INFINITY),
+                (0x42, true) => Ok(f64::NAN),
+                (0x43, true) => Ok(-0.0),
+                _ => Err(invalid()),
+            },
+            _ => {
+                let shift = match (first >> 4) & 0x03 {
+                    0 => 1,
+                    1 => 3,
+                    2 => 4,
+                    _ => return Err(invalid()),
+                };
+                let scale = i64::from((first >> 2) & 0x03);
+                let (exponent_length, rest) = match first & 0x03 {
+                    3 => {
+                        let (&length, rest) = rest.split_first().ok_or_else(invalid)?;
+                        (usize::from(length), rest)
+                    }
+                    length => (usize::from(length) + 1, rest),
+                };
+
+                if exponent_length == 0 || exponent_length > 8 || rest.len() <= exponent_length {
+                    return Err(invalid());
+                }
+                let (exponent, mantissa) = rest.split_at(exponent_length);
+                if !self.config.encoding_rules.is_ber() {
+                    Self::check_canonical_binary_real(first, exponent, mantissa)?;
+                }
+
+                let exponent = exponent
+                    .iter()
+                    .fold(if exponent[0] & 0x80 == 0 { 0 } else { -1 }, |acc, byte| {
+                        (acc << 8) | i64::from(*byte)
+                    });
+                let mantissa = mantissa
+                    .iter()
+                    .fold(0.0, |acc, byte| acc * 256.0 + f64::from(*byte));
+
+                let mut value = mantissa;
+                let mut power = exponent.saturating_mul(shift).saturating_add(scale);
+                // Scale in steps that can't overflow on their own, so that a
+                // small mantissa with a large exponent is still exact.
+                while power != 0 && value != 0.0 && value.is_finite() {
+                    let step = power.clamp(-512, 512);
+                    value *= num_traits::float::FloatCore::powi(2.0f64, step as i32);
+                    power -= step;
+                }
+
+                Ok(if first & 0x40 == 0 { value } else { -value })
+            }
+        }
+    }
+
+    /// Checks the rules X.690 11.3.1 adds to binary `REAL` values under CER
+    /// and DER: base 2, a scaling factor of zero, an odd mantissa without
+    /// leading zero octets, and an exponent in the fewest octets.
+    fn check_canonical_binary_real(first: u8, exponent: &[u8], mantissa: &[u8]) -> Result<()> {
+        let rule = if first & 0x30 != 0 {
+            "the base must be 2"
+        } else if first & 0x0C != 0 {
+            "the scaling factor must be zero"
+        } else if mantissa[mantissa.len() - 1] & 1 == 0 {
+            "the mantissa must be odd"
+        } else if mantissa[0] == 0 {
+            "the mantissa must not have leading zero octets"
+        } else if (first & 0x03 == 0x03 && exponent.len() <= 3)
+            || (exponent.len() > 1
+                && matches!((exponent[0], exponent[1] & 0x80), (0x00, 0) | (0xFF, 0x80)))
+        {
+            "the exponent must be encoded in the fewest octets"
+        } else {
+            return Ok(());
+        };
+
+        Err(DerDecodeErrorKind::NonCanonicalReal { rule }.into())
+    }
+
+    /// Decodes the text of a decimal `REAL` value in one of the ISO 6093
+    /// NR1, NR2 or NR3 forms indicated by `form`. BER accepts any of the
+    /// forms regardless of the indicator, while CER and DER require NR3.
+    fn decode_decimal_real(&self, form: u8, text: &[u8]) -> Result<f64> {
+        let invalid_text = || {
+            DecodeError::from(BerDecodeErrorKind::InvalidDecimalReal {
+                text: alloc::string::String::from_utf8_lossy(text).into_owned(),
+            })
+        };
+
+        if !(1..=3).contains(&form) || (!self.config.encoding_rules.is_ber() && form != 3) {
+            return Err(invalid_text());
+        }
+
+        let number = core::str::from_utf8(text).map_err(|_| invalid_text())?;
+        let number = number.trim_start_matches(' ');
+        let (mantissa, exponent) = match number.find(['E', 'e']) {
+            Some(index) => (&number[..index], Some(&number[index + 1..])),
+            None => (number, None),
+        };
+
+        let unsigned = |s: &str| s.strip_prefix(['+', '-']).unwrap_or(s);
+        let is_digits = |s: &str| s.bytes().all(|byte| byte.is_ascii_digit());
+        let (integer, fraction) = unsigned(mantissa)
+            .split_once(['.', ','])
+            .unwrap_or((unsigned(mantissa), ""));
+        let valid_mantissa = is_digits(integer)
+            && is_digits(fraction)
+            && !(integer.is_empty() && fraction.is_empty());
+        let valid_exponent = exponent.is_none_or(|exponent| {
+            let exponent = unsigned(exponent);
+            !exponent.is_empty() && is_digits(exponent)
+        });
+
+        if !valid_mantissa || !valid_exponent {
+            return Err(invalid_text());
+        }
+
+        number.replace(',', ".").parse().map_err(|_| invalid_text())
+    }
+
     /// Decode an object identifier from a byte slice in BER format.
     /// Function is public to be used by other codecs.
     pub fn decode_object_identifier_from_bytes(
         &self,
         data: &[u8],
     ) -> Result<crate::types::ObjectIdentifier, DecodeError> {
-        let (mut contents, root_octets) =
-            parser::parse_base128_number(data).map_err(|e| match e {
-                ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
-                ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
-            })?;
-        let first: u32;
-        let second: u32;
-        const MAX_OID_THRESHOLD: u32 = MAX_OID_SECOND_OCTET + 1;
+        let (mut contents, first, second) = self.parse_object_identifier_root(data)?;
+
+        // Typical identifiers fit inline, longer ones grow on the heap one arc
+        // at a time rather than trusting the length of malformed or malicious
+        // packets.
+        let mut arcs = crate::types::oid::Arcs::new();
+        arcs.push(first);
+        arcs.push(second);
+
+        while !contents.is_empty() {
+            if let Some(limit) = self.config.max_oid_arcs {
+                if arcs.len() >= limit {
+                    return Err(BerDecodeErrorKind::TooManyOidArcs { limit }.into());
+                }
+            }
+            let (c, number) = self.parse_object_identifier_arc(contents)?;
+            contents = c;
+            arcs.push(number);
+        }
+        crate::types::ObjectIdentifier::from_arcs(arcs)
+            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+    }
+
+    /// Parses the first encoded arc of an object identifier, which holds its
+    /// first two arcs, returning the remaining contents and both arcs.
+    fn parse_object_identifier_root<'data>(
+        &self,
+        data: &'data [u8],
+    ) -> Result<(&'data [u8], u128, u128)> {
+        if data.is_empty() {
+            return Err(BerDecodeErrorKind::InvalidObjectIdentifier.into());
+        }
+        let (contents, root_octets) = self.parse_object_identifier_arc(data)?;
+        let first: u128;
+        let second: u128;
+        const MAX_OID_THRESHOLD: u128 = MAX_OID_SECOND_OCTET + 1;
         if root_octets > MAX_OID_FIRST_OCTET * MAX_OID_THRESHOLD + MAX_OID_SECOND_OCTET {
             first = MAX_OID_FIRST_OCTET;
             second = root_octets - MAX_OID_FIRST_OCTET * MAX_OID_THRESHOLD;
@@ -145,171 +417,406 @@ impl<'input> Decoder<'input> {
             first = (root_octets - second) / MAX_OID_THRESHOLD;
         }
 
-        // preallocate some capacity for the OID arcs, maxing out at 16 elements
-        // to prevent excessive preallocation from malformed or malicious
-        // packets
-        let mut buffer = alloc::vec::Vec::with_capacity(core::cmp::min(contents.len() + 2, 16));
-        buffer.push(first);
-        buffer.push(second);
+        Ok((contents, first, second))
+    }
 
-        while !contents.is_empty() {
-            let (c, number) = parser::parse_base128_number(contents).map_err(|e| match e {
-                ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
-                ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
-            })?;
-            contents = c;
-            buffer.push(number);
+    /// Parses a single base-128 arc of an object identifier, which must end
+    /// within `data`. Redundant leading zero septets are skipped under BER,
+    /// and rejected under the canonical encoding rules.
+    fn parse_object_identifier_arc<'data>(&self, data: &'data [u8]) -> Result<(&'data [u8], u128)> {
+        let padding = data.iter().take_while(|&&octet| octet == 0x80).count();
+        if padding > 0 && !self.config.encoding_rules.is_ber() {
+            return Err(BerDecodeErrorKind::InvalidObjectIdentifierArc.into());
         }
-        crate::types::ObjectIdentifier::new(buffer)
-            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+
+        parser::parse_base128_number(&data[padding..]).map_err(|e| match e {
+            ParseNumberError::Overflow => DecodeError::integer_overflow(128u32, self.codec()),
+            _ => BerDecodeErrorKind::InvalidObjectIdentifierArc.into(),
+        })
     }
+
     /// Parse any GeneralizedTime string, allowing for any from ASN.1 definition
-    /// TODO, move to type itself?
     pub fn parse_any_generalized_time_string(
         string: alloc::string::String,
     ) -> Result<types::GeneralizedTime, DecodeError> {
-        // Reference https://obj-sys.com/asn1tutorial/node14.html
-        // If data contains ., 3 decimal places of seconds are expected
-        // If data contains explict Z, result is UTC
-        // If data contains + or -, explicit timezone is given
-        // If neither Z nor + nor -, purely local time is implied
-        // Replace comma with dot for fractional seconds.
-        let mut s = if string.contains(',') {
-            string.replace(',', ".")
-        } else {
-            string
-        };
-        if s.ends_with("Z") {
-            s.pop(); // We default to UTC
-        }
-        // Timezone offset markers are in static location if present
-        let has_offset = s.len() >= 5 && {
-            let bytes = s.as_bytes();
-            bytes[s.len() - 5] == b'+' || bytes[s.len() - 5] == b'-'
-        };
-        let format_candidates: &[&str] = if s.contains('.') {
-            if has_offset {
-                &["%Y%m%d%H%M%S%.f%z", "%Y%m%d%H%M%.f%z", "%Y%m%d%H%.f%z"] // We don't know the count of fractions
-            } else {
-                &["%Y%m%d%H%M%S%.f", "%Y%m%d%H%M%.f", "%Y%m%d%H%.f"] // We don't know the count of fractions
-            }
-        } else if has_offset {
-            match s.len() {
-                // Length including timezone offset (YYYYMMDDHHMMSS+HHMM)
-                19 => &["%Y%m%d%H%M%S%z"],
-                17 => &["%Y%m%d%H%M%z"],
-                15 => &["%Y%m%d%H%z"],
-                _ => &["%Y%m%d%H%M%S%z", "%Y%m%d%H%M%z", "%Y%m%d%H%z"],
-            }
-        } else {
-            // For local times without timezone, default to UTC later
-            match s.len() {
-                8 => &["%Y%m%d"],
-                10 => &["%Y%m%d%H"],
-                12 => &["%Y%m%d%H%M"],
-                14 => &["%Y%m%d%H%M%S"],
-                _ => &[],
-            }
-        };
-        for fmt in format_candidates {
-            if has_offset {
-                if let Ok(dt) = DateTime::parse_from_str(&s, fmt) {
-                    return Ok(dt);
-                }
-            } else if let Ok(dt) = NaiveDateTime::parse_from_str(&s, fmt) {
-                return Ok(dt.and_utc().into());
-            }
-        }
-        Err(BerDecodeErrorKind::invalid_date(s).into())
+        Self::generalized_time(types::DateTime::parse_generalized_time(&string), string)
     }
+
     /// Enforce CER/DER restrictions defined in Section 11.7, strictly raise error on non-compliant
     pub fn parse_canonical_generalized_time_string(
         string: alloc::string::String,
     ) -> Result<types::GeneralizedTime, DecodeError> {
-        let len = string.len();
-        // Helper function to deal with fractions of seconds and without timezone
-        let parse_without_timezone =
-            |string: &str| -> core::result::Result<NaiveDateTime, DecodeError> {
-                let len = string.len();
-                if string.contains('.') {
-                    // https://github.com/chronotope/chrono/issues/238#issuecomment-378737786
-                    NaiveDateTime::parse_from_str(string, "%Y%m%d%H%M%S%.f")
-                        .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()).into())
-                } else if len == 14 {
-                    NaiveDateTime::parse_from_str(string, "%Y%m%d%H%M%S")
-                        .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()).into())
-                } else {
-                    // CER/DER encoding rules don't allow for timezone offset +/
-                    // Or missing seconds/minutes/hours
-                    // Or comma , instead of dot .
-                    // Or local time without timezone
-                    Err(BerDecodeErrorKind::invalid_date(string.to_string()).into())
+        // Name the broken rule for times that BER would accept, so that it's
+        // clear the value is only rejected because of the encoding rules.
+        if let Some(rule) = types::DateTime::non_canonical_generalized_time(&string) {
+            if types::DateTime::parse_generalized_time(&string).is_some() {
+                return Err(DerDecodeErrorKind::NonCanonicalTime {
+                    value: string,
+                    rule,
                 }
-            };
-        if string.ends_with('Z') {
-            let naive = parse_without_timezone(&string[..len - 1])?;
-            Ok(naive.and_utc().into())
-        } else {
-            Err(BerDecodeErrorKind::invalid_date(string.to_string()).into())
+                .into());
+            }
         }
+
+        Self::generalized_time(
+            types::DateTime::parse_canonical_generalized_time(&string),
+            string,
+        )
     }
+
+    fn generalized_time(
+        value: Option<types::DateTime>,
+        string: alloc::string::String,
+    ) -> Result<types::GeneralizedTime, DecodeError> {
+        value
+            .and_then(|value| types::GeneralizedTime::try_from(value).ok())
+            .ok_or_else(|| BerDecodeErrorKind::invalid_date(string).into())
+    }
+
     /// Parse any UTCTime string, can be any from ASN.1 definition
-    /// TODO, move to type itself?
     pub fn parse_any_utc_time_string(
         string: alloc::string::String,
     ) -> Result<types::UtcTime, DecodeError> {
-        // When compared to GeneralizedTime, UTC time has no fractions.
-        let len = string.len();
-        // Largest string, e.g. "820102070000-0500".len() == 17
-        if len > 17 {
-            return Err(BerDecodeErrorKind::invalid_date(string.to_string()).into());
-        }
-        let format = if string.contains('Z') {
-            if len == 11 {
-                "%y%m%d%H%MZ"
-            } else {
-                "%y%m%d%H%M%SZ"
-            }
-        } else if len == 15 {
-            "%y%m%d%H%M%z"
-        } else {
-            "%y%m%d%H%M%S%z"
-        };
-        match len {
-            11 | 13 => {
-                let naive = NaiveDateTime::parse_from_str(&string, format)
-                    .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
-                Ok(naive.and_utc())
-            }
-            15 | 17 => Ok(DateTime::parse_from_str(&string, format)
-                .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?
-                .into()),
-            _ => Err(BerDecodeErrorKind::invalid_date(string.to_string()).into()),
-        }
+        Self::utc_time(types::DateTime::parse_utc_time(&string), &string)
     }
 
     /// Enforce CER/DER restrictions defined in Section 11.8, strictly raise error on non-compliant
     pub fn parse_canonical_utc_time_string(string: &str) -> Result<types::UtcTime, DecodeError> {
-        let len = string.len();
-        if string.ends_with('Z') {
-            let naive = match len {
-                13 => NaiveDateTime::parse_from_str(string, "%y%m%d%H%M%SZ")
-                    .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?,
-                _ => Err(BerDecodeErrorKind::invalid_date(string.to_string()))?,
-            };
-            Ok(naive.and_utc())
-        } else {
-            Err(BerDecodeErrorKind::invalid_date(string.to_string()).into())
+        if let Some(rule) = types::DateTime::non_canonical_utc_time(string) {
+            if types::DateTime::parse_utc_time(string).is_some() {
+                return Err(DerDecodeErrorKind::NonCanonicalTime {
+                    value: string.to_string(),
+                    rule,
+                }
+                .into());
+            }
         }
+
+        Self::utc_time(types::DateTime::parse_canonical_utc_time(string), string)
+    }
+
+    fn utc_time(
+        value: Option<types::DateTime>,
+        string: &str,
+    ) -> Result<types::UtcTime, DecodeError> {
+        value
+            .and_then(|value| types::UtcTime::try_from(value).ok())
+            .ok_or_else(|| BerDecodeErrorKind::invalid_date(string.to_string()).into())
     }
 
     /// X.690 8.26.2 and 11.9 -> YYYYMMDD
+    #[cfg(feature = "chrono")]
     pub fn parse_date_string(string: &str) -> Result<types::Date, DecodeError> {
-        let date = NaiveDate::parse_from_str(string, "%Y%m%d")
+        let date = chrono::NaiveDate::parse_from_str(string, "%Y%m%d")
             .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
 
         Ok(date)
     }
+
+    /// Decodes an OCTET STRING, borrowing its contents from the input when
+    /// it is encoded in primitive form. Constructed encodings are joined into
+    /// an owned buffer.
+    pub fn decode_octet_string_borrowed(&mut self, tag: Tag) -> Result<Cow<'input, [u8]>> {
+        let input = self.input;
+        let (identifier, contents) = self.parse_value(tag)?;
+
+        match contents {
+            Some(contents) if identifier.is_primitive() => Ok(Cow::Borrowed(contents)),
+            _ => {
+                self.input = input;
+                crate::Decoder::decode_octet_string::<Vec<u8>>(self, tag, Constraints::default())
+                    .map(Cow::Owned)
+            }
+        }
+    }
+
+    /// Decodes an OCTET STRING into `buffer` without allocating, returning
+    /// the number of octets written. Constructed encodings are joined in
+    /// `buffer`, and contents that don't fit fail with
+    /// [`DecodeErrorKind::SizeConstraintNotSatisfied`].
+    /// ```
+    /// use rasn::{ber::de::{Decoder, DecoderOptions}, types::Tag};
+    ///
+    /// let mut decoder = Decoder::new(&[0x04, 0x03, 1, 2, 3], DecoderOptions::ber());
+    /// let mut buffer = [0; 16];
+    /// let len = decoder.decode_octet_string_into(Tag::OCTET_STRING, &mut buffer).unwrap();
+    ///
+    /// assert_eq!(&[1, 2, 3], &buffer[..len]);
+    /// ```
+    pub fn decode_octet_string_into(&mut self, tag: Tag, buffer: &mut [u8]) -> Result<usize> {
+        self.value_offset = self.position();
+        let mut written = 0;
+        self.input = self::parser::parse_octet_string_into(
+            self.config,
+            self.input,
+            tag,
+            self.depth,
+            buffer,
+            &mut written,
+        )
+        .map_err(|error| self.locate_error(error))?;
+
+        Ok(written)
+    }
+
+    /// Decodes an OBJECT IDENTIFIER into `arcs` without allocating, returning
+    /// the number of arcs written. Identifiers with more arcs than fit in
+    /// `arcs`, or than [`DecoderOptions::with_max_oid_arcs`] allows, fail with
+    /// [`BerDecodeErrorKind::TooManyOidArcs`].
+    /// ```
+    /// use rasn::{ber::de::{Decoder, DecoderOptions}, types::Tag};
+    ///
+    /// let data = &[0x06, 0x03, 0x2B, 0x06, 0x01];
+    /// let mut decoder = Decoder::new(data, DecoderOptions::ber());
+    /// let mut arcs = [0; 8];
+    /// let len = decoder.decode_object_identifier_into(Tag::OBJECT_IDENTIFIER, &mut arcs).unwrap();
+    ///
+    /// assert_eq!(&[1, 3, 6, 1], &arcs[..len]);
+    /// ```
+    pub fn decode_object_identifier_into(&mut self, tag: Tag, arcs: &mut [u128]) -> Result<usize> {
+        let contents = self.parse_primitive_value(tag)?;
+        let (mut contents, first, second) = self.parse_object_identifier_root(contents)?;
+        let limit = self
+            .config
+            .max_oid_arcs
+            .map_or(arcs.len(), |limit| limit.min(arcs.len()));
+
+        let [first_arc, second_arc, ..] = arcs else {
+            return Err(BerDecodeErrorKind::TooManyOidArcs { limit }.into());
+        };
+        *first_arc = first;
+        *second_arc = second;
+        let mut len = 2;
+
+        while !contents.is_empty() {
+            if len >= limit {
+                return Err(BerDecodeErrorKind::TooManyOidArcs { limit }.into());
+            }
+            let (c, number) = self.parse_object_identifier_arc(contents)?;
+            contents = c;
+            arcs[len] = number;
+            len += 1;
+        }
+
+        Ok(len)
+    }
+
+    /// Decodes a `BIT STRING` into the start of `buffer` instead of a newly
+    /// allocated [`BitString`][types::BitString], returning its length in
+    /// bits. Values in primitive form are copied straight from the input,
+    /// while constructed ones are joined first.
+    /// # Errors
+    /// Returns `DecodeError` if the value isn't a valid `BIT STRING`, or is
+    /// longer than `buffer`.
+    pub fn decode_bit_string_into(
+        &mut self,
+        tag: Tag,
+        buffer: &mut types::BitStr,
+    ) -> Result<usize> {
+        let input = self.input;
+        let (identifier, contents) = self.parse_value(tag)?;
+
+        let joined;
+        let bits = match contents {
+            Some(contents) if identifier.is_primitive() => {
+                let (&unused_bits, bytes) = contents
+                    .split_first()
+                    .ok_or_else(|| DecodeError::unexpected_empty_input(self.codec()))?;
+                let length = (bytes.len() * 8)
+                    .checked_sub(usize::from(unused_bits))
+                    .filter(|_| unused_bits <= 7)
+                    .ok_or_else(|| DecodeError::invalid_bit_string(unused_bits, self.codec()))?;
+                let bits = types::BitStr::from_slice(bytes);
+
+                // CER and DER require the unused bits to be zero.
+                if bits[length..].any() && !self.config.encoding_rules.is_ber() {
+                    return Err(BerDecodeErrorKind::NonZeroBitStringPadding.into());
+                }
+
+                &bits[..length]
+            }
+            _ => {
+                self.input = input;
+                joined = crate::Decoder::decode_bit_string(self, tag, Constraints::default())?;
+                &joined[..]
+            }
+        };
+
+        let Some(target) = buffer.get_mut(..bits.len()) else {
+            return Err(DecodeError::size_constraint_not_satisfied(
+                Some(bits.len()),
+                alloc::format!("0..={}", buffer.len()),
+                self.codec(),
+            ));
+        };
+        target.copy_from_bitslice(bits);
+
+        Ok(bits.len())
+    }
+
+    /// Decodes a `UTF8String`, borrowing its contents from the input when it
+    /// is encoded in primitive form. Constructed encodings are joined into an
+    /// owned string.
+    pub fn decode_utf8_string_borrowed(&mut self, tag: Tag) -> Result<Cow<'input, str>> {
+        let contents = self.decode_octet_string_borrowed(tag)?;
+        Self::utf8_string_from_contents(contents, self.config)
+    }
+
+    /// Converts the contents of a `UTF8String` into a string, replacing
+    /// invalid sequences if lossy conversion is enabled in `config`.
+    fn utf8_string_from_contents(
+        contents: Cow<'_, [u8]>,
+        config: DecoderOptions,
+    ) -> Result<Cow<'_, str>> {
+        let invalid = |error: core::str::Utf8Error| {
+            DecodeError::invalid_utf8(error.valid_up_to(), config.current_codec())
+        };
+
+        match contents {
+            Cow::Borrowed(contents) => match core::str::from_utf8(contents) {
+                Ok(string) => Ok(Cow::Borrowed(string)),
+                Err(_) if config.lossy_utf8 => Ok(alloc::string::String::from_utf8_lossy(contents)),
+                Err(error) => Err(invalid(error)),
+            },
+            Cow::Owned(contents) => match types::Utf8String::from_utf8(contents) {
+                Ok(string) => Ok(Cow::Owned(string)),
+                Err(error) if config.lossy_utf8 => Ok(Cow::Owned(
+                    alloc::string::String::from_utf8_lossy(error.as_bytes()).into_owned(),
+                )),
+                Err(error) => Err(invalid(error.utf8_error())),
+            },
+        }
+    }
+
+    /// Decodes a constructed value with `tag` (such as a `SEQUENCE`), passing
+    /// a decoder for its contents to `decode_fn`. Unlike
+    /// [`crate::Decoder::decode_sequence`] the nested decoder keeps the
+    /// lifetime of the input, so fields can be decoded with
+    /// [`DecodeBorrowed`].
+    pub fn decode_constructed_borrowed<D, F>(&mut self, tag: Tag, decode_fn: F) -> Result<D>
+    where
+        F: FnOnce(&mut Self) -> Result<D>,
+    {
+        self.parse_constructed_contents(tag, true, decode_fn)
+    }
+
+    /// Decodes a value that borrows from the input.
+    pub fn decode_borrowed<T: DecodeBorrowed<'input>>(&mut self) -> Result<T> {
+        T::decode_borrowed(self)
+    }
+
+    /// Decodes a value that borrows from the input, identified by `tag`
+    /// instead of its universal tag.
+    pub fn decode_borrowed_with_tag<T: DecodeBorrowed<'input>>(&mut self, tag: Tag) -> Result<T> {
+        T::decode_borrowed_with_tag(self, tag)
+    }
+
+    /// Decodes a value that borrows from the input if the next value has
+    /// `tag`, and returns `None` otherwise.
+    pub fn decode_optional_borrowed_with_tag<T: DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<T>> {
+        if self.input.is_empty() || self.peek_tag()? != tag {
+            return Ok(None);
+        }
+
+        T::decode_borrowed_with_tag(self, tag).map(Some)
+    }
+
+    /// Decodes a value that borrows from the input, wrapped in an explicit
+    /// tag.
+    pub fn decode_explicit_prefix_borrowed<T: DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<T> {
+        self.parse_constructed_contents(tag, false, T::decode_borrowed)
+    }
+
+    /// Decodes a value that borrows from the input, wrapped in an explicit
+    /// tag, if the next value has `tag`, and returns `None` otherwise.
+    pub fn decode_optional_explicit_prefix_borrowed<T: DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<T>> {
+        if self.input.is_empty() || self.peek_tag()? != tag {
+            return Ok(None);
+        }
+
+        self.decode_explicit_prefix_borrowed(tag).map(Some)
+    }
+}
+
+/// A type that can be decoded from BER while borrowing from the input buffer,
+/// avoiding a copy of its contents.
+///
+/// Values that are encoded in constructed form cannot be borrowed, so types
+/// such as `Cow<'input, [u8]>` fall back to an owned copy in that case, while
+/// plain references return an error.
+pub trait DecodeBorrowed<'input>: Sized {
+    /// Decode this value from `decoder`.
+    fn decode_borrowed(decoder: &mut Decoder<'input>) -> Result<Self>;
+
+    /// Decode this value from `decoder`, identified by `tag` instead of its
+    /// universal tag.
+    fn decode_borrowed_with_tag(decoder: &mut Decoder<'input>, tag: Tag) -> Result<Self>;
+}
+
+impl<'input> DecodeBorrowed<'input> for Cow<'input, [u8]> {
+    fn decode_borrowed(decoder: &mut Decoder<'input>) -> Result<Self> {
+        Self::decode_borrowed_with_tag(decoder, Tag::OCTET_STRING)
+    }
+
+    fn decode_borrowed_with_tag(decoder: &mut Decoder<'input>, tag: Tag) -> Result<Self> {
+        decoder.decode_octet_string_borrowed(tag)
+    }
+}
+
+impl<'input> DecodeBorrowed<'input> for &'input [u8] {
+    fn decode_borrowed(decoder: &mut Decoder<'input>) -> Result<Self> {
+        Self::decode_borrowed_with_tag(decoder, Tag::OCTET_STRING)
+    }
+
+    fn decode_borrowed_with_tag(decoder: &mut Decoder<'input>, tag: Tag) -> Result<Self> {
+        decoder.parse_primitive_value(tag)
+    }
+}
+
+impl<'input> DecodeBorrowed<'input> for types::OctetSlice<'input> {
+    fn decode_borrowed(decoder: &mut Decoder<'input>) -> Result<Self> {
+        Self::decode_borrowed_with_tag(decoder, Tag::OCTET_STRING)
+    }
+
+    fn decode_borrowed_with_tag(decoder: &mut Decoder<'input>, tag: Tag) -> Result<Self> {
+        decoder.parse_primitive_value(tag).map(types::OctetSlice)
+    }
+}
+
+impl<'input> DecodeBorrowed<'input> for Cow<'input, str> {
+    fn decode_borrowed(decoder: &mut Decoder<'input>) -> Result<Self> {
+        Self::decode_borrowed_with_tag(decoder, Tag::UTF8_STRING)
+    }
+
+    fn decode_borrowed_with_tag(decoder: &mut Decoder<'input>, tag: Tag) -> Result<Self> {
+        decoder.decode_utf8_string_borrowed(tag)
+    }
+}
+
+impl<'input> DecodeBorrowed<'input> for &'input str {
+    fn decode_borrowed(decoder: &mut Decoder<'input>) -> Result<Self> {
+        Self::decode_borrowed_with_tag(decoder, Tag::UTF8_STRING)
+    }
+
+    fn decode_borrowed_with_tag(decoder: &mut Decoder<'input>, tag: Tag) -> Result<Self> {
+        let contents = decoder.parse_primitive_value(tag)?;
+        core::str::from_utf8(contents).map_err(|e| {
+            DecodeError::string_conversion_failed(
+                types::Tag::UTF8_STRING,
+                e.to_string(),
+                decoder.codec(),
+            )
+        })
+    }
 }
 
 impl<'input> crate::Decoder for Decoder<'input> {
@@ -329,6 +836,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
                 self.config,
                 self.input,
                 identifier.tag,
+                self.depth,
                 |input, _| Ok(alloc::vec::Vec::from(input)),
             )?;
             input = i;
@@ -343,7 +851,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
     }
 
     fn decode_bool(&mut self, tag: Tag) -> Result<bool> {
-        let (_, contents) = self.parse_primitive_value(tag)?;
+        let contents = self.parse_primitive_value(tag)?;
         DecodeError::assert_length(1, contents.len(), self.codec())?;
         Ok(match contents[0] {
             0 => false,
@@ -366,37 +874,63 @@ impl<'input> crate::Decoder for Decoder<'input> {
     }
 
     fn decode_integer<I: types::IntegerType>(&mut self, tag: Tag, _: Constraints) -> Result<I> {
-        let primitive_bytes = self.parse_primitive_value(tag)?.1;
-        let integer_width = I::WIDTH as usize / 8;
-        if primitive_bytes.len() > integer_width {
-            // in the case of superfluous leading bytes (especially zeroes),
-            // we may still want to try to decode the integer even though
-            // the length is > integer width ...
-            let leading_byte = if primitive_bytes[0] & 0x80 == 0x80 {
-                0xFF
-            } else {
-                0x00
-            };
-            let input_iter = primitive_bytes
-                .iter()
-                .copied()
-                .skip_while(|n| *n == leading_byte);
-            let data_length = input_iter.clone().count();
-            I::try_from_bytes(
-                &primitive_bytes[primitive_bytes.len() - data_length..primitive_bytes.len()],
-                self.codec(),
-            )
+        let contents = self.parse_primitive_value(tag)?;
+
+        // BER tolerates redundant sign octets, so they are skipped before the
+        // contents are checked against the width of `I`. Non-negative values
+        // are read as unsigned so that e.g. `00 FF` still fits in a `u8`,
+        // while negative values can only ever fit in signed types.
+        let (start, negative) = match contents.first() {
+            Some(first) if first & 0x80 == 0 => {
+                let start = contents
+                    .iter()
+                    .position(|byte| *byte != 0)
+                    .unwrap_or(contents.len() - 1);
+                // Keep a leading zero when it is needed for the sign.
+                let start = if contents[start] & 0x80 == 0x80 {
+                    start - 1
+                } else {
+                    start
+                };
+                (start, false)
+            }
+            Some(_) => {
+                let start = contents
+                    .windows(2)
+                    .take_while(|pair| pair[0] == 0xFF && pair[1] & 0x80 == 0x80)
+                    .count();
+                (start, true)
+            }
+            None => return Err(DecodeError::unexpected_empty_input(self.codec())),
+        };
+
+        // X.690 8.3.2, CER and DER require the minimal encoding.
+        if start > 0 && !self.config.encoding_rules.is_ber() {
+            return Err(BerDecodeErrorKind::NonMinimalInteger.into());
+        }
+
+        if negative {
+            I::try_from_signed_bytes(&contents[start..], self.codec())
         } else {
-            I::try_from_bytes(primitive_bytes, self.codec())
+            let contents = &contents[start..];
+            let contents = match contents {
+                [0, rest @ ..] if !rest.is_empty() => rest,
+                _ => contents,
+            };
+            I::try_from_unsigned_bytes(contents, self.codec())
         }
     }
 
     fn decode_real<R: types::RealType>(
         &mut self,
-        _: Tag,
+        tag: Tag,
         _: Constraints,
     ) -> Result<R, Self::Error> {
-        Err(DecodeError::real_not_supported(self.codec()))
+        let contents = self.parse_primitive_value(tag)?;
+        let value = self.decode_real_from_bytes(contents)?;
+        R::try_from_float(value).ok_or_else(|| {
+            DecodeError::from_kind(DecodeErrorKind::InvalidRealEncoding, self.codec())
+        })
     }
 
     fn decode_octet_string<'b, T: From<&'b [u8]> + From<Vec<u8>>>(
@@ -422,8 +956,10 @@ impl<'input> crate::Decoder for Decoder<'input> {
                         self.config,
                         contents,
                         Tag::OCTET_STRING,
+                        self.depth + 1,
                         |input, _| Ok(alloc::vec::Vec::from(input)),
-                    )?;
+                    )
+                    .map_err(parser::within_definite_length)?;
                     contents = c;
 
                     buffer.append(&mut vec);
@@ -434,6 +970,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
                         self.config,
                         self.input,
                         Tag::OCTET_STRING,
+                        self.depth + 1,
                         |input, _| Ok(alloc::vec::Vec::from(input)),
                     )?;
                     self.input = c;
@@ -447,20 +984,44 @@ impl<'input> crate::Decoder for Decoder<'input> {
         }
     }
 
+    #[cfg(not(feature = "arc-slice"))]
+    fn decode_octet_string_shared(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::OctetString> {
+        if let Some(source) = self.source.clone() {
+            let input = self.input;
+            if let (identifier, Some(contents)) = self.parse_value(tag)? {
+                if identifier.is_primitive() {
+                    return Ok(source.slice_ref(contents).into());
+                }
+            }
+            self.input = input;
+        }
+
+        self.decode_octet_string::<Vec<u8>>(tag, constraints)
+            .map(From::from)
+    }
+
     fn decode_null(&mut self, tag: Tag) -> Result<()> {
-        let (_, contents) = self.parse_primitive_value(tag)?;
+        let contents = self.parse_primitive_value(tag)?;
         DecodeError::assert_length(0, contents.len(), self.codec())?;
         Ok(())
     }
 
     fn decode_object_identifier(&mut self, tag: Tag) -> Result<crate::types::ObjectIdentifier> {
-        let contents = self.parse_primitive_value(tag)?.1;
+        let contents = self.parse_primitive_value(tag)?;
         self.decode_object_identifier_from_bytes(contents)
     }
 
     fn decode_bit_string(&mut self, tag: Tag, _: Constraints) -> Result<types::BitString> {
-        let (input, bs) =
-            self::parser::parse_encoded_value(self.config, self.input, tag, |input, codec| {
+        let (input, bs) = self::parser::parse_encoded_value(
+            self.config,
+            self.input,
+            tag,
+            self.depth,
+            |input, codec| {
                 let unused_bits = input
                     .first()
                     .copied()
@@ -471,7 +1032,12 @@ impl<'input> crate::Decoder for Decoder<'input> {
                     bits @ 0..=7 => {
                         let mut buffer = input[1..].to_owned();
                         if let Some(last) = buffer.last_mut() {
-                            *last &= !((1 << bits) - 1);
+                            let padding = (1 << bits) - 1;
+                            // CER and DER require the unused bits to be zero.
+                            if *last & padding != 0 && codec != crate::Codec::Ber {
+                                return Err(BerDecodeErrorKind::NonZeroBitStringPadding.into());
+                            }
+                            *last &= !padding;
                         }
 
                         let mut string = types::BitString::from_vec(buffer);
@@ -485,7 +1051,8 @@ impl<'input> crate::Decoder for Decoder<'input> {
                     }
                     _ => Err(DecodeError::invalid_bit_string(unused_bits, codec)),
                 }
-            })?;
+            },
+        )?;
 
         self.input = input;
         Ok(bs)
@@ -568,14 +1135,8 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::Utf8String> {
-        let vec = self.decode_octet_string(tag, constraints)?;
-        types::Utf8String::from_utf8(vec).map_err(|e| {
-            DecodeError::string_conversion_failed(
-                types::Tag::UTF8_STRING,
-                e.to_string(),
-                self.codec(),
-            )
-        })
+        let contents: Vec<u8> = self.decode_octet_string(tag, constraints)?;
+        Self::utf8_string_from_contents(Cow::Owned(contents), self.config).map(Cow::into_owned)
     }
 
     fn decode_general_string(
@@ -603,24 +1164,38 @@ impl<'input> crate::Decoder for Decoder<'input> {
     }
 
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime> {
+        self.decode_raw_generalized_time(tag)
+            .map(|value| value.time())
+    }
+
+    fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime> {
+        self.decode_raw_utc_time(tag).map(|value| value.time())
+    }
+
+    fn decode_raw_generalized_time(&mut self, tag: Tag) -> Result<types::RawGeneralizedTime> {
         let string = self.decode_utf8_string(tag, Constraints::default())?;
-        if self.config.encoding_rules.is_ber() {
-            Self::parse_any_generalized_time_string(string)
+        let time = if self.config.encoding_rules.is_ber() {
+            Self::parse_any_generalized_time_string(string.clone())
         } else {
-            Self::parse_canonical_generalized_time_string(string)
-        }
+            Self::parse_canonical_generalized_time_string(string.clone())
+        }?;
+
+        Ok(types::RawGeneralizedTime::from_parts(string, time))
     }
 
-    fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime> {
+    fn decode_raw_utc_time(&mut self, tag: Tag) -> Result<types::RawUtcTime> {
         // Reference https://obj-sys.com/asn1tutorial/node15.html
         let string = self.decode_utf8_string(tag, Constraints::default())?;
-        if self.config.encoding_rules.is_ber() {
-            Self::parse_any_utc_time_string(string)
+        let time = if self.config.encoding_rules.is_ber() {
+            Self::parse_any_utc_time_string(string.clone())
         } else {
             Self::parse_canonical_utc_time_string(&string)
-        }
+        }?;
+
+        Ok(types::RawUtcTime::from_parts(string, time))
     }
 
+    #[cfg(feature = "chrono")]
     fn decode_date(&mut self, tag: Tag) -> core::result::Result<types::Date, Self::Error> {
         let string = self.decode_utf8_string(tag, Constraints::default())?;
         Self::parse_date_string(&string)
@@ -629,40 +1204,57 @@ impl<'input> crate::Decoder for Decoder<'input> {
     fn decode_sequence_of<D: Decode>(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
     ) -> Result<Vec<D>, Self::Error> {
-        self.parse_constructed_contents(tag, true, |decoder| {
-            let mut items = Vec::new();
-
-            if decoder.input.is_empty() {
-                return Ok(items);
-            }
-
-            while let Ok(item) = D::decode(decoder) {
-                items.push(item);
-
-                if decoder.input.is_empty() {
-                    return Ok(items);
-                }
-            }
+        let mut items = Vec::new();
+        self.decode_sequence_of_with(tag, constraints, |item| {
+            items.push(item);
+            Ok(ControlFlow::Continue(()))
+        })?;
 
-            Ok(items)
-        })
+        Ok(items)
     }
 
     fn decode_set_of<D: Decode + Eq + core::hash::Hash>(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
     ) -> Result<types::SetOf<D>, Self::Error> {
-        self.parse_constructed_contents(tag, true, |decoder| {
-            let mut items = types::SetOf::new();
+        let mut items = types::SetOf::new();
+        self.decode_set_of_with(tag, constraints, |item| {
+            items.insert(item);
+            Ok(ControlFlow::Continue(()))
+        })?;
+
+        Ok(items)
+    }
 
-            while let Ok(item) = D::decode(decoder) {
-                items.insert(item);
+    fn decode_sequence_of_with<D, F>(&mut self, tag: Tag, _: Constraints, mut f: F) -> Result<()>
+    where
+        D: Decode,
+        F: FnMut(D) -> Result<ControlFlow<()>>,
+    {
+        self.parse_constructed_contents(tag, true, |decoder| {
+            let mut count = 0;
+
+            while decoder.has_element() {
+                decoder.check_element_count(count)?;
+                let item = D::decode(decoder)?;
+                count += 1;
+
+                if (f)(item)?.is_break() {
+                    // The end of indefinite length contents can only be
+                    // found by skipping over the remaining elements.
+                    while decoder.has_element() {
+                        decoder.check_element_count(count)?;
+                        decoder.decode_any()?;
+                        count += 1;
+                    }
+                    break;
+                }
             }
 
-            Ok(items)
+            Ok(())
         })
     }
 
@@ -724,15 +1316,50 @@ impl<'input> crate::Decoder for Decoder<'input> {
     {
         self.parse_constructed_contents(tag, true, |decoder| {
             let mut fields = Vec::new();
+            let mut previous_tag = None;
+
+            while !decoder.input.is_empty() && !decoder.input.starts_with(EOC) {
+                let tag = decoder.peek_tag()?;
+
+                // X.690 9.3 and 10.3, CER and DER require the components to
+                // be in ascending tag order.
+                if !decoder.config.encoding_rules.is_ber()
+                    && previous_tag.is_some_and(|previous| previous > tag)
+                {
+                    return Err(decoder
+                        .locate_error(BerDecodeErrorKind::UnorderedSetComponent { tag }.into()));
+                }
+                previous_tag = Some(tag);
+
+                let is_known = SET::FIELDS
+                    .iter()
+                    .chain(SET::EXTENDED_FIELDS.iter().flat_map(|fields| fields.iter()))
+                    .any(|field| types::TagTree::tag_contains(&tag, &[field.tag_tree]));
+
+                // Unknown components of an extensible set are extensions
+                // added in a later version of the specification.
+                if !is_known && SET::IS_EXTENSIBLE {
+                    crate::Decoder::decode_any(decoder)?;
+                    continue;
+                }
 
-            while let Ok(value) = FIELDS::decode(decoder) {
-                fields.push(value);
+                fields.push(FIELDS::decode(decoder)?);
             }
 
             (field_fn)(fields)
         })
     }
 
+    fn decode_remaining_components(&mut self) -> Result<Vec<types::Any>, Self::Error> {
+        let mut components = Vec::new();
+
+        while !self.input.is_empty() && !self.input.starts_with(EOC) {
+            components.push(crate::Decoder::decode_any(self)?);
+        }
+
+        Ok(components)
+    }
+
     fn decode_optional<D: Decode>(&mut self) -> Result<Option<D>, Self::Error> {
         if D::TAG == Tag::EOC {
             Ok(D::decode(self).ok())
@@ -767,10 +1394,8 @@ impl<'input> crate::Decoder for Decoder<'input> {
     where
         D: crate::types::DecodeChoice,
     {
-        let (_, identifier) = parser::parse_identifier_octet(self.input).map_err(|e| match e {
-            ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
-            ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
-        })?;
+        let (_, identifier) = parser::parse_identifier_octet(self.config, self.input)
+            .map_err(|e| e.into_decode_error(self.codec()))?;
         D::from_tag(self, identifier.tag)
     }
 
@@ -848,6 +1473,26 @@ mod tests {
     fn boolean() {
         assert!(decode::<bool>(&[0x01, 0x01, 0xff]).unwrap());
         assert!(!decode::<bool>(&[0x01, 0x01, 0x00]).unwrap());
+        assert!(decode::<bool>(&[0x01, 0x01, 0x01]).unwrap());
+
+        for err in [
+            crate::der::decode::<bool>(&[0x01, 0x01, 0x01]).unwrap_err(),
+            crate::cer::decode::<bool>(&[0x01, 0x01, 0x01]).unwrap_err(),
+        ] {
+            assert!(matches!(
+                *err.kind,
+                DecodeErrorKind::InvalidBool { value: 0x01 }
+            ));
+        }
+
+        for data in [
+            &[0x01, 0x02, 0x00, 0x00][..],
+            &[0x01, 0x00],
+            &[0x21, 0x01, 0xff],
+        ] {
+            assert!(decode::<bool>(data).is_err());
+            assert!(crate::der::decode::<bool>(data).is_err());
+        }
     }
 
     #[test]
@@ -860,6 +1505,23 @@ mod tests {
 
     #[test]
     fn integer() {
+        assert_eq!(255, decode::<u8>(&[0x02, 0x02, 0x00, 0xff]).unwrap());
+        assert_eq!(
+            u64::MAX,
+            decode::<u64>(&[0x02, 0x09, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff])
+                .unwrap()
+        );
+        assert_eq!(
+            u128::MAX,
+            decode::<u128>(&[&[0x02, 0x11, 0x00][..], &[0xff; 16]].concat()).unwrap()
+        );
+        assert_eq!(-1, decode::<i8>(&[0x02, 0x03, 0xff, 0xff, 0xff]).unwrap());
+        assert_eq!(1, decode::<u8>(&[0x02, 0x03, 0x00, 0x00, 0x01]).unwrap());
+        assert!(decode::<u8>(&[0x02, 0x02, 0x01, 0x00]).is_err());
+        assert!(decode::<u8>(&[0x02, 0x01, 0xff]).is_err());
+        assert!(decode::<u16>(&[0x02, 0x02, 0xff, 0xff]).is_err());
+        assert!(decode::<i8>(&[0x02, 0x02, 0x00, 0x80]).is_err());
+        assert!(decode::<i8>(&[0x02, 0x02, 0xff, 0x7f]).is_err());
         assert_eq!(
             32768,
             decode::<i32>(&[0x02, 0x03, 0x00, 0x80, 0x00,]).unwrap()
@@ -906,6 +1568,50 @@ mod tests {
             octet_string,
             decode::<types::OctetString>(constructed_encoded).unwrap()
         );
+
+        // Definite length outer value containing a nested constructed segment.
+        let nested_encoded = &[
+            0x24, 0x10, 0x04, 0x02, 1, 2, 0x24, 0x80, 0x04, 0x02, 3, 4, 0x00, 0x00, 0x04, 0x02, 5,
+            6,
+        ];
+        assert_eq!(
+            octet_string,
+            decode::<types::OctetString>(nested_encoded).unwrap()
+        );
+
+        // Indefinite length outer value containing a definite length
+        // constructed segment, followed by another value.
+        let mut decoder = Decoder::new(
+            &[
+                0x24, 0x80, 0x24, 0x08, 0x04, 0x02, 1, 2, 0x04, 0x02, 3, 4, 0x04, 0x02, 5, 6, 0x00,
+                0x00, 0x05, 0x00,
+            ],
+            DecoderOptions::ber(),
+        );
+        assert_eq!(
+            octet_string,
+            crate::Decoder::decode_octet_string::<types::OctetString>(
+                &mut decoder,
+                Tag::OCTET_STRING,
+                Constraints::default()
+            )
+            .unwrap()
+        );
+        assert_eq!(&[0x05, 0x00], decoder.remaining());
+
+        // Missing end-of-contents.
+        assert!(decode::<types::OctetString>(&constructed_encoded[..12]).is_err());
+
+        assert!(matches!(
+            &*crate::der::decode::<types::OctetString>(constructed_encoded)
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Der(
+                    DerDecodeErrorKind::ConstructedEncodingNotAllowed
+                )
+            }
+        ));
     }
 
     #[test]
@@ -963,6 +1669,39 @@ mod tests {
     }
 
     #[test]
+    fn invalid_utf8_string() {
+        let name = String::from("Zoë Ångström");
+        let encoded = crate::ber::encode(&name).unwrap();
+        assert_eq!(name, decode::<String>(&encoded).unwrap());
+
+        let data = &[0x0C, 0x04, b'a', b'b', 0xC3, 0x28];
+        assert!(matches!(
+            *decode::<String>(data).unwrap_err().kind,
+            DecodeErrorKind::InvalidUtf8 { offset: 2 }
+        ));
+        assert!(matches!(
+            *Decoder::new(data, DecoderOptions::ber())
+                .decode_utf8_string_borrowed(Tag::UTF8_STRING)
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::InvalidUtf8 { offset: 2 }
+        ));
+
+        let options = DecoderOptions::ber().with_lossy_utf8(true);
+        assert_eq!(
+            "ab\u{FFFD}(",
+            String::decode(&mut Decoder::new(data, options)).unwrap()
+        );
+        assert_eq!(
+            "ab\u{FFFD}(",
+            Decoder::new(data, options)
+                .decode_utf8_string_borrowed(Tag::UTF8_STRING)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
     fn utc_time() {
         let time =
             crate::types::GeneralizedTime::parse_from_str("991231235959+0000", "%y%m%d%H%M%S%z")
@@ -995,6 +1734,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
     fn generalized_time() {
         let time = crate::types::GeneralizedTime::parse_from_str(
             "20001231205959.999+0000",
@@ -1011,6 +1751,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lenient_generalized_time() {
+        fn encode(time: &str) -> Vec<u8> {
+            let mut encoded = alloc::vec![0x18, u8::try_from(time.len()).unwrap()];
+            encoded.extend_from_slice(time.as_bytes());
+            encoded
+        }
+
+        for (lenient, canonical, rule) in [
+            ("20170102030405,5Z", "20170102030405.5Z", "11.7.4"),
+            ("20170102030405.50Z", "20170102030405.5Z", "11.7.3"),
+            ("20170102030405.0Z", "20170102030405Z", "11.7.3"),
+            ("2017010203Z", "20170102030000Z", "11.7.2"),
+            ("201701020304Z", "20170102030400Z", "11.7.2"),
+            ("2017010203", "20170102030000Z", "11.7.1"),
+        ] {
+            let expected = crate::der::decode::<GeneralizedTime>(&encode(canonical)).unwrap();
+            assert_eq!(
+                expected,
+                decode::<GeneralizedTime>(&encode(lenient)).unwrap()
+            );
+
+            let error = crate::der::decode::<GeneralizedTime>(&encode(lenient)).unwrap_err();
+            let DecodeErrorKind::CodecSpecific {
+                inner:
+                    CodecDecodeError::Der(DerDecodeErrorKind::NonCanonicalTime {
+                        value,
+                        rule: broken,
+                    }),
+            } = &*error.kind
+            else {
+                panic!("unexpected error for {lenient}: {error}");
+            };
+            assert_eq!(lenient, value.as_str());
+            assert!(broken.contains(rule), "{broken}");
+        }
+
+        // Malformed times are still reported as invalid dates.
+        assert!(matches!(
+            &*crate::der::decode::<GeneralizedTime>(&encode("2017010203xxZ"))
+                .unwrap_err()
+                .kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: CodecDecodeError::Ber(BerDecodeErrorKind::InvalidDate { .. })
+            }
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn utc_time_without_seconds() {
+        fn encode(time: &str) -> Vec<u8> {
+            let mut encoded = alloc::vec![0x17, u8::try_from(time.len()).unwrap()];
+            encoded.extend_from_slice(time.as_bytes());
+            encoded
+        }
+
+        let expected = crate::der::decode::<UtcTime>(&encode("991231235900Z")).unwrap();
+        assert_eq!(
+            "1999-12-31T23:59:00Z",
+            expected.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        );
+
+        for (lenient, rule) in [
+            ("9912312359Z", "seconds must be present"),
+            ("0001010059+0100", "11.8.1"),
+            ("991231235900+0000", "11.8.1"),
+        ] {
+            assert_eq!(expected, decode::<UtcTime>(&encode(lenient)).unwrap());
+
+            let error = crate::der::decode::<UtcTime>(&encode(lenient)).unwrap_err();
+            let DecodeErrorKind::CodecSpecific {
+                inner:
+                    CodecDecodeError::Der(DerDecodeErrorKind::NonCanonicalTime {
+                        value,
+                        rule: broken,
+                    }),
+            } = &*error.kind
+            else {
+                panic!("unexpected error for {lenient}: {error}");
+            };
+            assert_eq!(lenient, value.as_str());
+            assert!(broken.contains(rule), "{broken}");
+        }
+    }
+
     #[test]
     fn sequence_of() {
         let vec = alloc::vec!["Jon", "es"];
@@ -1162,4 +1988,476 @@ mod tests {
         let oid = oid.unwrap();
         assert_eq!(ObjectIdentifier::new([2, 999, 1].to_vec()).unwrap(), oid);
     }
+
+    #[test]
+    fn decoding_oid_with_large_arcs() {
+        // 2.25.329800735698586629295641978511506172918, the OID of the UUID
+        // f81d4fae-7dec-11d0-a765-00a0c91e6bf6 (X.667).
+        let data = &[
+            0x06, 0x14, 0x69, 0x83, 0xF0, 0x9D, 0xA7, 0xEB, 0xCF, 0xDE, 0xE0, 0xC7, 0xA1, 0xA7,
+            0xB2, 0xC0, 0x94, 0x8C, 0xC8, 0xF9, 0xD7, 0x76,
+        ];
+        let oid = ObjectIdentifier::new(alloc::vec![
+            2,
+            25,
+            329_800_735_698_586_629_295_641_978_511_506_172_918
+        ])
+        .unwrap();
+        assert_eq!(oid, decode::<ObjectIdentifier>(data).unwrap());
+        assert_eq!(oid, crate::der::decode::<ObjectIdentifier>(data).unwrap());
+        assert_eq!(&data[..], crate::der::encode(&oid).unwrap());
+
+        // The largest arc that fits: 2.25.340282366920938463463374607431768211455
+        let data = [&[0x06, 0x14, 0x69, 0x83][..], &[0xFF; 17], &[0x7F]].concat();
+        assert_eq!(
+            ObjectIdentifier::new(alloc::vec![2, 25, u128::MAX]).unwrap(),
+            decode::<ObjectIdentifier>(&data).unwrap()
+        );
+
+        // One more doesn't fit in a `u128`.
+        let data = [&[0x06, 0x14, 0x69, 0x84][..], &[0x80; 17], &[0x00]].concat();
+        let error = decode::<ObjectIdentifier>(&data).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::IntegerOverflow { max_width: 128 }
+        ));
+    }
+
+    #[test]
+    fn decoding_empty_oid() {
+        let error = decode::<ObjectIdentifier>(&[0x06, 0x00]).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(
+                    BerDecodeErrorKind::InvalidObjectIdentifier
+                )
+            }
+        ));
+    }
+
+    #[test]
+    fn malformed_object_identifiers() {
+        let corpus: &[&[u8]] = &[
+            // Missing length.
+            &[0x06],
+            // Empty contents.
+            &[0x06, 0x00],
+            // Truncated contents.
+            &[0x06, 0x03, 0x2B, 0x06],
+            // The final octet has the continuation bit set.
+            &[0x06, 0x01, 0x81],
+            &[0x06, 0x02, 0x2B, 0x86],
+            // An arc with a leading 0x80 octet.
+            &[0x06, 0x03, 0x2B, 0x80, 0x01],
+            // A 20 byte arc, which can't fit in a `u128`.
+            &[
+                0x06, 0x15, 0x2B, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00,
+            ],
+            // A root arc which can't fit in a `u128`.
+            &[
+                0x06, 0x14, 0x84, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00,
+            ],
+            // Primitive values can't use the indefinite length.
+            &[0x06, 0x80, 0x2B, 0x06, 0x00, 0x00],
+        ];
+
+        for data in corpus {
+            for options in [
+                DecoderOptions::ber(),
+                DecoderOptions::cer(),
+                DecoderOptions::der(),
+            ] {
+                let mut decoder = Decoder::new(data, options);
+                assert!(
+                    <ObjectIdentifier as crate::Decode>::decode(&mut decoder).is_err(),
+                    "{data:02X?}"
+                );
+            }
+        }
+
+        // An arc of a megabyte of continuation octets.
+        let mut data = alloc::vec![0x06, 0x83, 0x10, 0x00, 0x00];
+        data.resize(data.len() + (1 << 20), 0x81);
+        assert!(decode::<ObjectIdentifier>(&data).is_err());
+
+        // Every truncation of a valid value is an error, and changing any
+        // octet of it never panics.
+        let valid = alloc::vec![0x06, 0x08, 0x2B, 0x06, 0x01, 0x04, 0x01, 0x81, 0xFF, 0x59];
+        assert!(decode::<ObjectIdentifier>(&valid).is_ok());
+        for len in 0..valid.len() {
+            assert!(decode::<ObjectIdentifier>(&valid[..len]).is_err());
+        }
+        for index in 0..valid.len() {
+            for byte in 0..=u8::MAX {
+                let mut data = valid.clone();
+                data[index] = byte;
+                let _ = decode::<ObjectIdentifier>(&data);
+                let _ = decode::<crate::types::Open>(&data);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_bit_string() {
+        // Unused bit count greater than 7.
+        assert!(decode::<BitString>(&[0x03, 0x02, 0x08, 0xFF]).is_err());
+        // Unused bits in an empty bit string.
+        assert!(decode::<BitString>(&[0x03, 0x01, 0x04]).is_err());
+        // Empty bit string.
+        assert_eq!(
+            BitString::new(),
+            decode::<BitString>(&[0x03, 0x01, 0x00]).unwrap()
+        );
+    }
+
+    #[test]
+    fn bit_string_padding() {
+        let data = &[0x03, 0x02, 0x04, 0xFF];
+        let expected = BitString::from_slice(&[0xF0])[..4].to_bitvec();
+
+        // BER ignores the padding bits.
+        assert_eq!(expected, decode::<BitString>(data).unwrap());
+
+        // CER and DER require them to be zero.
+        assert!(matches!(
+            &*crate::der::decode::<BitString>(data).unwrap_err().kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(
+                    BerDecodeErrorKind::NonZeroBitStringPadding
+                )
+            }
+        ));
+        assert!(crate::cer::decode::<BitString>(data).is_err());
+        assert_eq!(
+            expected,
+            crate::der::decode::<BitString>(&[0x03, 0x02, 0x04, 0xF0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn constructed_bit_string_unaligned_segment() {
+        // The first segment has four unused bits, which is only allowed in
+        // the final segment.
+        let data = &[
+            0x23, 0x80, 0x03, 0x02, 0x04, 0xF0, 0x03, 0x02, 0x00, 0xFF, 0x00, 0x00,
+        ];
+        assert!(matches!(
+            &*decode::<BitString>(data).unwrap_err().kind,
+            DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(
+                    BerDecodeErrorKind::UnalignedBitStringSegment
+                )
+            }
+        ));
+
+        let data = &[
+            0x23, 0x80, 0x03, 0x02, 0x00, 0xFF, 0x03, 0x02, 0x04, 0xF0, 0x00, 0x00,
+        ];
+        assert_eq!(12, decode::<BitString>(data).unwrap().len());
+    }
+
+    #[test]
+    fn constructed_restricted_strings() {
+        // "café" with the two byte "é" split across segments.
+        let data = &[
+            0x2C, 0x80, 0x04, 0x04, b'c', b'a', b'f', 0xC3, 0x04, 0x01, 0xA9, 0x00, 0x00,
+        ];
+        assert_eq!("café", decode::<Utf8String>(data).unwrap());
+        assert!(crate::der::decode::<Utf8String>(data).is_err());
+
+        let data = &[0x36, 0x08, 0x04, 0x02, b'r', b'a', 0x04, 0x02, b's', b'n'];
+        assert_eq!(
+            Ia5String::try_from("rasn").unwrap(),
+            decode::<Ia5String>(data).unwrap()
+        );
+        assert!(crate::der::decode::<Ia5String>(data).is_err());
+
+        let data = &[
+            0x33, 0x80, 0x04, 0x02, b'r', b'a', 0x04, 0x02, b's', b'n', 0x00, 0x00,
+        ];
+        assert_eq!(
+            PrintableString::try_from("rasn").unwrap(),
+            decode::<PrintableString>(data).unwrap()
+        );
+    }
+
+    #[test]
+    fn nesting_too_deep() {
+        let data = [0x30, 0x80].repeat(10_000);
+        let is_too_deep = |error: DecodeError| {
+            matches!(
+                &*error.kind,
+                DecodeErrorKind::CodecSpecific {
+                    inner: crate::error::CodecDecodeError::Ber(
+                        BerDecodeErrorKind::NestingTooDeep { limit: 64 }
+                    )
+                }
+            )
+        };
+
+        assert!(is_too_deep(decode::<Any>(&data).unwrap_err()));
+        assert!(decode::<Open>(&data).is_err());
+
+        let data = [0x24, 0x80].repeat(10_000);
+        assert!(is_too_deep(decode::<OctetString>(&data).unwrap_err()));
+
+        let data = [0xA2, 0x80].repeat(10_000);
+        assert!(decode::<Explicit<C2, bool>>(&data).is_err());
+    }
+
+    #[test]
+    fn absurd_declared_lengths() {
+        // Each value declares a length of 2^31 - 1 with only a few octets of
+        // contents present, so decoding must fail without allocating for
+        // the declared length.
+        let header = |identifier: u8| vec![identifier, 0x84, 0x7F, 0xFF, 0xFF, 0xFF];
+        let with_contents = |identifier: u8, contents: &[u8]| {
+            let mut data = header(identifier);
+            data.extend_from_slice(contents);
+            data
+        };
+
+        let elements = &[0x01, 0x01, 0xFF];
+        assert!(decode::<Vec<bool>>(&with_contents(0x30, elements)).is_err());
+        assert!(decode::<SetOf<bool>>(&with_contents(0x31, elements)).is_err());
+
+        let segment = &[0x04, 0x01, 0xAB];
+        assert!(decode::<OctetString>(&with_contents(0x24, segment)).is_err());
+        assert!(decode::<OctetString>(&with_contents(0x04, &[0xAB])).is_err());
+
+        let segment = &[0x03, 0x02, 0x00, 0xAB];
+        assert!(decode::<BitString>(&with_contents(0x23, segment)).is_err());
+        assert!(decode::<Utf8String>(&with_contents(0x2C, &[0x04, 0x01, b'a'])).is_err());
+
+        // Constructed segments nested inside a constructed value with an
+        // absurd length.
+        let mut nested = header(0x24);
+        nested.extend_from_slice(&with_contents(0x24, &[0x04, 0x01, 0xAB]));
+        assert!(decode::<OctetString>(&nested).is_err());
+    }
+
+    #[test]
+    fn configurable_max_depth() {
+        let data = &[0x30, 0x05, 0x30, 0x03, 0x01, 0x01, 0xFF];
+        let options = DecoderOptions::ber().with_max_depth(2);
+        let mut decoder = Decoder::new(data, options);
+        assert_eq!(
+            vec![vec![true]],
+            Vec::<Vec<bool>>::decode(&mut decoder).unwrap()
+        );
+
+        let options = DecoderOptions::ber().with_max_depth(1);
+        let mut decoder = Decoder::new(data, options);
+        assert!(Vec::<Vec<bool>>::decode(&mut decoder).is_err());
+    }
+
+    #[test]
+    fn resource_limits() {
+        fn codec_error(error: DecodeError) -> BerDecodeErrorKind {
+            match *error.kind {
+                DecodeErrorKind::CodecSpecific {
+                    inner: crate::error::CodecDecodeError::Ber(kind),
+                } => kind,
+                kind => panic!("unexpected error: {kind:?}"),
+            }
+        }
+
+        // Declares a length of 2^31 with only ten bytes of contents present.
+        let mut data = vec![0x04, 0x84, 0x80, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0; 10]);
+        let options = DecoderOptions::ber().with_max_value_length(1024);
+        assert!(matches!(
+            codec_error(OctetString::decode(&mut Decoder::new(&data, options)).unwrap_err()),
+            BerDecodeErrorKind::ValueTooLong {
+                length: 0x8000_0000,
+                limit: 1024
+            }
+        ));
+        assert!(matches!(
+            *decode::<OctetString>(&data).unwrap_err().kind,
+            DecodeErrorKind::Incomplete { .. }
+        ));
+
+        let data = &[
+            0x30, 0x09, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00, 0x01, 0x01, 0xFF,
+        ];
+        let options = DecoderOptions::ber().with_max_elements(2);
+        assert!(matches!(
+            codec_error(Vec::<bool>::decode(&mut Decoder::new(data, options)).unwrap_err()),
+            BerDecodeErrorKind::TooManyElements { limit: 2 }
+        ));
+        let data = &[
+            0x31, 0x09, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00, 0x01, 0x01, 0xFF,
+        ];
+        assert!(matches!(
+            codec_error(SetOf::<bool>::decode(&mut Decoder::new(data, options)).unwrap_err()),
+            BerDecodeErrorKind::TooManyElements { limit: 2 }
+        ));
+        // The limit is checked before the element past it is decoded, so a
+        // malformed third element isn't looked at.
+        let data = &[0x30, 0x08, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00, 0x01, 0x05];
+        assert!(matches!(
+            codec_error(Vec::<bool>::decode(&mut Decoder::new(data, options)).unwrap_err()),
+            BerDecodeErrorKind::TooManyElements { limit: 2 }
+        ));
+        let data = &[
+            0x31, 0x09, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00, 0x01, 0x01, 0xFF,
+        ];
+        let options = DecoderOptions::ber().with_max_elements(3);
+        assert_eq!(
+            3,
+            SetOf::<bool>::decode(&mut Decoder::new(data, options))
+                .unwrap()
+                .len()
+        );
+        // The limit doesn't apply to the components of a SET.
+        #[derive(AsnType, Decode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate", set)]
+        struct Flags {
+            #[rasn(tag(0))]
+            first: bool,
+            #[rasn(tag(1))]
+            second: bool,
+        }
+        let data = &[0x31, 0x06, 0x80, 0x01, 0xFF, 0x81, 0x01, 0x00];
+        let options = DecoderOptions::ber().with_max_elements(1);
+        assert_eq!(
+            Flags {
+                first: true,
+                second: false
+            },
+            Flags::decode(&mut Decoder::new(data, options)).unwrap()
+        );
+
+        let data = &[0x06, 0x04, 0x2B, 0x06, 0x01, 0x04];
+        let options = DecoderOptions::ber().with_max_oid_arcs(4);
+        assert!(matches!(
+            codec_error(ObjectIdentifier::decode(&mut Decoder::new(data, options)).unwrap_err()),
+            BerDecodeErrorKind::TooManyOidArcs { limit: 4 }
+        ));
+        let options = DecoderOptions::ber().with_max_oid_arcs(5);
+        assert_eq!(
+            ObjectIdentifier::new(vec![1, 3, 6, 1, 4]).unwrap(),
+            ObjectIdentifier::decode(&mut Decoder::new(data, options)).unwrap()
+        );
+    }
+
+    #[test]
+    fn borrowed_decoding() {
+        use crate::Encode;
+
+        #[derive(AsnType, Decode, Encode)]
+        #[rasn(crate_root = "crate")]
+        struct Owned {
+            name: Utf8String,
+            data: OctetString,
+        }
+
+        struct Borrowed<'a> {
+            name: &'a str,
+            data: Cow<'a, [u8]>,
+        }
+
+        impl<'a> DecodeBorrowed<'a> for Borrowed<'a> {
+            fn decode_borrowed(decoder: &mut Decoder<'a>) -> Result<Self> {
+                Self::decode_borrowed_with_tag(decoder, Tag::SEQUENCE)
+            }
+
+            fn decode_borrowed_with_tag(decoder: &mut Decoder<'a>, tag: Tag) -> Result<Self> {
+                decoder.decode_constructed_borrowed(tag, |decoder| {
+                    Ok(Self {
+                        name: decoder.decode_borrowed()?,
+                        data: decoder.decode_borrowed()?,
+                    })
+                })
+            }
+        }
+
+        let encoded = crate::ber::encode(&Owned {
+            name: "rasn".into(),
+            data: OctetString::from_static(&[1, 2, 3, 4]),
+        })
+        .unwrap();
+
+        let value: Borrowed = crate::ber::decode_borrowed(&encoded).unwrap();
+        assert_eq!("rasn", value.name);
+        assert_eq!(&[1, 2, 3, 4], &*value.data);
+        assert!(matches!(value.data, Cow::Borrowed(_)));
+        assert!(encoded.as_ptr_range().contains(&value.name.as_ptr()));
+        assert_eq!(encoded[encoded.len() - 4..].as_ptr(), value.data.as_ptr());
+
+        // Constructed encodings fall back to an owned copy.
+        let constructed = [0x24, 0x80, 0x04, 0x01, 0x01, 0x04, 0x01, 0x02, 0x00, 0x00];
+        let data: Cow<[u8]> = crate::ber::decode_borrowed(&constructed).unwrap();
+        assert!(matches!(data, Cow::Owned(_)));
+        assert_eq!(&[1, 2], &*data);
+        assert!(crate::ber::decode_borrowed::<&[u8]>(&constructed).is_err());
+
+        let text: Cow<str> = crate::ber::decode_borrowed(&[0x0C, 0x02, b'h', b'i']).unwrap();
+        assert!(matches!(text, Cow::Borrowed("hi")));
+    }
+
+    #[test]
+    fn integer_minimal_encoding() {
+        let non_minimal = [&[0x02, 0x02, 0x00, 0x7F][..], &[0x02, 0x02, 0xFF, 0x80]];
+
+        assert!(decode::<i32>(&[0x02, 0x00]).is_err());
+        assert!(crate::der::decode::<i32>(&[0x02, 0x00]).is_err());
+        assert!(decode::<Integer>(&[0x02, 0x00]).is_err());
+
+        assert_eq!(127, decode::<i32>(non_minimal[0]).unwrap());
+        assert_eq!(-128, decode::<i32>(non_minimal[1]).unwrap());
+
+        for data in non_minimal {
+            for err in [
+                crate::der::decode::<i32>(data).unwrap_err(),
+                crate::cer::decode::<i32>(data).unwrap_err(),
+                crate::der::decode::<Integer>(data).unwrap_err(),
+            ] {
+                assert!(matches!(
+                    &*err.kind,
+                    DecodeErrorKind::CodecSpecific {
+                        inner: crate::error::CodecDecodeError::Ber(
+                            BerDecodeErrorKind::NonMinimalInteger
+                        )
+                    }
+                ));
+            }
+        }
+
+        // A leading octet that is needed for the sign is not redundant.
+        assert_eq!(
+            128,
+            crate::der::decode::<u8>(&[0x02, 0x02, 0x00, 0x80]).unwrap()
+        );
+        assert_eq!(
+            -129,
+            crate::der::decode::<i16>(&[0x02, 0x02, 0xFF, 0x7F]).unwrap()
+        );
+    }
+
+    #[test]
+    fn peek_tag() {
+        let data = [0x02, 0x01, 0x05, 0x5F, 0x21, 0x01, 0xFF, 0x04, 0x00];
+        let mut decoder = Decoder::new(&data, DecoderOptions::ber());
+
+        assert_eq!(Tag::INTEGER, decoder.peek_tag().unwrap());
+        assert_eq!(data.len(), decoder.remaining().len());
+        assert_eq!(5, u8::decode(&mut decoder).unwrap());
+
+        let tag = decoder.peek_tag().unwrap();
+        assert_eq!(Tag::new(Class::Application, 33), tag);
+        assert!(decoder.peek_identifier().unwrap().is_primitive());
+        assert!(bool::decode_with_tag(&mut decoder, tag).unwrap());
+
+        assert_eq!(Tag::OCTET_STRING, decoder.peek_tag().unwrap());
+        assert_eq!(2, decoder.remaining().len());
+        assert!(OctetString::decode(&mut decoder).unwrap().is_empty());
+
+        assert!(decoder.peek_tag().is_err());
+        assert!(decoder.remaining().is_empty());
+    }
 }
@@ -0,0 +1,199 @@
+//! Reading a single BER value from a [`tokio::io::AsyncRead`] source.
+
+use alloc::vec::Vec;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{
+    parser::MAX_BASE128_OCTETS, reader::io_error, BerDecodeErrorKind, DecodeError, DecoderOptions,
+};
+use crate::de::Error;
+
+/// Reads exactly one complete value, identifier and length octets included,
+/// from `reader` and appends it to `buffer`. Nothing past the end of the value
+/// is read, so consecutive values can be read from the same source.
+///
+/// Unlike the blocking version, nested indefinite length values are tracked
+/// with a counter rather than recursion, which `async fn` doesn't allow
+/// without boxing.
+pub(crate) async fn read_value<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    config: DecoderOptions,
+    buffer: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    // The number of indefinite length values whose end-of-contents hasn't
+    // been read yet.
+    let mut open = 0;
+
+    loop {
+        let initial = read_byte(reader, config, buffer).await?;
+        let is_constructed = initial & 0x20 != 0;
+
+        // Long form tag numbers are limited to a `u32`, as in the parser, so a
+        // source that keeps sending continuation octets can't be read forever.
+        if initial & 0x1F == 0x1F {
+            let mut octets = 1;
+            while read_byte(reader, config, buffer).await? & 0x80 != 0 {
+                if octets == MAX_BASE128_OCTETS {
+                    return Err(DecodeError::integer_overflow(32, config.current_codec()));
+                }
+                octets += 1;
+            }
+        }
+
+        let length = read_byte(reader, config, buffer).await?;
+
+        if length == 0x80 {
+            if !is_constructed || !config.encoding_rules.allows_indefinite() {
+                return Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into());
+            }
+
+            if open >= config.max_depth {
+                return Err(BerDecodeErrorKind::NestingTooDeep {
+                    limit: config.max_depth,
+                }
+                .into());
+            }
+
+            open += 1;
+            continue;
+        }
+
+        if length == 0xFF {
+            return Err(BerDecodeErrorKind::ReservedLengthOctet.into());
+        }
+
+        let length = if length & 0x80 == 0 {
+            usize::from(length)
+        } else {
+            let start = buffer.len();
+            for _ in 0..(length & 0x7F) {
+                read_byte(reader, config, buffer).await?;
+            }
+            super::parser::parse_long_length(&buffer[start..]).ok_or_else(|| {
+                DecodeError::length_exceeds_platform_width(
+                    "Length longer than possible capacity.".into(),
+                    config.current_codec(),
+                )
+            })?
+        };
+
+        if let Some(limit) = config.max_value_length {
+            if length > limit {
+                return Err(BerDecodeErrorKind::ValueTooLong { length, limit }.into());
+            }
+        }
+
+        let read = (&mut *reader)
+            .take(length as u64)
+            .read_to_end(buffer)
+            .await
+            .map_err(|error| io_error(error, config))?;
+
+        if read != length {
+            return Err(DecodeError::incomplete(
+                nom::Needed::new(length - read),
+                config.current_codec(),
+            ));
+        }
+
+        if initial == 0 && length == 0 && open > 0 {
+            open -= 1;
+        }
+
+        if open == 0 {
+            return Ok(());
+        }
+    }
+}
+
+async fn read_byte<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    config: DecoderOptions,
+    buffer: &mut Vec<u8>,
+) -> Result<u8, DecodeError> {
+    let byte = reader
+        .read_u8()
+        .await
+        .map_err(|error| io_error(error, config))?;
+    buffer.push(byte);
+    Ok(byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_test::{block_on, io::Builder};
+
+    use crate::error::DecodeErrorKind;
+
+    #[test]
+    fn small_reads_across_values() {
+        // An indefinite length SEQUENCE OF INTEGER followed by an INTEGER,
+        // arriving in chunks that don't line up with either value.
+        let mut reader = Builder::new()
+            .read(&[0x30])
+            .read(&[0x80, 0x02])
+            .read(&[0x01, 0x05, 0x00])
+            .read(&[0x00, 0x02, 0x01])
+            .read(&[0x07])
+            .build();
+
+        assert_eq!(
+            alloc::vec![5u8],
+            block_on(crate::ber::decode_async::<alloc::vec::Vec<u8>, _>(
+                &mut reader
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            7,
+            block_on(crate::ber::decode_async::<u8, _>(&mut reader)).unwrap()
+        );
+    }
+
+    #[test]
+    fn nested_indefinite_lengths() {
+        let mut reader = Builder::new()
+            .read(&[0x30, 0x80, 0x30, 0x80, 0x02, 0x01, 0x01])
+            .read(&[0x00, 0x00, 0x00])
+            .read(&[0x00])
+            .build();
+
+        assert_eq!(
+            alloc::vec![alloc::vec![1u8]],
+            block_on(crate::ber::decode_async::<
+                alloc::vec::Vec<alloc::vec::Vec<u8>>,
+                _,
+            >(&mut reader))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut reader = Builder::new()
+            .read(&[0x04, 0x03, 0xAA])
+            .read_error(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "reset",
+            ))
+            .build();
+        let error = block_on(crate::ber::decode_async::<u8, _>(&mut reader)).unwrap_err();
+        assert!(matches!(&*error.kind, DecodeErrorKind::Io { .. }));
+
+        // The source ends part way through the contents.
+        let mut reader = Builder::new().read(&[0x04, 0x03, 0xAA]).build();
+        let error = block_on(crate::ber::decode_async::<u8, _>(&mut reader)).unwrap_err();
+        assert!(matches!(&*error.kind, DecodeErrorKind::Incomplete { .. }));
+
+        // A tag number with more continuation octets than fit in a `u32` is
+        // rejected without reading any further.
+        let mut reader = Builder::new()
+            .read(&[0x1F, 0x81, 0x81, 0x81, 0x81, 0x81])
+            .build();
+        let error = block_on(crate::ber::decode_async::<u8, _>(&mut reader)).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            DecodeErrorKind::IntegerOverflow { max_width: 32 }
+        ));
+    }
+}
@@ -1,9 +1,17 @@
 use crate::ber::EncodingRules;
 
+/// The default maximum number of nested constructed values.
+const DEFAULT_MAX_DEPTH: usize = 64;
+
 /// The options for the [`Decoder`][super::Decoder].
 #[derive(Clone, Copy, Debug)]
 pub struct DecoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) max_depth: usize,
+    pub(crate) max_value_length: Option<usize>,
+    pub(crate) max_elements: Option<usize>,
+    pub(crate) max_oid_arcs: Option<usize>,
+    pub(crate) lossy_utf8: bool,
 }
 
 impl DecoderOptions {
@@ -12,6 +20,11 @@ impl DecoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_value_length: None,
+            max_elements: None,
+            max_oid_arcs: None,
+            lossy_utf8: false,
         }
     }
 
@@ -20,6 +33,11 @@ impl DecoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_value_length: None,
+            max_elements: None,
+            max_oid_arcs: None,
+            lossy_utf8: false,
         }
     }
 
@@ -28,9 +46,58 @@ impl DecoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_value_length: None,
+            max_elements: None,
+            max_oid_arcs: None,
+            lossy_utf8: false,
         }
     }
 
+    /// Sets the maximum number of constructed values that may be nested
+    /// inside each other before decoding fails, protecting against stack
+    /// exhaustion from untrusted input. Defaults to 64.
+    #[must_use]
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum length in bytes of the contents of any single value.
+    /// Values declaring a longer length are rejected before their contents
+    /// are read. Unlimited by default.
+    #[must_use]
+    pub const fn with_max_value_length(mut self, max_value_length: usize) -> Self {
+        self.max_value_length = Some(max_value_length);
+        self
+    }
+
+    /// Sets the maximum number of elements in a single `SEQUENCE OF` or
+    /// `SET OF` value. Unlimited by default.
+    #[must_use]
+    pub const fn with_max_elements(mut self, max_elements: usize) -> Self {
+        self.max_elements = Some(max_elements);
+        self
+    }
+
+    /// Sets the maximum number of arcs in a single `OBJECT IDENTIFIER`.
+    /// Unlimited by default.
+    #[must_use]
+    pub const fn with_max_oid_arcs(mut self, max_oid_arcs: usize) -> Self {
+        self.max_oid_arcs = Some(max_oid_arcs);
+        self
+    }
+
+    /// Sets whether invalid UTF-8 in a `UTF8String` is replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER` instead of failing to decode, e.g. to
+    /// inspect certificates with Latin-1 text in `UTF8String` fields.
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_lossy_utf8(mut self, lossy_utf8: bool) -> Self {
+        self.lossy_utf8 = lossy_utf8;
+        self
+    }
+
     /// Returns the currently selected codec.
     #[must_use]
     pub fn current_codec(&self) -> crate::Codec {
@@ -1,12 +1,12 @@
 use alloc::vec::Vec;
 
 use nom::IResult;
-use num_traits::ToPrimitive;
 
-use super::{BerDecodeErrorKind, DecodeError, DecoderOptions, DerDecodeErrorKind};
+use super::{BerDecodeErrorKind, DecodeError, DecodeErrorKind, DecoderOptions, DerDecodeErrorKind};
 use crate::{
     ber::identifier::Identifier,
-    types::{Class, IntegerType, Tag},
+    error::CodecDecodeError,
+    types::{Class, Tag},
 };
 
 #[allow(clippy::type_complexity)]
@@ -15,17 +15,14 @@ pub(crate) fn parse_value(
     input: &[u8],
     tag: Option<Tag>,
 ) -> super::Result<(&[u8], (Identifier, Option<&[u8]>))> {
-    let (input, identifier) = parse_identifier_octet(input).map_err(|e| match e {
-        ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, config.current_codec()),
-        ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, config.current_codec()),
-    })?;
+    let (input, identifier) = parse_identifier_octet(config, input)
+        .map_err(|e| e.into_decode_error(config.current_codec()))?;
 
     if let Some(tag) = tag {
         BerDecodeErrorKind::assert_tag(tag, identifier.tag)?;
     }
 
-    let (input, contents) = parse_contents(config, identifier, input)
-        .map_err(|e| DecodeError::map_nom_err(e, config.current_codec()))?;
+    let (input, contents) = parse_contents(config, identifier, input)?;
 
     Ok((input, (identifier, contents)))
 }
@@ -34,6 +31,7 @@ pub(crate) fn parse_encoded_value<'input, RV>(
     config: DecoderOptions,
     slice: &'input [u8],
     tag: Tag,
+    depth: usize,
     primitive_callback: fn(&'input [u8], crate::Codec) -> super::Result<RV>,
 ) -> super::Result<(&'input [u8], RV)>
 where
@@ -42,34 +40,56 @@ where
     let (input, (identifier, contents)) = parse_value(config, slice, Some(tag))?;
 
     if identifier.is_primitive() {
+        // `parse_length` rejects the indefinite form for primitive values.
+        let contents = contents.ok_or(BerDecodeErrorKind::IndefiniteLengthNotAllowed)?;
         Ok((
             input,
-            (primitive_callback)(contents.unwrap(), config.current_codec())?,
+            (primitive_callback)(contents, config.current_codec())?,
         ))
     } else if config.encoding_rules.allows_constructed_strings() {
+        if depth >= config.max_depth {
+            return Err(BerDecodeErrorKind::NestingTooDeep {
+                limit: config.max_depth,
+            }
+            .into());
+        }
+
         let mut container = RV::new();
-        let mut input = input;
 
         const EOC: &[u8] = &[0, 0];
 
-        while !input.is_empty() && !input.starts_with(EOC) {
-            let (_, identifier) = parse_identifier_octet(input).map_err(|e| match e {
-                ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, config.current_codec()),
-                ParseNumberError::Overflow => {
-                    DecodeError::integer_overflow(32u32, config.current_codec())
+        // Definite length values contain their segments, while indefinite
+        // length values are followed by their segments and an end-of-contents.
+        let mut segments = contents.unwrap_or(input);
+
+        while !segments.is_empty() && !(contents.is_none() && segments.starts_with(EOC)) {
+            let (_, identifier) = parse_identifier_octet(config, segments)
+                .map_err(|e| e.into_decode_error(config.current_codec()))?;
+            let (i, mut child) = parse_encoded_value(
+                config,
+                segments,
+                identifier.tag,
+                depth + 1,
+                primitive_callback,
+            )
+            .map_err(|error| {
+                if contents.is_some() {
+                    within_definite_length(error)
+                } else {
+                    error
                 }
             })?;
-            let (i, mut child) =
-                parse_encoded_value(config, input, identifier.tag, primitive_callback)?;
-            input = i;
-            container.append(&mut child);
+            segments = i;
+            container.append(&mut child)?;
         }
 
-        if contents.is_none() {
-            let (i, _) = nom::bytes::streaming::tag(EOC)(input)
+        let input = if contents.is_none() {
+            let (i, _) = nom::bytes::streaming::tag(EOC)(segments)
                 .map_err(|e| DecodeError::map_nom_err(e, config.current_codec()))?;
-            input = i;
-        }
+            i
+        } else {
+            input
+        };
 
         Ok((input, container))
     } else {
@@ -77,7 +97,101 @@ where
     }
 }
 
+/// Parses an `OCTET STRING` like [`parse_encoded_value`], but copies the
+/// contents of its segments into `buffer` after the first `written` octets
+/// instead of collecting them, and advances `written` past them.
+pub(crate) fn parse_octet_string_into<'input>(
+    config: DecoderOptions,
+    slice: &'input [u8],
+    tag: Tag,
+    depth: usize,
+    buffer: &mut [u8],
+    written: &mut usize,
+) -> super::Result<&'input [u8]> {
+    let (input, (identifier, contents)) = parse_value(config, slice, Some(tag))?;
+
+    if identifier.is_primitive() {
+        let contents = contents.ok_or(BerDecodeErrorKind::IndefiniteLengthNotAllowed)?;
+        let end = *written + contents.len();
+        let Some(target) = buffer.get_mut(*written..end) else {
+            return Err(DecodeError::size_constraint_not_satisfied(
+                None,
+                alloc::format!("0..={}", buffer.len()),
+                config.current_codec(),
+            ));
+        };
+        target.copy_from_slice(contents);
+        *written = end;
+
+        Ok(input)
+    } else if config.encoding_rules.allows_constructed_strings() {
+        if depth >= config.max_depth {
+            return Err(BerDecodeErrorKind::NestingTooDeep {
+                limit: config.max_depth,
+            }
+            .into());
+        }
+
+        const EOC: &[u8] = &[0, 0];
+
+        let mut segments = contents.unwrap_or(input);
+
+        while !segments.is_empty() && !(contents.is_none() && segments.starts_with(EOC)) {
+            segments = parse_octet_string_into(
+                config,
+                segments,
+                Tag::OCTET_STRING,
+                depth + 1,
+                buffer,
+                written,
+            )
+            .map_err(|error| {
+                if contents.is_some() {
+                    within_definite_length(error)
+                } else {
+                    error
+                }
+            })?;
+        }
+
+        if contents.is_none() {
+            let (i, _) = nom::bytes::streaming::tag(EOC)(segments)
+                .map_err(|e| DecodeError::map_nom_err(e, config.current_codec()))?;
+            Ok(i)
+        } else {
+            Ok(input)
+        }
+    } else {
+        Err(DerDecodeErrorKind::ConstructedEncodingNotAllowed.into())
+    }
+}
+
+/// Converts running out of input while parsing the contents of a definite
+/// length value into [`BerDecodeErrorKind::LengthExceedsContainer`], as the
+/// contents were already known to be complete. Running out of input anywhere
+/// else is reported as incomplete, so that callers can read more input.
+///
+/// Errors recording the fields they occurred in are looked through, as a
+/// field that runs out of input is just as malformed.
+pub(crate) fn within_definite_length(mut error: DecodeError) -> DecodeError {
+    fn convert(error: &mut DecodeError) {
+        match &mut *error.kind {
+            DecodeErrorKind::FieldError { nested, .. } => convert(nested),
+            kind @ DecodeErrorKind::Incomplete { .. } => {
+                *kind = DecodeErrorKind::CodecSpecific {
+                    inner: CodecDecodeError::Ber(BerDecodeErrorKind::LengthExceedsContainer),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    convert(&mut error);
+    error
+}
+
 pub(crate) fn parse_identifier_octet(
+    config: DecoderOptions,
     input: &[u8],
 ) -> Result<(&[u8], Identifier), ParseNumberError<&[u8]>> {
     use nom::error::ParseError;
@@ -85,22 +199,31 @@ pub(crate) fn parse_identifier_octet(
     let (input, identifier) = parse_initial_octet(input)?;
 
     if identifier.tag == Tag::EOC {
-        return Err(ParseNumberError::Nom(nom::Err::Failure(
-            <_>::from_error_kind(input, nom::error::ErrorKind::Eof),
-        )));
+        return Err(ParseNumberError::EndOfContents);
     }
 
     let (input, tag) = if identifier.tag.value >= 0x1f {
-        let (input, tag) = parse_encoded_number(input)?;
-
-        match tag.to_u32() {
-            Some(value) => (input, value),
-            None => {
-                return Err(ParseNumberError::Nom(nom::Err::Failure(
-                    <_>::from_error_kind(input, nom::error::ErrorKind::TooLarge),
-                )));
-            }
+        // Leading zero septets are redundant, which BER decoders commonly
+        // accept, but the canonical encoding rules don't.
+        let padding = input.iter().take_while(|&&octet| octet == 0x80).count();
+        if padding > 0 && !config.encoding_rules.is_ber() {
+            return Err(ParseNumberError::Nom(nom::Err::Failure(
+                <_>::from_error_kind(input, nom::error::ErrorKind::Verify),
+            )));
+        }
+
+        let start = &input[padding..];
+        let (input, tag) = parse_encoded_number(start)?;
+
+        // The long form is only allowed for tag numbers that don't fit in the
+        // initial octet.
+        if tag < 0x1f {
+            return Err(ParseNumberError::Nom(nom::Err::Failure(
+                <_>::from_error_kind(start, nom::error::ErrorKind::Verify),
+            )));
         }
+
+        (input, tag)
     } else {
         (input, identifier.tag.value)
     };
@@ -109,16 +232,52 @@ pub(crate) fn parse_identifier_octet(
 }
 
 pub fn parse_encoded_number(input: &[u8]) -> Result<(&[u8], u32), ParseNumberError<&[u8]>> {
-    let (input, body) = nom::bytes::streaming::take_while(|i| i & 0x80 != 0)(input)?;
+    let (input, (body, end)) = take_base128_octets(input, MAX_BASE128_OCTETS)?;
+
+    Ok((input, concat_number(body, end)?))
+}
+
+/// The most octets a base 128 `u32` can be encoded in.
+pub(crate) const MAX_BASE128_OCTETS: usize = 5;
+
+/// The most octets a base 128 `u128` can be encoded in.
+const MAX_BASE128_ARC_OCTETS: usize = 19;
+
+/// Takes the octets of a base 128 number, split into the octets with the
+/// continuation bit set and the final octet. Only `max_octets` octets are
+/// looked at, so a long run of continuation octets fails with
+/// [`ParseNumberError::Overflow`] without reading any further.
+fn take_base128_octets(
+    input: &[u8],
+    max_octets: usize,
+) -> Result<(&[u8], (&[u8], u8)), ParseNumberError<&[u8]>> {
+    let (input, body) =
+        nom::bytes::streaming::take_while_m_n(0, max_octets - 1, |i| i & 0x80 != 0)(input)?;
     let (input, end) = nom::bytes::streaming::take(1usize)(input)?;
 
-    Ok((input, concat_number(body, end[0])?))
+    if end[0] & 0x80 != 0 {
+        return Err(ParseNumberError::Overflow);
+    }
+
+    Ok((input, (body, end[0])))
 }
 
 #[derive(Debug)]
 pub(crate) enum ParseNumberError<Input> {
     Nom(nom::Err<nom::error::Error<Input>>),
     Overflow,
+    /// An end-of-contents marker was found instead of an identifier.
+    EndOfContents,
+}
+
+impl<Input: core::fmt::Debug> ParseNumberError<Input> {
+    pub(crate) fn into_decode_error(self, codec: crate::Codec) -> DecodeError {
+        match self {
+            Self::Nom(e) => DecodeError::map_nom_err(e, codec),
+            Self::Overflow => DecodeError::integer_overflow(32u32, codec),
+            Self::EndOfContents => BerDecodeErrorKind::UnexpectedEndOfContents.into(),
+        }
+    }
 }
 
 impl<Input> From<nom::Err<nom::error::Error<Input>>> for ParseNumberError<Input> {
@@ -127,23 +286,23 @@ impl<Input> From<nom::Err<nom::error::Error<Input>>> for ParseNumberError<Input>
     }
 }
 
-pub fn parse_base128_number(input: &[u8]) -> Result<(&[u8], u32), ParseNumberError<&[u8]>> {
-    let (input, body) = nom::bytes::streaming::take_while(|i| i & 0x80 != 0)(input)?;
-    let (input, end) = nom::bytes::streaming::take(1usize)(input)?;
+/// Parses a base 128 object identifier arc, which can be as wide as a `u128`.
+pub fn parse_base128_number(input: &[u8]) -> Result<(&[u8], u128), ParseNumberError<&[u8]>> {
+    let (input, (body, end)) = take_base128_octets(input, MAX_BASE128_ARC_OCTETS)?;
 
-    let mut number = 0u32;
+    let mut number = 0u128;
     for byte in body {
-        number = match number.checked_shl(7) {
+        number = match number.checked_mul(1 << 7) {
             Some(n) => n,
             None => return Err(ParseNumberError::Overflow),
         };
-        number |= u32::from(*byte & 0x7F);
+        number |= u128::from(*byte & 0x7F);
     }
-    number = match number.checked_shl(7) {
+    number = match number.checked_mul(1 << 7) {
         Some(n) => n,
         None => return Err(ParseNumberError::Overflow),
     };
-    number |= u32::from(end[0]);
+    number |= u128::from(end);
     Ok((input, number))
 }
 
@@ -163,29 +322,96 @@ pub(crate) fn parse_contents(
     config: DecoderOptions,
     identifier: Identifier,
     input: &[u8],
-) -> IResult<&[u8], Option<&[u8]>> {
-    let (input, length) = nom::bytes::streaming::take(1usize)(input)?;
-    let length = length[0];
-    if length == 0x80 {
-        if identifier.is_primitive() || !config.encoding_rules.allows_indefinite() {
-            nom::error::context("Indefinite length not allowed", |_| {
-                Err(nom::Err::Failure(nom::error::Error::new(
-                    input,
-                    nom::error::ErrorKind::Tag,
-                )))
-            })(input)
-        } else {
-            Ok((input, None))
+) -> super::Result<(&[u8], Option<&[u8]>)> {
+    let (input, length) = parse_length(config, identifier, input)?;
+
+    let Some(Length { length, minimal }) = length else {
+        return Ok((input, None));
+    };
+
+    if !minimal && !config.encoding_rules.is_ber() {
+        return Err(DerDecodeErrorKind::NonMinimalLength.into());
+    }
+
+    if let Some(limit) = config.max_value_length {
+        if length > limit {
+            return Err(BerDecodeErrorKind::ValueTooLong { length, limit }.into());
+        }
+    }
+
+    let (input, contents) = nom::bytes::streaming::take(length)(input)
+        .map_err(|e| DecodeError::map_nom_err(e, config.current_codec()))?;
+    Ok((input, Some(contents)))
+}
+
+/// A definite length read from the length octets.
+pub(crate) struct Length {
+    pub(crate) length: usize,
+    /// Whether the length was encoded in the fewest possible octets.
+    pub(crate) minimal: bool,
+}
+
+/// Parses the length octets, returning `None` for the indefinite form.
+pub(crate) fn parse_length(
+    config: DecoderOptions,
+    identifier: Identifier,
+    input: &[u8],
+) -> super::Result<(&[u8], Option<Length>)> {
+    let codec = config.current_codec();
+    let (input, length) = nom::bytes::streaming::take(1usize)(input)
+        .map_err(|e| DecodeError::map_nom_err(e, codec))?;
+
+    match length[0] {
+        0x80 => {
+            if identifier.is_primitive() || !config.encoding_rules.allows_indefinite() {
+                Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into())
+            } else {
+                Ok((input, None))
+            }
+        }
+        // X.690 8.1.3.5 c): reserved for future extensions.
+        0xFF => Err(BerDecodeErrorKind::ReservedLengthOctet.into()),
+        length @ 0..=0x7F => Ok((
+            input,
+            Some(Length {
+                length: usize::from(length),
+                minimal: true,
+            }),
+        )),
+        length => {
+            let length = length ^ 0x80;
+            let (input, length_slice) = nom::bytes::streaming::take(length)(input)
+                .map_err(|e| DecodeError::map_nom_err(e, codec))?;
+
+            if let Some(length) = parse_long_length(length_slice) {
+                // The long form is only minimal without leading zero octets
+                // and for lengths that don't fit in the short form.
+                let minimal = length_slice[0] != 0 && length > 0x7F;
+                Ok((input, Some(Length { length, minimal })))
+            } else {
+                Err(DecodeError::length_exceeds_platform_width(
+                    "Length longer than possible capacity.".into(),
+                    codec,
+                ))
+            }
         }
-    } else {
-        let (input, contents) = take_contents(input, length)?;
-        Ok((input, Some(contents)))
     }
 }
 
+/// Reads the octets of a long form length, returning `None` if the length
+/// has more significant octets than fit in a `usize`. Leading zero octets are
+/// allowed, as BER doesn't require the minimal number of length octets.
+pub(crate) fn parse_long_length(octets: &[u8]) -> Option<usize> {
+    octets.iter().try_fold(0usize, |length, octet| {
+        length
+            .checked_mul(1 << 8)
+            .map(|length| length | usize::from(*octet))
+    })
+}
+
 pub(crate) trait Appendable: Sized {
     fn new() -> Self;
-    fn append(&mut self, other: &mut Self);
+    fn append(&mut self, other: &mut Self) -> super::Result<()>;
 }
 
 impl Appendable for Vec<u8> {
@@ -193,8 +419,9 @@ impl Appendable for Vec<u8> {
         Self::new()
     }
 
-    fn append(&mut self, other: &mut Self) {
+    fn append(&mut self, other: &mut Self) -> super::Result<()> {
         self.append(other);
+        Ok(())
     }
 }
 
@@ -203,8 +430,15 @@ impl Appendable for crate::types::BitString {
         Self::new()
     }
 
-    fn append(&mut self, other: &mut Self) {
+    fn append(&mut self, other: &mut Self) -> super::Result<()> {
+        // Only the final segment may have unused bits, so anything appended
+        // after a segment that wasn't a whole number of octets is invalid.
+        if self.len() % 8 != 0 {
+            return Err(BerDecodeErrorKind::UnalignedBitStringSegment.into());
+        }
+
         self.append(other);
+        Ok(())
     }
 }
 
@@ -219,14 +453,14 @@ fn concat_number(body: &[u8], start: u8) -> Result<u32, ParseNumberError<&[u8]>>
     let mut number = u32::from(body[0] & 0x7F);
 
     for byte in &body[1..] {
-        number = match number.checked_shl(7) {
+        number = match number.checked_mul(1 << 7) {
             Some(n) => n,
             None => return Err(ParseNumberError::Overflow),
         };
         number |= u32::from(byte & 0x7F);
     }
 
-    number = match number.checked_shl(7) {
+    number = match number.checked_mul(1 << 7) {
         Some(n) => n,
         None => return Err(ParseNumberError::Overflow),
     };
@@ -234,35 +468,6 @@ fn concat_number(body: &[u8], start: u8) -> Result<u32, ParseNumberError<&[u8]>>
     Ok(number | start)
 }
 
-fn take_contents(input: &[u8], length: u8) -> IResult<&[u8], &[u8]> {
-    match length {
-        0xff => nom::error::context("Reserved Length Octet found.", |_| {
-            Err(nom::Err::Failure(nom::error::Error::new(
-                input,
-                nom::error::ErrorKind::Tag,
-            )))
-        })(input),
-        0 => Ok((input, &[])),
-        1..=0x7f => nom::bytes::streaming::take(length)(input),
-        _ => {
-            let length = length ^ 0x80;
-            let (input, length_slice) = nom::bytes::streaming::take(length)(input)?;
-            let length = usize::try_from_bytes(length_slice, crate::Codec::Ber);
-
-            if let Ok(length) = length {
-                nom::bytes::streaming::take(length)(input)
-            } else {
-                nom::error::context("Length longer than possible capacity.", |_| {
-                    Err(nom::Err::Failure(nom::error::Error::new(
-                        input,
-                        nom::error::ErrorKind::Tag,
-                    )))
-                })(input)
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,11 +478,87 @@ mod tests {
 
     #[test]
     fn long_tag() {
-        let (_, identifier) = parse_identifier_octet([0xFF, 0x83, 0x7F][..].into()).unwrap();
+        let (_, identifier) =
+            parse_identifier_octet(BER_OPTIONS, [0xFF, 0x83, 0x7F][..].into()).unwrap();
         assert!(identifier.is_constructed);
         assert_eq!(Tag::new(Class::Private, 511), identifier.tag);
     }
 
+    #[test]
+    fn high_tag_number() {
+        let (_, identifier) = parse_identifier_octet(BER_OPTIONS, &[0xBF, 0x87, 0x68]).unwrap();
+        assert!(identifier.is_constructed);
+        assert_eq!(Tag::new(Class::Context, 1000), identifier.tag);
+
+        let (_, identifier) = parse_identifier_octet(BER_OPTIONS, &[0x9F, 0x1F]).unwrap();
+        assert!(!identifier.is_constructed);
+        assert_eq!(Tag::new(Class::Context, 31), identifier.tag);
+
+        let (_, identifier) =
+            parse_identifier_octet(BER_OPTIONS, &[0x9F, 0x8F, 0xFF, 0xFF, 0xFF, 0x7F]).unwrap();
+        assert_eq!(Tag::new(Class::Context, u32::MAX), identifier.tag);
+    }
+
+    #[test]
+    fn non_minimal_high_tag_number() {
+        // Leading zero septets, which only BER accepts.
+        for data in [&[0xBF, 0x80, 0x87, 0x68][..], &[0x1F, 0x80, 0x41, 0x02]] {
+            assert!(parse_identifier_octet(CER_OPTIONS, data).is_err());
+            assert!(parse_identifier_octet(DER_OPTIONS, data).is_err());
+        }
+        let (_, identifier) =
+            parse_identifier_octet(BER_OPTIONS, &[0xBF, 0x80, 0x87, 0x68]).unwrap();
+        assert_eq!(Tag::new(Class::Context, 1000), identifier.tag);
+        let (rest, identifier) =
+            parse_identifier_octet(BER_OPTIONS, &[0x1F, 0x80, 0x80, 0x41, 0x02]).unwrap();
+        assert_eq!(Tag::new(Class::Universal, 65), identifier.tag);
+        assert_eq!(&[0x02], rest);
+
+        // Tag number small enough for the short form.
+        assert!(parse_identifier_octet(BER_OPTIONS, &[0x9F, 0x05]).is_err());
+        // Tag number wider than 32 bits.
+        assert!(matches!(
+            parse_identifier_octet(BER_OPTIONS, &[0x9F, 0x90, 0x80, 0x80, 0x80, 0x00]),
+            Err(ParseNumberError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn long_base128_numbers() {
+        // Only the first five octets are looked at, however many follow.
+        let continuation = alloc::vec![0x81; 1 << 20];
+        assert!(matches!(
+            parse_base128_number(&continuation),
+            Err(ParseNumberError::Overflow)
+        ));
+        assert!(matches!(
+            parse_encoded_number(&continuation),
+            Err(ParseNumberError::Overflow)
+        ));
+
+        let identifier = [&[0x9F][..], &continuation].concat();
+        assert!(matches!(
+            parse_identifier_octet(BER_OPTIONS, &identifier),
+            Err(ParseNumberError::Overflow)
+        ));
+
+        // The largest numbers that fit are still five and nineteen octets
+        // long.
+        assert_eq!(
+            u32::MAX,
+            parse_encoded_number(&[0x8F, 0xFF, 0xFF, 0xFF, 0x7F])
+                .unwrap()
+                .1
+        );
+        let largest_arc = [&[0x83][..], &[0xFF; 17], &[0x7F]].concat();
+        assert_eq!(u128::MAX, parse_base128_number(&largest_arc).unwrap().1);
+        let too_large_arc = [&[0x84][..], &[0x80; 17], &[0x00]].concat();
+        assert!(matches!(
+            parse_base128_number(&too_large_arc),
+            Err(ParseNumberError::Overflow)
+        ));
+    }
+
     #[test]
     fn value_long_length_form() {
         let (_, (_, contents)) = parse_value(
@@ -309,4 +590,116 @@ mod tests {
         assert!(parse_value(DER_OPTIONS, bytes, Tag::SEQUENCE.into()).is_err());
         assert!(parse_value(CER_OPTIONS, bytes, Tag::SEQUENCE.into()).is_ok());
     }
+
+    #[test]
+    fn non_minimal_length() {
+        // The same BOOLEAN with a minimal, and two redundant length encodings.
+        for data in [
+            &[0x01, 0x01, 0xFF][..],
+            &[0x01, 0x81, 0x01, 0xFF],
+            &[0x01, 0x82, 0x00, 0x01, 0xFF],
+        ] {
+            let (_, (_, contents)) = parse_value(BER_OPTIONS, data, Tag::BOOL.into()).unwrap();
+            assert_eq!(contents.unwrap(), &[0xFF]);
+        }
+
+        // X.690 10.1 requires the minimal form under both CER and DER.
+        for options in [CER_OPTIONS, DER_OPTIONS] {
+            assert!(parse_value(options, &[0x01, 0x01, 0xFF], Tag::BOOL.into()).is_ok());
+            assert!(parse_value(options, &[0x01, 0x81, 0x01, 0xFF], Tag::BOOL.into()).is_err());
+            assert!(
+                parse_value(options, &[0x01, 0x82, 0x00, 0x01, 0xFF], Tag::BOOL.into()).is_err()
+            );
+
+            // A long form length that needs it is fine.
+            let mut data = alloc::vec![0x04, 0x81, 0x80];
+            data.extend_from_slice(&[0; 0x80]);
+            assert!(parse_value(options, &data, Tag::OCTET_STRING.into()).is_ok());
+        }
+    }
+
+    #[test]
+    fn oversized_length() {
+        // Nine significant length octets can't fit in a `usize`.
+        let data = [0x04, 0x89, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0xAA];
+        assert!(parse_value(BER_OPTIONS, &data, Tag::OCTET_STRING.into()).is_err());
+        assert_eq!(None, parse_long_length(&data[2..11]));
+
+        // Leading zero octets aren't significant.
+        let data = [0x04, 0x89, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0xAA];
+        let (_, (_, contents)) = parse_value(BER_OPTIONS, &data, Tag::OCTET_STRING.into()).unwrap();
+        assert_eq!(contents.unwrap(), &[0xAA]);
+
+        // Lengths that fit in a `usize` but are longer than the input.
+        let data = [
+            0x02, 0x88, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+        ];
+        assert!(parse_value(BER_OPTIONS, &data, Tag::INTEGER.into()).is_err());
+
+        let mut data = alloc::vec![0x04, 0x82, 0x01, 0x00];
+        data.extend_from_slice(&[0; 0xFF]);
+        assert!(parse_value(BER_OPTIONS, &data, Tag::OCTET_STRING.into()).is_err());
+        data.push(0);
+        assert!(parse_value(BER_OPTIONS, &data, Tag::OCTET_STRING.into()).is_ok());
+    }
+
+    #[test]
+    fn malformed_lengths() {
+        let kind = |data: &[u8]| *parse_value(BER_OPTIONS, data, None).unwrap_err().kind;
+        let ber_kind = |data: &[u8]| match kind(data) {
+            DecodeErrorKind::CodecSpecific {
+                inner: CodecDecodeError::Ber(kind),
+            } => kind,
+            kind => panic!("unexpected error: {kind:?}"),
+        };
+        let needed = |data: &[u8]| match kind(data) {
+            DecodeErrorKind::Incomplete { needed } => needed,
+            kind => panic!("unexpected error: {kind:?}"),
+        };
+
+        // The reserved length octet, however much input follows.
+        assert!(matches!(
+            ber_kind(&[0x30, 0xFF]),
+            BerDecodeErrorKind::ReservedLengthOctet
+        ));
+        let mut data = alloc::vec![0x30, 0xFF];
+        data.extend_from_slice(&[0; 0x7F]);
+        assert!(matches!(
+            ber_kind(&data),
+            BerDecodeErrorKind::ReservedLengthOctet
+        ));
+
+        // Long form lengths missing some of their length octets.
+        assert_eq!(nom::Needed::new(4), needed(&[0x04, 0x84]));
+        assert_eq!(nom::Needed::new(1), needed(&[0x04, 0x81]));
+        assert_eq!(nom::Needed::new(2), needed(&[0x04, 0x83, 0x01]));
+
+        // Indefinite lengths on primitive values.
+        assert!(matches!(
+            ber_kind(&[0x04, 0x80, 0x00, 0x00]),
+            BerDecodeErrorKind::IndefiniteLengthNotAllowed
+        ));
+
+        // The longest possible long form, which can't fit in a `usize`.
+        let mut data = alloc::vec![0x04, 0xFE];
+        data.extend_from_slice(&[0xFF; 0x7E]);
+        assert!(matches!(
+            kind(&data),
+            DecodeErrorKind::LengthExceedsPlatformWidth { .. }
+        ));
+
+        // No header of up to three octets panics.
+        for options in [BER_OPTIONS, CER_OPTIONS, DER_OPTIONS] {
+            for identifier in 0..=u8::MAX {
+                for length in 0..=u8::MAX {
+                    for next in [0x00, 0x01, 0x7F, 0x80, 0xFF] {
+                        let data = [identifier, length, next];
+                        for end in 0..=data.len() {
+                            let _ = parse_value(options, &data[..end], None);
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,118 @@
+//! Reading a single BER value from an [`std::io::Read`] source.
+
+use alloc::vec::Vec;
+use std::io::Read;
+
+use super::{
+    parser::MAX_BASE128_OCTETS, BerDecodeErrorKind, DecodeError, DecodeErrorKind, DecoderOptions,
+};
+use crate::de::Error;
+
+/// Reads exactly one complete value, identifier and length octets included,
+/// from `reader` and appends it to `buffer`. Nothing past the end of the value
+/// is read, so consecutive values can be read from the same source.
+pub(crate) fn read_value<R: Read>(
+    reader: &mut R,
+    config: DecoderOptions,
+    buffer: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    read_nested_value(reader, config, buffer, 0).map(drop)
+}
+
+/// Reads a value, returning whether it was an end-of-contents marker.
+fn read_nested_value<R: Read>(
+    reader: &mut R,
+    config: DecoderOptions,
+    buffer: &mut Vec<u8>,
+    depth: usize,
+) -> Result<bool, DecodeError> {
+    let initial = read_byte(reader, config, buffer)?;
+    let is_constructed = initial & 0x20 != 0;
+
+    // Long form tag numbers are limited to a `u32`, as in the parser, so a
+    // source that keeps sending continuation octets can't be read forever.
+    if initial & 0x1F == 0x1F {
+        let mut octets = 1;
+        while read_byte(reader, config, buffer)? & 0x80 != 0 {
+            if octets == MAX_BASE128_OCTETS {
+                return Err(DecodeError::integer_overflow(32, config.current_codec()));
+            }
+            octets += 1;
+        }
+    }
+
+    let length = read_byte(reader, config, buffer)?;
+
+    if length == 0x80 {
+        if !is_constructed || !config.encoding_rules.allows_indefinite() {
+            return Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into());
+        }
+
+        if depth >= config.max_depth {
+            return Err(BerDecodeErrorKind::NestingTooDeep {
+                limit: config.max_depth,
+            }
+            .into());
+        }
+
+        while !read_nested_value(reader, config, buffer, depth + 1)? {}
+        return Ok(false);
+    }
+
+    if length == 0xFF {
+        return Err(BerDecodeErrorKind::ReservedLengthOctet.into());
+    }
+
+    let length = if length & 0x80 == 0 {
+        usize::from(length)
+    } else {
+        let start = buffer.len();
+        for _ in 0..(length & 0x7F) {
+            read_byte(reader, config, buffer)?;
+        }
+        super::parser::parse_long_length(&buffer[start..]).ok_or_else(|| {
+            DecodeError::length_exceeds_platform_width(
+                "Length longer than possible capacity.".into(),
+                config.current_codec(),
+            )
+        })?
+    };
+
+    if let Some(limit) = config.max_value_length {
+        if length > limit {
+            return Err(BerDecodeErrorKind::ValueTooLong { length, limit }.into());
+        }
+    }
+
+    let read = reader
+        .by_ref()
+        .take(length as u64)
+        .read_to_end(buffer)
+        .map_err(|error| io_error(error, config))?;
+
+    if read != length {
+        return Err(DecodeError::incomplete(
+            nom::Needed::new(length - read),
+            config.current_codec(),
+        ));
+    }
+
+    Ok(initial == 0 && length == 0)
+}
+
+fn read_byte<R: Read>(
+    reader: &mut R,
+    config: DecoderOptions,
+    buffer: &mut Vec<u8>,
+) -> Result<u8, DecodeError> {
+    let mut byte = [0];
+    reader
+        .read_exact(&mut byte)
+        .map_err(|error| io_error(error, config))?;
+    buffer.push(byte[0]);
+    Ok(byte[0])
+}
+
+pub(super) fn io_error(error: std::io::Error, config: DecoderOptions) -> DecodeError {
+    DecodeError::from_kind(DecodeErrorKind::Io { error }, config.current_codec())
+}
@@ -0,0 +1,256 @@
+//! A human readable listing of BER encoded data, in the style of
+//! `openssl asn1parse`.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::tlv::{iter, Iter, Tlv};
+use crate::types::{any::write_octets, Class, Integer, ObjectIdentifier, Tag};
+
+/// Lists the BER encoded values in `input`, one per line, with their offset,
+/// depth, header and contents lengths, tag, and a preview of the contents of
+/// primitive values. Contents without a readable form are shown in
+/// hexadecimal, as in the `Debug` output of [`Any`](crate::types::Any).
+///
+/// Malformed input doesn't fail the listing, instead a line starting with
+/// `error:` marks the offset where parsing stopped.
+/// ```
+/// let data = &[0x30, 0x06, 0x02, 0x01, 0x05, 0x0C, 0x01, b'a'];
+///
+/// assert_eq!(
+///     rasn::ber::dump(data),
+///     "    0:d=0  hl=2 l=   6 cons: SEQUENCE\n\
+///      \x20   2:d=1  hl=2 l=   1 prim:   INTEGER  :5\n\
+///      \x20   5:d=1  hl=2 l=   1 prim:   UTF8String  :a\n"
+/// );
+/// ```
+#[must_use]
+pub fn dump(input: &[u8]) -> String {
+    let mut output = String::new();
+    dump_values(&mut output, iter(input), 0);
+    output
+}
+
+fn dump_values(output: &mut String, values: Iter<'_>, mut offset: usize) {
+    for value in values {
+        match value {
+            Ok(tlv) => {
+                dump_value(output, &tlv);
+                offset = tlv.offset() + tlv.as_bytes().len();
+            }
+            Err(error) => {
+                let _ = writeln!(output, "{offset:>5}:error: {}", error.kind);
+            }
+        }
+    }
+}
+
+fn dump_value(output: &mut String, tlv: &Tlv<'_>) {
+    let _ = write!(
+        output,
+        "{:>5}:d={:<2} hl={} l=",
+        tlv.offset(),
+        tlv.depth(),
+        tlv.header_len()
+    );
+    if tlv.is_indefinite() {
+        output.push_str(" inf");
+    } else {
+        let _ = write!(output, "{:>4}", tlv.contents().len());
+    }
+
+    let form = if tlv.is_constructed() { "cons" } else { "prim" };
+    let _ = write!(output, " {form}: {:indent$}", "", indent = tlv.depth() * 2);
+    write_tag_name(output, tlv.tag());
+
+    if tlv.is_constructed() {
+        output.push('\n');
+        dump_values(output, tlv.iter_children(), tlv.offset() + tlv.header_len());
+    } else {
+        if let Some(preview) = preview(tlv) {
+            let _ = write!(output, "  :{preview}");
+        }
+        output.push('\n');
+    }
+}
+
+fn write_tag_name(output: &mut String, tag: Tag) {
+    let name = match tag {
+        Tag::EOC => "EOC",
+        Tag::BOOL => "BOOLEAN",
+        Tag::INTEGER => "INTEGER",
+        Tag::BIT_STRING => "BIT STRING",
+        Tag::OCTET_STRING => "OCTET STRING",
+        Tag::NULL => "NULL",
+        Tag::OBJECT_IDENTIFIER => "OBJECT IDENTIFIER",
+        Tag::OBJECT_DESCRIPTOR => "ObjectDescriptor",
+        Tag::EXTERNAL => "EXTERNAL",
+        Tag::REAL => "REAL",
+        Tag::ENUMERATED => "ENUMERATED",
+        Tag::EMBEDDED_PDV => "EMBEDDED PDV",
+        Tag::UTF8_STRING => "UTF8String",
+        Tag::RELATIVE_OID => "RELATIVE-OID",
+        Tag::TIME => "TIME",
+        Tag::SEQUENCE => "SEQUENCE",
+        Tag::SET => "SET",
+        Tag::NUMERIC_STRING => "NumericString",
+        Tag::PRINTABLE_STRING => "PrintableString",
+        Tag::TELETEX_STRING => "TeletexString",
+        Tag::VIDEOTEX_STRING => "VideotexString",
+        Tag::IA5_STRING => "IA5String",
+        Tag::UTC_TIME => "UTCTime",
+        Tag::GENERALIZED_TIME => "GeneralizedTime",
+        Tag::GRAPHIC_STRING => "GraphicString",
+        Tag::VISIBLE_STRING => "VisibleString",
+        Tag::GENERAL_STRING => "GeneralString",
+        Tag::UNIVERSAL_STRING => "UniversalString",
+        Tag::CHARACTER_STRING => "CHARACTER STRING",
+        Tag::BMP_STRING => "BMPString",
+        Tag::DATE => "DATE",
+        Tag::TIME_OF_DAY => "TIME-OF-DAY",
+        Tag::DATE_TIME => "DATE-TIME",
+        Tag::DURATION => "DURATION",
+        Tag::OID_IRI => "OID-IRI",
+        Tag::RELATIVE_OID_IRI => "RELATIVE-OID-IRI",
+        _ => {
+            let class = match tag.class {
+                Class::Universal => "univ",
+                Class::Application => "appl",
+                Class::Context => "cont",
+                Class::Private => "priv",
+            };
+            let _ = write!(output, "{class} [{}]", tag.value);
+            return;
+        }
+    };
+
+    output.push_str(name);
+}
+
+/// Formats the contents of a primitive value, or returns `None` for values
+/// without contents.
+fn preview(tlv: &Tlv<'_>) -> Option<String> {
+    let contents = tlv.contents();
+    if contents.is_empty() {
+        return None;
+    }
+
+    let decoded = match tlv.tag() {
+        Tag::BOOL => tlv
+            .decode::<bool>()
+            .ok()
+            .map(|value| String::from(if value { "TRUE" } else { "FALSE" })),
+        Tag::INTEGER | Tag::ENUMERATED => tlv
+            .decode::<Integer>()
+            .ok()
+            .map(|value| alloc::format!("{value}")),
+        Tag::OBJECT_IDENTIFIER => tlv
+            .decode::<ObjectIdentifier>()
+            .ok()
+            .map(|value| alloc::format!("{value}")),
+        Tag::UTF8_STRING
+        | Tag::NUMERIC_STRING
+        | Tag::PRINTABLE_STRING
+        | Tag::TELETEX_STRING
+        | Tag::IA5_STRING
+        | Tag::UTC_TIME
+        | Tag::GENERALIZED_TIME
+        | Tag::GRAPHIC_STRING
+        | Tag::VISIBLE_STRING
+        | Tag::GENERAL_STRING
+        | Tag::TIME
+        | Tag::DATE
+        | Tag::TIME_OF_DAY
+        | Tag::DATE_TIME
+        | Tag::DURATION
+        | Tag::OID_IRI
+        | Tag::RELATIVE_OID_IRI => core::str::from_utf8(contents)
+            .ok()
+            .filter(|string| !string.chars().any(char::is_control))
+            .map(String::from),
+        _ => None,
+    };
+
+    Some(decoded.unwrap_or_else(|| hex(contents)))
+}
+
+fn hex(contents: &[u8]) -> String {
+    let mut output = String::new();
+    let _ = write_octets(&mut output, contents);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_values() {
+        let data = &[
+            0x30, 0x80, 0x30, 0x08, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x01, b'a', 0xA0, 0x0B,
+            0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B, 0x01, 0x01, 0xFF,
+            0x82, 0x02, 0xAB, 0xCD, 0x00, 0x00, 0x02, 0x02, 0xFF, 0x7F, 0x05, 0x00,
+        ];
+
+        let expected = "    0:d=0  hl=2 l= inf cons: SEQUENCE
+    2:d=1  hl=2 l=   8 cons:   SEQUENCE
+    4:d=2  hl=2 l=   3 prim:     OBJECT IDENTIFIER  :2.5.4.3
+    9:d=2  hl=2 l=   1 prim:     UTF8String  :a
+   12:d=1  hl=2 l=  11 cons:   cont [0]
+   14:d=2  hl=2 l=   9 prim:     OBJECT IDENTIFIER  :1.2.840.113549.1.1.11
+   25:d=1  hl=2 l=   1 prim:   BOOLEAN  :TRUE
+   28:d=1  hl=2 l=   2 prim:   cont [2]  :ab cd
+   34:d=0  hl=2 l=   2 prim: INTEGER  :-129
+   38:d=0  hl=2 l=   0 prim: NULL
+";
+
+        pretty_assertions::assert_eq!(expected, dump(data));
+    }
+
+    #[test]
+    fn time_and_iri_types() {
+        // DATE, DURATION and OID-IRI have tag numbers of 31 and above, so
+        // their identifiers use the high tag number form.
+        let data = &[
+            0x30, 0x1F, 0x1F, 0x1F, 0x0A, b'2', b'0', b'2', b'4', b'-', b'0', b'2', b'-', b'2',
+            b'9', 0x1F, 0x22, 0x03, b'P', b'1', b'D', 0x1F, 0x23, 0x09, b'/', b'I', b'S', b'O',
+            b'/', b'R', b'e', b'g', b'.',
+        ];
+
+        let expected = "    0:d=0  hl=2 l=  31 cons: SEQUENCE
+    2:d=1  hl=3 l=  10 prim:   DATE  :2024-02-29
+   15:d=1  hl=3 l=   3 prim:   DURATION  :P1D
+   21:d=1  hl=3 l=   9 prim:   OID-IRI  :/ISO/Reg.
+";
+
+        pretty_assertions::assert_eq!(expected, dump(data));
+    }
+
+    #[test]
+    fn marks_malformed_input() {
+        // An `OCTET STRING`, followed by a `SEQUENCE` that is missing the
+        // rest of its contents.
+        let data = &[0x04, 0x02, 0xAB, 0xCD, 0x30, 0x05, 0x30, 0x03, 0x02];
+        let output = dump(data);
+        let lines = output.lines().collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(2, lines.len());
+        assert_eq!(
+            "    0:d=0  hl=2 l=   2 prim: OCTET STRING  :ab cd",
+            lines[0]
+        );
+        assert!(lines[1].starts_with("    4:error: "));
+    }
+
+    #[test]
+    fn truncates_long_contents() {
+        let mut data = alloc::vec![0x04, 0x11];
+        data.extend(0..0x11);
+
+        assert_eq!(
+            "    0:d=0  hl=2 l=  17 prim: OCTET STRING  \
+             :00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f ...\n",
+            dump(&data)
+        );
+    }
+}
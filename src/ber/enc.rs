@@ -2,15 +2,14 @@
 
 mod config;
 
-use alloc::{borrow::ToOwned, collections::VecDeque, string::ToString, vec::Vec};
-use chrono::Timelike;
+use alloc::{borrow::ToOwned, vec::Vec};
 
 use super::Identifier;
 use crate::{
     bits::octet_string_ascending,
     types::{
         self,
-        oid::{MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET},
+        oid::{is_valid_object_identifier, MAX_OID_SECOND_OCTET},
         Constraints, Enumerated, IntegerType, Tag,
     },
     Codec, Encode,
@@ -23,11 +22,95 @@ const START_OF_CONTENTS: u8 = 0x80;
 const END_OF_CONTENTS: &[u8] = &[0, 0];
 
 /// Encodes Rust structures into Basic Encoding Rules data.
-pub struct Encoder {
+///
+/// By default the encoding is built up in a `Vec<u8>`, with the definite
+/// lengths of constructed values inserted once the encoding is finished. An
+/// encoder can instead write to a [`Sink`] front to back, after the lengths
+/// have been counted in a first pass over the value, which is how
+/// [`encode_into`][super::encode_into] writes to a slice without buffering.
+pub struct Encoder<W = Vec<u8>> {
     output: Vec<u8>,
     config: EncoderOptions,
     is_set_encoding: bool,
     set_buffer: alloc::collections::BTreeMap<Tag, Vec<u8>>,
+    /// Definite lengths of constructed values that are still missing from
+    /// `output`, as the position they belong at and the length to encode.
+    /// They're inserted once encoding is finished, so that the contents of
+    /// nested values are only moved once regardless of their depth.
+    lengths: Vec<(usize, usize)>,
+    /// The number of octets the entries of `lengths` will take up.
+    length_octets: usize,
+    /// Where the output goes instead of `output` when it's written front to
+    /// back.
+    stream: Option<Stream<W>>,
+}
+
+/// A destination for an encoding that is written front to back.
+pub trait Sink {
+    /// Appends `bytes` to the encoding written so far.
+    fn write(&mut self, bytes: &[u8]);
+}
+
+impl Sink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+/// A [`Sink`] that discards its input, for counting the octets of an encoding.
+pub(crate) struct Discard;
+
+impl Sink for Discard {
+    fn write(&mut self, _: &[u8]) {}
+}
+
+/// A [`Sink`] writing to the start of a slice, which skips any writes that
+/// don't fit instead of panicking.
+pub(crate) struct SliceSink<'buffer> {
+    pub(crate) buffer: &'buffer mut [u8],
+    pub(crate) position: usize,
+}
+
+impl Sink for SliceSink<'_> {
+    fn write(&mut self, bytes: &[u8]) {
+        let end = self.position + bytes.len();
+        if let Some(buffer) = self.buffer.get_mut(self.position..end) {
+            buffer.copy_from_slice(bytes);
+        }
+        self.position = end;
+    }
+}
+
+/// A [`Sink`] writing to an [`std::io::Write`], which keeps the first error
+/// and skips any writes after it.
+#[cfg(feature = "std")]
+pub(crate) struct IoSink<'writer, W: std::io::Write> {
+    pub(crate) writer: &'writer mut W,
+    pub(crate) error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Sink for IoSink<'_, W> {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_none() {
+            if let Err(error) = self.writer.write_all(bytes) {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+/// The state of an [`Encoder`] writing to a [`Sink`] front to back.
+struct Stream<W> {
+    sink: W,
+    /// The number of octets written to `sink`.
+    written: usize,
+    /// The definite lengths of constructed values in the order they start,
+    /// which are recorded while counting and used while writing.
+    lengths: Vec<usize>,
+    /// The index in `lengths` of the next constructed value while writing,
+    /// or `None` while counting.
+    next_length: Option<usize>,
 }
 
 /// A convenience type around results needing to return one or many bytes.
@@ -36,34 +119,95 @@ enum ByteOrBytes {
     Many(Vec<u8>),
 }
 
+/// The octets of a definite length, which are at most an initial octet
+/// followed by the octets of a `usize`.
+struct DefiniteLength {
+    octets: [u8; 1 + core::mem::size_of::<usize>()],
+    len: usize,
+}
+
+impl core::ops::Deref for DefiniteLength {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.octets[..self.len]
+    }
+}
+
+/// Counts the octets of the encoding of `value` with `config`, along with the
+/// definite lengths of the constructed values in it in the order they start,
+/// without buffering the encoding.
+pub(crate) fn count<T: Encode>(
+    config: EncoderOptions,
+    value: &T,
+) -> Result<(usize, Vec<usize>), EncodeError> {
+    let mut encoder = Encoder::with_stream(
+        config,
+        Stream {
+            sink: Discard,
+            written: 0,
+            lengths: Vec::new(),
+            next_length: None,
+        },
+    );
+    value.encode(&mut encoder)?;
+    let stream = encoder.stream.expect("encoder is streaming");
+
+    Ok((stream.written, stream.lengths))
+}
+
+/// Writes the encoding of `value` with `config` to `sink` front to back,
+/// using the `length` of the encoding and the `lengths` of its constructed
+/// values returned by [`count`].
+pub(crate) fn write<T: Encode, W: Sink>(
+    config: EncoderOptions,
+    value: &T,
+    sink: W,
+    (length, lengths): (usize, Vec<usize>),
+) -> Result<W, EncodeError> {
+    let constructed = lengths.len();
+    let mut encoder = Encoder::with_stream(
+        config,
+        Stream {
+            sink,
+            written: 0,
+            lengths,
+            next_length: Some(0),
+        },
+    );
+    value.encode(&mut encoder)?;
+    let stream = encoder.stream.expect("encoder is streaming");
+
+    if stream.written == length && stream.next_length == Some(constructed) {
+        Ok(stream.sink)
+    } else {
+        Err(inconsistent_encoding(config.current_codec()))
+    }
+}
+
+/// The error returned when a value doesn't encode the same way when it's
+/// written as when it was counted.
+fn inconsistent_encoding(codec: Codec) -> EncodeError {
+    EncodeError::from_kind(
+        EncodeErrorKind::Custom {
+            msg: "value encoded differently when written than when counted".into(),
+        },
+        codec,
+    )
+}
+
 impl Encoder {
     /// Creates a new instance from the given `config`.
     #[must_use]
     pub fn new(config: EncoderOptions) -> Self {
-        Self {
-            config,
-            is_set_encoding: false,
-            output: <_>::default(),
-            set_buffer: <_>::default(),
-        }
-    }
-
-    /// Returns the currently selected codec.
-    #[must_use]
-    pub fn codec(&self) -> crate::Codec {
-        self.config.current_codec()
+        Self::with_output(config, Vec::new(), false)
     }
 
     /// Creates a new instance from the given `config`, and uses SET encoding
     /// logic, ensuring that all messages are encoded in order by tag.
     #[must_use]
     pub fn new_set(config: EncoderOptions) -> Self {
-        Self {
-            config,
-            is_set_encoding: true,
-            output: <_>::default(),
-            set_buffer: <_>::default(),
-        }
+        Self::with_output(config, Vec::new(), true)
     }
 
     /// Creates a new instance from the given `config` and a user-supplied
@@ -73,17 +217,86 @@ impl Encoder {
     #[must_use]
     pub fn new_with_buffer(config: EncoderOptions, mut buffer: Vec<u8>) -> Self {
         buffer.clear();
+        Self::with_output(config, buffer, false)
+    }
+
+    /// Creates a new instance from the given `config` which appends its
+    /// output to the existing contents of `buffer`.
+    pub(crate) fn from_buffer(config: EncoderOptions, buffer: Vec<u8>) -> Self {
+        Self::with_output(config, buffer, false)
+    }
+
+    #[must_use]
+    /// Canonical byte presentation for CER/DER as defined in X.690 section 11.7.
+    /// Also used for BER on this crate.
+    pub fn datetime_to_canonical_generalized_time_bytes(value: &types::GeneralizedTime) -> Vec<u8> {
+        types::DateTime::from(*value)
+            .to_generalized_time_string(false)
+            .into_bytes()
+    }
+
+    #[must_use]
+    /// Canonical byte presentation for CER/DER UTCTime as defined in X.690 section 11.8.
+    /// Also used for BER on this crate.
+    pub fn datetime_to_canonical_utc_time_bytes(value: &types::UtcTime) -> Vec<u8> {
+        types::DateTime::from(*value)
+            .to_utc_time_string()
+            .into_bytes()
+    }
+
+    /// Like [`Self::datetime_to_canonical_utc_time_bytes`], but returns an
+    /// error instead of wrapping years outside of 1950..=2049, which UTCTime
+    /// can't represent (RFC 5280 4.1.2.5.1).
+    pub(crate) fn checked_utc_time_bytes(
+        value: &types::UtcTime,
+        codec: Codec,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let year = types::DateTime::from(*value).to_utc().year;
+        if !(1950..=2049).contains(&year) {
+            return Err(EncodeError::utc_time_year_out_of_range(year, codec));
+        }
+
+        Ok(Self::datetime_to_canonical_utc_time_bytes(value))
+    }
+
+    #[must_use]
+    /// Canonical byte presentation for CER/DER DATE as defined in X.690 section 8.26.2
+    /// Also used for BER on this crate.
+    #[cfg(feature = "chrono")]
+    pub fn naivedate_to_date_bytes(value: &chrono::NaiveDate) -> Vec<u8> {
+        alloc::format!("{}", value.format("%Y%m%d")).into_bytes()
+    }
+}
+
+impl<W: Sink> Encoder<W> {
+    fn with_output(config: EncoderOptions, output: Vec<u8>, is_set_encoding: bool) -> Self {
         Self {
-            output: buffer,
+            output,
             config,
-            is_set_encoding: false,
+            is_set_encoding,
             set_buffer: <_>::default(),
+            lengths: Vec::new(),
+            length_octets: 0,
+            stream: None,
         }
     }
 
+    fn with_stream(config: EncoderOptions, stream: Stream<W>) -> Self {
+        Self {
+            stream: Some(stream),
+            ..Self::with_output(config, Vec::new(), false)
+        }
+    }
+
+    /// Returns the currently selected codec.
+    #[must_use]
+    pub fn codec(&self) -> crate::Codec {
+        self.config.current_codec()
+    }
+
     /// Consumes the encoder and returns the output of the encoding.
     #[must_use]
-    pub fn output(self) -> Vec<u8> {
+    pub fn output(mut self) -> Vec<u8> {
         if self.is_set_encoding {
             self.set_buffer
                 .into_values()
@@ -92,27 +305,66 @@ impl Encoder {
                     acc
                 })
         } else {
+            self.insert_lengths();
             self.output
         }
     }
 
+    /// Inserts the deferred definite lengths into the output, moving each
+    /// section of the output at most once.
+    fn insert_lengths(&mut self) {
+        if self.lengths.is_empty() {
+            return;
+        }
+
+        let mut lengths = core::mem::take(&mut self.lengths);
+        lengths.sort_unstable_by_key(|(position, _)| *position);
+
+        let mut end = self.output.len();
+        let mut shift = self.length_octets;
+        self.output.resize(end + shift, 0);
+
+        for (position, length) in lengths.into_iter().rev() {
+            self.output.copy_within(position..end, position + shift);
+
+            let bytes = Self::encode_definite_length(length);
+            shift -= bytes.len();
+            self.output[position + shift..position + shift + bytes.len()].copy_from_slice(&bytes);
+            end = position;
+        }
+
+        self.length_octets = 0;
+    }
+
+    /// Appends `bytes` to the output.
+    fn write(&mut self, bytes: &[u8]) {
+        match &mut self.stream {
+            Some(stream) => {
+                stream.sink.write(bytes);
+                stream.written += bytes.len();
+            }
+            None => self.output.extend_from_slice(bytes),
+        }
+    }
+
     fn append_byte_or_bytes(&mut self, bytes: ByteOrBytes) {
         match bytes {
-            ByteOrBytes::Single(b) => self.output.push(b),
-            ByteOrBytes::Many(mut bs) => self.output.append(&mut bs),
+            ByteOrBytes::Single(b) => self.write(&[b]),
+            ByteOrBytes::Many(bs) => self.write(&bs),
         }
     }
 
-    pub(super) fn encode_as_base128(&self, number: u32, buffer: &mut Vec<u8>) {
+    pub(super) fn encode_as_base128(&self, number: u128, buffer: &mut Vec<u8>) {
         const WIDTH: u8 = 7;
         const SEVEN_BITS: u8 = 0x7F;
         const EIGHTH_BIT: u8 = 0x80;
 
-        if number < EIGHTH_BIT as u32 {
+        if number < u128::from(EIGHTH_BIT) {
             buffer.push(number as u8);
         } else {
             let mut n: u8;
-            let mut bits_left = 35;
+            // Enough seven bit groups for any `u128`.
+            let mut bits_left = 133;
             let mut cont = false;
             while bits_left > 0 {
                 bits_left -= WIDTH;
@@ -160,7 +412,7 @@ impl Encoder {
 
         if tag_number >= FIVE_BITS {
             let mut buffer = alloc::vec![tag_byte | FIVE_BITS as u8];
-            self.encode_as_base128(tag_number, &mut buffer);
+            self.encode_as_base128(u128::from(tag_number), &mut buffer);
             ByteOrBytes::Many(buffer)
         } else {
             tag_byte |= tag_number as u8;
@@ -169,33 +421,42 @@ impl Encoder {
     }
 
     fn encode_length(&mut self, identifier: Identifier, value: &[u8]) {
-        if identifier.is_primitive() || !self.config.encoding_rules.is_cer() {
-            let len_bytes = self.encode_definite_length(value.len());
-            self.append_byte_or_bytes(len_bytes);
-            self.output.extend_from_slice(value);
+        if identifier.is_primitive() || !self.config.uses_indefinite_length() {
+            self.write(&Self::encode_definite_length(value.len()));
+            self.write(value);
         } else {
-            self.output.push(START_OF_CONTENTS);
-            self.output.extend_from_slice(value);
-            self.output.extend_from_slice(END_OF_CONTENTS);
+            self.write(&[START_OF_CONTENTS]);
+            self.write(value);
+            self.write(END_OF_CONTENTS);
         }
     }
 
-    fn encode_definite_length(&mut self, len: usize) -> ByteOrBytes {
+    /// Returns the number of octets needed to encode `len` as a definite length.
+    fn definite_length_size(len: usize) -> usize {
         if len <= 127 {
-            #[allow(clippy::cast_possible_truncation)]
-            ByteOrBytes::Single(len as u8)
+            1
         } else {
-            let mut length = len;
-            let mut length_buffer = VecDeque::new();
-
-            while length != 0 {
-                length_buffer.push_front((length & 0xff) as u8);
-                length >>= 8;
-            }
+            1 + (usize::BITS - len.leading_zeros()).div_ceil(8) as usize
+        }
+    }
 
-            length_buffer.push_front(length_buffer.len() as u8 | 0x80);
+    fn encode_definite_length(len: usize) -> DefiniteLength {
+        let mut octets = [0; 1 + core::mem::size_of::<usize>()];
 
-            ByteOrBytes::Many(length_buffer.into())
+        if len <= 127 {
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                octets[0] = len as u8;
+            }
+            DefiniteLength { octets, len: 1 }
+        } else {
+            let size = Self::definite_length_size(len);
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                octets[0] = (size - 1) as u8 | 0x80;
+            }
+            octets[1..size].copy_from_slice(&len.to_be_bytes()[octets.len() - size..]);
+            DefiniteLength { octets, len: size }
         }
     }
 
@@ -218,13 +479,13 @@ impl Encoder {
             let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
             self.append_byte_or_bytes(ident_bytes);
 
-            self.output.push(START_OF_CONTENTS);
+            self.write(&[START_OF_CONTENTS]);
 
             for chunk in value.chunks(max_string_length) {
                 self.encode_primitive(nested_tag, chunk);
             }
 
-            self.output.extend_from_slice(END_OF_CONTENTS);
+            self.write(END_OF_CONTENTS);
             self.encode_to_set(tag);
         } else {
             self.encode_primitive(tag, value);
@@ -241,8 +502,70 @@ impl Encoder {
         self.encode_value(Identifier::from_tag(tag, true), value);
     }
 
+    /// Encodes a constructed value whose contents are written by `encode_fn`
+    /// directly into the output, deferring the length until the output is
+    /// finished instead of encoding the contents into a separate buffer. When
+    /// writing to a sink, the length is either counted or already known.
+    fn encode_constructed_with<F>(&mut self, tag: Tag, encode_fn: F) -> Result<(), EncodeError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), EncodeError>,
+    {
+        // Each value encoded while building a SET is moved into the set
+        // buffer, so the contents need an encoder of their own.
+        if self.is_set_encoding {
+            let mut encoder = Self::with_output(self.config, Vec::new(), false);
+            (encode_fn)(&mut encoder)?;
+            self.encode_constructed(tag, &encoder.output());
+            return Ok(());
+        }
+
+        let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
+        self.append_byte_or_bytes(ident_bytes);
+
+        if self.config.uses_indefinite_length() {
+            self.write(&[START_OF_CONTENTS]);
+            (encode_fn)(self)?;
+            self.write(END_OF_CONTENTS);
+        } else if let Some(stream) = &mut self.stream {
+            match stream.next_length {
+                Some(index) => {
+                    let length = *stream
+                        .lengths
+                        .get(index)
+                        .ok_or_else(|| inconsistent_encoding(self.config.current_codec()))?;
+                    stream.next_length = Some(index + 1);
+                    self.write(&Self::encode_definite_length(length));
+                    (encode_fn)(self)?;
+                }
+                None => {
+                    let index = stream.lengths.len();
+                    let start = stream.written;
+                    stream.lengths.push(0);
+                    (encode_fn)(self)?;
+
+                    let stream = self.stream.as_mut().expect("encoder is streaming");
+                    let length = stream.written - start;
+                    stream.lengths[index] = length;
+                    // The length octets are counted without being written.
+                    stream.written += Self::definite_length_size(length);
+                }
+            }
+        } else {
+            let start = self.output.len();
+            let nested_length_octets = self.length_octets;
+            (encode_fn)(self)?;
+            // The contents include the lengths of nested values, which are
+            // not yet part of the output.
+            let length = self.output.len() - start + self.length_octets - nested_length_octets;
+            self.lengths.push((start, length));
+            self.length_octets += Self::definite_length_size(length);
+        }
+
+        Ok(())
+    }
+
     /// Encodes a given ASN.1 BER value with the `identifier`.
-    fn encode_value(&mut self, identifier: Identifier, value: &[u8]) {
+    pub(crate) fn encode_value(&mut self, identifier: Identifier, value: &[u8]) {
         let ident_bytes = self.encode_identifier(identifier);
         self.append_byte_or_bytes(ident_bytes);
         self.encode_length(identifier, value);
@@ -257,10 +580,111 @@ impl Encoder {
                 .insert(tag, core::mem::take(&mut self.output));
         }
     }
+
+    /// Returns the contents of a `REAL` value that is zero, infinite or NaN,
+    /// which use the same encoding in every form.
+    fn special_real_bytes(value: f64) -> Option<Vec<u8>> {
+        if value == 0.0 {
+            Some(if value.is_sign_negative() {
+                alloc::vec![0x43]
+            } else {
+                Vec::new()
+            })
+        } else if value.is_nan() {
+            Some(alloc::vec![0x42])
+        } else if value.is_infinite() {
+            Some(alloc::vec![if value.is_sign_negative() {
+                0x41
+            } else {
+                0x40
+            }])
+        } else {
+            None
+        }
+    }
+
+    /// Encodes `value` as the contents of a binary `REAL` in base 2, with an
+    /// odd mantissa as required by CER and DER.
+    fn real_as_binary_bytes(value: f64) -> Vec<u8> {
+        if let Some(bytes) = Self::special_real_bytes(value) {
+            return bytes;
+        }
+
+        let (mantissa, exponent, sign) = num_traits::float::FloatCore::integer_decode(value);
+        let shift = mantissa.trailing_zeros();
+        let mantissa = mantissa >> shift;
+        let exponent = i32::from(exponent) + shift as i32;
+
+        let exponent_bytes = exponent.to_be_bytes();
+        let mut start = 0;
+        while start < exponent_bytes.len() - 1
+            && ((exponent_bytes[start] == 0 && exponent_bytes[start + 1] & 0x80 == 0)
+                || (exponent_bytes[start] == 0xFF && exponent_bytes[start + 1] & 0x80 != 0))
+        {
+            start += 1;
+        }
+        let exponent_bytes = &exponent_bytes[start..];
+
+        let mut first = 0x80 | (exponent_bytes.len() as u8 - 1);
+        if sign < 0 {
+            first |= 0x40;
+        }
+
+        let mantissa_bytes = mantissa.to_be_bytes();
+        let mantissa_start = mantissa_bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(mantissa_bytes.len() - 1);
+
+        let mut bytes = alloc::vec![first];
+        bytes.extend_from_slice(exponent_bytes);
+        bytes.extend_from_slice(&mantissa_bytes[mantissa_start..]);
+        bytes
+    }
+
+    /// Encodes `value` as the contents of a decimal `REAL` in the ISO 6093
+    /// NR3 form, using the canonical representation required by CER and DER:
+    /// an integer mantissa without trailing zeros, followed by `.E` and the
+    /// exponent.
+    fn real_as_decimal_bytes(value: f64) -> Vec<u8> {
+        if let Some(bytes) = Self::special_real_bytes(value) {
+            return bytes;
+        }
+
+        // `{:e}` produces the shortest digits that round trip, as
+        // `[-]d[.ddd]e[-]x`.
+        let scientific = alloc::format!("{value:e}");
+        let (mantissa, exponent) = scientific.split_once('e').unwrap();
+        let exponent: i32 = exponent.parse().unwrap();
+        let (sign, mantissa) = match mantissa.strip_prefix('-') {
+            Some(mantissa) => ("-", mantissa),
+            None => ("", mantissa),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let mut digits = alloc::string::String::from(integer);
+        digits.push_str(fraction);
+        let mut exponent = exponent - fraction.len() as i32;
+        while digits.len() > 1 && digits.ends_with('0') {
+            digits.pop();
+            exponent += 1;
+        }
+
+        let text = if exponent == 0 {
+            alloc::format!("{sign}{digits}.E+0")
+        } else {
+            alloc::format!("{sign}{digits}.E{exponent}")
+        };
+
+        let mut bytes = alloc::vec![0x03];
+        bytes.extend_from_slice(text.as_bytes());
+        bytes
+    }
+
     /// Converts an object identifier into a byte vector in BER format.
     /// Reusable function by other codecs.
-    pub fn object_identifier_as_bytes(&mut self, oid: &[u32]) -> Result<Vec<u8>, EncodeError> {
-        if oid.len() < 2 {
+    pub fn object_identifier_as_bytes(&mut self, oid: &[u128]) -> Result<Vec<u8>, EncodeError> {
+        if !is_valid_object_identifier(oid) {
             return Err(BerEncodeErrorKind::invalid_object_identifier(oid.to_owned()).into());
         }
         let mut bytes = Vec::new();
@@ -268,60 +692,55 @@ impl Encoder {
         let first = oid[0];
         let second = oid[1];
 
-        if first > MAX_OID_FIRST_OCTET {
+        let Some(root) = (first * (MAX_OID_SECOND_OCTET + 1)).checked_add(second) else {
             return Err(BerEncodeErrorKind::invalid_object_identifier(oid.to_owned()).into());
-        }
-        self.encode_as_base128((first * (MAX_OID_SECOND_OCTET + 1)) + second, &mut bytes);
+        };
+        self.encode_as_base128(root, &mut bytes);
         for component in oid.iter().skip(2) {
             self.encode_as_base128(*component, &mut bytes);
         }
         Ok(bytes)
     }
-    #[must_use]
-    /// Canonical byte presentation for CER/DER as defined in X.690 section 11.7.
-    /// Also used for BER on this crate.
-    pub fn datetime_to_canonical_generalized_time_bytes(
-        value: &chrono::DateTime<chrono::FixedOffset>,
-    ) -> Vec<u8> {
-        let mut string;
-        // Convert to UTC so we can always append Z.
-        let value = value.naive_utc();
-        if value.nanosecond() > 0 {
-            string = value.format("%Y%m%d%H%M%S.%f").to_string();
-            // No trailing zeros with fractions
-            while string.ends_with('0') {
-                string.pop();
-            }
-        } else {
-            string = value.format("%Y%m%d%H%M%S").to_string();
-        }
-        string.push('Z');
-        string.into_bytes()
-    }
 
-    #[must_use]
-    /// Canonical byte presentation for CER/DER UTCTime as defined in X.690 section 11.8.
-    /// Also used for BER on this crate.
-    pub fn datetime_to_canonical_utc_time_bytes(value: &chrono::DateTime<chrono::Utc>) -> Vec<u8> {
-        value
-            .naive_utc()
-            .format("%y%m%d%H%M%SZ")
-            .to_string()
-            .into_bytes()
+    /// Encodes `values` as a `SET OF`, with their encodings in ascending
+    /// order as CER and DER require (X.690 11.6).
+    fn encode_sorted_set_of<'v, E: Encode + 'v>(
+        &mut self,
+        tag: Tag,
+        values: impl IntoIterator<Item = &'v E>,
+    ) -> Result<(), EncodeError> {
+        let mut encoded_values = values
+            .into_iter()
+            .map(|val| {
+                let mut sequence_encoder = Encoder::new(self.config);
+                val.encode(&mut sequence_encoder)
+                    .map(|()| sequence_encoder.output())
+            })
+            .collect::<Result<Vec<Vec<u8>>, _>>()?;
+
+        // The encodings of the component values of a set-of value shall appear in ascending order,
+        // the encodings being compared as octet strings [...]
+        encoded_values.sort_by(octet_string_ascending);
+        let sorted_elements: Vec<u8> = encoded_values.into_iter().flatten().collect();
+
+        self.encode_constructed(tag, &sorted_elements);
+
+        Ok(())
     }
 
-    #[must_use]
-    /// Canonical byte presentation for CER/DER DATE as defined in X.690 section 8.26.2
-    /// Also used for BER on this crate.
-    pub fn naivedate_to_date_bytes(value: &chrono::NaiveDate) -> Vec<u8> {
-        value.format("%Y%m%d").to_string().into_bytes()
+    /// Byte presentation for BER GeneralizedTime that keeps the value's UTC
+    /// offset, e.g. `20240101120000+0200`, unless it is zero.
+    fn datetime_to_generalized_time_bytes_with_offset(value: &types::GeneralizedTime) -> Vec<u8> {
+        types::DateTime::from(*value)
+            .to_generalized_time_string(true)
+            .into_bytes()
     }
 }
 
-impl crate::Encoder<'_> for Encoder {
+impl<W: Sink> crate::Encoder<'_> for Encoder<W> {
     type Ok = ();
     type Error = EncodeError;
-    type AnyEncoder<'this, const R: usize, const E: usize> = Encoder;
+    type AnyEncoder<'this, const R: usize, const E: usize> = Encoder<W>;
 
     fn codec(&self) -> Codec {
         Self::codec(self)
@@ -337,7 +756,7 @@ impl crate::Encoder<'_> for Encoder {
             return Err(BerEncodeErrorKind::AnyInSet.into());
         }
 
-        self.output.extend_from_slice(&value.contents);
+        self.write(&value.contents);
 
         Ok(())
     }
@@ -349,8 +768,16 @@ impl crate::Encoder<'_> for Encoder {
         value: &types::BitStr,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        let value = if self.config.trim_bit_strings {
+            &value[..value.last_one().map_or(0, |last| last + 1)]
+        } else {
+            value
+        };
         let bit_length = value.len();
-        let vec = value.to_bitvec();
+        let mut vec = value.to_bitvec();
+        // Slices that don't start on an octet boundary keep their offset.
+        vec.force_align();
+        vec.set_uninitialized(false);
         let bytes = vec.as_raw_slice();
         let unused_bits: u8 = ((bytes.len() * 8) - bit_length).try_into().map_err(|err| {
             EncodeError::from_kind(
@@ -414,12 +841,24 @@ impl crate::Encoder<'_> for Encoder {
 
     fn encode_real<R: types::RealType>(
         &mut self,
-        _: Tag,
+        tag: Tag,
         _: Constraints,
-        _: &R,
+        value: &R,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(EncodeError::real_not_supported(self.codec()))
+        use num_traits::ToPrimitive;
+
+        let value = value
+            .try_to_float()
+            .and_then(|value| value.to_f64())
+            .ok_or(BerEncodeErrorKind::ExceedsSupportedRealRange)?;
+        let contents = if self.config.decimal_real {
+            Self::real_as_decimal_bytes(value)
+        } else {
+            Self::real_as_binary_bytes(value)
+        };
+        self.encode_primitive(tag, &contents);
+        Ok(())
     }
 
     fn encode_null(
@@ -434,7 +873,7 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_object_identifier(
         &mut self,
         tag: Tag,
-        oid: &[u32],
+        oid: &[u128],
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         let bytes = self.object_identifier_as_bytes(oid)?;
@@ -548,10 +987,8 @@ impl crate::Encoder<'_> for Encoder {
         value: &types::UtcTime,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(
-            tag,
-            Self::datetime_to_canonical_utc_time_bytes(value).as_slice(),
-        );
+        let bytes = Encoder::checked_utc_time_bytes(value, self.codec())?;
+        self.encode_primitive(tag, &bytes);
 
         Ok(())
     }
@@ -562,21 +999,46 @@ impl crate::Encoder<'_> for Encoder {
         value: &types::GeneralizedTime,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(
-            tag,
-            Self::datetime_to_canonical_generalized_time_bytes(value).as_slice(),
-        );
+        let bytes = if self.config.generalized_time_offset {
+            Self::datetime_to_generalized_time_bytes_with_offset(value)
+        } else {
+            Encoder::datetime_to_canonical_generalized_time_bytes(value)
+        };
+        self.encode_primitive(tag, &bytes);
 
         Ok(())
     }
 
+    fn encode_raw_utc_time(
+        &mut self,
+        tag: Tag,
+        value: &types::RawUtcTime,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes());
+
+        Ok(())
+    }
+
+    fn encode_raw_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::RawGeneralizedTime,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
     fn encode_date(
         &mut self,
         tag: Tag,
         value: &types::Date,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, Self::naivedate_to_date_bytes(value).as_slice());
+        self.encode_primitive(tag, Encoder::naivedate_to_date_bytes(value).as_slice());
 
         Ok(())
     }
@@ -635,15 +1097,29 @@ impl crate::Encoder<'_> for Encoder {
         _constraints: Constraints,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut sequence_encoder = Self::new(self.config);
+        self.encode_constructed_with(tag, |encoder| {
+            for value in values {
+                value.encode(encoder)?;
+            }
 
-        for value in values {
-            value.encode(&mut sequence_encoder)?;
-        }
+            Ok(())
+        })
+    }
 
-        self.encode_constructed(tag, &sequence_encoder.output);
+    fn encode_sequence_of_iter<E: Encode, I: IntoIterator<Item = E>>(
+        &mut self,
+        tag: Tag,
+        values: I,
+        _constraints: Constraints,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_constructed_with(tag, |encoder| {
+            for value in values {
+                value.encode(encoder)?;
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn encode_set_of<E: Encode + Eq + core::hash::Hash>(
@@ -653,24 +1129,21 @@ impl crate::Encoder<'_> for Encoder {
         _constraints: Constraints,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut encoded_values = values
-            .to_vec()
-            .iter()
-            .map(|val| {
-                let mut sequence_encoder = Self::new(self.config);
-                val.encode(&mut sequence_encoder)
-                    .map(|()| sequence_encoder.output)
-            })
-            .collect::<Result<Vec<Vec<u8>>, _>>()?;
-
-        // The encodings of the component values of a set-of value shall appear in ascending order,
-        // the encodings being compared as octet strings [...]
-        encoded_values.sort_by(octet_string_ascending);
-        let sorted_elements: Vec<u8> = encoded_values.into_iter().flatten().collect();
-
-        self.encode_constructed(tag, &sorted_elements);
+        self.encode_sorted_set_of(tag, values.to_vec())
+    }
 
-        Ok(())
+    fn encode_set_of_slice<E: Encode>(
+        &mut self,
+        tag: Tag,
+        values: &[E],
+        constraints: Constraints,
+        identifier: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        if self.config.encoding_rules.is_ber() {
+            self.encode_sequence_of(tag, values, constraints, identifier)
+        } else {
+            self.encode_sorted_set_of(tag, values)
+        }
     }
 
     fn encode_explicit_prefix<V: Encode>(
@@ -680,9 +1153,7 @@ impl crate::Encoder<'_> for Encoder {
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         if value.is_present() {
-            let mut encoder = Self::new(self.config);
-            value.encode(&mut encoder)?;
-            self.encode_constructed(tag, &encoder.output);
+            self.encode_constructed_with(tag, |encoder| value.encode(encoder))?;
         }
         Ok(())
     }
@@ -697,13 +1168,7 @@ impl crate::Encoder<'_> for Encoder {
         C: crate::types::Constructed<RC, EC>,
         F: FnOnce(&mut Self::AnyEncoder<'b, 0, 0>) -> Result<(), Self::Error>,
     {
-        let mut encoder = Self::new(self.config);
-
-        (encoder_scope)(&mut encoder)?;
-
-        self.encode_constructed(tag, &encoder.output);
-
-        Ok(())
+        self.encode_constructed_with(tag, encoder_scope)
     }
 
     fn encode_set<'b, const RC: usize, const EC: usize, C, F>(
@@ -716,7 +1181,7 @@ impl crate::Encoder<'_> for Encoder {
         C: crate::types::Constructed<RC, EC>,
         F: FnOnce(&mut Self::AnyEncoder<'b, 0, 0>) -> Result<(), Self::Error>,
     {
-        let mut encoder = Self::new_set(self.config);
+        let mut encoder = Self::with_output(self.config, Vec::new(), true);
 
         (encoder_scope)(&mut encoder)?;
 
@@ -751,6 +1216,17 @@ impl crate::Encoder<'_> for Encoder {
     {
         value.encode(self)
     }
+
+    fn encode_remaining_components(
+        &mut self,
+        components: &[types::Any],
+    ) -> Result<(), Self::Error> {
+        for component in components {
+            self.encode_any(Tag::EOC, component, types::Identifier::EMPTY)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -802,7 +1278,7 @@ mod tests {
 
     #[test]
     fn encoding_oid() {
-        fn oid_to_bytes(oid: &[u32]) -> Vec<u8> {
+        fn oid_to_bytes(oid: &[u128]) -> Vec<u8> {
             use crate::Encoder;
             let mut enc = self::Encoder::new(EncoderOptions::ber());
             enc.encode_object_identifier(Tag::OBJECT_IDENTIFIER, oid, Identifier::EMPTY)
@@ -837,7 +1313,7 @@ mod tests {
 
     #[test]
     fn base128_test() {
-        fn encode(n: u32) -> Vec<u8> {
+        fn encode(n: u128) -> Vec<u8> {
             let enc = self::Encoder::new(EncoderOptions::ber());
             let mut buffer: Vec<u8> = vec![];
             enc.encode_as_base128(n, &mut buffer);
@@ -1,9 +1,16 @@
-use crate::ber::EncodingRules;
+use crate::{
+    ber::EncodingRules,
+    error::{DerEncodeErrorKind, EncodeError},
+};
 
 /// Options for configuring the [`Encoder`][super::Encoder].
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) indefinite_length: bool,
+    pub(crate) decimal_real: bool,
+    pub(crate) trim_bit_strings: bool,
+    pub(crate) generalized_time_offset: bool,
 }
 
 impl EncoderOptions {
@@ -12,6 +19,10 @@ impl EncoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            indefinite_length: false,
+            decimal_real: false,
+            trim_bit_strings: false,
+            generalized_time_offset: false,
         }
     }
 
@@ -20,6 +31,10 @@ impl EncoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            indefinite_length: false,
+            decimal_real: false,
+            trim_bit_strings: false,
+            generalized_time_offset: false,
         }
     }
 
@@ -28,9 +43,70 @@ impl EncoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            indefinite_length: false,
+            decimal_real: false,
+            trim_bit_strings: false,
+            generalized_time_offset: false,
         }
     }
 
+    /// Sets whether constructed values are encoded with indefinite lengths,
+    /// terminated by end-of-contents octets, instead of definite lengths.
+    /// Primitive values always use definite lengths. CER always uses
+    /// indefinite lengths for constructed values regardless of this option.
+    ///
+    /// # Errors
+    /// Returns [`DerEncodeErrorKind::IndefiniteLengthNotAllowed`] when
+    /// enabling indefinite lengths for DER.
+    pub fn with_indefinite_length(mut self, indefinite_length: bool) -> Result<Self, EncodeError> {
+        if indefinite_length && self.encoding_rules.is_der() {
+            return Err(DerEncodeErrorKind::IndefiniteLengthNotAllowed.into());
+        }
+        self.indefinite_length = indefinite_length;
+        Ok(self)
+    }
+
+    /// Sets whether `REAL` values are encoded in the decimal ISO 6093 NR3
+    /// form instead of the binary form. The decimal form is easier to read
+    /// but may not represent every binary floating point value exactly, so
+    /// only enable it when an exact representation isn't required.
+    #[must_use]
+    pub const fn with_decimal_real(mut self, decimal_real: bool) -> Self {
+        self.decimal_real = decimal_real;
+        self
+    }
+
+    /// Sets whether trailing zero bits are removed from `BIT STRING` values
+    /// before they are encoded, as X.690 11.2.2 requires for DER encodings of
+    /// types with a named bit list. Trailing zero bits are significant for
+    /// other `BIT STRING` types, so only enable it when every `BIT STRING` in
+    /// the encoded value has a named bit list.
+    #[must_use]
+    pub const fn with_trimmed_bit_strings(mut self, trim_bit_strings: bool) -> Self {
+        self.trim_bit_strings = trim_bit_strings;
+        self
+    }
+
+    /// Sets whether `GeneralizedTime` values keep their UTC offset, e.g.
+    /// `20240101120000+0200`, instead of being converted to UTC. Values with
+    /// a zero offset are still encoded with the `Z` suffix.
+    ///
+    /// # Errors
+    /// Returns [`DerEncodeErrorKind::GeneralizedTimeOffsetNotAllowed`] when
+    /// enabling it for CER or DER, which require UTC.
+    pub fn with_generalized_time_offset(mut self, offset: bool) -> Result<Self, EncodeError> {
+        if offset && !self.encoding_rules.is_ber() {
+            return Err(DerEncodeErrorKind::GeneralizedTimeOffsetNotAllowed.into());
+        }
+        self.generalized_time_offset = offset;
+        Ok(self)
+    }
+
+    /// Whether constructed values are encoded with indefinite lengths.
+    pub(crate) fn uses_indefinite_length(&self) -> bool {
+        self.indefinite_length || self.encoding_rules.is_cer()
+    }
+
     /// Returns the currently selected codec.
     #[must_use]
     pub fn current_codec(&self) -> crate::Codec {
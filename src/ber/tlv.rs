@@ -0,0 +1,665 @@
+//! Walking the tag, length, and value (TLV) structure of BER encoded data
+//! without a schema, e.g. for dumping or searching an encoding.
+
+use super::{
+    de::{parser, DecoderOptions},
+    Identifier,
+};
+use crate::{
+    error::{BerDecodeErrorKind, DecodeError},
+    types::Tag,
+};
+
+/// The end-of-contents octets terminating an indefinite length value.
+const EOC: &[u8] = &[0, 0];
+
+/// Iterates over the BER encoded values in `input`, one after another.
+///
+/// Both definite and indefinite lengths are supported, and no contents are
+/// copied.
+/// ```
+/// use rasn::types::Tag;
+///
+/// // A `SEQUENCE` containing an `INTEGER`, followed by a `NULL`.
+/// let data = &[0x30, 0x03, 0x02, 0x01, 0x05, 0x05, 0x00];
+/// let values = rasn::ber::iter(data).collect::<Result<Vec<_>, _>>().unwrap();
+///
+/// assert_eq!(Tag::SEQUENCE, values[0].tag());
+/// assert_eq!(Tag::NULL, values[1].tag());
+///
+/// let integer = values[0].iter_children().next().unwrap().unwrap();
+/// assert_eq!(Tag::INTEGER, integer.tag());
+/// assert_eq!(&[0x05], integer.contents());
+/// ```
+#[must_use]
+pub fn iter(input: &[u8]) -> Iter<'_> {
+    Iter {
+        input,
+        options: DecoderOptions::ber(),
+        depth: 0,
+        offset: 0,
+    }
+}
+
+/// An iterator over consecutive BER encoded values, returned by [`iter`] and
+/// [`Tlv::iter_children`].
+///
+/// The iterator stops after the first error.
+#[derive(Clone, Debug)]
+pub struct Iter<'input> {
+    input: &'input [u8],
+    options: DecoderOptions,
+    depth: usize,
+    /// Offset of `input` from the start of the original input.
+    offset: usize,
+}
+
+impl<'input> Iterator for Iter<'input> {
+    type Item = Result<Tlv<'input>, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+
+        let result = check_depth(self.options, self.depth)
+            .and_then(|()| parse(self.options, self.input, self.depth, self.offset));
+
+        match result {
+            Ok((input, tlv)) => {
+                self.input = input;
+                self.offset += tlv.encoded.len();
+                Some(Ok(tlv))
+            }
+            Err(error) => {
+                self.input = &[];
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// A single BER encoded value, borrowed from the input.
+#[derive(Clone, Copy, Debug)]
+pub struct Tlv<'input> {
+    identifier: Identifier,
+    contents: &'input [u8],
+    encoded: &'input [u8],
+    indefinite: bool,
+    options: DecoderOptions,
+    depth: usize,
+    offset: usize,
+}
+
+impl<'input> Tlv<'input> {
+    /// Returns the identifier, i.e. the tag and whether the value is
+    /// constructed.
+    #[must_use]
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// Returns the tag of the value.
+    #[must_use]
+    pub fn tag(&self) -> Tag {
+        self.identifier.tag
+    }
+
+    /// Returns whether the value is encoded in constructed form.
+    #[must_use]
+    pub fn is_constructed(&self) -> bool {
+        self.identifier.is_constructed()
+    }
+
+    /// Returns whether the value is encoded with an indefinite length.
+    #[must_use]
+    pub fn is_indefinite(&self) -> bool {
+        self.indefinite
+    }
+
+    /// Returns the contents octets. For an indefinite length value these
+    /// exclude the end-of-contents octets.
+    #[must_use]
+    pub fn contents(&self) -> &'input [u8] {
+        self.contents
+    }
+
+    /// Returns the number of identifier and length octets before the
+    /// contents.
+    #[must_use]
+    pub fn header_len(&self) -> usize {
+        let eoc_len = if self.indefinite { EOC.len() } else { 0 };
+        self.encoded.len() - self.contents.len() - eoc_len
+    }
+
+    /// Returns the offset of the value from the start of the input passed to
+    /// [`iter`].
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the nesting depth of the value, where values passed to
+    /// [`iter`] have a depth of zero.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the complete encoding of the value, including its identifier
+    /// and length octets.
+    #[must_use]
+    pub fn as_bytes(&self) -> &'input [u8] {
+        self.encoded
+    }
+
+    /// Iterates over the values inside a constructed value. Primitive values
+    /// have no children, use [`iter`] on their [`contents`][Self::contents]
+    /// to walk values that are wrapped in an `OCTET STRING`.
+    #[must_use]
+    pub fn iter_children(&self) -> Iter<'input> {
+        Iter {
+            input: if self.is_constructed() {
+                self.contents
+            } else {
+                &[]
+            },
+            options: self.options,
+            depth: self.depth + 1,
+            offset: self.offset + self.header_len(),
+        }
+    }
+
+    /// Decodes the value as `T`.
+    /// # Errors
+    /// Returns `DecodeError` if the value is not a valid BER encoding of `T`.
+    pub fn decode<T: crate::Decode>(&self) -> Result<T, DecodeError> {
+        super::decode_with_options(self.options, self.encoded)
+    }
+}
+
+/// The identifier and length octets at the start of a BER encoded value,
+/// returned by [`peek_header`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    /// The tag of the value, and whether it is constructed.
+    pub identifier: Identifier,
+    /// The number of identifier and length octets.
+    pub header_len: usize,
+    /// The length of the contents.
+    pub length: ContentLength,
+}
+
+impl Header {
+    /// Returns the number of octets of the whole value, including the
+    /// header, or `None` if the value has an indefinite length.
+    #[must_use]
+    pub fn total_len(&self) -> Option<usize> {
+        match self.length {
+            ContentLength::Definite(length) => self.header_len.checked_add(length),
+            ContentLength::Indefinite => None,
+        }
+    }
+}
+
+/// The length of the contents of a BER encoded value, as declared by its
+/// length octets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentLength {
+    /// The contents are this many octets long.
+    Definite(usize),
+    /// The contents are terminated by end-of-contents octets.
+    Indefinite,
+}
+
+/// Reads the identifier and length octets at the start of `input`, without
+/// looking at the contents.
+///
+/// This allows waiting until a complete value has arrived before decoding
+/// it, see also [`total_length`].
+/// ```
+/// use rasn::{ber::ContentLength, types::Tag};
+///
+/// // The first octets of a `SEQUENCE` of 300 octets.
+/// let header = rasn::ber::peek_header(&[0x30, 0x82, 0x01, 0x2C, 0x02]).unwrap();
+///
+/// assert_eq!(Tag::SEQUENCE, header.identifier.tag);
+/// assert_eq!(4, header.header_len);
+/// assert_eq!(ContentLength::Definite(300), header.length);
+/// ```
+/// # Errors
+/// Returns [`DecodeErrorKind::Incomplete`] if `input` ends before the end
+/// of the header, or another kind if the header is invalid.
+///
+/// [`DecodeErrorKind::Incomplete`]: crate::error::DecodeErrorKind::Incomplete
+pub fn peek_header(input: &[u8]) -> Result<Header, DecodeError> {
+    let options = DecoderOptions::ber();
+    let codec = options.current_codec();
+
+    let (rest, identifier) =
+        parser::parse_identifier_octet(options, input).map_err(|e| e.into_decode_error(codec))?;
+    let (rest, length) = parser::parse_length(options, identifier, rest)?;
+
+    Ok(Header {
+        identifier,
+        header_len: input.len() - rest.len(),
+        length: length.map_or(ContentLength::Indefinite, |length| {
+            ContentLength::Definite(length.length)
+        }),
+    })
+}
+
+/// Returns the number of octets of the BER encoded value at the start of
+/// `input`, including its header, as declared by the header.
+///
+/// Only the header needs to be present, and `None` is returned for values of
+/// an indefinite length, whose end can only be found by walking their
+/// contents, e.g. with [`iter`].
+/// ```
+/// use rasn::error::DecodeErrorKind;
+///
+/// assert_eq!(Some(5), rasn::ber::total_length(&[0x02, 0x03, 0x01]).unwrap());
+/// assert_eq!(None, rasn::ber::total_length(&[0x30, 0x80]).unwrap());
+///
+/// let error = rasn::ber::total_length(&[0x30, 0x82, 0x01]).unwrap_err();
+/// assert!(matches!(*error.kind, DecodeErrorKind::Incomplete { .. }));
+/// ```
+/// # Errors
+/// Returns [`DecodeErrorKind::Incomplete`] if `input` ends before the end
+/// of the header, or another kind if the header is invalid.
+///
+/// [`DecodeErrorKind::Incomplete`]: crate::error::DecodeErrorKind::Incomplete
+pub fn total_length(input: &[u8]) -> Result<Option<usize>, DecodeError> {
+    let header = peek_header(input)?;
+
+    match header.length {
+        ContentLength::Definite(_) => header.total_len().map(Some).ok_or_else(|| {
+            DecodeError::length_exceeds_platform_width(
+                "value length exceeds usize::MAX".into(),
+                crate::Codec::Ber,
+            )
+        }),
+        ContentLength::Indefinite => Ok(None),
+    }
+}
+
+/// Checks that `input` is exactly one well-formed BER encoded value, without
+/// decoding it or copying any contents.
+///
+/// Every identifier and length is read, the contents of constructed values
+/// must consist of complete values that exactly fill them, indefinite
+/// lengths must be terminated by end-of-contents octets, and values may not
+/// be nested deeper than the decoder's depth limit. The contents of
+/// primitive values are not interpreted.
+/// ```
+/// use rasn::error::{BerDecodeErrorKind, CodecDecodeError, DecodeErrorKind};
+///
+/// // A `SEQUENCE` containing an `INTEGER`.
+/// assert!(rasn::ber::validate(&[0x30, 0x03, 0x02, 0x01, 0x05]).is_ok());
+///
+/// // The `INTEGER` claims more octets than the `SEQUENCE` contains.
+/// let error = rasn::ber::validate(&[0x30, 0x03, 0x02, 0x02, 0x05, 0x00]).unwrap_err();
+/// assert_eq!(Some(2), error.offset);
+/// assert!(matches!(
+///     *error.kind,
+///     DecodeErrorKind::CodecSpecific {
+///         inner: CodecDecodeError::Ber(BerDecodeErrorKind::LengthExceedsContainer),
+///     }
+/// ));
+/// ```
+/// # Errors
+/// Returns `DecodeError` for the first malformed value, with the offset of
+/// that value from the start of `input`.
+pub fn validate(input: &[u8]) -> Result<(), DecodeError> {
+    validate_with_stats(input).map(drop)
+}
+
+/// Like [`validate`], but also returns statistics about the values in
+/// `input`.
+/// ```
+/// // A `SEQUENCE` containing a `SEQUENCE` and a `NULL`.
+/// let data = &[0x30, 0x06, 0x30, 0x02, 0x05, 0x00, 0x05, 0x00];
+/// let stats = rasn::ber::validate_with_stats(data).unwrap();
+///
+/// assert_eq!(4, stats.values);
+/// assert_eq!(2, stats.max_depth);
+/// ```
+/// # Errors
+/// Returns `DecodeError` for the first malformed value, with the offset of
+/// that value from the start of `input`.
+pub fn validate_with_stats(input: &[u8]) -> Result<Stats, DecodeError> {
+    let options = DecoderOptions::ber();
+    let mut stats = Stats::default();
+    let rest = walk(options, input, 0, 0, &mut stats)?;
+
+    if rest.is_empty() {
+        Ok(stats)
+    } else {
+        Err(
+            DecodeError::unexpected_extra_data(rest.len(), options.current_codec())
+                .with_offset(input.len() - rest.len()),
+        )
+    }
+}
+
+/// Statistics about a well-formed BER encoded value, returned by
+/// [`validate_with_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Stats {
+    /// The number of values, counting the outermost value and every value
+    /// nested inside it.
+    pub values: usize,
+    /// The greatest nesting depth of any value, where the outermost value
+    /// has a depth of zero.
+    pub max_depth: usize,
+}
+
+fn check_depth(options: DecoderOptions, depth: usize) -> Result<(), DecodeError> {
+    if depth > options.max_depth {
+        Err(BerDecodeErrorKind::NestingTooDeep {
+            limit: options.max_depth,
+        }
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+fn parse(
+    options: DecoderOptions,
+    input: &[u8],
+    depth: usize,
+    offset: usize,
+) -> Result<(&[u8], Tlv<'_>), DecodeError> {
+    let (rest, (identifier, contents)) = parser::parse_value(options, input, None)?;
+
+    let (rest, contents, indefinite) = match contents {
+        Some(contents) => (rest, contents, false),
+        None => {
+            // The contents of an indefinite length value end at the first
+            // end-of-contents octets that aren't inside one of its children.
+            check_depth(options, depth + 1)?;
+            let mut remaining = rest;
+            while !remaining.starts_with(EOC) {
+                let child_offset = offset + input.len() - remaining.len();
+                remaining = parse(options, remaining, depth + 1, child_offset)?.0;
+            }
+
+            let contents = &rest[..rest.len() - remaining.len()];
+            (&remaining[EOC.len()..], contents, true)
+        }
+    };
+
+    let tlv = Tlv {
+        identifier,
+        contents,
+        encoded: &input[..input.len() - rest.len()],
+        indefinite,
+        options,
+        depth,
+        offset,
+    };
+
+    Ok((rest, tlv))
+}
+
+/// Checks the value at the start of `input` and everything nested inside
+/// it, returning the input after the value.
+fn walk<'input>(
+    options: DecoderOptions,
+    input: &'input [u8],
+    depth: usize,
+    offset: usize,
+    stats: &mut Stats,
+) -> Result<&'input [u8], DecodeError> {
+    check_depth(options, depth).map_err(|error| error.with_offset(offset))?;
+    let (rest, (identifier, contents)) =
+        parser::parse_value(options, input, None).map_err(|error| error.with_offset(offset))?;
+
+    stats.values += 1;
+    stats.max_depth = stats.max_depth.max(depth);
+
+    match contents {
+        Some(contents) if identifier.is_constructed() => {
+            let start = offset + input.len() - rest.len() - contents.len();
+            let mut remaining = contents;
+            while !remaining.is_empty() {
+                let child_offset = start + contents.len() - remaining.len();
+                remaining = walk(options, remaining, depth + 1, child_offset, stats)
+                    .map_err(parser::within_definite_length)?;
+            }
+
+            Ok(rest)
+        }
+        Some(_) => Ok(rest),
+        None => {
+            let start = offset + input.len() - rest.len();
+            let mut remaining = rest;
+            while !remaining.starts_with(EOC) {
+                let child_offset = start + rest.len() - remaining.len();
+                remaining = walk(options, remaining, depth + 1, child_offset, stats)?;
+            }
+
+            Ok(&remaining[EOC.len()..])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+    use crate::{
+        error::DecodeErrorKind,
+        types::{Class, ObjectIdentifier},
+    };
+
+    /// A certificate-like structure followed by a `NULL`:
+    ///
+    /// ```text
+    /// SEQUENCE (indefinite length)
+    ///     SEQUENCE
+    ///         OBJECT IDENTIFIER 2.5.4.3
+    ///         UTF8String "a"
+    ///     [0]
+    ///         OBJECT IDENTIFIER 1.2.840.113549.1.1.11
+    ///     BOOLEAN TRUE
+    /// NULL
+    /// ```
+    const DATA: &[u8] = &[
+        0x30, 0x80, 0x30, 0x08, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x01, b'a', 0xA0, 0x0B, 0x06,
+        0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B, 0x01, 0x01, 0xFF, 0x00, 0x00,
+        0x05, 0x00,
+    ];
+
+    fn collect_oids(tlv: Tlv<'_>, oids: &mut Vec<ObjectIdentifier>) {
+        if tlv.tag() == Tag::OBJECT_IDENTIFIER {
+            oids.push(tlv.decode().unwrap());
+        }
+
+        for child in tlv.iter_children() {
+            collect_oids(child.unwrap(), oids);
+        }
+    }
+
+    #[test]
+    fn walks_nested_values() {
+        let values = iter(DATA).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(2, values.len());
+
+        let sequence = values[0];
+        assert!(sequence.is_constructed());
+        assert!(sequence.is_indefinite());
+        assert_eq!(&DATA[2..28], sequence.contents());
+        assert_eq!(&DATA[..30], sequence.as_bytes());
+        assert_eq!(2, sequence.header_len());
+        assert_eq!(30, values[1].offset());
+
+        let children = sequence
+            .iter_children()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            vec![2, 12, 25],
+            children.iter().map(Tlv::offset).collect::<Vec<_>>()
+        );
+        assert!(children.iter().all(|child| child.depth() == 1));
+        assert_eq!(Tag::NULL, values[1].tag());
+        assert!(values[1].contents().is_empty());
+
+        let mut oids = Vec::new();
+        for value in values {
+            collect_oids(value, &mut oids);
+        }
+
+        assert_eq!(
+            vec![
+                ObjectIdentifier::new(vec![2, 5, 4, 3]).unwrap(),
+                ObjectIdentifier::new(vec![1, 2, 840, 113_549, 1, 1, 11]).unwrap(),
+            ],
+            oids
+        );
+    }
+
+    #[test]
+    fn headers() {
+        let header = |data: &[u8]| peek_header(data).unwrap();
+        let is_incomplete = |data: &[u8]| {
+            matches!(
+                *total_length(data).unwrap_err().kind,
+                DecodeErrorKind::Incomplete { .. }
+            )
+        };
+
+        // Short form, with none of the contents present.
+        assert_eq!(
+            Header {
+                identifier: Identifier::from_tag(Tag::INTEGER, false),
+                header_len: 2,
+                length: ContentLength::Definite(3),
+            },
+            header(&[0x02, 0x03])
+        );
+        assert_eq!(Some(5), total_length(&[0x02, 0x03, 0x01]).unwrap());
+        assert_eq!(Some(2), total_length(&[0x05, 0x00]).unwrap());
+
+        // Long form, including a non-minimal one.
+        let long = header(&[0x30, 0x82, 0x01, 0x2C]);
+        assert!(long.identifier.is_constructed());
+        assert_eq!(4, long.header_len);
+        assert_eq!(ContentLength::Definite(300), long.length);
+        assert_eq!(Some(304), total_length(&[0x30, 0x82, 0x01, 0x2C]).unwrap());
+        assert_eq!(Some(4), total_length(&[0x04, 0x81, 0x01, 0xFF]).unwrap());
+
+        // A high tag number in context specific class.
+        let tagged = header(&[0xBF, 0x81, 0x00, 0x83, 0x01, 0x00, 0x00]);
+        assert_eq!(Tag::new(Class::Context, 128), tagged.identifier.tag);
+        assert_eq!(7, tagged.header_len);
+        assert_eq!(
+            Some(65_543),
+            total_length(&[0xBF, 0x81, 0x00, 0x83, 0x01, 0x00, 0x00]).unwrap()
+        );
+
+        // Indefinite form.
+        assert_eq!(ContentLength::Indefinite, header(DATA).length);
+        assert_eq!(2, header(DATA).header_len);
+        assert_eq!(None, header(DATA).total_len());
+        assert_eq!(None, total_length(DATA).unwrap());
+
+        // Truncated headers.
+        assert!(is_incomplete(&[]));
+        assert!(is_incomplete(&[0x30]));
+        assert!(is_incomplete(&[0x30, 0x82, 0x01]));
+        assert!(is_incomplete(&[0x1F, 0x81]));
+
+        // Invalid headers.
+        assert!(total_length(&[0x30, 0xFF]).is_err());
+        assert!(total_length(&[0x02, 0x80]).is_err());
+        assert!(total_length(&[0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn stops_after_errors() {
+        let mut values = iter(&DATA[..20]);
+        assert!(values.next().unwrap().is_err());
+        assert!(values.next().is_none());
+
+        let data = [0x30, 0x80].repeat(100);
+        assert!(iter(&data).next().unwrap().is_err());
+    }
+
+    #[test]
+    fn validates_structure() {
+        fn ber_kind(error: &DecodeError) -> &BerDecodeErrorKind {
+            match &*error.kind {
+                DecodeErrorKind::CodecSpecific {
+                    inner: crate::error::CodecDecodeError::Ber(kind),
+                } => kind,
+                kind => panic!("unexpected error: {kind:?}"),
+            }
+        }
+
+        assert_eq!(
+            Stats {
+                values: 7,
+                max_depth: 2,
+            },
+            validate_with_stats(&DATA[..30]).unwrap()
+        );
+        assert_eq!(
+            Stats {
+                values: 1,
+                max_depth: 0,
+            },
+            validate_with_stats(&DATA[30..]).unwrap()
+        );
+
+        // SEQUENCE { INTEGER 1, SEQUENCE { INTEGER 5 } }, where the inner
+        // SEQUENCE claims one octet more than the outer one has left.
+        let data = &[
+            0x30, 0x08, 0x02, 0x01, 0x01, 0x30, 0x04, 0x02, 0x01, 0x05, 0x05, 0x00,
+        ];
+        let error = validate(data).unwrap_err();
+        assert_eq!(Some(5), error.offset);
+        assert!(matches!(
+            ber_kind(&error),
+            BerDecodeErrorKind::LengthExceedsContainer
+        ));
+
+        // The same within an indefinite length value.
+        let data = &[0x30, 0x80, 0x30, 0x03, 0x02, 0x05, 0x01, 0x00, 0x00];
+        let error = validate(data).unwrap_err();
+        assert_eq!(Some(4), error.offset);
+        assert!(matches!(
+            ber_kind(&error),
+            BerDecodeErrorKind::LengthExceedsContainer
+        ));
+
+        // Missing end-of-contents octets, and truncated values.
+        let error = validate(&[0x30, 0x80, 0x05, 0x00]).unwrap_err();
+        assert!(matches!(*error.kind, DecodeErrorKind::Incomplete { .. }));
+        assert_eq!(Some(4), error.offset);
+        assert!(validate(&DATA[..20]).is_err());
+        assert!(validate(&[]).is_err());
+
+        // Trailing data after the value.
+        let error = validate(DATA).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            DecodeErrorKind::UnexpectedExtraData { length: 2 }
+        ));
+        assert_eq!(Some(30), error.offset);
+
+        // Nesting beyond the depth limit.
+        let mut data = [0x30, 0x80].repeat(100);
+        data.extend([0x00, 0x00].repeat(100));
+        let error = validate(&data).unwrap_err();
+        assert!(matches!(
+            ber_kind(&error),
+            BerDecodeErrorKind::NestingTooDeep { .. }
+        ));
+        assert_eq!(Some(130), error.offset);
+    }
+}
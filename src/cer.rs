@@ -1,11 +1,18 @@
 //! # Canonical Encoding Rules
 
-/// Attempts to decode `T` from `input` using CER.
+/// Attempts to decode `T` from `input` using CER, failing if any input
+/// remains after the value.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut crate::ber::de::Decoder::new(
-        input,
-        crate::ber::de::DecoderOptions::cer(),
-    ))
+    let (value, remainder) = decode_with_remainder(input)?;
+
+    if remainder.is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            remainder.len(),
+            crate::Codec::Cer,
+        ))
+    }
 }
 /// Attempts to decode `T` from `input` using CER. Returns both `T` and reference to the remainder of the input.
 ///
@@ -15,7 +22,7 @@ pub fn decode_with_remainder<T: crate::Decode>(
     input: &[u8],
 ) -> Result<(T, &[u8]), crate::error::DecodeError> {
     let decoder = &mut crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::cer());
-    let decoded_instance = T::decode(decoder)?;
+    let decoded_instance = T::decode(decoder).map_err(|error| decoder.locate_error(error))?;
     Ok((decoded_instance, decoder.remaining()))
 }
 
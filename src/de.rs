@@ -1,6 +1,9 @@
 //! Generic ASN.1 decoding framework.
 
-use alloc::{boxed::Box, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::ops::ControlFlow;
 use num_bigint::BigInt;
 
 use crate::error::DecodeError;
@@ -160,6 +163,12 @@ pub trait Decode: Sized + AsnType {
 
 /// A **data format** decode any ASN.1 data type.
 ///
+/// A decoder owns its input and position within it. Each `decode_*` method
+/// reads exactly one value and advances past it, so consecutive calls decode
+/// consecutive values. Nested contents, such as those of a `SEQUENCE` or an
+/// explicit tag, are decoded by passing a closure to methods like
+/// [`Decoder::decode_sequence`], which receives a decoder scoped to them.
+///
 /// Const `RCL` is the count of root components in the root component list of a sequence or set.
 /// Const `ECL` is the count of extension additions in the extension addition component type list in a sequence or set.
 pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
@@ -239,6 +248,46 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::SetOf<D>, Self::Error>;
+    /// Decode a `SEQUENCE OF D` identified by `tag`, passing each element to
+    /// `f` as it is decoded instead of collecting them.
+    ///
+    /// Once `f` returns [`ControlFlow::Break`] no further elements are passed
+    /// to it, and the decoder is left after the end of the `SEQUENCE OF`. By
+    /// default the elements are collected anyway, but BER, CER, DER, OER and
+    /// PER decode each element as it is reached.
+    fn decode_sequence_of_with<D, F>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        mut f: F,
+    ) -> Result<(), Self::Error>
+    where
+        D: Decode,
+        F: FnMut(D) -> Result<ControlFlow<()>, Self::Error>,
+    {
+        for item in self.decode_sequence_of(tag, constraints)? {
+            if (f)(item)?.is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+    /// Decode a `SET OF D` identified by `tag`, passing each element to `f`
+    /// as it is decoded instead of collecting them, like
+    /// [`Self::decode_sequence_of_with`].
+    fn decode_set_of_with<D, F>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        f: F,
+    ) -> Result<(), Self::Error>
+    where
+        D: Decode,
+        F: FnMut(D) -> Result<ControlFlow<()>, Self::Error>,
+    {
+        self.decode_sequence_of_with(tag, constraints, f)
+    }
     /// Decode a `OCTET STRING` identified by `tag` from the available input.
     fn decode_octet_string<'buf, T>(
         &'buf mut self,
@@ -322,7 +371,27 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
     fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime, Self::Error>;
     /// Decode a `GeneralizedTime` identified by `tag` from the available input.
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime, Self::Error>;
+    /// Decode a `UtcTime` identified by `tag` from the available input,
+    /// keeping the contents it was encoded with.
+    ///
+    /// Codecs that don't transmit times as their contents, or only accept the
+    /// canonical form, return the canonical contents of the decoded value.
+    fn decode_raw_utc_time(&mut self, tag: Tag) -> Result<types::RawUtcTime, Self::Error> {
+        self.decode_utc_time(tag).map(From::from)
+    }
+    /// Decode a `GeneralizedTime` identified by `tag` from the available
+    /// input, keeping the contents it was encoded with.
+    ///
+    /// Codecs that don't transmit times as their contents, or only accept the
+    /// canonical form, return the canonical contents of the decoded value.
+    fn decode_raw_generalized_time(
+        &mut self,
+        tag: Tag,
+    ) -> Result<types::RawGeneralizedTime, Self::Error> {
+        self.decode_generalized_time(tag).map(From::from)
+    }
     /// Decode a 'DATE' identified by 'tag' from the available input
+    #[cfg(feature = "chrono")]
     fn decode_date(&mut self, tag: Tag) -> Result<types::Date, Self::Error>;
 
     /// Decode a `SET` identified by `tag` from the available input. Decoding
@@ -521,6 +590,34 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
     >(
         &mut self,
     ) -> Result<Option<D>, Self::Error>;
+
+    /// Decode an `OCTET STRING` identified by `tag` as an [`types::OctetString`].
+    ///
+    /// Decoders that read from a shared buffer can return a view of it
+    /// instead of copying the contents.
+    fn decode_octet_string_shared(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::OctetString, Self::Error> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "arc-slice")] {
+                self.decode_octet_string(tag, constraints)
+            } else {
+                self.decode_octet_string::<Vec<u8>>(tag, constraints).map(From::from)
+            }
+        }
+    }
+
+    /// Decode every remaining component of the `SEQUENCE` currently being
+    /// decoded as an unknown value, preserving their encodings.
+    ///
+    /// Only codecs that delimit each component with its own tag and length
+    /// can recover unknown components, the others always return an empty
+    /// list.
+    fn decode_remaining_components(&mut self) -> Result<Vec<types::Any>, Self::Error> {
+        Ok(Vec::new())
+    }
 }
 
 /// A generic error that can occur while decoding ASN.1.
@@ -627,15 +724,71 @@ impl_integers! {
     BigInt
 }
 
+macro_rules! impl_non_zero_integers {
+    ($($int:ty => $inner:ty),+ $(,)?) => {
+        $(
+        impl Decode for $int {
+            fn decode_with_tag_and_constraints<D: Decoder>(decoder: &mut D, tag: Tag, constraints: Constraints) -> Result<Self, D::Error> {
+                let value = decoder.decode_integer::<$inner>(tag, constraints)?;
+                <$int>::new(value).ok_or_else(|| D::Error::from(DecodeError::unexpected_zero_integer(decoder.codec())))
+            }
+        }
+        )+
+    }
+}
+
+impl_non_zero_integers! {
+    core::num::NonZeroI8 => i8,
+    core::num::NonZeroI16 => i16,
+    core::num::NonZeroI32 => i32,
+    core::num::NonZeroI64 => i64,
+    core::num::NonZeroI128 => i128,
+    core::num::NonZeroIsize => isize,
+    core::num::NonZeroU8 => u8,
+    core::num::NonZeroU16 => u16,
+    core::num::NonZeroU32 => u32,
+    core::num::NonZeroU64 => u64,
+    core::num::NonZeroU128 => u128,
+    core::num::NonZeroUsize => usize,
+}
+
 impl<const START: i128, const END: i128> Decode for types::ConstrainedInteger<START, END> {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
         tag: Tag,
         constraints: Constraints,
     ) -> Result<Self, D::Error> {
-        decoder
-            .decode_integer::<types::Integer>(tag, constraints)
-            .map(Self)
+        let value = decoder.decode_integer::<types::Integer>(tag, constraints)?;
+
+        if Self::contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(DecodeError::value_constraint_not_satisfied(
+                value.into(),
+                types::constraints::Bounded::const_new(START, END),
+                decoder.codec(),
+            )
+            .into())
+        }
+    }
+}
+
+impl Decode for types::Unsigned {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let value = decoder.decode_integer::<types::Integer>(tag, constraints)?;
+
+        Self::try_from(value).map_err(|error| {
+            DecodeError::value_constraint_not_satisfied(
+                error.into_original(),
+                types::constraints::Bounded::start_from(0),
+                decoder.codec(),
+            )
+            .into()
+        })
     }
 }
 
@@ -671,28 +824,68 @@ impl Decode for f64 {
     }
 }
 
-impl<T: Decode> Decode for Box<T> {
+macro_rules! impl_pointers {
+    ($($(#[$meta:meta])* $pointer:ident),+ $(,)?) => {
+        $(
+        $(#[$meta])*
+        impl<T: Decode> Decode for $pointer<T> {
+            fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
+                T::decode(decoder).map($pointer::new)
+            }
+
+            fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+                T::decode_with_tag(decoder, tag).map($pointer::new)
+            }
+
+            fn decode_with_constraints<DE: Decoder>(
+                decoder: &mut DE,
+                constraints: Constraints,
+            ) -> Result<Self, DE::Error> {
+                T::decode_with_constraints(decoder, constraints).map($pointer::new)
+            }
+
+            fn decode_with_tag_and_constraints<DE: Decoder>(
+                decoder: &mut DE,
+                tag: Tag,
+                constraints: Constraints,
+            ) -> Result<Self, DE::Error> {
+                T::decode_with_tag_and_constraints(decoder, tag, constraints).map($pointer::new)
+            }
+        }
+        )+
+    }
+}
+
+impl_pointers! {
+    Box,
+    Rc,
+    #[cfg(target_has_atomic = "ptr")]
+    Arc,
+}
+
+/// Always decodes into [`Cow::Owned`][alloc::borrow::Cow::Owned].
+impl<T: Decode + Clone> Decode for alloc::borrow::Cow<'_, T> {
     fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
-        T::decode(decoder).map(Box::new)
+        T::decode(decoder).map(Self::Owned)
     }
 
     fn decode_with_tag<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
-        T::decode_with_tag(decoder, tag).map(Box::new)
+        T::decode_with_tag(decoder, tag).map(Self::Owned)
     }
 
-    fn decode_with_constraints<DE: Decoder>(
-        decoder: &mut DE,
+    fn decode_with_constraints<D: Decoder>(
+        decoder: &mut D,
         constraints: Constraints,
-    ) -> Result<Self, DE::Error> {
-        T::decode_with_constraints(decoder, constraints).map(Box::new)
+    ) -> Result<Self, D::Error> {
+        T::decode_with_constraints(decoder, constraints).map(Self::Owned)
     }
 
-    fn decode_with_tag_and_constraints<DE: Decoder>(
-        decoder: &mut DE,
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
         tag: Tag,
         constraints: Constraints,
-    ) -> Result<Self, DE::Error> {
-        T::decode_with_tag_and_constraints(decoder, tag, constraints).map(Box::new)
+    ) -> Result<Self, D::Error> {
+        T::decode_with_tag_and_constraints(decoder, tag, constraints).map(Self::Owned)
     }
 }
 
@@ -702,13 +895,7 @@ impl Decode for types::OctetString {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<Self, D::Error> {
-        cfg_if::cfg_if! {
-            if #[cfg(feature = "arc-slice")] {
-                decoder.decode_octet_string(tag, constraints)
-            } else {
-                decoder.decode_octet_string::<Vec<u8>>(tag, constraints).map(From::from)
-            }
-        }
+        decoder.decode_octet_string_shared(tag, constraints)
     }
 }
 
@@ -752,6 +939,26 @@ impl Decode for types::GeneralizedTime {
     }
 }
 
+impl Decode for types::RawUtcTime {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_raw_utc_time(tag)
+    }
+}
+
+impl Decode for types::RawGeneralizedTime {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_raw_generalized_time(tag)
+    }
+}
+
 impl Decode for types::Any {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -782,6 +989,16 @@ impl<T: Decode + Eq + core::hash::Hash> Decode for SetOf<T> {
     }
 }
 
+impl<T: Decode> Decode for types::SetVec<T> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_sequence_of(tag, constraints).map(Self)
+    }
+}
+
 impl<T: Decode, const N: usize> Decode for [T; N] {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -799,6 +1016,60 @@ impl<T: Decode, const N: usize> Decode for [T; N] {
     }
 }
 
+macro_rules! impl_tuples {
+    ($($len:literal => ($($index:tt $ty:ident),+);)+) => {
+        $(
+        impl<$($ty: Decode),+> Decode for ($($ty,)+) {
+            fn decode_with_tag_and_constraints<D: Decoder>(
+                decoder: &mut D,
+                tag: Tag,
+                _: Constraints,
+            ) -> Result<Self, D::Error> {
+                decoder.decode_sequence(tag, None::<fn() -> Self>, |sequence| {
+                    Ok(($($ty::decode(sequence)?,)+))
+                })
+            }
+        }
+        )+
+    }
+}
+
+impl_tuples! {
+    1 => (0 A);
+    2 => (0 A, 1 B);
+    3 => (0 A, 1 B, 2 C);
+    4 => (0 A, 1 B, 2 C, 3 D);
+    5 => (0 A, 1 B, 2 C, 3 D, 4 E);
+    6 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+    7 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+    8 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+    9 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+    10 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+    11 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+    12 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+}
+
+impl<T: Decode + types::Length, const MIN: usize, const MAX: usize> Decode
+    for types::SizeConstrained<T, MIN, MAX>
+{
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let value = T::decode_with_tag_and_constraints(decoder, tag, constraints)?;
+
+        Self::new(value).map_err(|error| {
+            DecodeError::size_constraint_not_satisfied(
+                Some(error.size),
+                alloc::format!("{MIN}..={MAX}"),
+                decoder.codec(),
+            )
+            .into()
+        })
+    }
+}
+
 impl<T: AsnType, V: Decode> Decode for types::Implicit<T, V> {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
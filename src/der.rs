@@ -1,14 +1,37 @@
 //! # Distinguished Encoding Rules
 
+mod canonical;
+
 pub use crate::ber::*;
 
-/// Attempts to decode `T` from `input` using DER.
+pub use self::canonical::canonicalize;
+
+/// Attempts to decode `T` from `input` using DER, failing if any input
+/// remains after the value.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut crate::ber::de::Decoder::new(
-        input,
-        crate::ber::de::DecoderOptions::der(),
-    ))
+    let (value, remainder) = decode_with_remainder(input)?;
+
+    if remainder.is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            remainder.len(),
+            crate::Codec::Der,
+        ))
+    }
+}
+
+/// Decodes consecutive DER encoded values of `T` from `input`, such as a file
+/// of concatenated certificates.
+///
+/// Iteration ends at the end of `input`, or after the first error, whose
+/// offset refers to `input`.
+pub fn decode_all<T: crate::Decode>(
+    input: &[u8],
+) -> impl Iterator<Item = Result<T, crate::error::DecodeError>> + '_ {
+    crate::ber::decode_all_with_options(de::DecoderOptions::der(), input)
 }
+
 /// Attempts to decode `T` from `input` using DER. Returns both `T` and reference to the remainder of the input.
 ///
 /// # Errors
@@ -17,10 +40,63 @@ pub fn decode_with_remainder<T: crate::Decode>(
     input: &[u8],
 ) -> Result<(T, &[u8]), crate::error::DecodeError> {
     let decoder = &mut de::Decoder::new(input, de::DecoderOptions::der());
-    let decoded_instance = T::decode(decoder)?;
+    let decoded_instance = T::decode(decoder).map_err(|error| decoder.locate_error(error))?;
     Ok((decoded_instance, decoder.remaining()))
 }
 
+/// Attempts to decode `T` from `input` using DER, borrowing string and
+/// octet string contents from `input` instead of copying them.
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid DER encoding of `T`.
+pub fn decode_borrowed<'input, T: de::DecodeBorrowed<'input>>(
+    input: &'input [u8],
+) -> Result<T, crate::error::DecodeError> {
+    let decoder = &mut de::Decoder::new(input, de::DecoderOptions::der());
+    let value = decoder
+        .decode_borrowed()
+        .map_err(|error| decoder.locate_error(error))?;
+
+    if decoder.remaining().is_empty() {
+        Ok(value)
+    } else {
+        Err(crate::error::DecodeError::unexpected_extra_data(
+            decoder.remaining().len(),
+            crate::Codec::Der,
+        ))
+    }
+}
+
+/// Attempts to decode `T` from a single DER encoded value read from `reader`.
+///
+/// Only the bytes belonging to the value are read, so multiple values can be
+/// decoded one after another from the same stream.
+/// # Errors
+/// Returns `DecodeError` if reading from `reader` fails, or the value read is
+/// not a valid DER encoding of `T`.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode, R: std::io::Read>(
+    reader: &mut R,
+) -> Result<T, crate::error::DecodeError> {
+    let mut buffer = alloc::vec::Vec::new();
+    de::reader::read_value(reader, de::DecoderOptions::der(), &mut buffer)?;
+    decode(&buffer)
+}
+
+/// Attempts to decode `T` from a single DER encoded value read
+/// asynchronously from `reader`. See [`crate::ber::decode_async`] for how the
+/// value is read and its cancel safety.
+/// # Errors
+/// Returns `DecodeError` if reading from `reader` fails, or the value read is
+/// not a valid DER encoding of `T`.
+#[cfg(feature = "tokio")]
+pub async fn decode_async<T: crate::Decode, R: tokio::io::AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<T, crate::error::DecodeError> {
+    let mut buffer = alloc::vec::Vec::new();
+    de::async_reader::read_value(reader, de::DecoderOptions::der(), &mut buffer).await?;
+    decode(&buffer)
+}
+
 /// Attempts to encode `value` to DER.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -0,0 +1,322 @@
+//! Converting BER encoded data to DER by walking its TLV structure, without
+//! decoding it into types.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    ber::{
+        enc::{Encoder, EncoderOptions},
+        iter, Identifier, Tlv,
+    },
+    error::{BerDecodeErrorKind, DecodeError},
+    types::Tag,
+    Codec,
+};
+
+/// Re-encodes the BER encoded values in `ber` as DER, without needing to know
+/// their types.
+///
+/// - Indefinite lengths are replaced with definite lengths, and all lengths
+///   use the fewest possible octets.
+/// - Constructed `BIT STRING`, `OCTET STRING` and character string values are
+///   merged into their primitive form, and the unused bits of a `BIT STRING`
+///   are cleared.
+/// - `BOOLEAN` values are re-encoded as `0x00` or `0xFF`.
+/// - The components of a `SET` are sorted by their tags, and the elements of
+///   a `SET OF` by their encodings. As both share a tag, components with
+///   distinct tags are sorted as a `SET`, so a `SET OF` of a `CHOICE` whose
+///   elements all have different tags may be left in the wrong order.
+///
+/// Other values, including implicitly tagged ones, only have their lengths
+/// normalised, as their type can't be known from the encoding alone.
+/// ```
+/// // A BOOLEAN inside an indefinite length SEQUENCE.
+/// let ber = &[0x30, 0x80, 0x01, 0x01, 0x01, 0x00, 0x00];
+///
+/// assert_eq!(
+///     &[0x30, 0x03, 0x01, 0x01, 0xFF],
+///     &*rasn::der::canonicalize(ber).unwrap()
+/// );
+/// ```
+/// # Errors
+/// Returns `DecodeError` if `ber` isn't valid BER.
+pub fn canonicalize(ber: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut output = Vec::with_capacity(ber.len());
+
+    for value in iter(ber) {
+        output.extend(canonicalize_value(value?)?);
+    }
+
+    Ok(output)
+}
+
+fn canonicalize_value(tlv: Tlv<'_>) -> Result<Vec<u8>, DecodeError> {
+    let tag = tlv.tag();
+    let (identifier, contents) = if tag == Tag::BIT_STRING {
+        let mut data = Vec::new();
+        let mut unused_bits = 0;
+        bit_string_segments(tlv, &mut data, &mut unused_bits)?;
+
+        // DER requires the unused bits to be zero.
+        if let Some(last) = data.last_mut() {
+            *last &= !((1 << unused_bits) - 1);
+        }
+
+        let mut contents = vec![unused_bits];
+        contents.append(&mut data);
+        (Identifier::from_tag(tag, false), contents)
+    } else if is_string(tag) {
+        let mut contents = Vec::new();
+        string_segments(tlv, &mut contents)?;
+        (Identifier::from_tag(tag, false), contents)
+    } else if tag == Tag::BOOL {
+        if tlv.is_constructed() {
+            return Err(BerDecodeErrorKind::InvalidConstructedIdentifier.into());
+        }
+
+        DecodeError::assert_length(1, tlv.contents().len(), Codec::Ber)?;
+        let value = if tlv.contents()[0] == 0 { 0 } else { 0xFF };
+        (tlv.identifier(), vec![value])
+    } else if tlv.is_constructed() {
+        let mut children = tlv
+            .iter_children()
+            .map(|child| {
+                let child = child?;
+                Ok((child.tag(), canonicalize_value(child)?))
+            })
+            .collect::<Result<Vec<_>, DecodeError>>()?;
+
+        if tag == Tag::SET {
+            sort_set(&mut children);
+        }
+
+        let contents = children
+            .into_iter()
+            .flat_map(|(_, encoding)| encoding)
+            .collect();
+        (tlv.identifier(), contents)
+    } else {
+        (tlv.identifier(), tlv.contents().to_vec())
+    };
+
+    let mut encoder = Encoder::new(EncoderOptions::der());
+    encoder.encode_value(identifier, &contents);
+    Ok(encoder.output())
+}
+
+/// Sorts the components of a `SET` by their tags (X.690 10.3), or the
+/// elements of a `SET OF` by their encodings (X.690 11.6). Both share the
+/// same tag, so components with distinct tags are taken to be a `SET`, and a
+/// repeated tag, which a `SET` can't have, to be a `SET OF`.
+fn sort_set(children: &mut [(Tag, Vec<u8>)]) {
+    children.sort_by_key(|&(tag, _)| tag);
+
+    if children.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        children.sort_by(|(_, a), (_, b)| a.cmp(b));
+    }
+}
+
+/// Whether `tag` is a universal string type, which BER allows to be split
+/// into segments using the constructed form.
+fn is_string(tag: Tag) -> bool {
+    matches!(
+        tag,
+        Tag::OCTET_STRING
+            | Tag::OBJECT_DESCRIPTOR
+            | Tag::UTF8_STRING
+            | Tag::NUMERIC_STRING
+            | Tag::PRINTABLE_STRING
+            | Tag::TELETEX_STRING
+            | Tag::VIDEOTEX_STRING
+            | Tag::IA5_STRING
+            | Tag::UTC_TIME
+            | Tag::GENERALIZED_TIME
+            | Tag::GRAPHIC_STRING
+            | Tag::VISIBLE_STRING
+            | Tag::GENERAL_STRING
+            | Tag::UNIVERSAL_STRING
+            | Tag::BMP_STRING
+    )
+}
+
+/// Appends the contents of a string value, or of each of its segments, to
+/// `contents`. The segments of character strings are `OCTET STRING`s.
+fn string_segments(tlv: Tlv<'_>, contents: &mut Vec<u8>) -> Result<(), DecodeError> {
+    if !tlv.is_constructed() {
+        contents.extend_from_slice(tlv.contents());
+        return Ok(());
+    }
+
+    for segment in tlv.iter_children() {
+        let segment = segment?;
+        if segment.tag() != tlv.tag() && segment.tag() != Tag::OCTET_STRING {
+            return Err(BerDecodeErrorKind::MismatchedTag {
+                expected: Tag::OCTET_STRING,
+                actual: segment.tag(),
+            }
+            .into());
+        }
+
+        string_segments(segment, contents)?;
+    }
+
+    Ok(())
+}
+
+/// Appends the bits of a `BIT STRING`, or of each of its segments, to `data`,
+/// setting `unused_bits` to the unused bits of the final segment.
+fn bit_string_segments(
+    tlv: Tlv<'_>,
+    data: &mut Vec<u8>,
+    unused_bits: &mut u8,
+) -> Result<(), DecodeError> {
+    if tlv.is_constructed() {
+        for segment in tlv.iter_children() {
+            let segment = segment?;
+            if segment.tag() != Tag::BIT_STRING {
+                return Err(BerDecodeErrorKind::MismatchedTag {
+                    expected: Tag::BIT_STRING,
+                    actual: segment.tag(),
+                }
+                .into());
+            }
+
+            bit_string_segments(segment, data, unused_bits)?;
+        }
+
+        return Ok(());
+    }
+
+    // Only the final segment may have unused bits.
+    if *unused_bits != 0 {
+        return Err(BerDecodeErrorKind::UnalignedBitStringSegment.into());
+    }
+
+    let (&bits, bytes) = tlv
+        .contents()
+        .split_first()
+        .ok_or(DecodeError::unexpected_empty_input(Codec::Ber))?;
+
+    if bits > 7 || (bytes.is_empty() && bits != 0) {
+        return Err(DecodeError::invalid_bit_string(bits, Codec::Ber));
+    }
+
+    data.extend_from_slice(bytes);
+    *unused_bits = bits;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BitString, SetOf};
+
+    #[test]
+    fn constructed_bit_string() {
+        let ber = &[
+            0x23, 0x80, 0x03, 0x03, 0x00, 0x0A, 0x3B, 0x03, 0x05, 0x04, 0x5F, 0x29, 0x1C, 0xD0,
+            0x00, 0x00,
+        ];
+        let der = canonicalize(ber).unwrap();
+
+        assert_eq!(
+            &[0x03, 0x07, 0x04, 0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0],
+            &*der
+        );
+        assert_eq!(
+            crate::ber::decode::<BitString>(ber).unwrap(),
+            crate::der::decode::<BitString>(&der).unwrap()
+        );
+
+        // Non-zero unused bits are cleared.
+        assert_eq!(
+            &[0x03, 0x02, 0x04, 0xF0],
+            &*canonicalize(&[0x03, 0x02, 0x04, 0xFF]).unwrap()
+        );
+    }
+
+    #[test]
+    fn constructed_strings() {
+        let ber = &[
+            0x2C, 0x80, 0x04, 0x03, b'J', b'o', b'n', 0x24, 0x04, 0x04, 0x02, b'e', b's', 0x00,
+            0x00,
+        ];
+
+        assert_eq!(
+            &[0x0C, 0x05, b'J', b'o', b'n', b'e', b's'],
+            &*canonicalize(ber).unwrap()
+        );
+        assert!(canonicalize(&[0x24, 0x03, 0x02, 0x01, 0x05]).is_err());
+    }
+
+    #[test]
+    fn lengths_booleans_and_sets() {
+        // An indefinite length SEQUENCE containing a BOOLEAN with a
+        // non-minimal length and an implicitly tagged constructed value,
+        // followed by a SET OF INTEGER in the wrong order.
+        let ber = &[
+            0x30, 0x80, 0x01, 0x81, 0x01, 0x05, 0xA0, 0x80, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x31, 0x06, 0x02, 0x01, 0x07, 0x02, 0x01, 0x03,
+        ];
+        let der = canonicalize(ber).unwrap();
+
+        assert_eq!(
+            &[
+                0x30, 0x07, 0x01, 0x01, 0xFF, 0xA0, 0x02, 0x05, 0x00, 0x31, 0x06, 0x02, 0x01, 0x03,
+                0x02, 0x01, 0x07,
+            ],
+            &*der
+        );
+        assert_eq!(
+            &*crate::der::encode(&SetOf::from_vec(vec![7, 3])).unwrap(),
+            &der[9..]
+        );
+        assert!(canonicalize(&[0x01, 0x02, 0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn set_components() {
+        // A SET of `[1] INTEGER` and `[0] SEQUENCE {}`, which sorts differently
+        // by tag than by encoding as only the second is constructed.
+        let ber = &[0x31, 0x05, 0x81, 0x01, 0x05, 0xA0, 0x00];
+        assert_eq!(
+            &[0x31, 0x05, 0xA0, 0x00, 0x81, 0x01, 0x05],
+            &*canonicalize(ber).unwrap()
+        );
+
+        // A SET OF CHOICE with a repeated tag is sorted by encoding.
+        let ber = &[0x31, 0x07, 0xA0, 0x00, 0x81, 0x01, 0x05, 0xA0, 0x00];
+        assert_eq!(
+            &[0x31, 0x07, 0x81, 0x01, 0x05, 0xA0, 0x00, 0xA0, 0x00],
+            &*canonicalize(ber).unwrap()
+        );
+    }
+
+    #[test]
+    fn idempotent() {
+        let ber = &[
+            0x30, 0x80, 0x23, 0x80, 0x03, 0x02, 0x00, 0xFF, 0x03, 0x02, 0x01, 0xFF, 0x00, 0x00,
+            0x31, 0x80, 0x0C, 0x01, b'b', 0x0C, 0x01, b'a', 0x00, 0x00, 0x00, 0x00,
+        ];
+        let once = canonicalize(ber).unwrap();
+
+        assert_eq!(once, canonicalize(&once).unwrap());
+        assert_eq!(
+            &[
+                0x30, 0x0D, 0x03, 0x03, 0x01, 0xFF, 0xFE, 0x31, 0x06, 0x0C, 0x01, b'a', 0x0C, 0x01,
+                b'b',
+            ],
+            &*once
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(canonicalize(&[0x30, 0x80, 0x05, 0x00]).is_err());
+        // Only the final segment of a bit string may have unused bits.
+        assert!(canonicalize(&[
+            0x23, 0x80, 0x03, 0x02, 0x04, 0xF0, 0x03, 0x02, 0x00, 0xFF, 0x00, 0x00
+        ])
+        .is_err());
+    }
+}
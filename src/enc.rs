@@ -106,6 +106,28 @@ pub trait Encode: AsnType {
         constraints: Constraints,
         identifier: Identifier,
     ) -> Result<(), E::Error>;
+
+    /// Returns the number of octets `self` encodes to with DER, including
+    /// the identifier and length octets, e.g. to write a length prefix before
+    /// the encoding. BER encodings with definite lengths are the same size.
+    ///
+    /// The default implementation counts the octets while encoding `self`,
+    /// without buffering the encoding, so implementors only need to override
+    /// it when they can count the octets more cheaply.
+    /// ```
+    /// use rasn::{types::OctetString, Encode};
+    ///
+    /// let value = OctetString::from(vec![0; 300]);
+    /// assert_eq!(304, value.encoded_len().unwrap());
+    /// assert_eq!(rasn::der::encode(&value).unwrap().len(), 304);
+    /// ```
+    fn encoded_len(&self) -> Result<usize, crate::error::EncodeError>
+    where
+        Self: Sized,
+    {
+        crate::ber::enc::count(crate::ber::enc::EncoderOptions::der(), self)
+            .map(|(length, _)| length)
+    }
 }
 
 /// A **data format** encode any ASN.1 data type.
@@ -166,7 +188,7 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
     fn encode_object_identifier(
         &mut self,
         tag: Tag,
-        value: &[u32],
+        value: &[u128],
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error>;
 
@@ -297,7 +319,35 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `UtcTime` value with the contents it was decoded with.
+    ///
+    /// Codecs that don't transmit times as their contents, or only accept the
+    /// canonical form, encode the parsed value instead.
+    fn encode_raw_utc_time(
+        &mut self,
+        tag: Tag,
+        value: &types::RawUtcTime,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utc_time(tag, &value.time(), identifier)
+    }
+
+    /// Encode a `GeneralizedTime` value with the contents it was decoded
+    /// with.
+    ///
+    /// Codecs that don't transmit times as their contents, or only accept the
+    /// canonical form, encode the parsed value instead.
+    fn encode_raw_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::RawGeneralizedTime,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_generalized_time(tag, &value.time(), identifier)
+    }
+
     /// Encode a 'Date' value.
+    #[cfg(feature = "chrono")]
     fn encode_date(
         &mut self,
         tag: Tag,
@@ -339,6 +389,22 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `SEQUENCE OF` value from the elements yielded by `values`,
+    /// without requiring them to be collected first. By default they are
+    /// collected anyway, as most codecs need to know the number of elements
+    /// before the first one, but BER, CER and DER encode each element as it
+    /// is yielded.
+    fn encode_sequence_of_iter<E: Encode, I: IntoIterator<Item = E>>(
+        &mut self,
+        tag: Tag,
+        values: I,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        let values = values.into_iter().collect::<alloc::vec::Vec<_>>();
+        self.encode_sequence_of(tag, &values, constraints, identifier)
+    }
+
     /// Encode a `SET` value.
     ///
     /// Const `RC` is the count of root components in a set.
@@ -365,6 +431,19 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `SET OF` value from elements kept in a particular order, such
+    /// as a [`types::SetVec`]. The elements are encoded in that order, unless
+    /// the encoding rules require a canonical one, like DER does.
+    fn encode_set_of_slice<E: Encode>(
+        &mut self,
+        tag: Tag,
+        values: &[E],
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_sequence_of(tag, values, constraints, identifier)
+    }
+
     /// Encode the value a field or skip if it matches the default..
     fn encode_or_default<E: Encode + Default + PartialEq>(
         &mut self,
@@ -532,6 +611,19 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
     ) -> Result<Self::Ok, Self::Error>
     where
         E: Encode + crate::types::Constructed<RC, EC>;
+
+    /// Encode the unknown components of the `SEQUENCE` currently being
+    /// encoded, as collected by
+    /// [`crate::Decoder::decode_remaining_components`], unchanged.
+    ///
+    /// Only codecs that delimit each component with its own tag and length
+    /// can recover unknown components, so the others skip them here as well.
+    fn encode_remaining_components(
+        &mut self,
+        _components: &[types::Any],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 /// A generic error that occurred while trying to encode ASN.1.
@@ -760,6 +852,33 @@ impl_integers! {
     usize
 }
 
+macro_rules! impl_non_zero_integers {
+    ($($int:ty),+ $(,)?) => {
+        $(
+            impl Encode for $int {
+                fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(&self, encoder: &mut E, tag: Tag, constraints: Constraints, identifier: Identifier) -> Result<(), E::Error> {
+                    self.get().encode_with_tag_and_constraints(encoder, tag, constraints, identifier)
+                }
+            }
+        )+
+    }
+}
+
+impl_non_zero_integers! {
+    core::num::NonZeroI8,
+    core::num::NonZeroI16,
+    core::num::NonZeroI32,
+    core::num::NonZeroI64,
+    core::num::NonZeroI128,
+    core::num::NonZeroIsize,
+    core::num::NonZeroU8,
+    core::num::NonZeroU16,
+    core::num::NonZeroU32,
+    core::num::NonZeroU64,
+    core::num::NonZeroU128,
+    core::num::NonZeroUsize,
+}
+
 impl Encode for BigInt {
     fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
         &self,
@@ -788,6 +907,20 @@ impl<const START: i128, const END: i128> Encode for types::ConstrainedInteger<ST
     }
 }
 
+impl Encode for types::Unsigned {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_integer(tag, constraints, &**self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
 impl Encode for types::Integer {
     fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
         &self,
@@ -930,7 +1063,7 @@ impl Encode for types::GeneralizedTime {
     }
 }
 
-impl Encode for types::Any {
+impl Encode for types::RawUtcTime {
     fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
         &self,
         encoder: &mut E,
@@ -939,40 +1072,130 @@ impl Encode for types::Any {
         identifier: Identifier,
     ) -> Result<(), E::Error> {
         encoder
-            .encode_any(tag, self, identifier.or(Self::IDENTIFIER))
+            .encode_raw_utc_time(tag, self, identifier.or(Self::IDENTIFIER))
             .map(drop)
     }
 }
 
-impl<E: Encode> Encode for alloc::boxed::Box<E> {
-    fn encode<'b, EN: Encoder<'b>>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
-        E::encode(self, encoder)
+impl Encode for types::RawGeneralizedTime {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_raw_generalized_time(tag, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
     }
+}
 
-    fn encode_with_tag<'b, EN: Encoder<'b>>(
+impl Encode for types::Any {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
         &self,
-        encoder: &mut EN,
+        encoder: &mut E,
         tag: Tag,
-    ) -> Result<(), EN::Error> {
-        E::encode_with_tag(self, encoder, tag)
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_any(tag, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
     }
+}
 
-    fn encode_with_constraints<'b, EN: Encoder<'b>>(
+macro_rules! impl_pointers {
+    ($($(#[$meta:meta])* $pointer:ty $(where E: $bound:path)?),+ $(,)?) => {
+        $(
+        $(#[$meta])*
+        impl<E: Encode> Encode for $pointer $(where E: $bound)? {
+            fn encode<'b, EN: Encoder<'b>>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
+                E::encode(self, encoder)
+            }
+
+            fn encode_with_tag<'b, EN: Encoder<'b>>(
+                &self,
+                encoder: &mut EN,
+                tag: Tag,
+            ) -> Result<(), EN::Error> {
+                E::encode_with_tag(self, encoder, tag)
+            }
+
+            fn encode_with_constraints<'b, EN: Encoder<'b>>(
+                &self,
+                encoder: &mut EN,
+                constraints: Constraints,
+            ) -> Result<(), EN::Error> {
+                E::encode_with_constraints(self, encoder, constraints)
+            }
+
+            fn encode_with_identifier<'b, EN: Encoder<'b>>(
+                &self,
+                encoder: &mut EN,
+                identifier: Identifier,
+            ) -> Result<(), EN::Error> {
+                E::encode_with_identifier(self, encoder, identifier)
+            }
+
+            fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
+                &self,
+                encoder: &mut EN,
+                tag: Tag,
+                constraints: Constraints,
+                identifier: Identifier,
+            ) -> Result<(), EN::Error> {
+                E::encode_with_tag_and_constraints(
+                    self,
+                    encoder,
+                    tag,
+                    constraints,
+                    identifier.or(Self::IDENTIFIER),
+                )
+            }
+        }
+        )+
+    }
+}
+
+impl_pointers! {
+    &'_ mut E,
+    alloc::borrow::Cow<'_, E> where E: Clone,
+    alloc::boxed::Box<E>,
+    alloc::rc::Rc<E>,
+    #[cfg(target_has_atomic = "ptr")]
+    alloc::sync::Arc<E>,
+}
+
+impl<E: Encode> Encode for alloc::vec::Vec<E> {
+    fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
         &self,
         encoder: &mut EN,
+        tag: Tag,
         constraints: Constraints,
+        identifier: Identifier,
     ) -> Result<(), EN::Error> {
-        E::encode_with_constraints(self, encoder, constraints)
+        encoder
+            .encode_sequence_of(tag, self, constraints, identifier.or(Self::IDENTIFIER))
+            .map(drop)
     }
+}
 
-    fn encode_with_identifier<'b, EN: Encoder<'b>>(
+impl<E: Encode + Eq + core::hash::Hash> Encode for SetOf<E> {
+    fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
         &self,
         encoder: &mut EN,
+        tag: Tag,
+        constraints: Constraints,
         identifier: Identifier,
     ) -> Result<(), EN::Error> {
-        E::encode_with_identifier(self, encoder, identifier)
+        encoder
+            .encode_set_of(tag, self, constraints, identifier.or(Self::IDENTIFIER))
+            .map(drop)
     }
+}
 
+impl<E: Encode> Encode for types::SetVec<E> {
     fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
         &self,
         encoder: &mut EN,
@@ -980,17 +1203,17 @@ impl<E: Encode> Encode for alloc::boxed::Box<E> {
         constraints: Constraints,
         identifier: Identifier,
     ) -> Result<(), EN::Error> {
-        E::encode_with_tag_and_constraints(
-            self,
-            encoder,
-            tag,
-            constraints,
-            identifier.or(Self::IDENTIFIER),
-        )
+        encoder
+            .encode_set_of_slice(tag, self, constraints, identifier.or(Self::IDENTIFIER))
+            .map(drop)
     }
 }
 
-impl<E: Encode> Encode for alloc::vec::Vec<E> {
+impl<I> Encode for types::SequenceOfIter<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Encode,
+{
     fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
         &self,
         encoder: &mut EN,
@@ -999,12 +1222,17 @@ impl<E: Encode> Encode for alloc::vec::Vec<E> {
         identifier: Identifier,
     ) -> Result<(), EN::Error> {
         encoder
-            .encode_sequence_of(tag, self, constraints, identifier.or(Self::IDENTIFIER))
+            .encode_sequence_of_iter(
+                tag,
+                self.0.clone(),
+                constraints,
+                identifier.or(Self::IDENTIFIER),
+            )
             .map(drop)
     }
 }
 
-impl<E: Encode + Eq + core::hash::Hash> Encode for SetOf<E> {
+impl<E: Encode> Encode for &'_ [E] {
     fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
         &self,
         encoder: &mut EN,
@@ -1013,7 +1241,7 @@ impl<E: Encode + Eq + core::hash::Hash> Encode for SetOf<E> {
         identifier: Identifier,
     ) -> Result<(), EN::Error> {
         encoder
-            .encode_set_of(tag, self, constraints, identifier.or(Self::IDENTIFIER))
+            .encode_sequence_of(tag, self, constraints, identifier.or(Self::IDENTIFIER))
             .map(drop)
     }
 }
@@ -1032,6 +1260,61 @@ impl<E: Encode, const N: usize> Encode for [E; N] {
     }
 }
 
+macro_rules! impl_tuples {
+    ($($len:literal => ($($index:tt $ty:ident),+);)+) => {
+        $(
+            impl<$($ty: Encode),+> Encode for ($($ty,)+) {
+                fn encode_with_tag_and_constraints<'b, EN: Encoder<'b>>(
+                    &self,
+                    encoder: &mut EN,
+                    tag: Tag,
+                    _: Constraints,
+                    identifier: Identifier,
+                ) -> Result<(), EN::Error> {
+                    encoder
+                        .encode_sequence::<$len, 0, Self, _>(
+                            tag,
+                            |sequence| {
+                                $(self.$index.encode(sequence)?;)+
+                                Ok(())
+                            },
+                            identifier.or(Self::IDENTIFIER),
+                        )
+                        .map(drop)
+                }
+            }
+        )+
+    }
+}
+
+impl_tuples! {
+    1 => (0 A);
+    2 => (0 A, 1 B);
+    3 => (0 A, 1 B, 2 C);
+    4 => (0 A, 1 B, 2 C, 3 D);
+    5 => (0 A, 1 B, 2 C, 3 D, 4 E);
+    6 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+    7 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+    8 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+    9 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+    10 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+    11 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+    12 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+}
+
+impl<T: Encode, const MIN: usize, const MAX: usize> Encode for types::SizeConstrained<T, MIN, MAX> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        self.0
+            .encode_with_tag_and_constraints(encoder, tag, constraints, identifier)
+    }
+}
+
 impl<T: AsnType, V: Encode> Encode for types::Implicit<T, V> {
     fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
         &self,
@@ -30,3 +30,115 @@ pub use encode::{
 };
 
 pub use components::InnerSubtypeConstraintError;
+
+/// Any error returned by encoding or decoding, for code that handles both or
+/// is generic over codecs.
+///
+/// Every [`Encoder::Error`][crate::Encoder::Error] and
+/// [`Decoder::Error`][crate::Decoder::Error] converts into this type. Its
+/// `Display` and `source` are those of the wrapped error.
+/// ```
+/// use rasn::types::Integer;
+///
+/// fn transcode(input: &[u8]) -> Result<Vec<u8>, rasn::Error> {
+///     let value: Integer = rasn::ber::decode(input)?;
+///     Ok(rasn::der::encode(&value)?)
+/// }
+///
+/// assert_eq!(transcode(&[0x02, 0x01, 0x05]).unwrap(), [0x02, 0x01, 0x05]);
+/// assert!(matches!(transcode(&[0x04, 0x00]), Err(rasn::Error::Decode(_))));
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error from decoding.
+    Decode(DecodeError),
+    /// An error from encoding.
+    Encode(EncodeError),
+}
+
+impl Error {
+    /// Returns the codec that returned the error.
+    #[must_use]
+    pub fn codec(&self) -> crate::Codec {
+        match self {
+            Self::Decode(error) => error.codec,
+            Self::Encode(error) => error.codec,
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        Self::Decode(error)
+    }
+}
+
+impl From<EncodeError> for Error {
+    fn from(error: EncodeError) -> Self {
+        Self::Encode(error)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Decode(error) => error.fmt(f),
+            Self::Encode(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Decode(error) => error.source(),
+            Self::Encode(error) => error.source(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use core::error::Error as _;
+
+    use super::*;
+    use crate::{AsnType, Decode};
+
+    #[test]
+    fn from_codec_errors() {
+        // A `SEQUENCE` whose `BOOLEAN` field has an `INTEGER` tag.
+        #[derive(AsnType, Decode, Debug)]
+        #[rasn(crate_root = "crate")]
+        struct Flag {
+            flag: bool,
+        }
+
+        let decode_error = crate::ber::decode::<Flag>(&[0x30, 0x03, 0x02, 0x01, 0x00]).unwrap_err();
+        let message = decode_error.to_string();
+        let error = Error::from(decode_error);
+
+        assert_eq!(crate::Codec::Ber, error.codec());
+        assert_eq!(message, error.to_string());
+        assert!(error.source().is_some());
+        match &error {
+            Error::Decode(error) => assert!(matches!(
+                &*error.kind,
+                DecodeErrorKind::FieldError {
+                    name: "Flag.flag",
+                    ..
+                }
+            )),
+            Error::Encode(_) => panic!("expected a decode error"),
+        }
+
+        let error = Error::from(EncodeError::from_kind(
+            EncodeErrorKind::Custom {
+                msg: "custom".into(),
+            },
+            crate::Codec::Der,
+        ));
+        assert!(matches!(&error, Error::Encode(error) if error.codec == crate::Codec::Der));
+    }
+}
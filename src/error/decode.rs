@@ -30,6 +30,39 @@ pub enum CodecDecodeError {
     Xer(XerDecodeErrorKind),
 }
 
+impl core::fmt::Display for CodecDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Ber(error) => error.fmt(f),
+            Self::Cer(error) => error.fmt(f),
+            Self::Der(error) => error.fmt(f),
+            Self::Uper(error) => error.fmt(f),
+            Self::Aper(error) => error.fmt(f),
+            Self::Jer(error) => error.fmt(f),
+            Self::Oer(error) => error.fmt(f),
+            Self::Coer(error) => error.fmt(f),
+            Self::Xer(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for CodecDecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        let error: &(dyn core::error::Error + 'static) = match self {
+            Self::Ber(error) => error,
+            Self::Cer(error) => error,
+            Self::Der(error) => error,
+            Self::Uper(error) => error,
+            Self::Aper(error) => error,
+            Self::Jer(error) => error,
+            Self::Oer(error) => error,
+            Self::Coer(error) => error,
+            Self::Xer(error) => error,
+        };
+        error.source()
+    }
+}
+
 macro_rules! impl_from {
     ($variant:ident, $error_kind:ty) => {
         impl From<$error_kind> for DecodeError {
@@ -142,6 +175,9 @@ pub struct DecodeError {
     pub kind: Box<DecodeErrorKind>,
     /// The codec that returned the error.
     pub codec: Codec,
+    /// The offset in bytes from the start of the input of the value that
+    /// failed to decode, if known.
+    pub offset: Option<usize>,
     /// The backtrace associated with the error.
     #[cfg(feature = "backtraces")]
     pub backtrace: Backtrace,
@@ -149,8 +185,18 @@ pub struct DecodeError {
 
 impl core::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        writeln!(f, "Error Kind: {}", self.kind)?;
+        match self.field_path() {
+            Some(path) => writeln!(
+                f,
+                "Error Kind: Error when decoding field `{path}`: {}",
+                self.root_cause().kind
+            )?,
+            None => writeln!(f, "Error Kind: {}", self.kind)?,
+        }
         writeln!(f, "Codec: {}", self.codec)?;
+        if let Some(offset) = self.offset {
+            writeln!(f, "Offset: {offset}")?;
+        }
         #[cfg(feature = "backtraces")]
         write!(f, "\nBacktrace:\n{}", self.backtrace)?;
         Ok(())
@@ -211,6 +257,12 @@ impl DecodeError {
         )
     }
 
+    /// Creates a wrapper around a duplicate map key error from a given codec.
+    #[must_use]
+    pub fn duplicate_map_key(codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::DuplicateMapKey, codec)
+    }
+
     /// Creates a wrapper around a range value error from a given codec.
     #[must_use]
     pub fn range_exceeds_platform_width(needed: u32, present: u32, codec: Codec) -> Self {
@@ -238,6 +290,13 @@ impl DecodeError {
         )
     }
 
+    /// Creates a wrapper around an IP address with a length other than 4 or
+    /// 16 octets from a given codec.
+    #[must_use]
+    pub fn invalid_ip_address_length(length: usize, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::InvalidIpAddressLength { length }, codec)
+    }
+
     /// Creates a wrapper around a sequence item error from a given codec.
     #[must_use]
     pub fn incorrect_item_number_in_sequence(expected: usize, actual: usize, codec: Codec) -> Self {
@@ -253,6 +312,20 @@ impl DecodeError {
         Self::from_kind(DecodeErrorKind::IntegerOverflow { max_width }, codec)
     }
 
+    /// Creates a wrapper around a decoded integer `value` which doesn't fit
+    /// in the primitive integer type `target` from a given codec.
+    #[must_use]
+    pub fn integer_out_of_range(target: &'static str, value: BigInt, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::IntegerOutOfRange { target, value }, codec)
+    }
+
+    /// Creates a wrapper around a zero decoded for a non-zero integer type
+    /// from a given codec.
+    #[must_use]
+    pub fn unexpected_zero_integer(codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::UnexpectedZeroInteger, codec)
+    }
+
     /// Creates a wrapper around a integer conversion error from a given codec.
     #[must_use]
     pub fn integer_type_conversion_failed(msg: alloc::string::String, codec: Codec) -> Self {
@@ -348,6 +421,13 @@ impl DecodeError {
         Self::from_kind(DecodeErrorKind::StringConversionFailed { tag, msg }, codec)
     }
 
+    /// Creates a wrapper around invalid UTF-8 in a `UTF8String` from a given
+    /// codec, where `offset` is the start of the first invalid sequence.
+    #[must_use]
+    pub fn invalid_utf8(offset: usize, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::InvalidUtf8 { offset }, codec)
+    }
+
     /// Creates a wrapper around unexpected extra data error from a given codec.
     #[must_use]
     pub fn unexpected_extra_data(length: usize, codec: Codec) -> Self {
@@ -393,11 +473,63 @@ impl DecodeError {
         Self {
             kind: Box::new(kind),
             codec,
+            offset: None,
             #[cfg(feature = "backtraces")]
             backtrace: Backtrace::generate(),
         }
     }
 
+    /// Returns the dotted path of the field that failed to decode, starting
+    /// from the outermost type, e.g. `Certificate.tbs_certificate.version`.
+    /// Returns `None` if the error did not occur while decoding a field.
+    #[must_use]
+    pub fn field_path(&self) -> Option<alloc::string::String> {
+        let DecodeErrorKind::FieldError { name, nested } = &*self.kind else {
+            return None;
+        };
+
+        let mut path = alloc::string::String::from(*name);
+        let mut error = &**nested;
+        while let DecodeErrorKind::FieldError { name, nested } = &*error.kind {
+            // Nested names are prefixed with the name of their type, which
+            // is already named by the parent field.
+            path.push('.');
+            path.push_str(name.split_once('.').map_or(*name, |(_, field)| field));
+            error = nested;
+        }
+
+        Some(path)
+    }
+
+    /// Returns the error that caused decoding to fail, skipping past the
+    /// errors that record the fields it occurred in.
+    #[must_use]
+    pub fn root_cause(&self) -> &DecodeError {
+        let mut error = self;
+        while let DecodeErrorKind::FieldError { nested, .. } = &*error.kind {
+            error = nested;
+        }
+        error
+    }
+
+    /// Returns whether decoding failed only because the input ended early,
+    /// i.e. the [`root_cause`][Self::root_cause] is
+    /// [`DecodeErrorKind::Incomplete`], so that decoding may succeed once
+    /// more input has arrived. Any other error means that the input is
+    /// malformed, and no amount of further input will fix it.
+    #[must_use]
+    pub fn is_incomplete(&self) -> bool {
+        matches!(*self.root_cause().kind, DecodeErrorKind::Incomplete { .. })
+    }
+
+    /// Records `offset` as the position in the input at which decoding
+    /// failed, unless a more precise offset was already recorded.
+    #[must_use]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+
     #[must_use]
     fn from_codec_kind(inner: CodecDecodeError) -> Self {
         let codec = match inner {
@@ -417,13 +549,24 @@ impl DecodeError {
         Self {
             kind: Box::new(DecodeErrorKind::CodecSpecific { inner }),
             codec,
+            offset: None,
             #[cfg(feature = "backtraces")]
             backtrace: Backtrace::generate(),
         }
     }
 }
 
-impl core::error::Error for DecodeError {}
+impl core::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &*self.kind {
+            DecodeErrorKind::FieldError { nested, .. } => Some(&**nested),
+            DecodeErrorKind::CodecSpecific { inner } => Some(inner),
+            #[cfg(feature = "std")]
+            DecodeErrorKind::Io { error } => Some(error),
+            _ => None,
+        }
+    }
+}
 
 /// `DecodeError` kinds which are common for all codecs.
 #[derive(Snafu)]
@@ -463,7 +606,7 @@ pub enum DecodeErrorKind {
     },
 
     /// Codec specific error.
-    #[snafu(display("Wrapped codec-specific decode error"))]
+    #[snafu(display("{inner}"))]
     CodecSpecific {
         /// The inner error type.
         inner: CodecDecodeError,
@@ -521,6 +664,10 @@ pub enum DecodeErrorKind {
         name: &'static str,
     },
 
+    /// The same key appears more than once in a map.
+    #[snafu(display("Duplicate key in map"))]
+    DuplicateMapKey,
+
     /// Exceeds maxmium allowed length.
     #[snafu(display("Expected maximum of {} items", length))]
     ExceedsMaxLength {
@@ -573,6 +720,20 @@ pub enum DecodeErrorKind {
         max_width: u32,
     },
 
+    /// A decoded integer doesn't fit in the primitive integer type it is
+    /// decoded into, e.g. 256 or -1 as a `u8`.
+    #[snafu(display("Integer {value} is out of the range of `{target}`"))]
+    IntegerOutOfRange {
+        /// The name of the primitive integer type.
+        target: &'static str,
+        /// The decoded value.
+        value: BigInt,
+    },
+
+    /// Zero was decoded for a non-zero integer type.
+    #[snafu(display("Decoded zero for an integer type that doesn't allow zero"))]
+    UnexpectedZeroInteger,
+
     /// Integer conversion failure.
     #[snafu(display("Failed to cast integer to another integer type: {msg} "))]
     IntegerTypeConversionFailed {
@@ -664,6 +825,12 @@ pub enum DecodeErrorKind {
         /// The error's message.
         msg: alloc::string::String,
     },
+    /// The contents of a `UTF8String` aren't valid UTF-8.
+    #[snafu(display("Invalid UTF-8 in UTF8String at byte {offset} of its contents"))]
+    InvalidUtf8 {
+        /// The offset of the first invalid byte sequence in the contents.
+        offset: usize,
+    },
     /// General error for failed ASN.1 fixed-sized string conversion from bytes.
     #[snafu(display(
     "Failed to convert byte array into valid fixed-sized ASN.1 string. String type as tag: {}, actual: {}, expected: {}",
@@ -679,6 +846,13 @@ pub enum DecodeErrorKind {
         /// Actual length
         actual: usize,
     },
+    /// An IP address doesn't have the 4 octets of an IPv4 address or the 16
+    /// octets of an IPv6 address.
+    #[snafu(display("IP address must be 4 or 16 octets, actual length: {length}"))]
+    InvalidIpAddressLength {
+        /// The number of octets decoded.
+        length: usize,
+    },
     /// An error when the choice cannot be created from the given variant.
     #[snafu(display("No valid choice for `{}`", name))]
     NoValidChoice {
@@ -689,6 +863,13 @@ pub enum DecodeErrorKind {
     /// An error when the type is not extensible when it should.
     #[snafu(display("Attempted to decode extension on non-extensible type"))]
     TypeNotExtensible,
+    /// An error occurred while reading the input.
+    #[cfg(feature = "std")]
+    #[snafu(display("Failed to read input: {error}"))]
+    Io {
+        /// The underlying IO error.
+        error: std::io::Error,
+    },
     /// Unexpected extra data found.
     #[snafu(display("Unexpected extra data found: length `{}` bytes", length))]
     UnexpectedExtraData {
@@ -718,6 +899,9 @@ pub enum BerDecodeErrorKind {
     /// An error when the length is not definite.
     #[snafu(display("Indefinite length encountered but not allowed."))]
     IndefiniteLengthNotAllowed,
+    /// The initial length octet was `0xFF`, which X.690 reserves.
+    #[snafu(display("Reserved length octet 0xFF encountered."))]
+    ReservedLengthOctet,
     /// An error if the value is not primitive when required.
     #[snafu(display("Invalid constructed identifier for ASN.1 value: not primitive."))]
     InvalidConstructedIdentifier,
@@ -730,8 +914,69 @@ pub enum BerDecodeErrorKind {
     /// An error when the object identifier is invalid.
     #[snafu(display("Invalid object identifier with missing or corrupt root nodes."))]
     InvalidObjectIdentifier,
+    /// A decimal `REAL` value didn't contain a valid ISO 6093 number.
+    #[snafu(display("Invalid decimal real value: {text:?}"))]
+    InvalidDecimalReal {
+        /// The text of the value.
+        text: alloc::string::String,
+    },
+    /// Constructed values were nested deeper than the configured limit.
+    #[snafu(display("Exceeded the maximum nesting depth of {limit}."))]
+    NestingTooDeep {
+        /// The maximum allowed depth.
+        limit: usize,
+    },
+    /// A value declared a length greater than the configured limit.
+    #[snafu(display("Value length of {length} exceeds the maximum of {limit}."))]
+    ValueTooLong {
+        /// The declared length.
+        length: usize,
+        /// The maximum allowed length.
+        limit: usize,
+    },
+    /// A `SEQUENCE OF` or `SET OF` contained more elements than the configured limit.
+    #[snafu(display("Collection contains more than the maximum of {limit} elements."))]
+    TooManyElements {
+        /// The maximum allowed number of elements.
+        limit: usize,
+    },
+    /// An object identifier contained more arcs than the configured limit.
+    #[snafu(display("Object identifier contains more than the maximum of {limit} arcs."))]
+    TooManyOidArcs {
+        /// The maximum allowed number of arcs.
+        limit: usize,
+    },
+    /// The unused bits of a bit string were not zero in a canonical encoding.
+    #[snafu(display("Bit string contains non-zero padding bits in canonical encoding."))]
+    NonZeroBitStringPadding,
+    /// A segment of a constructed bit string other than the last contained unused bits.
+    #[snafu(display(
+        "Only the final segment of a constructed bit string may contain unused bits."
+    ))]
+    UnalignedBitStringSegment,
+    /// An integer was encoded with redundant leading octets in a canonical encoding.
+    #[snafu(display("Integer contains redundant leading octets in canonical encoding."))]
+    NonMinimalInteger,
+    /// The components of a set were not in ascending tag order in a canonical encoding.
+    #[snafu(display(
+        "Set component with tag {tag} follows a component with a greater tag in canonical encoding."
+    ))]
+    UnorderedSetComponent {
+        /// The tag of the out of order component.
+        tag: Tag,
+    },
+    /// An end-of-contents marker was found where a value was expected.
+    #[snafu(display("Unexpected end-of-contents marker."))]
+    UnexpectedEndOfContents,
+    /// A value extended past the end of the definite length value containing
+    /// it, which unlike running out of input can't be fixed by more input.
+    #[snafu(display("Value extends past the end of its enclosing value."))]
+    LengthExceedsContainer,
+    /// An object identifier arc was truncated or had a redundant leading octet.
+    #[snafu(display("Invalid object identifier arc: truncated or not minimally encoded."))]
+    InvalidObjectIdentifierArc,
     /// The tag does not match what was expected.
-    #[snafu(display("Expected {:?} tag, actual tag: {:?}", expected, actual))]
+    #[snafu(display("Expected {expected} tag, actual tag: {actual}"))]
     MismatchedTag {
         /// The expected tag.
         expected: Tag,
@@ -770,6 +1015,24 @@ pub enum DerDecodeErrorKind {
     /// An error when constructed encoding encountered but not allowed.
     #[snafu(display("Constructed encoding encountered but not allowed."))]
     ConstructedEncodingNotAllowed,
+    /// A definite length wasn't encoded in the minimum number of octets.
+    #[snafu(display("Length not encoded in the minimum number of octets."))]
+    NonMinimalLength,
+    /// A time is valid BER, but not in the only form CER and DER allow.
+    #[snafu(display("Time {value:?} is not in canonical form: {rule}."))]
+    NonCanonicalTime {
+        /// The contents of the time.
+        value: alloc::string::String,
+        /// The canonical encoding rule that the time breaks.
+        rule: &'static str,
+    },
+    /// A binary `REAL` is valid BER, but not in the only form CER and DER
+    /// allow.
+    #[snafu(display("REAL is not in canonical form: {rule}."))]
+    NonCanonicalReal {
+        /// The canonical encoding rule that the value breaks.
+        rule: &'static str,
+    },
 }
 
 /// An error that occurred when decoding JER.
@@ -1016,13 +1279,16 @@ impl crate::de::Error for DecodeError {
     }
 
     fn field_error(name: &'static str, nested: DecodeError, codec: Codec) -> Self {
-        Self::from_kind(
+        let offset = nested.offset;
+        let mut error = Self::from_kind(
             DecodeErrorKind::FieldError {
                 name,
                 nested: Box::new(nested),
             },
             codec,
-        )
+        );
+        error.offset = offset;
+        error
     }
 
     fn duplicate_field(name: &'static str, codec: Codec) -> Self {
@@ -1092,4 +1358,69 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn field_path() {
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Validity {
+            not_before: UtcTime,
+            not_after: UtcTime,
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct TbsCertificate {
+            serial_number: Integer,
+            validity: Validity,
+        }
+
+        // `not_before` is encoded as an OCTET STRING instead of a UTCTime.
+        let data = [
+            0x30, 0x0C, 0x02, 0x01, 0x01, 0x30, 0x07, 0x04, 0x00, 0x17, 0x03, 0x30, 0x30, 0x5A,
+        ];
+        let error = crate::der::decode::<TbsCertificate>(&data).unwrap_err();
+
+        assert_eq!(
+            Some("TbsCertificate.validity.not_before".into()),
+            error.field_path()
+        );
+        assert!(!matches!(
+            &*error.root_cause().kind,
+            crate::error::DecodeErrorKind::FieldError { .. }
+        ));
+        assert_eq!(Some(7), error.offset);
+        assert!(error.to_string().starts_with(
+            "Error Kind: Error when decoding field `TbsCertificate.validity.not_before`: "
+        ));
+
+        let error = crate::der::decode::<UtcTime>(&data).unwrap_err();
+        assert_eq!(None, error.field_path());
+    }
+
+    #[test]
+    fn error_trait() {
+        use crate::error::{CodecDecodeError, DecodeError, DerDecodeErrorKind, EncodeError};
+        use core::error::Error;
+
+        fn assert_thread_safe<T: Error + Send + Sync + 'static>() {}
+        assert_thread_safe::<DecodeError>();
+        assert_thread_safe::<EncodeError>();
+
+        let error = crate::der::decode::<bool>(&[0x01, 0x81, 0x01, 0xFF]).unwrap_err();
+        assert!(error.to_string().starts_with(
+            "Error Kind: Length not encoded in the minimum number of octets.\nCodec: DER\n"
+        ));
+        assert!(matches!(
+            error
+                .source()
+                .and_then(|e| e.downcast_ref::<CodecDecodeError>()),
+            Some(CodecDecodeError::Der(DerDecodeErrorKind::NonMinimalLength))
+        ));
+
+        let error = crate::ber::encode(&ObjectIdentifier::new_unchecked(alloc::vec![3, 1].into()))
+            .unwrap_err();
+        assert!(error.source().is_some());
+        assert!(error.to_string().contains("Codec: BER"));
+    }
 }
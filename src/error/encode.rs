@@ -21,6 +21,37 @@ pub enum CodecEncodeError {
     Coer(CoerEncodeErrorKind),
     Xer(XerEncodeErrorKind),
 }
+
+impl core::fmt::Display for CodecEncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Ber(error) => error.fmt(f),
+            Self::Cer(error) => error.fmt(f),
+            Self::Der(error) => error.fmt(f),
+            Self::Uper(error) => error.fmt(f),
+            Self::Aper(error) => error.fmt(f),
+            Self::Jer(error) => error.fmt(f),
+            Self::Coer(error) => error.fmt(f),
+            Self::Xer(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for CodecEncodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        let error: &(dyn core::error::Error + 'static) = match self {
+            Self::Ber(error) => error,
+            Self::Cer(error) => error,
+            Self::Der(error) => error,
+            Self::Uper(error) => error,
+            Self::Aper(error) => error,
+            Self::Jer(error) => error,
+            Self::Coer(error) => error,
+            Self::Xer(error) => error,
+        };
+        error.source()
+    }
+}
 macro_rules! impl_from {
     ($variant:ident, $error_kind:ty) => {
         impl From<$error_kind> for EncodeError {
@@ -113,12 +144,21 @@ pub struct EncodeError {
     pub backtrace: Backtrace,
 }
 
-impl core::error::Error for EncodeError {}
+impl core::error::Error for EncodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &*self.kind {
+            EncodeErrorKind::CodecSpecific { inner } => Some(inner),
+            #[cfg(feature = "std")]
+            EncodeErrorKind::Io { error } => Some(error),
+            _ => None,
+        }
+    }
+}
 
 impl core::fmt::Display for EncodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(f, "Error Kind: {}", self.kind)?;
-        writeln!(f, "Codec: {}", self.kind)?;
+        writeln!(f, "Codec: {}", self.codec)?;
         #[cfg(feature = "backtraces")]
         write!(f, "\nBacktrace:\n{}", self.backtrace)?;
 
@@ -220,6 +260,13 @@ impl EncodeError {
         Self::from_kind(EncodeErrorKind::RealNotSuppored, codec)
     }
 
+    /// Returns an encode error when a `UTCTime` value's year can't be
+    /// represented with two digits.
+    #[must_use]
+    pub fn utc_time_year_out_of_range(year: i32, codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::UtcTimeYearOutOfRange { year }, codec)
+    }
+
     /// A helper function to construct an `EncodeError` from the given `kind` and `codec`.
     #[must_use]
     pub fn from_kind(kind: EncodeErrorKind, codec: crate::Codec) -> Self {
@@ -275,6 +322,19 @@ pub enum EncodeErrorKind {
         /// Expected length of the data
         expected: Bounded<usize>,
     },
+    /// The buffer supplied for the output is too small to hold the encoding.
+    #[snafu(display("Output buffer too small, {needed} octets needed"))]
+    BufferTooSmall {
+        /// The number of octets the encoding takes.
+        needed: usize,
+    },
+    /// An error occurred while writing the output.
+    #[cfg(feature = "std")]
+    #[snafu(display("Failed to write output: {error}"))]
+    Io {
+        /// The underlying IO error.
+        error: std::io::Error,
+    },
     /// Error when the length of the data is more than we can technically handle.
     #[snafu(display("invalid length, exceeds platform maximum size usize::MAX"))]
     LengthExceedsPlatformSize,
@@ -295,7 +355,7 @@ pub enum EncodeErrorKind {
         msg: alloc::string::String,
     },
     /// Wraps codec-specific errors as inner [`CodecEncodeError`].
-    #[snafu(display("Wrapped codec-specific encode error"))]
+    #[snafu(display("{inner}"))]
     CodecSpecific {
         /// Inner codec-specific error
         inner: CodecEncodeError,
@@ -341,6 +401,16 @@ pub enum EncodeErrorKind {
     /// Error when we try to encode a `REAL` type with an unspported codec.
     #[snafu(display("Encoder doesn't support `REAL` type"))]
     RealNotSuppored,
+
+    /// Error when a `UTCTime` value's year is outside of 1950..=2049, which
+    /// can't be represented with a two digit year.
+    #[snafu(display(
+        "UTCTime can only represent the years 1950 to 2049, use GeneralizedTime for year {year}"
+    ))]
+    UtcTimeYearOutOfRange {
+        /// The year of the value
+        year: i32,
+    },
 }
 /// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for BER.
 #[derive(Snafu, Debug)]
@@ -356,13 +426,17 @@ pub enum BerEncodeErrorKind {
     ))]
     InvalidObjectIdentifier {
         /// Bytes of the invalid object identifier
-        oid: alloc::vec::Vec<u32>,
+        oid: alloc::vec::Vec<u128>,
     },
+    /// Error to be thrown when a `REAL` value can't be represented as a
+    /// double precision floating point number.
+    #[snafu(display("Exceeds supported real value range"))]
+    ExceedsSupportedRealRange,
 }
 impl BerEncodeErrorKind {
     /// Create an error [`BerEncodeErrorKind::InvalidObjectIdentifier`}.
     #[must_use]
-    pub fn invalid_object_identifier(oid: alloc::vec::Vec<u32>) -> Self {
+    pub fn invalid_object_identifier(oid: alloc::vec::Vec<u128>) -> Self {
         Self::InvalidObjectIdentifier { oid }
     }
 }
@@ -378,7 +452,14 @@ pub enum CerEncodeErrorKind {}
 #[derive(Snafu, Debug)]
 #[snafu(visibility(pub))]
 #[non_exhaustive]
-pub enum DerEncodeErrorKind {}
+pub enum DerEncodeErrorKind {
+    /// DER requires every value to use the definite length form.
+    #[snafu(display("Indefinite length encoding is not allowed in DER"))]
+    IndefiniteLengthNotAllowed,
+    /// CER and DER require `GeneralizedTime` values to be in UTC.
+    #[snafu(display("GeneralizedTime values must be encoded in UTC in CER and DER"))]
+    GeneralizedTimeOffsetNotAllowed,
+}
 
 /// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for UPER.
 #[derive(Snafu, Debug)]
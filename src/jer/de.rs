@@ -6,10 +6,10 @@ use crate::{
     de::Error,
     error::{DecodeError, JerDecodeErrorKind},
     types::{
-        variants, Any, BitString, BmpString, Constraints, Constructed, Date, DecodeChoice,
-        Enumerated, GeneralString, GeneralizedTime, GraphicString, Ia5String, NumericString,
-        ObjectIdentifier, Oid, PrintableString, SequenceOf, SetOf, Tag, TeletexString, UtcTime,
-        Utf8String, VisibleString,
+        variants, Any, BitString, BmpString, Constraints, Constructed, DecodeChoice, Enumerated,
+        GeneralString, GeneralizedTime, GraphicString, Ia5String, NumericString, ObjectIdentifier,
+        Oid, PrintableString, SequenceOf, SetOf, Tag, TeletexString, UtcTime, Utf8String,
+        VisibleString,
     },
     Decode,
 };
@@ -359,7 +359,8 @@ impl crate::Decoder for Decoder {
         decode_jer_value!(Self::general_time_from_value, self.stack)
     }
 
-    fn decode_date(&mut self, _t: Tag) -> Result<Date, Self::Error> {
+    #[cfg(feature = "chrono")]
+    fn decode_date(&mut self, _t: Tag) -> Result<crate::types::Date, Self::Error> {
         decode_jer_value!(Self::date_from_value, self.stack)
     }
 
@@ -580,13 +581,13 @@ impl Decoder {
             })?
             .split('.')
             .map(|arc| {
-                arc.parse::<u32>()
+                arc.parse::<u128>()
                     .map_err(|_| JerDecodeErrorKind::TypeMismatch {
                         needed: "OID arc number",
                         found: arc.into(),
                     })
             })
-            .collect::<Result<alloc::vec::Vec<u32>, _>>()
+            .collect::<Result<alloc::vec::Vec<u128>, _>>()
             .ok()
             .and_then(|arcs| Oid::new(&arcs).map(ObjectIdentifier::from))
             .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
@@ -686,7 +687,7 @@ impl Decoder {
             .ok_or(JerDecodeErrorKind::InvalidJerOctetString {}.into())
     }
 
-    fn utc_time_from_value(value: Value) -> Result<chrono::DateTime<chrono::Utc>, DecodeError> {
+    fn utc_time_from_value(value: Value) -> Result<UtcTime, DecodeError> {
         crate::ber::de::Decoder::parse_any_utc_time_string(
             value
                 .as_str()
@@ -698,9 +699,7 @@ impl Decoder {
         )
     }
 
-    fn general_time_from_value(
-        value: Value,
-    ) -> Result<chrono::DateTime<chrono::FixedOffset>, DecodeError> {
+    fn general_time_from_value(value: Value) -> Result<GeneralizedTime, DecodeError> {
         crate::ber::de::Decoder::parse_any_generalized_time_string(
             value
                 .as_str()
@@ -712,7 +711,8 @@ impl Decoder {
         )
     }
 
-    fn date_from_value(value: Value) -> Result<chrono::NaiveDate, DecodeError> {
+    #[cfg(feature = "chrono")]
+    fn date_from_value(value: Value) -> Result<crate::types::Date, DecodeError> {
         crate::ber::de::Decoder::parse_date_string(value.as_str().ok_or_else(|| {
             JerDecodeErrorKind::TypeMismatch {
                 needed: "date string",
@@ -723,7 +723,7 @@ impl Decoder {
 }
 
 /// Parses a hex string into bytes.
-fn bytes_from_hexstring(hex_string: &str) -> Option<alloc::vec::Vec<u8>> {
+pub(crate) fn bytes_from_hexstring(hex_string: &str) -> Option<alloc::vec::Vec<u8>> {
     if !hex_string.len().is_multiple_of(2) {
         return None;
     }
@@ -100,6 +100,7 @@ impl crate::Encoder<'_> for Encoder {
     ) -> Result<Self::Ok, Self::Error> {
         let mut bitvec = value.to_bitvec();
         bitvec.force_align();
+        bitvec.set_uninitialized(false);
         let bytes = bitvec
             .into_vec()
             .iter()
@@ -132,7 +133,7 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_object_identifier(
         &mut self,
         _t: Tag,
-        value: &[u32],
+        value: &[u128],
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         self.update_root_or_constructed(Value::String(
@@ -343,13 +344,15 @@ impl crate::Encoder<'_> for Encoder {
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         self.update_root_or_constructed(Value::String(
-            alloc::string::String::from_utf8(
-                crate::ber::enc::Encoder::datetime_to_canonical_utc_time_bytes(value),
-            )
+            alloc::string::String::from_utf8(crate::ber::enc::Encoder::checked_utc_time_bytes(
+                value,
+                self.codec(),
+            )?)
             .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
         ))
     }
 
+    #[cfg(feature = "chrono")]
     fn encode_date(
         &mut self,
         _t: Tag,
@@ -9,6 +9,8 @@
 )]
 
 extern crate alloc;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
 
 #[macro_use]
 pub mod macros;
@@ -39,7 +41,8 @@ pub use self::{
     codec::Codec,
     de::{Decode, Decoder},
     enc::{Encode, Encoder},
-    types::AsnType,
+    error::Error,
+    types::{AsnType, Class},
 };
 
 #[doc(inline)]
@@ -133,6 +136,87 @@ mod tests {
         usize
     }
 
+    #[test]
+    fn borrowed_values() {
+        use alloc::borrow::Cow;
+
+        let owned = vec![Utf8String::from("a"), Utf8String::from("b")];
+        let expected = crate::ber::encode(&owned).unwrap();
+        assert_eq!(expected, crate::ber::encode(&vec!["a", "b"]).unwrap());
+        assert_eq!(expected, crate::ber::encode(&&["a", "b"][..]).unwrap());
+        assert_eq!(expected, crate::ber::encode(&&mut owned.clone()).unwrap());
+
+        let integer = Integer::from(42);
+        let borrowed = Cow::Borrowed(&integer);
+        let encoded = crate::ber::encode(&borrowed).unwrap();
+        assert_eq!(crate::ber::encode(&integer).unwrap(), encoded);
+        assert!(matches!(
+            crate::ber::decode::<Cow<'_, Integer>>(&encoded).unwrap(),
+            Cow::Owned(value) if value == integer
+        ));
+    }
+
+    #[test]
+    fn encoded_len() {
+        #[derive(AsnType, Debug, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Record {
+            id: Integer,
+            #[rasn(tag(explicit(context, 40)))]
+            name: Utf8String,
+            payloads: SequenceOf<OctetString>,
+            flag: Option<bool>,
+        }
+
+        #[track_caller]
+        fn assert_encoded_len<T: Encode>(value: &T) {
+            let length = value.encoded_len().unwrap();
+            assert_eq!(crate::der::encode(value).unwrap().len(), length);
+            assert_eq!(crate::ber::encode(value).unwrap().len(), length);
+        }
+
+        assert_encoded_len(&true);
+        assert_encoded_len(&());
+        assert_encoded_len(&Integer::from(i128::MIN));
+        assert_encoded_len(&ObjectIdentifier::new(vec![1, 2, 840, 113_549, 1, 1, 11]).unwrap());
+        for size in [0, 127, 128, 255, 256, 65_535, 65_536] {
+            assert_encoded_len(&OctetString::from(vec![0xAB; size]));
+        }
+
+        let record = Record {
+            id: 7.into(),
+            name: "long".repeat(40),
+            payloads: vec![OctetString::from(vec![1; 200]), OctetString::default()],
+            flag: Some(false),
+        };
+        assert_encoded_len(&record);
+        assert_encoded_len(&vec![record]);
+        assert_eq!(2, Vec::<Vec<Integer>>::new().encoded_len().unwrap());
+    }
+
+    #[test]
+    fn non_zero_integers() {
+        use core::num::{NonZeroI128, NonZeroI64, NonZeroU128, NonZeroU32, NonZeroU8};
+
+        round_trip(&NonZeroU8::MIN);
+        round_trip(&NonZeroU8::MAX);
+        round_trip(&NonZeroU32::new(300).unwrap());
+        round_trip(&NonZeroI64::new(-1).unwrap());
+        round_trip(&NonZeroI64::MIN);
+        round_trip(&NonZeroI128::MIN);
+        round_trip(&NonZeroU128::MIN);
+        round_trip(&NonZeroU128::new(u128::from(u64::MAX) + 1).unwrap());
+
+        let error = crate::ber::decode::<NonZeroU8>(&[0x02, 0x01, 0x00]).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            crate::error::DecodeErrorKind::UnexpectedZeroInteger
+        ));
+        assert!(error
+            .to_string()
+            .contains("Decoded zero for an integer type that doesn't allow zero"));
+    }
+
     #[test]
     fn integer() {
         round_trip(&89);
@@ -192,6 +276,44 @@ mod tests {
         round_trip(&OctetString::from(vec![5u8, 4, 3, 2, 1]));
     }
 
+    #[test]
+    fn octet_vec() {
+        let bytes = vec![1u8, 2, 3, 4, 5];
+        round_trip(&OctetVec::from(bytes.clone()));
+        round_trip(&OctetVec::default());
+
+        macro_rules! same_encoding {
+            ($($codec:ident),+ $(,)?) => {
+                $(
+                    let expected = crate::$codec::encode(&OctetString::from(bytes.clone())).unwrap();
+                    assert_eq!(expected, crate::$codec::encode(&OctetVec(bytes.clone())).unwrap());
+                    assert_eq!(expected, crate::$codec::encode(&OctetSlice(&bytes)).unwrap());
+                )+
+            }
+        }
+
+        same_encoding!(uper, aper, oer, coer, ber, der);
+        assert_eq!(bytes, Vec::from(OctetVec::from(&bytes[..])));
+    }
+
+    #[test]
+    fn tuples() {
+        round_trip(&(Integer::from(5), OctetString::from_static(b"ab")));
+        round_trip(&(true, Integer::from(-1), Some(OctetString::default()), false));
+        round_trip(&(true, Integer::from(-1), None::<OctetString>, false));
+    }
+
+    #[test]
+    fn size_constrained() {
+        let nonce = SizeConstrained::<OctetString, 8, 8>::new(OctetString::from_static(&[7; 8]));
+        round_trip(&nonce.unwrap());
+
+        let names = SizeConstrained::<SequenceOf<Integer>, 1, 10>::new(vec![1.into(); 10]);
+        round_trip(&names.unwrap());
+        let names = SizeConstrained::<SequenceOf<Integer>, 1, 10>::new(vec![5.into()]);
+        round_trip(&names.unwrap());
+    }
+
     #[test]
     fn utf8_string() {
         round_trip(&crate::types::Utf8String::from("Jones"));
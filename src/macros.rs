@@ -2,7 +2,8 @@
 //! constraints.
 
 pub use crate::{
-    constraints, permitted_alphabet_constraint, size_constraint, value_constraint, Decode, Encode,
+    constraints, defined_by, permitted_alphabet_constraint, size_constraint, value_constraint,
+    Decode, Encode,
 };
 
 #[macro_use]
@@ -187,23 +188,68 @@ macro_rules! bounded_constraint {
     };
 }
 
-/// Helper macro to create a `&'static Oid` from a string literal. The string
-/// literal must conform to the standard OID format and must be a valid OID
-/// (first arc must be <= 2), but also accepts OIDs with and without a leading
-/// `.`.
+/// Helper macro to create a `&'static Oid` from its arcs, written either as
+/// dotted numbers or as a string literal, with or without a leading `.`. The
+/// arcs are checked at compile time to form a valid object identifier: there
+/// must be at least two, the first must be 0, 1 or 2, and the second must be
+/// less than 40 unless the first is 2.
 ///
 /// Usage:
 /// ```rust
-/// const SYS_DESCR: &'static rasn::types::Oid = rasn::oid!(".1.3.6.1.2.1.1.1.0");
-/// assert_eq!(SYS_DESCR, rasn::types::Oid::new(&[1, 3, 6, 1, 2, 1, 1, 1, 0]).unwrap());
+/// use rasn::types::{ObjectIdentifier, Oid};
+///
+/// const SHA256_WITH_RSA: &Oid = rasn::oid!(1.2.840.113549.1.1.11);
+/// const SYS_DESCR: &'static Oid = rasn::oid!(".1.3.6.1.2.1.1.1.0");
+///
+/// assert_eq!(SYS_DESCR, Oid::new(&[1, 3, 6, 1, 2, 1, 1, 1, 0]).unwrap());
+/// assert_eq!(
+///     ObjectIdentifier::new(vec![1, 2, 840, 113549, 1, 1, 11]).unwrap(),
+///     SHA256_WITH_RSA
+/// );
 /// ```
+///
+/// Invalid object identifiers, such as `rasn::oid!(7.1)`, are compile errors.
 #[macro_export]
 macro_rules! oid {
-    ($s:literal) => {{
+    ($($arcs:tt)+) => {{
         const OID: &'static $crate::types::Oid = const {
+            // Dotted numbers are lexed as a mix of float literals and
+            // periods, so both forms are handled as the text of the tokens.
+            const TEXT: &'static [u8] = const {
+                match core::stringify!($($arcs)+).as_bytes() {
+                    [b'"', string @ .., b'"'] => string,
+                    tokens => tokens,
+                }
+            };
+
+            const TEXT_LEN: usize = const {
+                let mut len = 0;
+                let mut index = 0;
+                while index < TEXT.len() {
+                    if !TEXT[index].is_ascii_whitespace() {
+                        len += 1;
+                    }
+                    index += 1;
+                }
+                len
+            };
+
+            const TEXT_WITHOUT_WHITESPACE: [u8; TEXT_LEN] = const {
+                let mut bytes = [0u8; TEXT_LEN];
+                let mut len = 0;
+                let mut index = 0;
+                while index < TEXT.len() {
+                    if !TEXT[index].is_ascii_whitespace() {
+                        bytes[len] = TEXT[index];
+                        len += 1;
+                    }
+                    index += 1;
+                }
+                bytes
+            };
+
             const BYTE_STRING: &'static [u8] = const {
-                let s: &'static str = $s;
-                let bytes = s.as_bytes();
+                let bytes: &'static [u8] = &TEXT_WITHOUT_WHITESPACE;
 
                 core::assert!(!bytes.is_empty(), "OID string literals cannot be empty");
                 core::assert!(bytes.is_ascii(), "OID string literals must be ASCII");
@@ -236,15 +282,23 @@ macro_rules! oid {
                 component_count
             };
 
-            const COMPONENTS: [u32; COMPONENT_LEN] = const {
+            const COMPONENTS: [u128; COMPONENT_LEN] = const {
                 let mut bytes_index = 0;
 
-                let mut components = [0u32; COMPONENT_LEN];
+                let mut components = [0u128; COMPONENT_LEN];
                 let mut index = 0;
                 while bytes_index < BYTE_STRING.len() {
                     let byte = BYTE_STRING[bytes_index];
                     match byte {
-                        b'0'..=b'9' => components[index] = components[index] * 10 + ((byte - b'0') as u32),
+                        b'0'..=b'9' => {
+                            components[index] = match components[index].checked_mul(10) {
+                                Some(arc) => match arc.checked_add((byte - b'0') as u128) {
+                                    Some(arc) => arc,
+                                    None => core::panic!("OID arcs must fit in a u128"),
+                                },
+                                None => core::panic!("OID arcs must fit in a u128"),
+                            }
+                        }
                         b'.' => index += 1,
                         _ => core::unreachable!(),
                     }
@@ -252,7 +306,12 @@ macro_rules! oid {
                     bytes_index += 1;
                 }
 
+                core::assert!(COMPONENT_LEN >= 2, "OIDs must have at least two arcs");
                 core::assert!(components[0] <= 2, "the first OID arc must be <= 2");
+                core::assert!(
+                    components[0] == 2 || components[1] < 40,
+                    "the second OID arc must be < 40 when the first is 0 or 1"
+                );
 
                 components
             };
@@ -263,3 +322,104 @@ macro_rules! oid {
         OID
     }}
 }
+
+/// Defines an enum of the values that can be identified by an `OBJECT
+/// IDENTIFIER`, implementing [`DefinedBy`][crate::types::DefinedBy] for it.
+///
+/// Each variant is given the `&'static Oid` that identifies it. An `Unknown`
+/// variant holding an [`Any`][crate::types::Any] is added for any other
+/// `OBJECT IDENTIFIER`, so values that can't be interpreted are kept as they
+/// were encoded. A field of a derived `SEQUENCE` with this type can then use
+/// `#[rasn(defined_by = "field")]` to be decoded using the value of another
+/// field that precedes it.
+///
+/// Usage:
+/// ```rust
+/// use rasn::prelude::*;
+///
+/// rasn::defined_by! {
+///     #[derive(Clone, Debug, PartialEq)]
+///     pub enum AttributeValue {
+///         CommonName(Utf8String) = rasn::oid!("2.5.4.3"),
+///         SerialNumber(PrintableString) = rasn::oid!("2.5.4.5"),
+///     }
+/// }
+///
+/// #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+/// pub struct Attribute {
+///     pub id: ObjectIdentifier,
+///     #[rasn(defined_by = "id")]
+///     pub value: AttributeValue,
+/// }
+///
+/// let encoded = [0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x02, b'h', b'i'];
+/// let attribute: Attribute = rasn::der::decode(&encoded).unwrap();
+///
+/// assert_eq!(AttributeValue::CommonName("hi".into()), attribute.value);
+/// assert_eq!(Some(rasn::oid!("2.5.4.3")), attribute.value.oid());
+/// assert_eq!(&encoded[..], rasn::der::encode(&attribute).unwrap());
+/// ```
+#[macro_export]
+macro_rules! defined_by {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident($ty:ty) = $oid:expr
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant($ty),
+            )+
+            /// A value identified by an `OBJECT IDENTIFIER` that isn't known,
+            /// as it was encoded.
+            Unknown($crate::types::Any),
+        }
+
+        impl $name {
+            /// The `OBJECT IDENTIFIER` that identifies this value, or `None` if
+            /// the value is [`Self::Unknown`].
+            #[allow(dead_code)]
+            pub fn oid(&self) -> Option<&'static $crate::types::Oid> {
+                match self {
+                    $(Self::$variant(_) => Some($oid),)+
+                    Self::Unknown(_) => None,
+                }
+            }
+        }
+
+        impl $crate::AsnType for $name {
+            const TAG: $crate::types::Tag = <$crate::types::Any as $crate::AsnType>::TAG;
+            const TAG_TREE: $crate::types::TagTree =
+                <$crate::types::Any as $crate::AsnType>::TAG_TREE;
+        }
+
+        impl $crate::types::DefinedBy for $name {
+            fn decode_by_oid(
+                oid: &$crate::types::Oid,
+                any: &$crate::types::Any,
+            ) -> Result<Self, $crate::error::DecodeError> {
+                $(
+                    let expected: &$crate::types::Oid = $oid;
+                    if oid == expected {
+                        return any.decode_as().map(Self::$variant);
+                    }
+                )+
+
+                Ok(Self::Unknown(any.clone()))
+            }
+
+            fn encode_as_any(&self) -> Result<$crate::types::Any, $crate::error::EncodeError> {
+                match self {
+                    $(Self::$variant(value) => $crate::types::Any::from_value(value),)+
+                    Self::Unknown(any) => Ok(any.clone()),
+                }
+            }
+        }
+    };
+}
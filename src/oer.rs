@@ -262,4 +262,25 @@ mod tests {
         };
         round_trip!(oer, SequenceOptionals, test_seq, &[0x00, 0x01, 0x2A]);
     }
+
+    #[test]
+    fn test_sequence_of_with() {
+        use core::ops::ControlFlow;
+
+        // Elements don't have their own length, so the rest are decoded to
+        // skip over them.
+        let mut encoded = crate::oer::encode(&vec![1u8, 2, 3]).unwrap();
+        encoded.push(0xFF);
+        let mut decoder =
+            super::de::Decoder::<0, 0>::new(&encoded, super::de::DecoderOptions::oer());
+        let mut first = None;
+        decoder
+            .decode_sequence_of_with(Tag::SEQUENCE, Constraints::default(), |value: u8| {
+                first = Some(value);
+                Ok(ControlFlow::Break(()))
+            })
+            .unwrap();
+        assert_eq!(Some(1), first);
+        assert_eq!(&[0xFF], decoder.remaining());
+    }
 }
@@ -12,6 +12,7 @@ use alloc::{
 };
 
 use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
 use nom::Needed;
 
 use crate::{
@@ -471,6 +472,46 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
 
         Ok((result, extensible_present))
     }
+
+    /// Parses the quantity field that precedes the elements of a `SEQUENCE OF`
+    /// or `SET OF`.
+    fn parse_quantity(&mut self) -> Result<usize, DecodeError> {
+        let length_of_quantity = self.decode_length()?;
+        let coer = self.options.encoding_rules.is_coer();
+        let length_bytes = self.extract_data_by_length(length_of_quantity)?;
+        if coer && length_bytes.first() == Some(&0) && length_bytes.len() > 1 {
+            return Err(CoerDecodeErrorKind::NotValidCanonicalEncoding {
+                msg: "Quantity value in 'sequence/set of' should not have leading zeroes in COER"
+                    .to_string(),
+            }
+            .into());
+        }
+        usize::try_from_unsigned_bytes(length_bytes, self.codec())
+    }
+
+    /// Decodes `quantity` elements, passing each one to `f` until it breaks.
+    fn parse_sequence_of_elements<D, F>(
+        &mut self,
+        quantity: usize,
+        mut f: F,
+    ) -> Result<(), DecodeError>
+    where
+        D: Decode,
+        F: FnMut(D) -> Result<ControlFlow<()>, DecodeError>,
+    {
+        let mut decoder = Self::new(self.input, self.options);
+        let mut stopped = false;
+        // Elements don't have their own length, so the remaining ones are
+        // still decoded to skip over them once `f` has stopped.
+        for _ in 0..quantity {
+            let value = D::decode(&mut decoder)?;
+            self.input = decoder.input;
+            if !stopped {
+                stopped = (f)(value)?.is_break();
+            }
+        }
+        Ok(())
+    }
 }
 impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'input, RFC, EFC> {
     type Ok = ();
@@ -522,7 +563,11 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
             let discriminant: isize = self
                 .decode_integer_from_bytes(true, Some(length.into()))
                 .map_err(|e| {
-                    if matches!(&*e.kind, DecodeErrorKind::IntegerOverflow { .. }) {
+                    if matches!(
+                        &*e.kind,
+                        DecodeErrorKind::IntegerOverflow { .. }
+                            | DecodeErrorKind::IntegerOutOfRange { .. }
+                    ) {
                         DecodeError::length_exceeds_platform_width(
                             "Enumerated discriminant value too large for this platform."
                                 .to_string(),
@@ -571,7 +616,13 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
 
     fn decode_object_identifier(&mut self, _: Tag) -> Result<ObjectIdentifier, Self::Error> {
         let length = self.decode_length()?;
-        let ber_decoder = crate::ber::de::Decoder::new(&[], crate::ber::de::DecoderOptions::ber());
+        // COER, like DER, doesn't allow redundant leading zero septets.
+        let options = if self.options.encoding_rules.is_coer() {
+            crate::ber::de::DecoderOptions::der()
+        } else {
+            crate::ber::de::DecoderOptions::ber()
+        };
+        let ber_decoder = crate::ber::de::Decoder::new(&[], options);
         ber_decoder.decode_object_identifier_from_bytes(self.extract_data_by_length(length)?)
     }
 
@@ -634,27 +685,32 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         _: Tag,
         _: Constraints,
     ) -> Result<Vec<D>, Self::Error> {
-        let length_of_quantity = self.decode_length()?;
-        let coer = self.options.encoding_rules.is_coer();
-        let length_bytes = self.extract_data_by_length(length_of_quantity)?;
-        if coer && length_bytes.first() == Some(&0) && length_bytes.len() > 1 {
-            return Err(CoerDecodeErrorKind::NotValidCanonicalEncoding {
-                msg: "Quantity value in 'sequence/set of' should not have leading zeroes in COER"
-                    .to_string(),
-            }
-            .into());
-        }
-        let length = usize::try_from_unsigned_bytes(length_bytes, self.codec())?;
-        let mut sequence_of: Vec<D> = Vec::with_capacity(length);
-        let mut decoder = Self::new(self.input, self.options);
-        for _ in 0..length {
-            let value = D::decode(&mut decoder)?;
-            self.input = decoder.input;
+        let quantity = self.parse_quantity()?;
+        // The quantity is untrusted, so only reserve as many elements as the
+        // remaining input could hold at one octet per element.
+        let mut sequence_of: Vec<D> = Vec::with_capacity(quantity.min(self.input.len()));
+        self.parse_sequence_of_elements(quantity, |value| {
             sequence_of.push(value);
-        }
+            Ok(ControlFlow::Continue(()))
+        })?;
+
         Ok(sequence_of)
     }
 
+    fn decode_sequence_of_with<D, F>(
+        &mut self,
+        _: Tag,
+        _: Constraints,
+        f: F,
+    ) -> Result<(), Self::Error>
+    where
+        D: Decode,
+        F: FnMut(D) -> Result<ControlFlow<()>, Self::Error>,
+    {
+        let quantity = self.parse_quantity()?;
+        self.parse_sequence_of_elements(quantity, f)
+    }
+
     fn decode_set_of<D: Decode + Eq + core::hash::Hash>(
         &mut self,
         tag: Tag,
@@ -809,6 +865,7 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         crate::der::de::Decoder::parse_canonical_generalized_time_string(string)
     }
 
+    #[cfg(feature = "chrono")]
     fn decode_date(&mut self, tag: Tag) -> Result<types::Date, Self::Error> {
         let string = String::from_utf8(self.decode_octet_string(tag, Constraints::default())?)
             .map_err(|_| {
@@ -1038,6 +1095,15 @@ mod tests {
         assert!(decoded);
     }
 
+    #[test]
+    fn test_decode_absurd_quantity() {
+        // A `SEQUENCE OF` claiming 2^31 - 1 elements, with only one present.
+        let data = &[0x04, 0x7F, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01];
+        assert!(crate::oer::decode::<Vec<u32>>(data).is_err());
+        assert!(crate::oer::decode::<SetOf<u32>>(data).is_err());
+        assert!(crate::coer::decode::<Vec<u32>>(data).is_err());
+    }
+
     #[test]
     fn test_decode_length_invalid() {
         let data = &[0xffu8];
@@ -7,7 +7,7 @@ use num_traits::ToPrimitive;
 use crate::{
     oer::EncodingRules,
     types::{
-        Any, BitStr, BmpString, Choice, Constraints, Constructed, Date, Enumerated, GeneralString,
+        Any, BitStr, BmpString, Choice, Constraints, Constructed, Enumerated, GeneralString,
         GeneralizedTime, GraphicString, Ia5String, Identifier, IntegerType, NumericString,
         PrintableString, RealType, SetOf, Tag, TeletexString, UtcTime, VisibleString,
     },
@@ -751,7 +751,7 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
     fn encode_object_identifier(
         &mut self,
         tag: Tag,
-        value: &[u32],
+        value: &[u128],
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::ber());
@@ -926,15 +926,16 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         self.encode_octet_string(
             tag,
             Constraints::default(),
-            &crate::der::enc::Encoder::datetime_to_canonical_utc_time_bytes(value),
+            &crate::der::enc::Encoder::checked_utc_time_bytes(value, self.codec())?,
             Identifier::EMPTY,
         )
     }
 
+    #[cfg(feature = "chrono")]
     fn encode_date(
         &mut self,
         tag: Tag,
-        value: &Date,
+        value: &crate::types::Date,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         self.encode_octet_string(
@@ -2,6 +2,7 @@
 
 use alloc::{borrow::Cow, collections::VecDeque, string::ToString, vec::Vec};
 use bitvec::field::BitField;
+use core::ops::ControlFlow;
 
 use super::{
     FOURTY_EIGHT_K, LARGE_UNSIGNED_CONSTRAINT, SIXTEEN_K, SIXTY_FOUR_K, SMALL_UNSIGNED_CONSTRAINT,
@@ -845,6 +846,7 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         crate::ber::decode(&bytes)
     }
 
+    #[cfg(feature = "chrono")]
     fn decode_date(&mut self, tag: Tag) -> core::result::Result<types::Date, Self::Error> {
         let bytes = self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?;
 
@@ -853,27 +855,44 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
 
     fn decode_sequence_of<D: Decode>(
         &mut self,
-        _: Tag,
+        tag: Tag,
         constraints: Constraints,
     ) -> Result<Vec<D>, Self::Error> {
         let mut sequence_of = Vec::new();
+        self.decode_sequence_of_with(tag, constraints, |value| {
+            sequence_of.push(value);
+            Ok(ControlFlow::Continue(()))
+        })?;
+
+        Ok(sequence_of)
+    }
+
+    fn decode_sequence_of_with<D, F>(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+        mut f: F,
+    ) -> Result<(), Self::Error>
+    where
+        D: Decode,
+        F: FnMut(D) -> Result<ControlFlow<()>, Self::Error>,
+    {
         let options = self.options;
+        let mut stopped = false;
+        // Elements don't have their own length, so the remaining ones are
+        // still decoded to skip over them once `f` has stopped.
         self.decode_extensible_container(constraints, |mut input, length| {
-            sequence_of.append(
-                &mut (0..length)
-                    .map(|_| {
-                        let mut decoder = Self::new(input.0, options);
-                        let value = D::decode(&mut decoder)?;
-                        input = decoder.input;
-                        Ok(value)
-                    })
-                    .collect::<Result<Vec<_>>>()?,
-            );
+            for _ in 0..length {
+                let mut decoder = Self::new(input.0, options);
+                let value = D::decode(&mut decoder)?;
+                input = decoder.input;
+                if !stopped {
+                    stopped = (f)(value)?.is_break();
+                }
+            }
 
             Ok(input)
-        })?;
-
-        Ok(sequence_of)
+        })
     }
 
     fn decode_set_of<D: Decode + Eq + core::hash::Hash>(
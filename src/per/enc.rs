@@ -934,7 +934,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
     fn encode_object_identifier(
         &mut self,
         tag: Tag,
-        oid: &[u32],
+        oid: &[u128],
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         let mut encoder = crate::der::enc::Encoder::new(crate::der::enc::EncoderOptions::der());
@@ -1078,6 +1078,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         )
     }
 
+    #[cfg(feature = "chrono")]
     fn encode_date(
         &mut self,
         tag: Tag,
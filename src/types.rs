@@ -3,21 +3,42 @@
 //! are defined to represent various ASN.1 data types, and renamed to use
 //! ASN.1's terminology.
 
-mod any;
+mod character_string;
+mod components;
+mod defined_by;
+mod embedded_pdv;
+mod external;
+#[cfg(feature = "heapless")]
+mod heapless;
 mod identifier;
 mod instance;
+mod iri;
+mod map;
+mod net;
 mod open;
 mod prefix;
+mod raw_time;
+#[cfg(feature = "serde")]
+mod serde;
+mod size;
+mod syntax_identification;
 mod tag;
+mod time;
+#[cfg(feature = "uuid")]
+mod uuid;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 pub mod constraints;
 pub mod fields;
+pub mod oid;
 pub mod variants;
 
+pub(crate) mod any;
 pub(crate) mod constructed;
+#[cfg(feature = "chrono")]
 pub(crate) mod date;
 pub(crate) mod integer;
-pub(crate) mod oid;
 
 pub(crate) mod real;
 
@@ -29,36 +50,81 @@ use alloc::boxed::Box;
 pub use {
     self::{
         any::Any,
+        character_string::CharacterString,
+        components::{Components, DecodeComponents, EncodeComponents},
         constraints::{Constraint, Constraints, Extensible, InnerSubtypeConstraint},
-        constructed::{Constructed, SequenceOf, SetOf},
+        constructed::{Constructed, SequenceOf, SequenceOfIter, SetOf, SetVec},
+        defined_by::DefinedBy,
+        embedded_pdv::EmbeddedPdv,
+        external::{External, ExternalEncoding, ObjectDescriptor},
         identifier::Identifier,
         instance::InstanceOf,
-        integer::{ConstrainedInteger, Integer, IntegerType},
-        oid::{ObjectIdentifier, Oid},
-        open::Open,
+        integer::{ConstrainedInteger, Integer, IntegerType, Unsigned},
+        iri::{InvalidIri, OidIri, RelativeOidIri},
+        oid::{ObjectIdentifier, Oid, ParseOidError},
+        open::{NotUnknownError, Open, TryFromOpenError},
         prefix::{Explicit, Implicit},
+        size::{Length, SizeConstrained, SizeConstraintError},
         strings::{
             BitStr, BitString, BmpString, FixedBitString, FixedOctetString, GeneralString,
-            GraphicString, Ia5String, NumericString, OctetString, PrintableString, TeletexString,
-            Utf8String, VisibleString,
+            GraphicString, Ia5String, NumericString, OctetSlice, OctetString, OctetVec,
+            PrintableString, TeletexString, Utf8String, VisibleString,
         },
+        syntax_identification::{ContextNegotiation, SyntaxIdentification, Syntaxes},
         tag::{Class, Tag, TagTree},
     },
     rasn_derive::AsnType,
 };
 
+pub use self::raw_time::{RawGeneralizedTime, RawUtcTime};
 pub use self::real::RealType;
+#[cfg(not(feature = "chrono"))]
+pub use self::time::UtcDateTime;
+pub use self::time::{DateTime, InvalidDateTime};
 
 ///  The `UniversalString` type.
 pub type UniversalString = Implicit<tag::UNIVERSAL_STRING, Utf8String>;
 ///  The `UTCTime` type.
+#[cfg(feature = "chrono")]
 pub type UtcTime = chrono::DateTime<chrono::Utc>;
+///  The `UTCTime` type.
+#[cfg(not(feature = "chrono"))]
+pub type UtcTime = UtcDateTime;
 ///  The `GeneralizedTime` type.
+#[cfg(feature = "chrono")]
 pub type GeneralizedTime = chrono::DateTime<chrono::FixedOffset>;
+///  The `GeneralizedTime` type.
+#[cfg(not(feature = "chrono"))]
+pub type GeneralizedTime = DateTime;
 /// The `Date` type.
+#[cfg(feature = "chrono")]
 pub type Date = chrono::NaiveDate;
 
 /// A trait representing any type that can represented in ASN.1.
+///
+/// When derived for a `CHOICE`, every variant must have a distinct tag, as
+/// otherwise the variant to decode couldn't be determined from the input. So
+/// a `CHOICE` with two `Utf8String` variants is rejected at compile time.
+///
+/// The same applies to a derived `SEQUENCE`: each run of `OPTIONAL` or
+/// `DEFAULT` fields, together with the field that follows it, must have
+/// distinct tags. So an `Option<Utf8String>` field directly followed by a
+/// `Utf8String` field is rejected, as are two `OPTIONAL` fields of `u8` and
+/// `Integer`, which are both `INTEGER`s.
+///
+/// A mandatory field ends the run, so optional fields on either side of it
+/// may share a tag.
+///
+/// ```
+/// use rasn::prelude::*;
+///
+/// #[derive(AsnType)]
+/// struct Record {
+///     nickname: Option<Utf8String>,
+///     id: Integer,
+///     alias: Option<Utf8String>,
+/// }
+/// ```
 pub trait AsnType {
     /// The associated tag for the type.
     ///
@@ -80,6 +146,10 @@ pub trait AsnType {
     /// Whether the type is choice type. PER/OER encoding rules require this knowledge.
     const IS_CHOICE: bool = false;
 
+    /// Whether the type is an `OPTIONAL` value, used to determine the
+    /// presence of fields in generic `SEQUENCE` implementations.
+    const IS_OPTIONAL: bool = false;
+
     /// Whether the type is present with value. `OPTIONAL` fields are common in `SEQUENCE` or `SET`.
     ///
     /// Custom implementation is only used for `OPTIONAL` type.
@@ -253,6 +323,8 @@ asn_type! {
     Utf8String: UTF8_STRING,
     UtcTime: UTC_TIME,
     GeneralizedTime: GENERALIZED_TIME,
+    RawUtcTime: UTC_TIME,
+    RawGeneralizedTime: GENERALIZED_TIME,
     (): NULL,
     &'_ str: UTF8_STRING
 
@@ -285,6 +357,41 @@ asn_integer_type! {
     u128, // TODO upper constraint truncated
     usize,
 }
+
+macro_rules! asn_non_zero_integer_type {
+    ($($int:ty => $min:expr),+ $(,)?) => {
+        $(
+            impl AsnType for $int {
+                const TAG: Tag = Tag::INTEGER;
+                const IDENTIFIER: Identifier = Identifier::INTEGER;
+                #[allow(clippy::cast_possible_wrap)]
+                const CONSTRAINTS: Constraints = constraints!(value_constraint!($min, (<$int>::MAX.get() as i128)));
+            }
+        )+
+    }
+}
+
+asn_non_zero_integer_type! {
+    core::num::NonZeroI8 => (i8::MIN as i128),
+    core::num::NonZeroI16 => (i16::MIN as i128),
+    core::num::NonZeroI32 => (i32::MIN as i128),
+    core::num::NonZeroI64 => (i64::MIN as i128),
+    core::num::NonZeroI128 => i128::MIN,
+    core::num::NonZeroIsize => (isize::MIN as i128),
+    core::num::NonZeroU8 => 1,
+    core::num::NonZeroU16 => 1,
+    core::num::NonZeroU32 => 1,
+    core::num::NonZeroU64 => 1,
+    core::num::NonZeroUsize => 1,
+}
+
+impl AsnType for core::num::NonZeroU128 {
+    const TAG: Tag = Tag::INTEGER;
+    const IDENTIFIER: Identifier = Identifier::INTEGER;
+    // The upper bound doesn't fit in the `i128` bounds of a constraint.
+    const CONSTRAINTS: Constraints = constraints!(value_constraint!(start: 1));
+}
+
 impl AsnType for num_bigint::BigInt {
     const TAG: Tag = Tag::INTEGER;
     const IDENTIFIER: Identifier = Identifier::INTEGER;
@@ -295,20 +402,34 @@ impl AsnType for str {
     const IDENTIFIER: Identifier = Identifier::UTF8_STRING;
 }
 
-impl<T: AsnType> AsnType for &'_ T {
-    const TAG: Tag = T::TAG;
-    const TAG_TREE: TagTree = T::TAG_TREE;
-    const IDENTIFIER: Identifier = T::IDENTIFIER;
-
-    fn is_present(&self) -> bool {
-        (*self).is_present()
+macro_rules! asn_pointer_type {
+    ($($(#[$meta:meta])* $pointer:ty $(where T: $bound:path)?),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl<T: AsnType> AsnType for $pointer $(where T: $bound)? {
+                const TAG: Tag = T::TAG;
+                const TAG_TREE: TagTree = T::TAG_TREE;
+                const CONSTRAINTS: Constraints = T::CONSTRAINTS;
+                const IDENTIFIER: Identifier = T::IDENTIFIER;
+                const IS_CHOICE: bool = T::IS_CHOICE;
+                const IS_OPTIONAL: bool = T::IS_OPTIONAL;
+
+                fn is_present(&self) -> bool {
+                    (**self).is_present()
+                }
+            }
+        )+
     }
 }
 
-impl<T: AsnType> AsnType for Box<T> {
-    const TAG: Tag = T::TAG;
-    const TAG_TREE: TagTree = T::TAG_TREE;
-    const IDENTIFIER: Identifier = T::IDENTIFIER;
+asn_pointer_type! {
+    &'_ T,
+    &'_ mut T,
+    alloc::borrow::Cow<'_, T> where T: Clone,
+    Box<T>,
+    alloc::rc::Rc<T>,
+    #[cfg(target_has_atomic = "ptr")]
+    alloc::sync::Arc<T>,
 }
 
 impl<T: AsnType> AsnType for alloc::vec::Vec<T> {
@@ -320,17 +441,75 @@ impl<T: AsnType> AsnType for Option<T> {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
     const IDENTIFIER: Identifier = T::IDENTIFIER;
+    const IS_OPTIONAL: bool = true;
 
     fn is_present(&self) -> bool {
         self.is_some()
     }
 }
 
+// Tuples are a `SEQUENCE` of their elements in order.
+macro_rules! asn_tuple_type {
+    ($($len:literal => ($($index:tt $ty:ident),+);)+) => {
+        $(
+            impl<$($ty: AsnType),+> AsnType for ($($ty,)+) {
+                const TAG: Tag = Tag::SEQUENCE;
+                const IDENTIFIER: Identifier = Identifier::SEQUENCE;
+            }
+
+            impl<$($ty: AsnType),+> Constructed<$len> for ($($ty,)+) {
+                const FIELDS: fields::Fields<$len> = fields::Fields::from_static([
+                    $(
+                        fields::Field {
+                            index: $index,
+                            tag: $ty::TAG,
+                            tag_tree: $ty::TAG_TREE,
+                            presence: if $ty::IS_OPTIONAL {
+                                fields::FieldPresence::Optional
+                            } else {
+                                fields::FieldPresence::Required
+                            },
+                            name: stringify!($index),
+                        },
+                    )+
+                ]);
+            }
+        )+
+    }
+}
+
+asn_tuple_type! {
+    1 => (0 A);
+    2 => (0 A, 1 B);
+    3 => (0 A, 1 B, 2 C);
+    4 => (0 A, 1 B, 2 C, 3 D);
+    5 => (0 A, 1 B, 2 C, 3 D, 4 E);
+    6 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+    7 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+    8 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+    9 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+    10 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+    11 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+    12 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+}
+
 impl<T> AsnType for SetOf<T> {
     const TAG: Tag = Tag::SET;
     const IDENTIFIER: Identifier = Identifier::SET_OF;
 }
 
+impl<I> AsnType for SequenceOfIter<I> {
+    const TAG: Tag = Tag::SEQUENCE;
+    const IDENTIFIER: Identifier = Identifier::SEQUENCE_OF;
+}
+
+impl<T> AsnType for SetVec<T> {
+    const TAG: Tag = Tag::SET;
+    const IDENTIFIER: Identifier = Identifier::SET_OF;
+}
+
+/// Arrays are a `SEQUENCE OF` with exactly `N` elements, including `[u8; N]`.
+/// Use [`FixedOctetString`] for an `OCTET STRING` of exactly `N` bytes.
 impl<T: AsnType, const N: usize> AsnType for [T; N] {
     const TAG: Tag = Tag::SEQUENCE;
     const CONSTRAINTS: Constraints = constraints!(size_constraint!(N));
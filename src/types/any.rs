@@ -1,8 +1,27 @@
 use alloc::vec::Vec;
+use core::fmt;
+
+use super::{Class, Tag};
+use crate::ber::{
+    de::{parser, DecoderOptions},
+    Identifier,
+};
 
 /// Represents a complete encoded ASN.1 value of any type. Usually identified
 /// with an [`ObjectIdentifier`][crate::types::ObjectIdentifier].
-#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+///
+/// Equality, ordering, and hashing compare the encoded octets, so values
+/// that are equal but encoded differently, e.g. with BER and DER, differ.
+///
+/// `Debug` and `Display` show the tag, length, and the first octets of the
+/// contents in hexadecimal on a single line.
+/// ```
+/// use rasn::types::Any;
+///
+/// let any = Any::new(vec![0x30, 0x03, 0x02, 0x01, 0x05]);
+/// assert_eq!(any.to_string(), "[UNIVERSAL 16] (constructed) len 3: 02 01 05");
+/// ```
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct Any {
     pub(crate) contents: Vec<u8>,
 }
@@ -14,6 +33,86 @@ impl Any {
         Self { contents }
     }
 
+    /// Creates a new wrapper around a complete BER encoded value, checking
+    /// that `encoded` holds exactly one well-formed value.
+    ///
+    /// ```
+    /// use rasn::types::{Any, Tag};
+    ///
+    /// let any = Any::from_encoded(vec![0x04, 0x02, 0xCA, 0xFE]).unwrap();
+    /// assert_eq!(any.tag(), Some(Tag::OCTET_STRING));
+    /// assert_eq!(any.contents(), Some(&[0xCA, 0xFE][..]));
+    /// assert!(Any::from_encoded(vec![0x04, 0x02, 0xCA]).is_err());
+    /// ```
+    pub fn from_encoded(encoded: Vec<u8>) -> Result<Self, crate::error::DecodeError> {
+        crate::ber::decode::<Self>(&encoded)?;
+        Ok(Self::new(encoded))
+    }
+
+    /// Creates a new wrapper around the DER encoding of `value`.
+    ///
+    /// ```
+    /// use rasn::types::Any;
+    ///
+    /// let null = Any::from_value(&()).unwrap();
+    /// assert_eq!(null.as_bytes(), &[0x05, 0x00]);
+    /// ```
+    pub fn from_value<T: crate::Encode>(value: &T) -> Result<Self, crate::error::EncodeError> {
+        crate::der::encode(value).map(Self::new)
+    }
+
+    /// Interprets the opaque value as a BER encoded `T`, e.g. once the
+    /// accompanying [`ObjectIdentifier`][crate::types::ObjectIdentifier] has
+    /// determined what type the value holds.
+    ///
+    /// ```
+    /// use rasn::types::Any;
+    ///
+    /// let any = Any::new(vec![0x02, 0x01, 0x2A]);
+    /// assert_eq!(any.decode_as::<u8>().unwrap(), 42);
+    /// ```
+    pub fn decode_as<T: crate::Decode>(&self) -> Result<T, crate::error::DecodeError> {
+        crate::ber::decode(&self.contents)
+    }
+
+    /// The tag of the encoded value, or `None` if the value isn't a BER
+    /// encoding, such as an open type decoded with PER or OER.
+    #[must_use]
+    pub fn tag(&self) -> Option<Tag> {
+        self.parse().map(|(identifier, _)| identifier.tag)
+    }
+
+    /// Whether the encoded value uses the constructed form, or `None` if the
+    /// value isn't a BER encoding.
+    #[must_use]
+    pub fn is_constructed(&self) -> Option<bool> {
+        self.parse()
+            .map(|(identifier, _)| identifier.is_constructed())
+    }
+
+    /// The contents octets of the encoded value, without its identifier and
+    /// length octets or the end-of-contents of an indefinite length, or `None`
+    /// if the value isn't a BER encoding.
+    #[must_use]
+    pub fn contents(&self) -> Option<&[u8]> {
+        self.parse().map(|(_, contents)| contents)
+    }
+
+    /// Splits the encoding into its identifier and contents octets.
+    fn parse(&self) -> Option<(Identifier, &[u8])> {
+        let options = DecoderOptions::ber();
+        let (input, identifier) = parser::parse_identifier_octet(options, &self.contents).ok()?;
+        let (input, contents) = parser::parse_contents(options, identifier, input).ok()?;
+
+        match contents {
+            Some(contents) if input.is_empty() => Some((identifier, contents)),
+            Some(_) => None,
+            None => input
+                .strip_suffix(&[0, 0])
+                .map(|contents| (identifier, contents)),
+        }
+    }
+
     /// Provides the raw representation of the value as bytes.
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
@@ -38,3 +137,203 @@ impl From<Vec<u8>> for Any {
         Any::new(value)
     }
 }
+
+impl fmt::Display for Any {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let contents = match self.parse() {
+            Some((identifier, contents)) => {
+                let tag = identifier.tag;
+                match tag.class {
+                    Class::Universal => write!(f, "[UNIVERSAL {}] ", tag.value)?,
+                    Class::Application => write!(f, "[APPLICATION {}] ", tag.value)?,
+                    Class::Context => write!(f, "[{}] ", tag.value)?,
+                    Class::Private => write!(f, "[PRIVATE {}] ", tag.value)?,
+                }
+                if identifier.is_constructed() {
+                    f.write_str("(constructed) ")?;
+                }
+                contents
+            }
+            // Not a BER encoding, e.g. an open type decoded with PER or OER.
+            None => &self.contents,
+        };
+
+        write!(f, "len {}", contents.len())?;
+        write_hex(f, contents)
+    }
+}
+
+impl fmt::Debug for Any {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Any({self})")
+    }
+}
+
+/// The most octets shown in hexadecimal by [`write_octets`].
+const MAX_HEX_PREVIEW: usize = 16;
+
+/// Writes `: ` followed by the first octets of `bytes` in hexadecimal, and an
+/// ellipsis if there are more, or nothing if `bytes` is empty.
+pub(crate) fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    if bytes.is_empty() {
+        return Ok(());
+    }
+
+    f.write_str(": ")?;
+    write_octets(f, bytes)
+}
+
+/// Writes the first octets of `bytes` in hexadecimal separated by spaces, and
+/// an ellipsis if there are more.
+pub(crate) fn write_octets<W: fmt::Write + ?Sized>(output: &mut W, bytes: &[u8]) -> fmt::Result {
+    let mut separator = "";
+    for byte in bytes.iter().take(MAX_HEX_PREVIEW) {
+        write!(output, "{separator}{byte:02x}")?;
+        separator = " ";
+    }
+
+    if bytes.len() > MAX_HEX_PREVIEW {
+        output.write_str(" ...")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as rasn;
+    use rasn::prelude::*;
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    struct AlgorithmIdentifier {
+        algorithm: ObjectIdentifier,
+        parameters: Option<Any>,
+    }
+
+    #[test]
+    fn null_parameters() {
+        let value = AlgorithmIdentifier {
+            algorithm: Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA.into(),
+            parameters: Some(Any::from_value(&()).unwrap()),
+        };
+        let encoded = &[
+            0x30, 0x0D, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01, 0x05,
+            0x00,
+        ][..];
+
+        assert_eq!(encoded, &*rasn::der::encode(&value).unwrap());
+        let decoded = rasn::ber::decode::<AlgorithmIdentifier>(encoded).unwrap();
+        assert_eq!(value, decoded);
+        decoded.parameters.unwrap().decode_as::<()>().unwrap();
+    }
+
+    #[test]
+    fn absent_parameters() {
+        let value = AlgorithmIdentifier {
+            algorithm: Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA.into(),
+            parameters: None,
+        };
+        let encoded = &[
+            0x30, 0x0B, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01,
+        ][..];
+
+        assert_eq!(encoded, &*rasn::der::encode(&value).unwrap());
+        assert_eq!(
+            value,
+            rasn::ber::decode::<AlgorithmIdentifier>(encoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn decode_as_mismatch() {
+        let any = Any::from_value(&true).unwrap();
+        assert!(any.decode_as::<Integer>().is_err());
+        assert!(any.decode_as::<bool>().unwrap());
+    }
+
+    #[test]
+    fn accessors() {
+        let any = Any::from_value(&true).unwrap();
+        assert_eq!(any.tag(), Some(Tag::BOOL));
+        assert_eq!(any.is_constructed(), Some(false));
+        assert_eq!(any.contents(), Some(&[0xFF][..]));
+        assert_eq!(any.as_bytes(), &[0x01, 0x01, 0xFF]);
+
+        let any = Any::from_encoded(vec![0x30, 0x80, 0x05, 0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(any.tag(), Some(Tag::SEQUENCE));
+        assert_eq!(any.is_constructed(), Some(true));
+        assert_eq!(any.contents(), Some(&[0x05, 0x00][..]));
+
+        let any = Any::new(vec![0x30, 0x80]);
+        assert_eq!(any.tag(), None);
+        assert_eq!(any.contents(), None);
+    }
+
+    #[test]
+    fn map_keys() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let map = BTreeMap::from([
+            (Any::from_value(&true).unwrap(), "true"),
+            (Any::from_value(&5).unwrap(), "five"),
+        ]);
+        let set: HashSet<_> = map.keys().cloned().collect();
+
+        let key = Any::new(vec![0x02, 0x01, 0x05]);
+        assert_eq!(Some(&"five"), map.get(&key));
+        assert!(set.contains(&key));
+
+        // The same value in a non-minimal BER encoding is a different key.
+        let key = Any::new(vec![0x02, 0x81, 0x01, 0x05]);
+        assert_eq!(None, map.get(&key));
+        assert!(!set.contains(&key));
+    }
+
+    #[test]
+    fn formatting() {
+        let any = Any::from_value(&true).unwrap();
+        assert_eq!(any.to_string(), "[UNIVERSAL 1] len 1: ff");
+        assert_eq!(format!("{any:?}"), "Any([UNIVERSAL 1] len 1: ff)");
+
+        let any = Any::from_encoded(vec![0x30, 0x80, 0x05, 0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(any.to_string(), "[UNIVERSAL 16] (constructed) len 2: 05 00");
+
+        let any = Any::new(vec![0x45, 0x00]);
+        assert_eq!(any.to_string(), "[APPLICATION 5] len 0");
+
+        let mut encoded = vec![0x9F, 0x1F, 0x14];
+        encoded.extend(0..20);
+        assert_eq!(
+            Any::new(encoded).to_string(),
+            "[31] len 20: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f ..."
+        );
+
+        // An open type decoded with PER isn't a BER encoding.
+        assert_eq!(
+            Any::new(vec![0xCA, 0xFE, 0x80]).to_string(),
+            "len 3: ca fe 80"
+        );
+    }
+
+    #[test]
+    fn from_encoded() {
+        assert!(Any::from_encoded(vec![0x05, 0x00]).is_ok());
+        assert!(Any::from_encoded(vec![]).is_err());
+        assert!(Any::from_encoded(vec![0x05, 0x01]).is_err());
+        assert!(Any::from_encoded(vec![0x05, 0x00, 0x05, 0x00]).is_err());
+    }
+
+    #[test]
+    fn preserves_encoding() {
+        // A non-minimal length and an indefinite length are kept as is.
+        let encoded = &[
+            0x30, 0x12, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01, 0x30,
+            0x80, 0x05, 0x81, 0x00, 0x00, 0x00,
+        ][..];
+        let decoded = rasn::ber::decode::<AlgorithmIdentifier>(encoded).unwrap();
+        let parameters = decoded.parameters.as_ref().unwrap();
+        assert_eq!(parameters.as_bytes(), &encoded[13..]);
+        assert_eq!(parameters.contents(), Some(&[0x05, 0x81, 0x00][..]));
+        assert_eq!(encoded, &*rasn::ber::encode(&decoded).unwrap());
+    }
+}
@@ -0,0 +1,289 @@
+//! [`arbitrary`] support for rasn's own types, enabled with the `arbitrary`
+//! feature, for generating structurally valid values when fuzzing.
+//!
+//! Generated values always satisfy the invariants of their type, e.g. object
+//! identifiers have a valid first and second arc, and restricted strings only
+//! contain characters from their permitted alphabet, so every value can be
+//! encoded and decoded back to itself.
+//!
+//! `BitString`, `UtcTime`, and `GeneralizedTime` are aliases of `bitvec` and
+//! `chrono` types, or of built-in types without the `chrono` feature, so they
+//! don't implement `Arbitrary`. Use the functions in this module instead, e.g.
+//! with `#[arbitrary(with = ...)]` on a field.
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use rasn::types::{arbitrary::utc_time, ObjectIdentifier, PrintableString};
+//!
+//! let mut u = Unstructured::new(&[0x5A; 64]);
+//! let oid = ObjectIdentifier::arbitrary(&mut u).unwrap();
+//! let name = PrintableString::arbitrary(&mut u).unwrap();
+//! let time = utc_time(&mut u).unwrap();
+//!
+//! assert!(oid[0] <= 2);
+//! assert!(rasn::der::encode(&name).is_ok());
+//! assert!(rasn::der::encode(&time).is_ok());
+//! ```
+
+use alloc::{boxed::Box, vec::Vec};
+
+use arbitrary::{Arbitrary, Unstructured};
+use num_bigint::BigInt;
+
+use super::{
+    oid::{MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET},
+    strings::StaticPermittedAlphabet,
+    time::days_in_month,
+    Any, BitString, BmpString, Constraints, DateTime, GeneralString, GeneralizedTime,
+    GraphicString, Ia5String, Identifier, Implicit, Integer, NumericString, ObjectIdentifier,
+    OctetString, Open, PrintableString, Tag, UtcTime, VisibleString,
+};
+use crate::Encoder as _;
+
+/// The largest generated `INTEGER`, in bytes.
+const MAX_INTEGER_LEN: usize = 32;
+/// The largest number of arcs generated after an object identifier's root.
+const MAX_OID_ARCS: usize = 16;
+/// How deeply `SEQUENCE` and `SET` values are nested in an [`Open`] value.
+const MAX_OPEN_DEPTH: usize = 4;
+/// The largest number of components of a generated `SEQUENCE` or `SET`.
+const MAX_OPEN_COMPONENTS: usize = 4;
+
+/// Generates a `BIT STRING` of any length, including lengths which aren't a
+/// multiple of eight.
+pub fn bit_string(u: &mut Unstructured<'_>) -> arbitrary::Result<BitString> {
+    let mut bits = BitString::from_vec(Vec::arbitrary(u)?);
+    let unused = u.int_in_range(0..=7)?;
+    bits.truncate(bits.len().saturating_sub(unused));
+    Ok(bits)
+}
+
+/// Generates a `UTCTime` with whole seconds between 1950 and 2049, the years
+/// its two digit year can represent.
+pub fn utc_time(u: &mut Unstructured<'_>) -> arbitrary::Result<UtcTime> {
+    let value = date_time(u, 1950..=2049)?;
+    Ok(UtcTime::try_from(value).expect("fields are in range"))
+}
+
+/// Generates a `GeneralizedTime` between the years 1 and 9999 in UTC, with
+/// fractional seconds and any offset.
+pub fn generalized_time(u: &mut Unstructured<'_>) -> arbitrary::Result<GeneralizedTime> {
+    let value = DateTime {
+        nanos: u.int_in_range(0..=999_999_999)?,
+        ..date_time(u, 1..=9999)?
+    };
+    let offset_minutes = u.int_in_range(-(24 * 60 - 1)..=24 * 60 - 1)?;
+
+    Ok(GeneralizedTime::try_from(value.with_offset(offset_minutes)).expect("fields are in range"))
+}
+
+/// Generates whole seconds in UTC between the first and last of `years`.
+fn date_time(
+    u: &mut Unstructured<'_>,
+    years: core::ops::RangeInclusive<i32>,
+) -> arbitrary::Result<DateTime> {
+    let year = u.int_in_range(years)?;
+    let month = u.int_in_range(1..=12)?;
+
+    Ok(DateTime {
+        year,
+        month,
+        day: u.int_in_range(1..=days_in_month(year, month))?,
+        hour: u.int_in_range(0..=23)?,
+        minute: u.int_in_range(0..=59)?,
+        second: u.int_in_range(0..=59)?,
+        nanos: 0,
+        offset_minutes: 0,
+    })
+}
+
+/// Generates an `INTEGER` of at most 32 bytes.
+impl<'a> Arbitrary<'a> for Integer {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=MAX_INTEGER_LEN)?;
+        Ok(BigInt::from_signed_bytes_be(u.bytes(len)?).into())
+    }
+}
+
+impl<'a> Arbitrary<'a> for OctetString {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Vec::arbitrary(u).map(Self::from)
+    }
+}
+
+/// Generates a valid object identifier, where the second arc is less than 40
+/// when the first arc is 0 or 1.
+impl<'a> Arbitrary<'a> for ObjectIdentifier {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let first = u.int_in_range(0..=MAX_OID_FIRST_OCTET)?;
+        let second = if first < MAX_OID_FIRST_OCTET {
+            u.int_in_range(0..=MAX_OID_SECOND_OCTET)?
+        } else {
+            // The first two arcs are encoded as a single `u128` arc.
+            u.int_in_range(0..=u128::MAX - MAX_OID_FIRST_OCTET * (MAX_OID_SECOND_OCTET + 1))?
+        };
+
+        let mut arcs = alloc::vec![first, second];
+        for _ in 0..u.int_in_range(0..=MAX_OID_ARCS)? {
+            arcs.push(u128::arbitrary(u)?);
+        }
+
+        Ok(Self::new(arcs).expect("arcs are a valid object identifier"))
+    }
+}
+
+macro_rules! impl_arbitrary_restricted_string {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            /// Generates a string of characters from the permitted alphabet.
+            impl<'a> Arbitrary<'a> for $name {
+                fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+                    let mut string = Self::default();
+                    for _ in 0..u.arbitrary_len::<u32>()? {
+                        string.push_char(*u.choose(Self::CHARACTER_SET)?);
+                    }
+                    Ok(string)
+                }
+            }
+        )+
+    };
+}
+
+// `TeletexString` is left out until its T.61 character set is defined.
+impl_arbitrary_restricted_string!(
+    BmpString,
+    GeneralString,
+    GraphicString,
+    Ia5String,
+    NumericString,
+    PrintableString,
+    VisibleString,
+);
+
+/// Generates a universal type, a `SEQUENCE` or `SET` of open values, or an
+/// unknown context specific value.
+impl<'a> Arbitrary<'a> for Open {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        open(u, 0)
+    }
+}
+
+/// Generates the complete BER encoding of an [`Open`] value.
+impl<'a> Arbitrary<'a> for Any {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = Open::arbitrary(u)?;
+        crate::ber::encode(&value)
+            .map(Self::new)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+fn open(u: &mut Unstructured<'_>, depth: usize) -> arbitrary::Result<Open> {
+    // `SEQUENCE` and `SET` are last, so that they can be left out at the
+    // maximum depth.
+    let variants = if depth < MAX_OPEN_DEPTH { 14 } else { 12 };
+
+    Ok(match u.choose_index(variants)? {
+        0 => Open::BitString(bit_string(u)?),
+        1 => Open::Bool(u.arbitrary()?),
+        2 => Open::GeneralizedTime(generalized_time(u)?),
+        3 => Open::Integer(u.arbitrary()?),
+        4 => Open::Null,
+        5 => Open::ObjectIdentifier(u.arbitrary()?),
+        6 => Open::OctetString(u.arbitrary()?),
+        7 => Open::UniversalString(Implicit::new(u.arbitrary()?)),
+        8 => Open::UtcTime(utc_time(u)?),
+        9 => Open::Utf8String(u.arbitrary()?),
+        10 => Open::VisibleString(u.arbitrary()?),
+        11 => Open::Unknown(unknown(u)?),
+        12 => Open::Sequence(components(u, depth)?),
+        _ => Open::Set(components(u, depth)?),
+    })
+}
+
+fn components(u: &mut Unstructured<'_>, depth: usize) -> arbitrary::Result<Vec<Open>> {
+    (0..u.int_in_range(0..=MAX_OPEN_COMPONENTS)?)
+        .map(|_| open(u, depth + 1))
+        .collect()
+}
+
+/// A primitive context specific value, which no variant of [`Open`] matches.
+fn unknown(u: &mut Unstructured<'_>) -> arbitrary::Result<Any> {
+    let tag = Tag::new_context(u.int_in_range(0..=30)?);
+    let contents: Box<[u8]> = u.arbitrary()?;
+
+    let mut encoder = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::ber());
+    encoder
+        .encode_octet_string(tag, Constraints::default(), &contents, Identifier::EMPTY)
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+    Ok(Any::new(encoder.output()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decode, Encode};
+
+    const CASES: usize = 4000;
+
+    /// A deterministic xorshift generator, so that failures are reproducible.
+    fn inputs() -> impl Iterator<Item = Vec<u8>> {
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        (0..CASES).map(move |i| {
+            (0..(i % 512) + 1)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect()
+        })
+    }
+
+    fn round_trip<T, F>(mut generate: F)
+    where
+        T: Encode + Decode + PartialEq + core::fmt::Debug,
+        F: FnMut(&mut Unstructured<'_>) -> arbitrary::Result<T>,
+    {
+        for input in inputs() {
+            let Ok(value) = generate(&mut Unstructured::new(&input)) else {
+                continue;
+            };
+
+            let encoded = crate::ber::encode(&value).unwrap();
+            assert_eq!(value, crate::ber::decode::<T>(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip(Integer::arbitrary);
+        round_trip(OctetString::arbitrary);
+        round_trip(ObjectIdentifier::arbitrary);
+        round_trip(bit_string);
+        round_trip(utc_time);
+        round_trip(generalized_time);
+        round_trip(BmpString::arbitrary);
+        round_trip(GeneralString::arbitrary);
+        round_trip(GraphicString::arbitrary);
+        round_trip(Ia5String::arbitrary);
+        round_trip(NumericString::arbitrary);
+        round_trip(PrintableString::arbitrary);
+        round_trip(VisibleString::arbitrary);
+        round_trip(Open::arbitrary);
+        round_trip(Any::arbitrary);
+    }
+
+    #[test]
+    fn der_utc_time_round_trips() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let Ok(time) = utc_time(&mut u) else {
+                continue;
+            };
+            let encoded = crate::der::encode(&time).unwrap();
+            assert_eq!(time, crate::der::decode::<UtcTime>(&encoded).unwrap());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use super::{OctetString, SyntaxIdentification};
+use crate::{AsnType, Decode, Encode};
+
+/// The unrestricted `CHARACTER STRING` type, character data in an identified
+/// character abstract and transfer syntax, as encoded according to
+/// X.690 8.24.
+///
+/// The string value is kept as the encoded bytes, decoding them depends on
+/// the syntax given by `identification`.
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(tag(universal, 29))]
+pub struct CharacterString {
+    /// How the character abstract and transfer syntax of `string_value` are
+    /// identified.
+    #[rasn(tag(explicit(0)))]
+    pub identification: SyntaxIdentification,
+    /// The encoded characters.
+    #[rasn(tag(2))]
+    pub string_value: OctetString,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EmbeddedPdv, ObjectIdentifier, Tag};
+
+    #[test]
+    fn character_string() {
+        let string = CharacterString {
+            identification: SyntaxIdentification::PresentationContextId(3.into()),
+            string_value: OctetString::from_static(b"hi"),
+        };
+        let encoded = &[
+            0x3D, 0x09, 0xA0, 0x03, 0x82, 0x01, 0x03, 0x82, 0x02, b'h', b'i',
+        ][..];
+
+        assert_eq!(Tag::CHARACTER_STRING, CharacterString::TAG);
+        assert_eq!(encoded, crate::ber::encode(&string).unwrap());
+        assert_eq!(
+            string,
+            crate::der::decode::<CharacterString>(encoded).unwrap()
+        );
+
+        // The same contents as an `EMBEDDED PDV`.
+        let mut pdv = encoded.to_vec();
+        pdv[0] = 0x2B;
+        let pdv = crate::ber::decode::<EmbeddedPdv>(&pdv).unwrap();
+        assert_eq!(string.identification, pdv.identification);
+        assert_eq!(string.string_value, pdv.data_value);
+        assert!(crate::ber::decode::<CharacterString>(&crate::ber::encode(&pdv).unwrap()).is_err());
+
+        let string = CharacterString {
+            identification: SyntaxIdentification::TransferSyntax(
+                ObjectIdentifier::new(alloc::vec![2, 1, 1]).unwrap(),
+            ),
+            string_value: OctetString::from_static(b"hi"),
+        };
+        let encoded = crate::ber::encode(&string).unwrap();
+        assert_eq!(
+            string,
+            crate::ber::decode::<CharacterString>(&encoded).unwrap()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! Including the components of one `SEQUENCE` in another, as with ASN.1's
+//! `COMPONENTS OF`.
+
+use super::{fields::Field, AsnType};
+use crate::{de::Decoder, enc::Encoder};
+
+/// A `SEQUENCE` whose root components can be included in another
+/// `SEQUENCE`, as with `COMPONENTS OF`, by marking a field of its type with
+/// `#[rasn(flatten)]`.
+///
+/// Together with [`EncodeComponents`] and [`DecodeComponents`], this is
+/// derived for structs that are encoded as a `SEQUENCE` without extension
+/// additions, and should rarely need to be implemented by hand.
+///
+/// ```
+/// use rasn::prelude::*;
+///
+/// #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+/// struct Validity {
+///     not_before: Integer,
+///     not_after: Option<Integer>,
+/// }
+///
+/// // Ticket ::= SEQUENCE { name UTF8String, COMPONENTS OF Validity }
+/// #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+/// struct Ticket {
+///     name: Utf8String,
+///     #[rasn(flatten)]
+///     validity: Validity,
+/// }
+///
+/// let ticket = Ticket {
+///     name: "krbtgt".into(),
+///     validity: Validity { not_before: 1.into(), not_after: None },
+/// };
+/// let encoded = rasn::der::encode(&ticket).unwrap();
+/// assert_eq!(
+///     &[0x30, 0x0B, 0x0C, 0x06, b'k', b'r', b'b', b't', b'g', b't', 0x02, 0x01, 0x01],
+///     &*encoded
+/// );
+/// assert_eq!(ticket, rasn::der::decode(&encoded).unwrap());
+/// ```
+pub trait Components: AsnType {
+    /// The root components of the `SEQUENCE`, in order.
+    const COMPONENTS: &'static [Field];
+}
+
+/// A [`Components`] type that can encode its components without the
+/// `SEQUENCE` around them.
+pub trait EncodeComponents: Components {
+    /// Encodes each of the components of `self` into `encoder`, which is
+    /// encoding the enclosing `SEQUENCE`.
+    ///
+    /// # Errors
+    /// Returns the encoder's error if any component can't be encoded.
+    fn encode_components<'encoder, E: Encoder<'encoder>>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), E::Error>;
+}
+
+/// A [`Components`] type that can decode its components without the
+/// `SEQUENCE` around them.
+pub trait DecodeComponents: Components + Sized {
+    /// Decodes each of the components of `Self` from `decoder`, which is
+    /// decoding the enclosing `SEQUENCE`.
+    ///
+    /// # Errors
+    /// Returns the decoder's error if any component can't be decoded.
+    fn decode_components<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error>;
+}
+
+impl<T: Components> Components for &'_ T {
+    const COMPONENTS: &'static [Field] = T::COMPONENTS;
+}
+
+impl<T: EncodeComponents> EncodeComponents for &'_ T {
+    fn encode_components<'encoder, E: Encoder<'encoder>>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), E::Error> {
+        T::encode_components(self, encoder)
+    }
+}
@@ -38,6 +38,26 @@ pub trait Constructed<const RL: usize = 0, const EL: usize = 0> {
 /// ```
 pub type SequenceOf<T> = alloc::vec::Vec<T>;
 
+/// A `SEQUENCE OF` value whose elements are produced by an iterator when it
+/// is encoded, rather than held in a [`SequenceOf`]. The iterator is cloned
+/// for each encoding, so it should be cheap to clone, like a range mapped
+/// with a closure.
+///
+/// BER, CER and DER encode the elements as they are produced, while other
+/// codecs collect them first, as they encode the number of elements before
+/// them. It decodes like any other `SEQUENCE OF`, e.g. as a [`SequenceOf`].
+/// ```rust
+/// use rasn::types::{SequenceOf, SequenceOfIter};
+///
+/// let squares = SequenceOfIter((1u32..=3).map(|n| n * n));
+/// let ber = rasn::ber::encode(&squares).unwrap();
+///
+/// assert_eq!(&[0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x04, 0x02, 0x01, 0x09], &*ber);
+/// assert_eq!(vec![1, 4, 9], rasn::ber::decode::<SequenceOf<u32>>(&ber).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceOfIter<I>(pub I);
+
 /// The `SET OF` type - an unordered list of zero, one or more values of the component type.
 ///
 /// Works internally like  `Vec<T>`, where the order just does not matter.
@@ -198,6 +218,94 @@ impl<T: Eq> Default for SetOf<T> {
     }
 }
 
+/// A `SET OF` value that keeps its elements in the order they were decoded
+/// or inserted, including duplicates, and doesn't require anything of the
+/// element type beyond encoding and decoding.
+///
+/// BER encodes the elements in their stored order, so a decoded value is
+/// re-encoded as it was received, while CER and DER sort their encodings as
+/// they do for [`SetOf`]. Unlike [`SetOf`], values are equal only when they
+/// hold equal elements in the same order.
+/// ```rust
+/// use rasn::types::SetVec;
+///
+/// let set = SetVec::from(vec![2u8, 1, 2]);
+/// let ber = rasn::ber::encode(&set).unwrap();
+///
+/// assert_eq!(&[0x31, 0x09, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02], &*ber);
+/// assert_eq!(set, rasn::ber::decode::<SetVec<u8>>(&ber).unwrap());
+/// assert_eq!(
+///     &[0x31, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x02],
+///     &*rasn::der::encode(&set).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SetVec<T>(pub alloc::vec::Vec<T>);
+
+impl<T> SetVec<T> {
+    /// Construct a new empty set of value.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(alloc::vec::Vec::new())
+    }
+
+    /// Returns the elements, in their stored order.
+    #[must_use]
+    pub fn into_inner(self) -> alloc::vec::Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Default for SetVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::ops::Deref for SetVec<T> {
+    type Target = alloc::vec::Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for SetVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<alloc::vec::Vec<T>> for SetVec<T> {
+    fn from(vec: alloc::vec::Vec<T>) -> Self {
+        Self(vec)
+    }
+}
+
+impl<T> FromIterator<T> for SetVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for SetVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SetVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,41 @@
+//! Values whose type is identified by an accompanying `OBJECT IDENTIFIER`,
+//! e.g. `ANY DEFINED BY`, or an open type constrained by an information object
+//! set.
+
+use super::{Any, Oid};
+use crate::error::{DecodeError, EncodeError};
+
+/// A type holding one of several values, where the type of the value is
+/// selected by an `OBJECT IDENTIFIER` that appears alongside it.
+///
+/// This is usually implemented with [`defined_by!`][crate::defined_by], and
+/// used on a field of a derived `SEQUENCE` with `#[rasn(defined_by = "field")]`,
+/// which decodes the field as an [`Any`] and then passes it with the value of
+/// the named `OBJECT IDENTIFIER` field to [`DefinedBy::decode_by_oid`].
+///
+/// The [`Any`] holds a BER encoding, so this is only meaningful with the BER
+/// family of codecs.
+pub trait DefinedBy: Sized {
+    /// Decodes the value that `oid` identifies from `any`.
+    ///
+    /// # Errors
+    /// Returns `DecodeError` if `any` doesn't hold a valid value of the type
+    /// that `oid` identifies.
+    fn decode_by_oid(oid: &Oid, any: &Any) -> Result<Self, DecodeError>;
+
+    /// Encodes the value as an [`Any`].
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if the value can't be encoded.
+    fn encode_as_any(&self) -> Result<Any, EncodeError>;
+}
+
+impl DefinedBy for Any {
+    fn decode_by_oid(_: &Oid, any: &Any) -> Result<Self, DecodeError> {
+        Ok(any.clone())
+    }
+
+    fn encode_as_any(&self) -> Result<Any, EncodeError> {
+        Ok(self.clone())
+    }
+}
@@ -0,0 +1,81 @@
+use super::{OctetString, SyntaxIdentification};
+use crate::{AsnType, Decode, Encode};
+
+/// The `EMBEDDED PDV` type, a value whose abstract and transfer syntax are
+/// identified alongside it, as encoded according to X.690 8.19.
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(tag(universal, 11))]
+pub struct EmbeddedPdv {
+    /// How the abstract and transfer syntax of `data_value` are identified.
+    #[rasn(tag(explicit(0)))]
+    pub identification: SyntaxIdentification,
+    /// The encoded value.
+    #[rasn(tag(2))]
+    pub data_value: OctetString,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ContextNegotiation, ObjectIdentifier, Open, Syntaxes};
+
+    #[test]
+    fn presentation_context_id() {
+        let pdv = EmbeddedPdv {
+            identification: SyntaxIdentification::PresentationContextId(3.into()),
+            data_value: OctetString::from_static(&[0x05, 0x00]),
+        };
+        let encoded = &[
+            0x2B, 0x09, 0xA0, 0x03, 0x82, 0x01, 0x03, 0x82, 0x02, 0x05, 0x00,
+        ][..];
+
+        assert_eq!(encoded, crate::ber::encode(&pdv).unwrap());
+        assert_eq!(pdv, crate::ber::decode::<EmbeddedPdv>(encoded).unwrap());
+        assert_eq!(pdv, crate::der::decode::<EmbeddedPdv>(encoded).unwrap());
+
+        let open = crate::ber::decode::<Open>(encoded).unwrap();
+        assert_eq!(Some(&pdv), open.as_embedded_pdv().map(|pdv| &**pdv));
+        assert_eq!(encoded, crate::ber::encode(&open).unwrap());
+    }
+
+    #[test]
+    fn transfer_syntax() {
+        let pdv = EmbeddedPdv {
+            identification: SyntaxIdentification::TransferSyntax(
+                ObjectIdentifier::new(alloc::vec![2, 1, 1]).unwrap(),
+            ),
+            data_value: OctetString::from_static(&[0x01, 0x01, 0xFF]),
+        };
+        let encoded = &[
+            0x2B, 0x0B, 0xA0, 0x04, 0x84, 0x02, 0x51, 0x01, 0x82, 0x03, 0x01, 0x01, 0xFF,
+        ][..];
+
+        assert_eq!(encoded, crate::der::encode(&pdv).unwrap());
+        assert_eq!(pdv, crate::der::decode::<EmbeddedPdv>(encoded).unwrap());
+    }
+
+    #[test]
+    fn other_identifications() {
+        let oid = ObjectIdentifier::new(alloc::vec![2, 1, 1]).unwrap();
+        for identification in [
+            SyntaxIdentification::Syntaxes(Syntaxes {
+                r#abstract: oid.clone(),
+                transfer: oid.clone(),
+            }),
+            SyntaxIdentification::Syntax(oid.clone()),
+            SyntaxIdentification::ContextNegotiation(ContextNegotiation {
+                presentation_context_id: 1.into(),
+                transfer_syntax: oid,
+            }),
+            SyntaxIdentification::Fixed,
+        ] {
+            let pdv = EmbeddedPdv {
+                identification,
+                data_value: OctetString::from_static(&[0x05, 0x00]),
+            };
+            let encoded = crate::ber::encode(&pdv).unwrap();
+            assert_eq!(pdv, crate::ber::decode::<EmbeddedPdv>(&encoded).unwrap());
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use super::{Any, BitString, GraphicString, Implicit, Integer, ObjectIdentifier, OctetString};
+use crate::{AsnType, Decode, Encode};
+
+/// The `ObjectDescriptor` type, human readable text describing an object,
+/// encoded as a `GraphicString` with universal tag 7.
+pub type ObjectDescriptor = Implicit<super::tag::OBJECT_DESCRIPTOR, GraphicString>;
+
+/// The `EXTERNAL` type, a value whose type is identified by an object
+/// identifier and/or an integer negotiated by the presentation layer, as
+/// encoded according to X.690 8.18.
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(tag(universal, 8))]
+pub struct External {
+    /// The object identifier of the value's abstract and transfer syntax.
+    pub direct_reference: Option<ObjectIdentifier>,
+    /// The presentation context identifier of the value's abstract syntax.
+    pub indirect_reference: Option<Integer>,
+    /// A description of the value's type.
+    pub data_value_descriptor: Option<ObjectDescriptor>,
+    /// The encoded value.
+    pub encoding: ExternalEncoding,
+}
+
+/// The encoding of the value held in an [`External`].
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(choice)]
+pub enum ExternalEncoding {
+    /// A single ASN.1 value.
+    #[rasn(tag(explicit(0)))]
+    SingleAsn1Type(Any),
+    /// A value encoded as a whole number of octets.
+    #[rasn(tag(1))]
+    OctetAligned(OctetString),
+    /// A value encoded as an arbitrary number of bits.
+    #[rasn(tag(2))]
+    Arbitrary(BitString),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acse_user_information() {
+        // The EXTERNAL from the user-information of an ACSE AARQ, carrying an
+        // MMS initiate-RequestPDU in presentation context 3.
+        let encoded = &[
+            0x28, 0x2D, 0x02, 0x01, 0x03, 0xA0, 0x28, 0xA8, 0x26, 0x80, 0x03, 0x00, 0xFD, 0xE8,
+            0x81, 0x01, 0x0A, 0x82, 0x01, 0x0A, 0x83, 0x01, 0x05, 0xA4, 0x16, 0x80, 0x01, 0x01,
+            0x81, 0x03, 0x05, 0xF1, 0x00, 0x82, 0x0C, 0x03, 0xEE, 0x1C, 0x00, 0x00, 0x04, 0x08,
+            0x00, 0x00, 0x79, 0xEF, 0x18,
+        ][..];
+
+        let external = crate::ber::decode::<External>(encoded).unwrap();
+        assert_eq!(None, external.direct_reference);
+        assert_eq!(Some(Integer::from(3)), external.indirect_reference);
+        assert_eq!(None, external.data_value_descriptor);
+        assert_eq!(
+            ExternalEncoding::SingleAsn1Type(Any::new(encoded[7..].to_vec())),
+            external.encoding
+        );
+        assert_eq!(encoded, crate::ber::encode(&external).unwrap());
+    }
+
+    #[test]
+    fn object_descriptor() {
+        let hello = ObjectDescriptor::new(GraphicString::from_bytes(b"hello").unwrap());
+        let encoded = &[0x07, 0x05, 0x68, 0x65, 0x6C, 0x6C, 0x6F][..];
+
+        assert_eq!(
+            hello,
+            crate::ber::decode::<ObjectDescriptor>(encoded).unwrap()
+        );
+        assert_eq!(encoded, crate::ber::encode(&hello).unwrap());
+        assert_eq!(
+            hello,
+            crate::der::decode::<ObjectDescriptor>(encoded).unwrap()
+        );
+
+        // The constructed form is only allowed in BER.
+        let constructed = &[
+            0x27, 0x80, 0x04, 0x02, 0x68, 0x65, 0x04, 0x03, 0x6C, 0x6C, 0x6F, 0x00, 0x00,
+        ][..];
+        assert_eq!(
+            hello,
+            crate::ber::decode::<ObjectDescriptor>(constructed).unwrap()
+        );
+        assert!(crate::der::decode::<ObjectDescriptor>(constructed).is_err());
+
+        // A GraphicString has a different tag.
+        assert!(crate::ber::decode::<ObjectDescriptor>(&[0x19, 0x02, 0x68, 0x69]).is_err());
+    }
+
+    #[test]
+    fn octet_aligned() {
+        let external = External {
+            direct_reference: Some(ObjectIdentifier::new(alloc::vec![2, 1, 1]).unwrap()),
+            indirect_reference: None,
+            data_value_descriptor: Some(ObjectDescriptor::new(
+                GraphicString::from_bytes(b"text").unwrap(),
+            )),
+            encoding: ExternalEncoding::OctetAligned(OctetString::from_static(b"hello")),
+        };
+        let encoded = &[
+            0x28, 0x11, 0x06, 0x02, 0x51, 0x01, 0x07, 0x04, b't', b'e', b'x', b't', 0x81, 0x05,
+            b'h', b'e', b'l', b'l', b'o',
+        ][..];
+
+        assert_eq!(encoded, crate::der::encode(&external).unwrap());
+        assert_eq!(external, crate::der::decode::<External>(encoded).unwrap());
+    }
+}
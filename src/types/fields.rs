@@ -108,6 +108,56 @@ impl<const N: usize> Fields<N> {
     pub fn identifiers(&self) -> impl Iterator<Item = &str> + '_ {
         self.fields.iter().map(|f| f.name)
     }
+
+    /// Returns whether every `OPTIONAL` or `DEFAULT` field has tags distinct
+    /// from those of the fields following it, up to and including the next
+    /// required field, so that decoders can tell which field is present.
+    #[must_use]
+    pub const fn has_unambiguous_tags(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.fields[i].is_optional_or_default() {
+                let mut j = i + 1;
+                while j < N {
+                    if self.fields[i].tag_tree.overlaps(&self.fields[j].tag_tree) {
+                        return false;
+                    }
+                    if self.fields[j].is_not_optional_or_default() {
+                        break;
+                    }
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// Joins the fields of `parts` into one list, numbering them in order. This
+/// is how `#[rasn(flatten)]` includes the components of one `SEQUENCE` in
+/// another, like `COMPONENTS OF`.
+///
+/// # Panics
+/// If `parts` don't contain exactly `N` fields in total.
+#[must_use]
+pub const fn concat<const N: usize>(parts: &[&[Field]]) -> [Field; N] {
+    let mut fields = [Field::new_required(0, Tag::EOC, TagTree::empty(), ""); N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            assert!(len < N, "more fields than expected");
+            fields[len] = parts[i][j];
+            fields[len].index = len;
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(len == N, "fewer fields than expected");
+    fields
 }
 
 impl<const N: usize> core::ops::Deref for Fields<N> {
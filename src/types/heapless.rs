@@ -0,0 +1,152 @@
+//! Fixed capacity `heapless::Vec` and `heapless::String`, enabled with the
+//! `heapless` feature.
+
+use core::ops::ControlFlow;
+
+use heapless::{String, Vec};
+
+use super::{AsnType, Constraints, Identifier, Tag};
+use crate::{error::DecodeError, Codec, Decode, Decoder, Encode, Encoder};
+
+/// A `SEQUENCE OF` with at most `N` elements.
+/// ```
+/// let value = heapless::Vec::<u8, 4>::from_slice(&[1, 2]).unwrap();
+/// let encoded = rasn::der::encode(&value).unwrap();
+///
+/// assert_eq!(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02], &*encoded);
+/// assert_eq!(value, rasn::der::decode::<heapless::Vec<u8, 4>>(&encoded).unwrap());
+/// assert!(rasn::der::decode::<heapless::Vec<u8, 1>>(&encoded).is_err());
+/// ```
+impl<T: AsnType, const N: usize> AsnType for Vec<T, N> {
+    const TAG: Tag = Tag::SEQUENCE;
+    const CONSTRAINTS: Constraints = constraints!(size_constraint!(0, N));
+    const IDENTIFIER: Identifier = Identifier::SEQUENCE_OF;
+}
+
+impl<T: Decode, const N: usize> Decode for Vec<T, N> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let codec = decoder.codec();
+        let mut value = Self::new();
+        let mut length = 0;
+
+        // Elements are pushed as they are decoded, and those past the
+        // capacity are only counted so the error reports the actual length.
+        decoder.decode_sequence_of_with(tag, constraints, |item: T| {
+            length += 1;
+            let _ = value.push(item);
+            Ok(ControlFlow::Continue(()))
+        })?;
+
+        if length > N {
+            return Err(capacity_exceeded::<D>(codec, length, N));
+        }
+
+        Ok(value)
+    }
+}
+
+impl<T: Encode, const N: usize> Encode for Vec<T, N> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_sequence_of(tag, self, constraints, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
+/// A `UTF8String` of at most `N` bytes.
+impl<const N: usize> AsnType for String<N> {
+    const TAG: Tag = Tag::UTF8_STRING;
+    const IDENTIFIER: Identifier = Identifier::UTF8_STRING;
+}
+
+impl<const N: usize> Decode for String<N> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let string = decoder.decode_utf8_string(tag, constraints)?;
+
+        Self::try_from(&*string)
+            .map_err(|()| capacity_exceeded::<D>(decoder.codec(), string.len(), N))
+    }
+}
+
+impl<const N: usize> Encode for String<N> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_utf8_string(tag, constraints, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
+fn capacity_exceeded<D: Decoder>(codec: Codec, size: usize, capacity: usize) -> D::Error {
+    DecodeError::size_constraint_not_satisfied(Some(size), alloc::format!("0..={capacity}"), codec)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::DecodeErrorKind, types::Utf8String};
+
+    #[test]
+    fn round_trip() {
+        let values = Vec::<u32, 4>::from_slice(&[1, 300, 70_000]).unwrap();
+        let name = String::<8>::try_from("device").unwrap();
+
+        macro_rules! round_trip {
+            ($($codec:ident),+ $(,)?) => {
+                $(
+                    let encoded = crate::$codec::encode(&values).unwrap();
+                    assert_eq!(values, crate::$codec::decode::<Vec<u32, 4>>(&encoded).unwrap());
+                    let encoded = crate::$codec::encode(&name).unwrap();
+                    assert_eq!(name, crate::$codec::decode::<String<8>>(&encoded).unwrap());
+                )+
+            }
+        }
+
+        round_trip!(ber, der, cer, oer, coer, uper, aper);
+        assert_eq!(
+            crate::der::encode(&alloc::vec![1u32, 300, 70_000]).unwrap(),
+            crate::der::encode(&values).unwrap()
+        );
+        assert_eq!(
+            crate::der::encode(&Utf8String::from("device")).unwrap(),
+            crate::der::encode(&name).unwrap()
+        );
+    }
+
+    #[test]
+    fn exceeding_capacity() {
+        let encoded = crate::ber::encode(&alloc::vec![1u8, 2, 3]).unwrap();
+        let error = crate::ber::decode::<Vec<u8, 2>>(&encoded).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            DecodeErrorKind::SizeConstraintNotSatisfied { size: Some(3), .. }
+        ));
+
+        let encoded = crate::ber::encode(&Utf8String::from("too long")).unwrap();
+        let error = crate::ber::decode::<String<4>>(&encoded).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            DecodeErrorKind::SizeConstraintNotSatisfied { size: Some(8), .. }
+        ));
+    }
+}
@@ -1,11 +1,39 @@
-use super::{AsnType, Class, Constraints, Identifier, ObjectIdentifier, Tag};
+use super::{
+    Any, AsnType, Class, Constraints, DefinedBy, Identifier, ObjectIdentifier, Tag, TagTree,
+};
 use crate::{
     de::Decoder,
     enc::Encoder,
+    error::DecodeError,
     types::fields::{Field, FieldPresence, Fields},
 };
 
-/// An instance of a defined object class.
+/// The tag of the explicitly tagged value.
+const VALUE_TAG: Tag = Tag::new(Class::Context, 0);
+
+/// An instance of a defined object class, i.e. `INSTANCE OF` a class such as
+/// `TYPE-IDENTIFIER`, encoded with the tag of `EXTERNAL` as
+/// `SEQUENCE { type-id OBJECT IDENTIFIER, value [0] EXPLICIT T }`.
+///
+/// `T` is the type of the value when it's known, or [`Any`] to accept any
+/// value, which can then be interpreted with [`InstanceOf::decode_defined`].
+/// ```
+/// use rasn::types::{Any, InstanceOf, Utf8String};
+///
+/// let instance = InstanceOf {
+///     type_id: rasn::oid!(2.5.4.3).to_owned(),
+///     value: Utf8String::from("hi"),
+/// };
+/// let encoded = rasn::der::encode(&instance).unwrap();
+/// assert_eq!(
+///     &[0x28, 0x0B, 0x06, 0x03, 0x55, 0x04, 0x03, 0xA0, 0x04, 0x0C, 0x02, b'h', b'i'],
+///     &*encoded
+/// );
+///
+/// let any: InstanceOf<Any> = rasn::der::decode(&encoded).unwrap();
+/// assert_eq!(instance.type_id, any.type_id);
+/// assert_eq!(&encoded[9..], any.value.as_bytes());
+/// ```
 #[derive(Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash)]
 pub struct InstanceOf<T> {
     /// The OID identifying T's real type.
@@ -14,6 +42,18 @@ pub struct InstanceOf<T> {
     pub value: T,
 }
 
+impl InstanceOf<Any> {
+    /// Decodes the value as the type that `type_id` identifies, e.g. an enum
+    /// declared with [`defined_by!`][crate::defined_by].
+    ///
+    /// # Errors
+    /// Returns `DecodeError` if the value isn't a valid encoding of the type
+    /// that `type_id` identifies.
+    pub fn decode_defined<T: DefinedBy>(&self) -> Result<T, DecodeError> {
+        T::decode_by_oid(&self.type_id, &self.value)
+    }
+}
+
 impl<T> AsnType for InstanceOf<T> {
     const TAG: Tag = Tag::EXTERNAL;
     const IDENTIFIER: Identifier = Identifier::INSTANCE_OF;
@@ -27,7 +67,7 @@ impl<T: crate::Decode> crate::Decode for InstanceOf<T> {
     ) -> Result<Self, D::Error> {
         decoder.decode_sequence(tag, None::<fn() -> Self>, |sequence| {
             let type_id = ObjectIdentifier::decode(sequence)?;
-            let value = sequence.decode_explicit_prefix(Tag::new(Class::Context, 0))?;
+            let value = sequence.decode_explicit_prefix(VALUE_TAG)?;
 
             Ok(Self { type_id, value })
         })
@@ -47,9 +87,9 @@ impl<T: crate::Encode> crate::Encode for InstanceOf<T> {
             |sequence| {
                 self.type_id.encode(sequence)?;
                 sequence.encode_explicit_prefix(
-                    Tag::new(Class::Context, 0),
+                    VALUE_TAG,
                     &self.value,
-                    identifier,
+                    Identifier(Some("value")),
                 )?;
                 Ok(())
             },
@@ -71,10 +111,82 @@ impl<T: AsnType> crate::types::Constructed<2, 0> for InstanceOf<T> {
         },
         Field {
             index: 1,
-            tag: T::TAG,
-            tag_tree: T::TAG_TREE,
+            tag: VALUE_TAG,
+            tag_tree: TagTree::Leaf(VALUE_TAG),
             presence: FieldPresence::Required,
             name: "value",
         },
     ]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{External, ExternalEncoding, Utf8String};
+
+    crate::defined_by! {
+        #[derive(Clone, Debug, PartialEq)]
+        enum AttributeValue {
+            CommonName(Utf8String) = crate::oid!(2.5.4.3),
+        }
+    }
+
+    // INSTANCE OF TYPE-IDENTIFIER holding commonName "hi".
+    const ENCODED: &[u8] = &[
+        0x28, 0x0B, 0x06, 0x03, 0x55, 0x04, 0x03, 0xA0, 0x04, 0x0C, 0x02, b'h', b'i',
+    ];
+
+    #[test]
+    fn concrete_value() {
+        let instance = InstanceOf {
+            type_id: crate::oid!(2.5.4 .3).to_owned(),
+            value: Utf8String::from("hi"),
+        };
+
+        for encoded in [
+            crate::ber::encode(&instance).unwrap(),
+            crate::der::encode(&instance).unwrap(),
+        ] {
+            assert_eq!(ENCODED, encoded);
+        }
+        assert_eq!(instance, crate::ber::decode(ENCODED).unwrap());
+        assert_eq!(instance, crate::der::decode(ENCODED).unwrap());
+    }
+
+    #[test]
+    fn any_value() {
+        let instance = crate::ber::decode::<InstanceOf<Any>>(ENCODED).unwrap();
+        assert_eq!(*crate::oid!(2.5.4 .3), *instance.type_id);
+        assert_eq!(Any::new(ENCODED[9..].to_vec()), instance.value);
+        assert_eq!(ENCODED, crate::der::encode(&instance).unwrap());
+
+        assert_eq!(
+            AttributeValue::CommonName("hi".into()),
+            instance.decode_defined().unwrap()
+        );
+
+        let unknown = InstanceOf {
+            type_id: crate::oid!(2.5.4 .99).to_owned(),
+            value: instance.value.clone(),
+        };
+        assert!(matches!(
+            unknown.decode_defined().unwrap(),
+            AttributeValue::Unknown(value) if value == instance.value
+        ));
+    }
+
+    #[test]
+    fn external_compatible() {
+        // An INSTANCE OF is encoded like an EXTERNAL with only a direct
+        // reference and a single ASN.1 value.
+        let external = crate::ber::decode::<External>(ENCODED).unwrap();
+        assert_eq!(
+            Some(crate::oid!(2.5.4 .3).to_owned()),
+            external.direct_reference
+        );
+        assert_eq!(
+            ExternalEncoding::SingleAsn1Type(Any::new(ENCODED[9..].to_vec())),
+            external.encoding
+        );
+    }
+}
@@ -331,11 +331,40 @@ macro_rules! impl_try_from_integer {
 }
 impl_try_from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
-/// An integer which has encoded constraint range between `START` and `END`.
+/// An integer which has encoded constraint range between `START` and `END`,
+/// i.e. `INTEGER (START..END)`. The range is checked when converting to this
+/// type and when decoding it.
+/// ```
+/// use rasn::types::ConstrainedInteger;
+///
+/// type Octet = ConstrainedInteger<0, 255>;
+///
+/// assert_eq!((Octet::MIN, Octet::MAX), (0, 255));
+/// assert!(rasn::ber::decode::<Octet>(&[0x02, 0x02, 0x00, 0xFF]).is_ok());
+/// assert!(rasn::ber::decode::<Octet>(&[0x02, 0x02, 0x01, 0x00]).is_err());
+/// assert!(Octet::try_from(256).is_err());
+/// ```
 #[derive(Debug, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct ConstrainedInteger<const START: i128, const END: i128>(pub(crate) Integer);
 
 impl<const START: i128, const END: i128> ConstrainedInteger<START, END> {
+    /// The smallest value in the range.
+    pub const MIN: i128 = START;
+    /// The largest value in the range.
+    pub const MAX: i128 = END;
+
+    /// Whether `value` is within the range.
+    #[must_use]
+    pub fn contains(value: &Integer) -> bool {
+        i128::try_from(value).is_ok_and(|value| (START..=END).contains(&value))
+    }
+
+    /// Returns the inner integer.
+    #[must_use]
+    pub fn into_inner(self) -> Integer {
+        self.0
+    }
+
     /// Unchecked `new` where constraint boundaries are not checked - used only in tests.
     #[cfg(test)]
     pub(crate) fn new<T: Into<Integer>>(value: T) -> Self {
@@ -375,7 +404,121 @@ macro_rules! impl_try_from_integer_constrained {
         )*
     }
 }
-impl_try_from_integer_constrained!(isize, i32, i64, i128);
+impl_try_from_integer_constrained!(u8, u16, u32, u64, usize, i8, i16, isize, i32, i64, i128);
+
+impl<const START: i128, const END: i128> TryFrom<Integer> for ConstrainedInteger<START, END> {
+    type Error = TryFromIntegerError;
+
+    fn try_from(value: Integer) -> Result<Self, Self::Error> {
+        if Self::contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(TryFromIntegerError::new(value.into()))
+        }
+    }
+}
+
+/// A non-negative integer, such as a serial number or counter. Negative
+/// values are rejected when converting to this type and when decoding it.
+///
+/// The type carries no constraint, so PER and OER encode it as an
+/// unconstrained `INTEGER`, the same as [`Integer`]. Fields the schema
+/// declares as `INTEGER (0..MAX)` still need a `#[rasn(value("0.."))]`
+/// constraint.
+///
+/// `INTEGER` is always encoded in two's complement, so a value whose most
+/// significant bit is set needs a leading zero octet. A 16 octet certificate
+/// serial number with its top bit set is encoded as 17 contents octets.
+/// ```
+/// use rasn::types::Unsigned;
+///
+/// let value = Unsigned::from(128u8);
+/// assert_eq!(&[0x02, 0x02, 0x00, 0x80], &*rasn::der::encode(&value).unwrap());
+/// assert_eq!(value, rasn::der::decode(&[0x02, 0x02, 0x00, 0x80]).unwrap());
+/// assert!(rasn::der::decode::<Unsigned>(&[0x02, 0x01, 0xFF]).is_err());
+/// ```
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Unsigned(pub(crate) Integer);
+
+impl Unsigned {
+    /// Returns the inner integer.
+    #[must_use]
+    pub fn into_inner(self) -> Integer {
+        self.0
+    }
+
+    /// Returns the value as a [`BigUint`].
+    #[must_use]
+    pub fn to_biguint(&self) -> BigUint {
+        match &self.0 .0 {
+            IntegerKind::Primitive(value) => BigUint::from(value.unsigned_abs()),
+            IntegerKind::Variable(value) => value.magnitude().clone(),
+        }
+    }
+
+    /// Creates an unsigned integer from its big endian magnitude, e.g. the
+    /// octets of a serial number, without the leading zero octet that its
+    /// encoding needs.
+    #[must_use]
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        Self::from(BigUint::from_bytes_be(bytes))
+    }
+}
+
+impl AsnType for Unsigned {
+    const TAG: Tag = Tag::INTEGER;
+}
+
+impl core::ops::Deref for Unsigned {
+    type Target = Integer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for Unsigned {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Unsigned {
+                fn from(value: $t) -> Self {
+                    Self(value.into())
+                }
+            }
+        )*
+    }
+}
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<BigUint> for Unsigned {
+    fn from(value: BigUint) -> Self {
+        Self(BigInt::from(value).into())
+    }
+}
+
+impl From<Unsigned> for Integer {
+    fn from(value: Unsigned) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<Integer> for Unsigned {
+    type Error = TryFromIntegerError;
+
+    fn try_from(value: Integer) -> Result<Self, Self::Error> {
+        if value < Integer::ZERO {
+            Err(TryFromIntegerError::new(value.into()))
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
 
 /// Represents a integer type in Rust that can be decoded or encoded into any
 /// ASN.1 codec.
@@ -508,7 +651,11 @@ macro_rules! integer_type_impl {
                     return Err(crate::error::DecodeError::unexpected_empty_input(codec));
                 }
                 if input.len() > BYTE_SIZE {
-                    return Err(crate::error::DecodeError::integer_overflow(<$t1>::BITS, codec));
+                    return Err(crate::error::DecodeError::integer_out_of_range(
+                        stringify!($t1),
+                        BigInt::from_signed_bytes_be(input),
+                        codec,
+                    ));
                 }
 
                 // Use shifting to directly construct the primitive integer types
@@ -530,10 +677,20 @@ macro_rules! integer_type_impl {
                 input: &[u8],
                 codec: crate::Codec,
             ) -> Result<Self, crate::error::DecodeError> {
-                <$t1>::try_from(<$t2>::try_from_bytes(input, codec)?)
-                    .map_err(|_| {
-                    crate::error::DecodeError::integer_type_conversion_failed(alloc::format!("Failed to create signed integer from unsigned bytes, target bit-size {}, with bytes {:?}", <$t1>::BITS, input).into(), codec)
-                })
+                if input.is_empty() {
+                    return Err(crate::error::DecodeError::unexpected_empty_input(codec));
+                }
+
+                <$t2>::try_from_bytes(input, codec)
+                    .ok()
+                    .and_then(|value| <$t1>::try_from(value).ok())
+                    .ok_or_else(|| {
+                        crate::error::DecodeError::integer_out_of_range(
+                            stringify!($t1),
+                            BigUint::from_bytes_be(input).into(),
+                            codec,
+                        )
+                    })
 
             }
             #[inline(always)]
@@ -606,10 +763,20 @@ macro_rules! integer_type_impl {
                 input: &[u8],
                 codec: crate::Codec,
             ) -> Result<Self, crate::error::DecodeError> {
-                <$t1>::try_from(<$t2>::try_from_bytes(input, codec)?)
-                    .map_err(|_| {
-                    crate::error::DecodeError::integer_type_conversion_failed(alloc::format!("Failed to create unsigned integer from signed bytes, target bit-size {}, with bytes: {:?}", <$t1>::BITS, input).into(), codec)
-                })
+                if input.is_empty() {
+                    return Err(crate::error::DecodeError::unexpected_empty_input(codec));
+                }
+
+                <$t2>::try_from_bytes(input, codec)
+                    .ok()
+                    .and_then(|value| <$t1>::try_from(value).ok())
+                    .ok_or_else(|| {
+                        crate::error::DecodeError::integer_out_of_range(
+                            stringify!($t1),
+                            BigInt::from_signed_bytes_be(input),
+                            codec,
+                        )
+                    })
             }
 
             #[inline(always)]
@@ -622,7 +789,11 @@ macro_rules! integer_type_impl {
                     return Err(crate::error::DecodeError::unexpected_empty_input(codec));
                 }
                 if input.len() > BYTE_SIZE {
-                    return Err(crate::error::DecodeError::integer_overflow(<$t1>::BITS, codec));
+                    return Err(crate::error::DecodeError::integer_out_of_range(
+                        stringify!($t1),
+                        BigUint::from_bytes_be(input).into(),
+                        codec,
+                    ));
                 }
 
                 // Use shifting to directly construct the primitive integer types
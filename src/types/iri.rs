@@ -0,0 +1,230 @@
+//! The `OID-IRI` and `RELATIVE-OID-IRI` types, which identify objects with
+//! Unicode labels rather than numbers.
+
+use alloc::string::String;
+
+use super::{AsnType, Constraints, Identifier, Tag, Utf8String};
+use crate::{
+    de::{Decode, Decoder},
+    enc::{Encode, Encoder},
+    error::DecodeError,
+};
+
+/// An `OID-IRI` value, the path of an object in the international object
+/// identifier tree as a sequence of Unicode labels, each preceded by `/`.
+///
+/// ```
+/// use rasn::types::OidIri;
+///
+/// let iri = OidIri::new("/ISO/Registration_Authority/19785.CBEFF").unwrap();
+/// assert_eq!(iri.labels().count(), 3);
+/// assert_eq!(iri, rasn::der::decode(&rasn::der::encode(&iri).unwrap()).unwrap());
+///
+/// assert!(OidIri::new("ISO/Registration_Authority").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OidIri(Utf8String);
+
+/// A `RELATIVE-OID-IRI` value, the path of an object relative to some other
+/// object as a sequence of Unicode labels separated by `/`.
+///
+/// ```
+/// use rasn::types::RelativeOidIri;
+///
+/// let iri = RelativeOidIri::new("Registration_Authority/19785.CBEFF").unwrap();
+/// assert_eq!(iri.labels().count(), 2);
+///
+/// assert!(RelativeOidIri::new("/ISO").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativeOidIri(Utf8String);
+
+/// The error returned when a string isn't a valid [`OidIri`] or
+/// [`RelativeOidIri`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidIri {
+    /// The string was empty.
+    Empty,
+    /// An `OID-IRI` didn't start with `/`, or a `RELATIVE-OID-IRI` did.
+    InvalidStart,
+    /// A label between two `/`s, or after the last one, was empty.
+    EmptyLabel,
+}
+
+impl core::fmt::Display for InvalidIri {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "IRI must not be empty",
+            Self::InvalidStart => "OID-IRI must start with `/`, and RELATIVE-OID-IRI must not",
+            Self::EmptyLabel => "IRI labels must not be empty",
+        })
+    }
+}
+
+impl core::error::Error for InvalidIri {}
+
+/// Checks that `iri` is a sequence of non-empty labels separated by `/`,
+/// starting with `/` only if `absolute`.
+fn validate(iri: &str, absolute: bool) -> Result<(), InvalidIri> {
+    let labels = match (iri.strip_prefix('/'), absolute) {
+        _ if iri.is_empty() => return Err(InvalidIri::Empty),
+        (Some(labels), true) => labels,
+        (None, false) => iri,
+        _ => return Err(InvalidIri::InvalidStart),
+    };
+
+    if labels.split('/').any(str::is_empty) {
+        return Err(InvalidIri::EmptyLabel);
+    }
+
+    Ok(())
+}
+
+macro_rules! iri {
+    ($name:ident, $absolute:literal, $tag:ident, $identifier:ident) => {
+        impl $name {
+            /// Creates a new value from `iri`, checking that it is valid.
+            ///
+            /// # Errors
+            /// Returns `InvalidIri` if `iri` is empty, begins incorrectly or
+            /// has an empty label.
+            pub fn new(iri: impl Into<String>) -> Result<Self, InvalidIri> {
+                let iri = iri.into();
+                validate(&iri, $absolute)?;
+                Ok(Self(iri))
+            }
+
+            /// Returns the value as a string.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the labels of the value, in order.
+            pub fn labels(&self) -> impl Iterator<Item = &str> {
+                let labels = if $absolute { &self.0[1..] } else { &self.0[..] };
+                labels.split('/')
+            }
+        }
+
+        impl core::str::FromStr for $name {
+            type Err = InvalidIri;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = InvalidIri;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsnType for $name {
+            const TAG: Tag = Tag::$tag;
+            const IDENTIFIER: Identifier = Identifier::$identifier;
+        }
+
+        impl Encode for $name {
+            fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+                &self,
+                encoder: &mut E,
+                tag: Tag,
+                constraints: Constraints,
+                identifier: Identifier,
+            ) -> Result<(), E::Error> {
+                encoder
+                    .encode_utf8_string(tag, constraints, &self.0, identifier.or(Self::IDENTIFIER))
+                    .map(drop)
+            }
+        }
+
+        impl Decode for $name {
+            fn decode_with_tag_and_constraints<D: Decoder>(
+                decoder: &mut D,
+                tag: Tag,
+                constraints: Constraints,
+            ) -> Result<Self, D::Error> {
+                let iri = decoder.decode_utf8_string(tag, constraints)?;
+                validate(&iri, $absolute).map_err(|error| {
+                    DecodeError::string_conversion_failed(
+                        tag,
+                        alloc::format!("{error}: {iri:?}"),
+                        decoder.codec(),
+                    )
+                })?;
+
+                Ok(Self(iri))
+            }
+        }
+    };
+}
+
+iri!(OidIri, true, OID_IRI, IRI);
+iri!(RelativeOidIri, false, RELATIVE_OID_IRI, RELATIVE_IRI);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let iri = OidIri::new("/ISO/Registration_Authority/19785.CBEFF").unwrap();
+        let encoded = crate::ber::encode(&iri).unwrap();
+
+        // OID-IRI's tag number, 35, needs the high tag number form.
+        assert_eq!(&[0x1F, 0x23, 0x27, b'/', b'I', b'S', b'O'], &encoded[..7]);
+        assert_eq!(iri, crate::ber::decode(&encoded).unwrap());
+        assert_eq!(
+            ["ISO", "Registration_Authority", "19785.CBEFF"],
+            *iri.labels().collect::<alloc::vec::Vec<_>>()
+        );
+
+        let relative = RelativeOidIri::new("Зб/42").unwrap();
+        let encoded = crate::ber::encode(&relative).unwrap();
+        assert_eq!(&[0x1F, 0x24], &encoded[..2]);
+        assert_eq!(relative, crate::ber::decode(&encoded).unwrap());
+        assert_eq!(
+            relative,
+            crate::uper::decode(&crate::uper::encode(&relative).unwrap()).unwrap()
+        );
+        assert_eq!(
+            relative,
+            crate::oer::decode(&crate::oer::encode(&relative).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(Err(InvalidIri::Empty), OidIri::new(""));
+        assert_eq!(Err(InvalidIri::Empty), RelativeOidIri::new(""));
+        assert_eq!(Err(InvalidIri::InvalidStart), OidIri::new("ISO"));
+        assert_eq!(Err(InvalidIri::InvalidStart), RelativeOidIri::new("/ISO"));
+        assert_eq!(Err(InvalidIri::EmptyLabel), OidIri::new("/"));
+        assert_eq!(Err(InvalidIri::EmptyLabel), OidIri::new("/ISO//19785"));
+        assert_eq!(Err(InvalidIri::EmptyLabel), RelativeOidIri::new("ISO/"));
+
+        // A UTF8String that isn't a valid OID-IRI is rejected when decoding.
+        assert!(crate::ber::decode::<OidIri>(&[0x1F, 0x23, 0x03, b'I', b'S', b'O']).is_err());
+        assert!(crate::ber::decode::<RelativeOidIri>(&[0x1F, 0x24, 0x00]).is_err());
+        assert!(crate::ber::decode::<OidIri>(&[0x1F, 0x23, 0x04, b'/', b'I', b'S', b'O']).is_ok());
+        // The tag must match.
+        assert!(crate::ber::decode::<OidIri>(&[0x0C, 0x04, b'/', b'I', b'S', b'O']).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! `BTreeMap<K, V>`, encoded as `SEQUENCE OF SEQUENCE { key K, value V }`.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::{AsnType, Constraints, Constructed, Identifier, Tag};
+use crate::{
+    error::DecodeError,
+    types::fields::{Field, FieldPresence, Fields},
+    Decode, Decoder, Encode, Encoder,
+};
+
+/// A map, using the conventional `SEQUENCE OF SEQUENCE { key K, value V }`
+/// mapping. Entries are encoded in key order, and decoding a map that
+/// contains the same key twice is an error.
+/// ```
+/// use rasn::types::{Any, ObjectIdentifier};
+/// use std::collections::BTreeMap;
+///
+/// let mut extensions = BTreeMap::new();
+/// extensions.insert(
+///     ObjectIdentifier::new(vec![2, 5, 29, 19]).unwrap(),
+///     Any::new(vec![0x30, 0x00]),
+/// );
+/// let encoded = rasn::der::encode(&extensions).unwrap();
+///
+/// assert_eq!(
+///     &[0x30, 0x09, 0x30, 0x07, 0x06, 0x03, 0x55, 0x1D, 0x13, 0x30, 0x00],
+///     &*encoded
+/// );
+/// assert_eq!(extensions, rasn::der::decode(&encoded).unwrap());
+/// ```
+impl<K: AsnType, V: AsnType> AsnType for BTreeMap<K, V> {
+    const TAG: Tag = Tag::SEQUENCE;
+    const IDENTIFIER: Identifier = Identifier::SEQUENCE_OF;
+}
+
+impl<K: Decode + Ord, V: Decode> Decode for BTreeMap<K, V> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let entries = decoder.decode_sequence_of::<Entry<K, V>>(tag, constraints)?;
+        let mut map = Self::new();
+
+        for Entry { key, value } in entries {
+            if map.insert(key, value).is_some() {
+                return Err(DecodeError::duplicate_map_key(decoder.codec()).into());
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+impl<K: Encode, V: Encode> Encode for BTreeMap<K, V> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        let entries: Vec<_> = self
+            .iter()
+            .map(|(key, value)| Entry { key, value })
+            .collect();
+
+        encoder
+            .encode_sequence_of(tag, &entries, constraints, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
+/// A single `SEQUENCE { key K, value V }` entry of a map.
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K: AsnType, V: AsnType> AsnType for Entry<K, V> {
+    const TAG: Tag = Tag::SEQUENCE;
+    const IDENTIFIER: Identifier = Identifier::SEQUENCE;
+}
+
+impl<K: Decode, V: Decode> Decode for Entry<K, V> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_sequence(tag, None::<fn() -> Self>, |sequence| {
+            let key = K::decode(sequence)?;
+            let value = V::decode(sequence)?;
+
+            Ok(Self { key, value })
+        })
+    }
+}
+
+impl<K: Encode, V: Encode> Encode for Entry<K, V> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_sequence::<2, 0, Self, _>(
+            tag,
+            |sequence| {
+                self.key.encode(sequence)?;
+                self.value.encode(sequence)?;
+                Ok(())
+            },
+            identifier.or(Self::IDENTIFIER),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<K: AsnType, V: AsnType> Constructed<2, 0> for Entry<K, V> {
+    const FIELDS: Fields<2> = Fields::from_static([
+        Field {
+            index: 0,
+            tag: K::TAG,
+            tag_tree: K::TAG_TREE,
+            presence: FieldPresence::Required,
+            name: "key",
+        },
+        Field {
+            index: 1,
+            tag: V::TAG,
+            tag_tree: V::TAG_TREE,
+            presence: FieldPresence::Required,
+            name: "value",
+        },
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::DecodeErrorKind,
+        types::{Any, ObjectIdentifier},
+    };
+
+    fn extensions() -> BTreeMap<ObjectIdentifier, Any> {
+        let mut map = BTreeMap::new();
+        map.insert(
+            ObjectIdentifier::new(alloc::vec![2, 5, 29, 19]).unwrap(),
+            Any::new(alloc::vec![0x01, 0x01, 0xFF]),
+        );
+        map.insert(
+            ObjectIdentifier::new(alloc::vec![2, 5, 29, 15]).unwrap(),
+            Any::new(alloc::vec![0x05, 0x00]),
+        );
+        map
+    }
+
+    #[test]
+    fn oid_to_any() {
+        let map = extensions();
+        let expected = &[
+            0x30, 0x13, 0x30, 0x07, 0x06, 0x03, 0x55, 0x1D, 0x0F, 0x05, 0x00, 0x30, 0x08, 0x06,
+            0x03, 0x55, 0x1D, 0x13, 0x01, 0x01, 0xFF,
+        ];
+
+        let encoded = crate::der::encode(&map).unwrap();
+        assert_eq!(expected, &*encoded);
+        assert_eq!(map, crate::der::decode(&encoded).unwrap());
+        assert_eq!(map, crate::ber::decode(&encoded).unwrap());
+
+        let encoded = crate::cer::encode(&map).unwrap();
+        assert_eq!(map, crate::cer::decode(&encoded).unwrap());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut map = BTreeMap::new();
+        map.insert(7u8, true);
+        map.insert(1u8, false);
+        map.insert(200u8, true);
+
+        macro_rules! round_trip {
+            ($($codec:ident),+ $(,)?) => {
+                $(
+                    let encoded = crate::$codec::encode(&map).unwrap();
+                    assert_eq!(map, crate::$codec::decode::<BTreeMap<u8, bool>>(&encoded).unwrap());
+                )+
+            }
+        }
+
+        round_trip!(ber, der, cer, oer, coer, uper, aper);
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let entries = alloc::vec![(1u8, true), (2u8, false), (1u8, false)];
+        let encoded = crate::ber::encode(&entries).unwrap();
+        let error = crate::ber::decode::<BTreeMap<u8, bool>>(&encoded).unwrap_err();
+
+        assert!(matches!(*error.kind, DecodeErrorKind::DuplicateMapKey));
+    }
+}
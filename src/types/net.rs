@@ -0,0 +1,202 @@
+//! IP addresses as `OCTET STRING`s of their network order octets, as used by
+//! e.g. SNMP's `IpAddress` and X.509's `iPAddress` general name.
+
+use alloc::borrow::Cow;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::{AsnType, Constraints, FixedOctetString, Identifier, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+macro_rules! impl_fixed_ip_addr {
+    ($($addr:ty => $len:literal),+ $(,)?) => {
+        $(
+            /// Encoded as an `OCTET STRING` of exactly
+            #[doc = concat!(stringify!($len), " octets.")]
+            impl AsnType for $addr {
+                const TAG: Tag = FixedOctetString::<$len>::TAG;
+                const CONSTRAINTS: Constraints = FixedOctetString::<$len>::CONSTRAINTS;
+                const IDENTIFIER: Identifier = FixedOctetString::<$len>::IDENTIFIER;
+            }
+
+            impl Decode for $addr {
+                fn decode_with_tag_and_constraints<D: Decoder>(
+                    decoder: &mut D,
+                    tag: Tag,
+                    constraints: Constraints,
+                ) -> Result<Self, D::Error> {
+                    FixedOctetString::<$len>::decode_with_tag_and_constraints(
+                        decoder,
+                        tag,
+                        constraints,
+                    )
+                    .map(|octets| Self::from(*octets))
+                }
+            }
+
+            impl Encode for $addr {
+                fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+                    &self,
+                    encoder: &mut E,
+                    tag: Tag,
+                    constraints: Constraints,
+                    identifier: Identifier,
+                ) -> Result<(), E::Error> {
+                    encoder
+                        .encode_octet_string(
+                            tag,
+                            constraints,
+                            &self.octets(),
+                            identifier.or(Self::IDENTIFIER),
+                        )
+                        .map(drop)
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_ip_addr! {
+    Ipv4Addr => 4,
+    Ipv6Addr => 16,
+}
+
+/// Encoded as an `OCTET STRING` of 4 octets for an IPv4 address, or 16 octets
+/// for an IPv6 address.
+impl AsnType for IpAddr {
+    const TAG: Tag = Tag::OCTET_STRING;
+    const IDENTIFIER: Identifier = Identifier::OCTET_STRING;
+}
+
+impl Decode for IpAddr {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let codec = decoder.codec();
+        let octets = decoder.decode_octet_string::<Cow<[u8]>>(tag, constraints)?;
+
+        if let Ok(octets) = <[u8; 4]>::try_from(&*octets) {
+            Ok(Self::from(octets))
+        } else if let Ok(octets) = <[u8; 16]>::try_from(&*octets) {
+            Ok(Self::from(octets))
+        } else {
+            Err(crate::error::DecodeError::invalid_ip_address_length(octets.len(), codec).into())
+        }
+    }
+}
+
+impl Encode for IpAddr {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        match self {
+            Self::V4(addr) => addr.encode_with_tag_and_constraints(
+                encoder,
+                tag,
+                constraints,
+                identifier.or(Self::IDENTIFIER),
+            ),
+            Self::V6(addr) => addr.encode_with_tag_and_constraints(
+                encoder,
+                tag,
+                constraints,
+                identifier.or(Self::IDENTIFIER),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::DecodeErrorKind, types::Implicit};
+
+    macro_rules! round_trip {
+        ($value:expr, $ty:ty) => {{
+            let value: $ty = $value;
+            for encoded in [
+                crate::ber::encode(&value).unwrap(),
+                crate::der::encode(&value).unwrap(),
+            ] {
+                assert_eq!(value, crate::ber::decode::<$ty>(&encoded).unwrap());
+            }
+            assert_eq!(
+                value,
+                crate::uper::decode::<$ty>(&crate::uper::encode(&value).unwrap()).unwrap()
+            );
+            assert_eq!(
+                value,
+                crate::oer::decode::<$ty>(&crate::oer::encode(&value).unwrap()).unwrap()
+            );
+        }};
+    }
+
+    #[test]
+    fn round_trips() {
+        let v4 = Ipv4Addr::new(10, 11, 12, 13);
+        let v6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+
+        round_trip!(v4, Ipv4Addr);
+        round_trip!(v6, Ipv6Addr);
+        round_trip!(IpAddr::V4(v4), IpAddr);
+        round_trip!(IpAddr::V6(v6), IpAddr);
+
+        assert_eq!(
+            &[0x04, 0x04, 10, 11, 12, 13][..],
+            crate::der::encode(&IpAddr::V4(v4)).unwrap()
+        );
+        assert_eq!(
+            crate::der::encode(&v6).unwrap(),
+            crate::der::encode(&IpAddr::V6(v6)).unwrap()
+        );
+    }
+
+    #[test]
+    fn application_tagged() {
+        // SNMP's `IpAddress ::= [APPLICATION 0] IMPLICIT OCTET STRING (SIZE (4))`
+        #[derive(Debug, PartialEq)]
+        struct Application0;
+
+        impl AsnType for Application0 {
+            const TAG: Tag = Tag::new_application(0);
+        }
+
+        type IpAddress = Implicit<Application0, Ipv4Addr>;
+
+        let address = IpAddress::new(Ipv4Addr::new(10, 11, 12, 13));
+        let encoded = crate::ber::encode(&address).unwrap();
+        assert_eq!(&[0x40, 0x04, 10, 11, 12, 13][..], encoded);
+        assert_eq!(address, crate::ber::decode::<IpAddress>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn wrong_length() {
+        let data = &[0x04, 0x05, 1, 2, 3, 4, 5];
+
+        assert!(matches!(
+            *crate::ber::decode::<Ipv4Addr>(data).unwrap_err().kind,
+            DecodeErrorKind::FixedStringConversionFailed {
+                actual: 5,
+                expected: 4,
+                ..
+            }
+        ));
+        assert!(matches!(
+            *crate::ber::decode::<Ipv6Addr>(data).unwrap_err().kind,
+            DecodeErrorKind::FixedStringConversionFailed {
+                actual: 5,
+                expected: 16,
+                ..
+            }
+        ));
+        assert!(matches!(
+            *crate::ber::decode::<IpAddr>(data).unwrap_err().kind,
+            DecodeErrorKind::InvalidIpAddressLength { length: 5 }
+        ));
+    }
+}
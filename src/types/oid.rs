@@ -1,18 +1,47 @@
+//! Object identifiers, and named constants for commonly used ones.
+//!
+//! The constants are grouped into modules, each covering one arc and named
+//! after that arc's conventional name, or after the document that registers
+//! it. Each constant is named after the ASN.1 value it corresponds to, without
+//! the arc's prefix (e.g. `id-ce-`, `id-at-`, or `id-`), in upper snake case
+//! with a word break before every capital (e.g. `sha256WithRSAEncryption`
+//! becomes `SHA256_WITH_RSA_ENCRYPTION`).
+//!
+//! ```
+//! use rasn::types::{oid, ObjectIdentifier};
+//!
+//! let decoded: ObjectIdentifier = rasn::der::decode(&[0x06, 0x03, 0x55, 0x1D, 0x0F]).unwrap();
+//! assert_eq!(oid::x509_ce::KEY_USAGE, decoded);
+//! assert_eq!("1.3.6.1.2.1.1", oid::mib2::SYSTEM.to_string());
+//! ```
 #![allow(clippy::unreadable_literal)]
 use core::ops;
 
-pub(crate) const MAX_OID_FIRST_OCTET: u32 = 2;
-pub(crate) const MAX_OID_SECOND_OCTET: u32 = 39;
+mod well_known;
 
-const fn is_valid_oid(slice: &[u32]) -> bool {
+pub use self::well_known::*;
+
+pub(crate) const MAX_OID_FIRST_OCTET: u128 = 2;
+pub(crate) const MAX_OID_SECOND_OCTET: u128 = 39;
+
+const fn is_valid_oid(slice: &[u128]) -> bool {
     !slice.is_empty() && slice[0] <= MAX_OID_FIRST_OCTET
 }
 
+/// Whether `slice` is a complete object identifier as defined by X.660, i.e.
+/// it has at least two arcs, the first arc is 0, 1, or 2, and the second arc
+/// is less than 40 when the first arc is 0 or 1.
+pub(crate) const fn is_valid_object_identifier(slice: &[u128]) -> bool {
+    slice.len() >= 2
+        && slice[0] <= MAX_OID_FIRST_OCTET
+        && (slice[0] == MAX_OID_FIRST_OCTET || slice[1] <= MAX_OID_SECOND_OCTET)
+}
+
 /// A reference to a global unique identifier that identifies an concept, such
 /// as a organisation, or encoding rules.
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[repr(transparent)]
-pub struct Oid([u32]);
+pub struct Oid([u128]);
 
 impl Oid {
     /// Creates a new reference to a object identifier from `slice`.
@@ -25,7 +54,7 @@ impl Oid {
     /// let internet = Oid::new(&[1, 3, 6, 1]).unwrap();
     /// ```
     #[must_use]
-    pub const fn new(slice: &[u32]) -> Option<&Self> {
+    pub const fn new(slice: &[u128]) -> Option<&Self> {
         if is_valid_oid(slice) {
             Some(Self::new_unchecked(slice))
         } else {
@@ -38,7 +67,7 @@ impl Oid {
     /// Panics if `vec` is empty or the first
     /// component is greater than 2.
     #[must_use]
-    pub const fn const_new(oid: &'static [u32]) -> &'static Self {
+    pub const fn const_new(oid: &'static [u128]) -> &'static Self {
         match Self::new(oid) {
             Some(oid) => oid,
             None => panic!("not a valid OID"),
@@ -54,7 +83,7 @@ impl Oid {
     ///
     /// let internet = Oid::new(&[1, 3, 6, 1]).unwrap();
     /// ```
-    pub fn new_mut(slice: &mut [u32]) -> Option<&mut Self> {
+    pub fn new_mut(slice: &mut [u128]) -> Option<&mut Self> {
         if is_valid_oid(slice) {
             Some(Self::new_unchecked_mut(slice))
         } else {
@@ -68,8 +97,8 @@ impl Oid {
     /// This allows you to create potentially invalid object identifiers which
     /// may affect encoding validity.
     #[must_use]
-    pub const fn new_unchecked(slice: &[u32]) -> &Self {
-        unsafe { &*(core::ptr::from_ref::<[u32]>(slice) as *const Self) }
+    pub const fn new_unchecked(slice: &[u128]) -> &Self {
+        unsafe { &*(core::ptr::from_ref::<[u128]>(slice) as *const Self) }
     }
 
     /// Creates a new object identifier from `slice`.
@@ -77,8 +106,53 @@ impl Oid {
     /// # Safety
     /// This allows you to create potentially invalid object identifiers which
     /// may affect encoding validity.
-    pub fn new_unchecked_mut(slice: &mut [u32]) -> &mut Self {
-        unsafe { &mut *(core::ptr::from_mut::<[u32]>(slice) as *mut Self) }
+    pub fn new_unchecked_mut(slice: &mut [u128]) -> &mut Self {
+        unsafe { &mut *(core::ptr::from_mut::<[u128]>(slice) as *mut Self) }
+    }
+
+    /// Returns the object identifier this one is directly under, or `None` if
+    /// it only has a single arc. Use `starts_with`, `iter`, and `len` from
+    /// the arcs' slice to test for other relationships.
+    /// ```
+    /// use rasn::types::{ObjectIdentifier, Oid};
+    ///
+    /// let oid = ObjectIdentifier::new(vec![1, 3, 6, 1, 4, 1, 32473]).unwrap();
+    /// assert_eq!(oid.parent(), Oid::new(&[1, 3, 6, 1, 4, 1]));
+    /// assert_eq!(Oid::ISO.parent(), None);
+    ///
+    /// assert!(oid.starts_with(Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET));
+    /// assert!(oid.starts_with(&oid));
+    /// assert!(oid.starts_with(&[]));
+    /// assert!(!oid.starts_with(&[1, 3, 6, 1, 4, 1, 32473, 1]));
+    /// assert_eq!(oid.len(), 7);
+    /// assert_eq!(oid.iter().last(), Some(&32473));
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<&Self> {
+        match self.0.len() {
+            0 | 1 => None,
+            len => Some(Self::new_unchecked(&self.0[..len - 1])),
+        }
+    }
+
+    /// Returns the object identifier of `arc` directly under this one, or
+    /// `None` if it would not be a valid object identifier, i.e. when `arc` is
+    /// greater than 39 under the `0` and `1` roots.
+    /// ```
+    /// use rasn::types::Oid;
+    ///
+    /// let internet = Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET;
+    /// let private = internet.child(4).unwrap();
+    /// assert_eq!(private, Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_PRIVATE);
+    /// assert_eq!(private.parent(), Some(internet));
+    /// assert_eq!(*Oid::JOINT_ISO_ITU_T.child(999).unwrap(), [2, 999]);
+    /// assert!(Oid::ISO.child(40).is_none());
+    /// ```
+    #[must_use]
+    pub fn child(&self, arc: u128) -> Option<ObjectIdentifier> {
+        let mut arcs = Arcs::from_slice(&self.0);
+        arcs.push(arc);
+        ObjectIdentifier::from_arcs(arcs)
     }
 }
 
@@ -106,29 +180,29 @@ impl alloc::borrow::ToOwned for Oid {
     type Owned = ObjectIdentifier;
 
     fn to_owned(&self) -> Self::Owned {
-        Self::Owned::new_unchecked(self.0.to_owned().into())
+        ObjectIdentifier(Arcs::from_slice(&self.0))
     }
 }
 
-impl AsRef<[u32]> for Oid {
-    fn as_ref(&self) -> &[u32] {
+impl AsRef<[u128]> for Oid {
+    fn as_ref(&self) -> &[u128] {
         self.0.as_ref()
     }
 }
 
-impl PartialEq<[u32]> for Oid {
-    fn eq(&self, rhs: &[u32]) -> bool {
+impl PartialEq<[u128]> for Oid {
+    fn eq(&self, rhs: &[u128]) -> bool {
         &self.0 == rhs
     }
 }
 
-impl<const N: usize> PartialEq<[u32; N]> for Oid {
-    fn eq(&self, rhs: &[u32; N]) -> bool {
+impl<const N: usize> PartialEq<[u128; N]> for Oid {
+    fn eq(&self, rhs: &[u128; N]) -> bool {
         &self.0 == rhs
     }
 }
 
-impl PartialEq<Oid> for [u32] {
+impl PartialEq<Oid> for [u128] {
     fn eq(&self, rhs: &Oid) -> bool {
         self == &rhs.0
     }
@@ -152,14 +226,14 @@ impl PartialEq<Oid> for &ObjectIdentifier {
     }
 }
 
-impl<const N: usize> PartialEq<Oid> for [u32; N] {
+impl<const N: usize> PartialEq<Oid> for [u128; N] {
     fn eq(&self, rhs: &Oid) -> bool {
         self == &rhs.0
     }
 }
 
 impl ops::Deref for Oid {
-    type Target = [u32];
+    type Target = [u128];
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -174,17 +248,32 @@ impl ops::DerefMut for Oid {
 
 /// A global unique identifier that identifies an concept, such as a
 /// organisation, or encoding rules. The "owned" version of [`Oid`].
-#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-pub struct ObjectIdentifier(alloc::borrow::Cow<'static, [u32]>);
+///
+/// Each arc is stored as a `u128`, which fits any arc in use, including those
+/// under `2.25` derived from UUIDs; decoding an identifier containing a larger
+/// arc returns an error.
+///
+/// Identifiers with up to ten arcs, which covers most identifiers in use, are
+/// stored inline without allocating.
+#[derive(Clone)]
+pub struct ObjectIdentifier(Arcs);
 
 impl ObjectIdentifier {
     /// Creates a new object identifier from `vec`.
     ///
-    /// Returns `None` if `vec` contains less than two components or the first
-    /// component is greater than 2.
-    pub fn new(arcs: impl Into<alloc::borrow::Cow<'static, [u32]>>) -> Option<Self> {
-        let arcs = arcs.into();
-        is_valid_oid(&arcs).then_some(Self(arcs))
+    /// Returns `None` if `vec` contains less than two components, the first
+    /// component is greater than 2, or the second component is greater than
+    /// 39 when the first component is 0 or 1.
+    /// ```
+    /// use rasn::types::ObjectIdentifier;
+    ///
+    /// assert!(ObjectIdentifier::new(vec![1, 3, 6, 1]).is_some());
+    /// assert!(ObjectIdentifier::new(vec![1]).is_none());
+    /// assert!(ObjectIdentifier::new(vec![1, 40]).is_none());
+    /// assert!(ObjectIdentifier::new(vec![2, 999]).is_some());
+    /// ```
+    pub fn new(arcs: impl Into<alloc::borrow::Cow<'static, [u128]>>) -> Option<Self> {
+        Self::from_arcs(Arcs::from(arcs.into()))
     }
 
     /// Creates a new object identifier from `vec`.
@@ -193,8 +282,133 @@ impl ObjectIdentifier {
     /// This allows you to create potentially invalid object identifiers which
     /// may affect encoding validity.
     #[must_use]
-    pub const fn new_unchecked(vec: alloc::borrow::Cow<'static, [u32]>) -> Self {
-        Self(vec)
+    pub const fn new_unchecked(vec: alloc::borrow::Cow<'static, [u128]>) -> Self {
+        Self(Arcs::Cow(vec))
+    }
+
+    /// Creates a new object identifier from `arcs`, returning `None` if it
+    /// is not a valid object identifier.
+    pub(crate) fn from_arcs(arcs: Arcs) -> Option<Self> {
+        is_valid_object_identifier(&arcs).then_some(Self(arcs))
+    }
+}
+
+/// The number of arcs an [`ObjectIdentifier`] stores without allocating.
+///
+/// Arcs are dereferenced as `&[u128]`, so they have to be stored as `u128`
+/// and the inline arcs take 160 bytes. That is the price of not allocating
+/// for almost every identifier decoded, as the X.509 and PKCS identifiers
+/// that make up most of them have at most ten arcs.
+const INLINE_ARCS: usize = 10;
+
+/// The storage of an [`ObjectIdentifier`]'s arcs.
+#[derive(Clone)]
+pub(crate) enum Arcs {
+    /// Up to [`INLINE_ARCS`] arcs, stored without allocating.
+    Inline { len: u8, arcs: [u128; INLINE_ARCS] },
+    /// Static arcs, or arcs that don't fit inline.
+    Cow(alloc::borrow::Cow<'static, [u128]>),
+}
+
+impl Arcs {
+    pub(crate) const fn new() -> Self {
+        Self::Inline {
+            len: 0,
+            arcs: [0; INLINE_ARCS],
+        }
+    }
+
+    fn from_slice(slice: &[u128]) -> Self {
+        if slice.len() <= INLINE_ARCS {
+            let mut arcs = Self::new();
+            for &arc in slice {
+                arcs.push(arc);
+            }
+            arcs
+        } else {
+            Self::Cow(alloc::borrow::Cow::Owned(slice.to_vec()))
+        }
+    }
+
+    /// Appends `arc`, moving the arcs to the heap once they no longer fit
+    /// inline.
+    pub(crate) fn push(&mut self, arc: u128) {
+        match self {
+            Self::Inline { len, arcs } if usize::from(*len) < INLINE_ARCS => {
+                arcs[usize::from(*len)] = arc;
+                *len += 1;
+            }
+            Self::Cow(alloc::borrow::Cow::Owned(vec)) => vec.push(arc),
+            _ => {
+                let mut vec = alloc::vec::Vec::with_capacity(self.len() * 2);
+                vec.extend_from_slice(self);
+                vec.push(arc);
+                *self = Self::Cow(alloc::borrow::Cow::Owned(vec));
+            }
+        }
+    }
+
+    fn to_mut(&mut self) -> &mut [u128] {
+        if let Self::Cow(alloc::borrow::Cow::Borrowed(slice)) = *self {
+            *self = Self::from_slice(slice);
+        }
+
+        match self {
+            Self::Inline { len, arcs } => &mut arcs[..usize::from(*len)],
+            Self::Cow(cow) => cow.to_mut(),
+        }
+    }
+}
+
+impl From<alloc::borrow::Cow<'static, [u128]>> for Arcs {
+    fn from(arcs: alloc::borrow::Cow<'static, [u128]>) -> Self {
+        match arcs {
+            alloc::borrow::Cow::Owned(vec) if vec.len() <= INLINE_ARCS => Self::from_slice(&vec),
+            arcs => Self::Cow(arcs),
+        }
+    }
+}
+
+impl ops::Deref for Arcs {
+    type Target = [u128];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Inline { len, arcs } => &arcs[..usize::from(*len)],
+            Self::Cow(cow) => cow,
+        }
+    }
+}
+
+impl core::fmt::Debug for ObjectIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ObjectIdentifier").field(&&*self.0).finish()
+    }
+}
+
+impl PartialEq for ObjectIdentifier {
+    fn eq(&self, rhs: &Self) -> bool {
+        *self.0 == *rhs.0
+    }
+}
+
+impl Eq for ObjectIdentifier {}
+
+impl PartialOrd for ObjectIdentifier {
+    fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for ObjectIdentifier {
+    fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+        (*self.0).cmp(&*rhs.0)
+    }
+}
+
+impl core::hash::Hash for ObjectIdentifier {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (*self.0).hash(state);
     }
 }
 
@@ -211,9 +425,79 @@ impl core::fmt::Display for ObjectIdentifier {
     }
 }
 
-impl AsRef<[u32]> for ObjectIdentifier {
-    fn as_ref(&self) -> &[u32] {
-        self.0.as_ref()
+impl core::str::FromStr for ObjectIdentifier {
+    type Err = ParseOidError;
+
+    /// Parses an object identifier from its dot separated form.
+    /// ```
+    /// use rasn::types::{ObjectIdentifier, Oid};
+    ///
+    /// let oid: ObjectIdentifier = "1.3.6.1".parse().unwrap();
+    /// assert_eq!(oid, Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut arcs = Arcs::new();
+        for arc in s.split('.') {
+            if arc.is_empty() || !arc.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(ParseOidError::InvalidArc);
+            }
+
+            arcs.push(arc.parse::<u128>().map_err(|_| ParseOidError::InvalidArc)?);
+        }
+
+        if arcs.len() < 2 {
+            return Err(ParseOidError::TooFewArcs);
+        }
+
+        if arcs[0] > MAX_OID_FIRST_OCTET {
+            return Err(ParseOidError::InvalidRoot);
+        }
+
+        Self::from_arcs(arcs).ok_or(ParseOidError::InvalidSecondArc)
+    }
+}
+
+impl TryFrom<&str> for ObjectIdentifier {
+    type Error = ParseOidError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// The error returned when parsing an [`ObjectIdentifier`] from a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseOidError {
+    /// An arc was empty, contained something other than decimal digits, or
+    /// didn't fit in a `u128`.
+    InvalidArc,
+    /// The identifier contained less than two arcs.
+    TooFewArcs,
+    /// The first arc was greater than 2.
+    InvalidRoot,
+    /// The second arc was greater than 39 under the `0` or `1` root.
+    InvalidSecondArc,
+}
+
+impl core::fmt::Display for ParseOidError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InvalidArc => "object identifier arcs must be non-empty decimal numbers",
+            Self::TooFewArcs => "object identifier must contain at least two arcs",
+            Self::InvalidRoot => "object identifier root arc must be 0, 1, or 2",
+            Self::InvalidSecondArc => {
+                "object identifier second arc must be less than 40 under the 0 and 1 roots"
+            }
+        })
+    }
+}
+
+impl core::error::Error for ParseOidError {}
+
+impl AsRef<[u128]> for ObjectIdentifier {
+    fn as_ref(&self) -> &[u128] {
+        &self.0
     }
 }
 
@@ -243,7 +527,7 @@ impl ops::DerefMut for ObjectIdentifier {
     }
 }
 
-impl<const N: usize> PartialEq<ObjectIdentifier> for [u32; N] {
+impl<const N: usize> PartialEq<ObjectIdentifier> for [u128; N] {
     fn eq(&self, rhs: &ObjectIdentifier) -> bool {
         self == &**rhs
     }
@@ -261,9 +545,9 @@ impl PartialEq<ObjectIdentifier> for &Oid {
     }
 }
 
-impl PartialEq<[u32]> for ObjectIdentifier {
-    fn eq(&self, rhs: &[u32]) -> bool {
-        self.0 == rhs
+impl PartialEq<[u128]> for ObjectIdentifier {
+    fn eq(&self, rhs: &[u128]) -> bool {
+        *self.0 == *rhs
     }
 }
 
@@ -764,6 +1048,8 @@ oids! {
     JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_KMAC256 => 2, 16, 840, 1, 101, 3, 4, 2, 22;
 
     JOINT_ISO_ITU_T_REGISTRATION_PROCEDURES_MODULE_DIRECTORY_DEFS => 2, 17, 1, 2;
+
+    JOINT_ISO_ITU_T_UUID => 2, 25;
 }
 
 #[cfg(test)]
@@ -775,9 +1061,21 @@ mod test {
     fn transmute() {
         let mut oid = ObjectIdentifier::new_unchecked(alloc::vec![1, 3, 6].into());
 
-        assert_eq!([1u32, 3, 6][..], *oid);
+        assert_eq!([1u128, 3, 6][..], *oid);
+        oid.reverse();
+        assert_eq!([6u128, 3, 1][..], *oid);
+    }
+
+    #[test]
+    fn const_new_unchecked() {
+        const INTERNET: ObjectIdentifier =
+            ObjectIdentifier::new_unchecked(alloc::borrow::Cow::Borrowed(&[1, 3, 6, 1]));
+
+        let mut oid = INTERNET;
+        assert_eq!(Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET, oid);
         oid.reverse();
-        assert_eq!([6u32, 3, 1][..], *oid);
+        assert_eq!([1u128, 6, 3, 1][..], *oid);
+        assert_eq!(Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET, INTERNET);
     }
 
     #[test]
@@ -801,4 +1099,146 @@ mod test {
             ObjectIdentifier::new(vec![1, 2]).unwrap()
         );
     }
+
+    #[test]
+    fn from_str() {
+        use super::ParseOidError;
+        use alloc::string::ToString;
+
+        for oid in [
+            "0.0",
+            "1.2.840.113549.1.1.11",
+            "2.999.1",
+            "2.25.4294967295",
+            "2.25.4294967296",
+            "2.25.329800735698586629295641978511506172918",
+            "1.3.6.1.4.1.311.21.20",
+        ] {
+            let parsed: ObjectIdentifier = oid.parse().unwrap();
+            assert_eq!(oid, parsed.to_string());
+            assert_eq!(
+                parsed,
+                parsed.to_string().parse::<ObjectIdentifier>().unwrap()
+            );
+            assert_eq!(parsed, ObjectIdentifier::try_from(oid).unwrap());
+        }
+
+        for (oid, error) in [
+            ("", ParseOidError::InvalidArc),
+            ("1..2", ParseOidError::InvalidArc),
+            ("1.2.", ParseOidError::InvalidArc),
+            ("1.+2", ParseOidError::InvalidArc),
+            ("1.2a", ParseOidError::InvalidArc),
+            ("1. 2", ParseOidError::InvalidArc),
+            (
+                "2.25.340282366920938463463374607431768211456",
+                ParseOidError::InvalidArc,
+            ),
+            ("1", ParseOidError::TooFewArcs),
+            ("3.1", ParseOidError::InvalidRoot),
+            ("3.40", ParseOidError::InvalidRoot),
+            ("1.40", ParseOidError::InvalidSecondArc),
+            ("0.99.1", ParseOidError::InvalidSecondArc),
+        ] {
+            assert_eq!(Err(error), oid.parse::<ObjectIdentifier>(), "{oid}");
+        }
+    }
+
+    #[test]
+    fn borrow() {
+        let mut names = alloc::collections::BTreeMap::new();
+        names.insert(ObjectIdentifier::from(Oid::ISO_MEMBER_BODY), "member-body");
+        names.insert(
+            ObjectIdentifier::from(Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA),
+            "rsaEncryption",
+        );
+
+        assert_eq!(
+            Some(&"rsaEncryption"),
+            names.get(Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA)
+        );
+        assert_eq!(None, names.get(Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1));
+
+        const RSA: &Oid = Oid::const_new(&[1, 2, 840, 113549, 1, 1, 1]);
+        let decoded = ObjectIdentifier::new(alloc::vec![1, 2, 840, 113549, 1, 1, 1]).unwrap();
+        assert!(decoded == RSA);
+        assert!(decoded != Oid::ISO_MEMBER_BODY);
+        assert_eq!(RSA, &*decoded);
+    }
+
+    #[test]
+    fn oid_macro() {
+        const SHA256_WITH_RSA: &Oid = crate::oid!(1.2.840 .113549 .1 .1 .11);
+        const JOINT_ISO: &Oid = crate::oid!(2.999);
+
+        assert_eq!(
+            ObjectIdentifier::new(alloc::vec![1, 2, 840, 113549, 1, 1, 11]).unwrap(),
+            SHA256_WITH_RSA
+        );
+        assert_eq!(SHA256_WITH_RSA, crate::oid!("1.2.840.113549.1.1.11"));
+        assert_eq!(SHA256_WITH_RSA, crate::oid!(".1.2.840.113549.1.1.11"));
+        assert_eq!(Oid::ISO_MEMBER_BODY, crate::oid!(1.2));
+        assert_eq!(&[2, 999], &JOINT_ISO[..]);
+    }
+
+    #[test]
+    fn storage_independent() {
+        use core::hash::{BuildHasher, Hash, Hasher};
+        use std::collections::hash_map::RandomState;
+
+        let state = RandomState::new();
+        let hash = |oid: &ObjectIdentifier| {
+            let mut hasher = state.build_hasher();
+            oid.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        for arcs in [
+            &[1, 2][..],
+            &[1, 2, 840, 113549, 1, 1, 11],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            &[
+                2, 25, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+            ],
+        ] {
+            let owned = ObjectIdentifier::new(arcs.to_vec()).unwrap();
+            let parsed = owned.to_string().parse::<ObjectIdentifier>().unwrap();
+            let borrowed = ObjectIdentifier::from(Oid::new(arcs).unwrap());
+            let mut grown = ObjectIdentifier::new(arcs[..2].to_vec()).unwrap();
+            for &arc in &arcs[2..] {
+                grown = grown.child(arc).unwrap();
+            }
+
+            for oid in [&parsed, &borrowed, &grown] {
+                assert_eq!(owned, *oid);
+                assert_eq!(arcs, &***oid);
+                assert_eq!(hash(&owned), hash(oid));
+                assert_eq!(core::cmp::Ordering::Equal, owned.cmp(oid));
+                assert_eq!(alloc::format!("{owned:?}"), alloc::format!("{oid:?}"));
+            }
+        }
+
+        let shorter = ObjectIdentifier::new(alloc::vec![1, 2, 3]).unwrap();
+        let longer = ObjectIdentifier::new((1..=12).collect::<alloc::vec::Vec<_>>()).unwrap();
+        assert!(shorter < longer);
+        assert_eq!("ObjectIdentifier([1, 2, 3])", alloc::format!("{shorter:?}"));
+
+        let mut oid = ObjectIdentifier::new(&[1, 2][..]).unwrap();
+        oid[1] = 3;
+        assert_eq!(Oid::ISO_IDENTIFIED_ORGANISATION, oid);
+        assert_eq!([1, 2], *Oid::ISO_MEMBER_BODY);
+    }
+
+    #[test]
+    fn invalid_shapes() {
+        assert!(ObjectIdentifier::new(alloc::vec![]).is_none());
+        assert!(ObjectIdentifier::new(alloc::vec![1]).is_none());
+        assert!(ObjectIdentifier::new(alloc::vec![7, 1]).is_none());
+        assert!(ObjectIdentifier::new(alloc::vec![0, 200]).is_none());
+        assert!(ObjectIdentifier::new(alloc::vec![1, 40, 1]).is_none());
+        assert!(ObjectIdentifier::new(alloc::vec![0, 39]).is_some());
+        assert!(ObjectIdentifier::new(alloc::vec![1, 39]).is_some());
+        assert!(ObjectIdentifier::new(alloc::vec![2, 200]).is_some());
+    }
 }
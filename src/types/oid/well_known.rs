@@ -0,0 +1,337 @@
+//! Modules of named object identifier constants, one per arc.
+
+use super::Oid;
+
+macro_rules! well_known {
+    ($(
+        $(#[$meta:meta])*
+        $module:ident {
+            $($name:ident = $value:literal => $first:literal $(, $arc:literal)*;)+
+        }
+    )+) => {
+        $(
+            $(#[$meta])*
+            pub mod $module {
+                use super::Oid;
+
+                $(
+                    #[doc = concat!(
+                        "`", $value, "` (`", stringify!($first) $(, ".", stringify!($arc))*, "`)."
+                    )]
+                    pub const $name: &Oid = Oid::const_new(&[$first $(, $arc)*]);
+                )+
+            }
+        )+
+    };
+}
+
+well_known! {
+    /// PKCS #1 (RFC 8017) RSA algorithms, under `1.2.840.113549.1.1`.
+    pkcs1 {
+        RSA_ENCRYPTION = "rsaEncryption" => 1, 2, 840, 113549, 1, 1, 1;
+        MD2_WITH_RSA_ENCRYPTION = "md2WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 2;
+        MD5_WITH_RSA_ENCRYPTION = "md5WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 4;
+        SHA1_WITH_RSA_ENCRYPTION = "sha1WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 5;
+        RSAES_OAEP = "id-RSAES-OAEP" => 1, 2, 840, 113549, 1, 1, 7;
+        MGF1 = "id-mgf1" => 1, 2, 840, 113549, 1, 1, 8;
+        P_SPECIFIED = "id-pSpecified" => 1, 2, 840, 113549, 1, 1, 9;
+        RSASSA_PSS = "id-RSASSA-PSS" => 1, 2, 840, 113549, 1, 1, 10;
+        SHA256_WITH_RSA_ENCRYPTION = "sha256WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 11;
+        SHA384_WITH_RSA_ENCRYPTION = "sha384WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 12;
+        SHA512_WITH_RSA_ENCRYPTION = "sha512WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 13;
+        SHA224_WITH_RSA_ENCRYPTION = "sha224WithRSAEncryption" => 1, 2, 840, 113549, 1, 1, 14;
+    }
+
+    /// PKCS #7 (RFC 2315) and CMS (RFC 5652) content types, under
+    /// `1.2.840.113549.1.7`.
+    pkcs7 {
+        DATA = "id-data" => 1, 2, 840, 113549, 1, 7, 1;
+        SIGNED_DATA = "id-signedData" => 1, 2, 840, 113549, 1, 7, 2;
+        ENVELOPED_DATA = "id-envelopedData" => 1, 2, 840, 113549, 1, 7, 3;
+        SIGNED_AND_ENVELOPED_DATA = "signedAndEnvelopedData" => 1, 2, 840, 113549, 1, 7, 4;
+        DIGESTED_DATA = "id-digestedData" => 1, 2, 840, 113549, 1, 7, 5;
+        ENCRYPTED_DATA = "id-encryptedData" => 1, 2, 840, 113549, 1, 7, 6;
+    }
+
+    /// PKCS #9 (RFC 2985) attribute types, under `1.2.840.113549.1.9`.
+    pkcs9 {
+        EMAIL_ADDRESS = "pkcs-9-at-emailAddress" => 1, 2, 840, 113549, 1, 9, 1;
+        UNSTRUCTURED_NAME = "pkcs-9-at-unstructuredName" => 1, 2, 840, 113549, 1, 9, 2;
+        CONTENT_TYPE = "pkcs-9-at-contentType" => 1, 2, 840, 113549, 1, 9, 3;
+        MESSAGE_DIGEST = "pkcs-9-at-messageDigest" => 1, 2, 840, 113549, 1, 9, 4;
+        SIGNING_TIME = "pkcs-9-at-signingTime" => 1, 2, 840, 113549, 1, 9, 5;
+        COUNTER_SIGNATURE = "pkcs-9-at-counterSignature" => 1, 2, 840, 113549, 1, 9, 6;
+        CHALLENGE_PASSWORD = "pkcs-9-at-challengePassword" => 1, 2, 840, 113549, 1, 9, 7;
+        UNSTRUCTURED_ADDRESS = "pkcs-9-at-unstructuredAddress" => 1, 2, 840, 113549, 1, 9, 8;
+        EXTENSION_REQUEST = "pkcs-9-at-extensionRequest" => 1, 2, 840, 113549, 1, 9, 14;
+        SMIME_CAPABILITIES = "pkcs-9-at-smimeCapabilities" => 1, 2, 840, 113549, 1, 9, 15;
+        FRIENDLY_NAME = "pkcs-9-at-friendlyName" => 1, 2, 840, 113549, 1, 9, 20;
+        LOCAL_KEY_ID = "pkcs-9-at-localKeyId" => 1, 2, 840, 113549, 1, 9, 21;
+    }
+
+    /// ANSI X9.62 (RFC 5480, RFC 5758) elliptic curve keys and signatures,
+    /// under `1.2.840.10045`.
+    ansi_x962 {
+        EC_PUBLIC_KEY = "id-ecPublicKey" => 1, 2, 840, 10045, 2, 1;
+        PRIME256V1 = "prime256v1" => 1, 2, 840, 10045, 3, 1, 7;
+        ECDSA_WITH_SHA1 = "ecdsa-with-SHA1" => 1, 2, 840, 10045, 4, 1;
+        ECDSA_WITH_SHA224 = "ecdsa-with-SHA224" => 1, 2, 840, 10045, 4, 3, 1;
+        ECDSA_WITH_SHA256 = "ecdsa-with-SHA256" => 1, 2, 840, 10045, 4, 3, 2;
+        ECDSA_WITH_SHA384 = "ecdsa-with-SHA384" => 1, 2, 840, 10045, 4, 3, 3;
+        ECDSA_WITH_SHA512 = "ecdsa-with-SHA512" => 1, 2, 840, 10045, 4, 3, 4;
+    }
+
+    /// SEC 2 elliptic curves, under `1.3.132.0`.
+    secg {
+        SECP256K1 = "secp256k1" => 1, 3, 132, 0, 10;
+        SECP384R1 = "secp384r1" => 1, 3, 132, 0, 34;
+        SECP521R1 = "secp521r1" => 1, 3, 132, 0, 35;
+    }
+
+    /// RFC 8410 Edwards and Montgomery curve algorithms, under `1.3.101`.
+    rfc8410 {
+        X25519 = "id-X25519" => 1, 3, 101, 110;
+        X448 = "id-X448" => 1, 3, 101, 111;
+        ED25519 = "id-Ed25519" => 1, 3, 101, 112;
+        ED448 = "id-Ed448" => 1, 3, 101, 113;
+    }
+
+    /// OIW secure hash algorithms, under `1.3.14.3.2`.
+    oiw {
+        SHA1 = "id-sha1" => 1, 3, 14, 3, 2, 26;
+    }
+
+    /// NIST hash algorithms, under `2.16.840.1.101.3.4.2`.
+    nist_hash {
+        SHA256 = "id-sha256" => 2, 16, 840, 1, 101, 3, 4, 2, 1;
+        SHA384 = "id-sha384" => 2, 16, 840, 1, 101, 3, 4, 2, 2;
+        SHA512 = "id-sha512" => 2, 16, 840, 1, 101, 3, 4, 2, 3;
+        SHA224 = "id-sha224" => 2, 16, 840, 1, 101, 3, 4, 2, 4;
+        SHA512_224 = "id-sha512-224" => 2, 16, 840, 1, 101, 3, 4, 2, 5;
+        SHA512_256 = "id-sha512-256" => 2, 16, 840, 1, 101, 3, 4, 2, 6;
+        SHA3_224 = "id-sha3-224" => 2, 16, 840, 1, 101, 3, 4, 2, 7;
+        SHA3_256 = "id-sha3-256" => 2, 16, 840, 1, 101, 3, 4, 2, 8;
+        SHA3_384 = "id-sha3-384" => 2, 16, 840, 1, 101, 3, 4, 2, 9;
+        SHA3_512 = "id-sha3-512" => 2, 16, 840, 1, 101, 3, 4, 2, 10;
+    }
+
+    /// NIST AES modes (RFC 3565, RFC 5084), under `2.16.840.1.101.3.4.1`.
+    nist_aes {
+        AES128_CBC = "id-aes128-CBC" => 2, 16, 840, 1, 101, 3, 4, 1, 2;
+        AES128_GCM = "id-aes128-GCM" => 2, 16, 840, 1, 101, 3, 4, 1, 6;
+        AES192_CBC = "id-aes192-CBC" => 2, 16, 840, 1, 101, 3, 4, 1, 22;
+        AES192_GCM = "id-aes192-GCM" => 2, 16, 840, 1, 101, 3, 4, 1, 26;
+        AES256_CBC = "id-aes256-CBC" => 2, 16, 840, 1, 101, 3, 4, 1, 42;
+        AES256_GCM = "id-aes256-GCM" => 2, 16, 840, 1, 101, 3, 4, 1, 46;
+    }
+
+    /// X.509 certificate and CRL extensions (RFC 5280), under `2.5.29`.
+    x509_ce {
+        SUBJECT_DIRECTORY_ATTRIBUTES = "id-ce-subjectDirectoryAttributes" => 2, 5, 29, 9;
+        SUBJECT_KEY_IDENTIFIER = "id-ce-subjectKeyIdentifier" => 2, 5, 29, 14;
+        KEY_USAGE = "id-ce-keyUsage" => 2, 5, 29, 15;
+        PRIVATE_KEY_USAGE_PERIOD = "id-ce-privateKeyUsagePeriod" => 2, 5, 29, 16;
+        SUBJECT_ALT_NAME = "id-ce-subjectAltName" => 2, 5, 29, 17;
+        ISSUER_ALT_NAME = "id-ce-issuerAltName" => 2, 5, 29, 18;
+        BASIC_CONSTRAINTS = "id-ce-basicConstraints" => 2, 5, 29, 19;
+        CRL_NUMBER = "id-ce-cRLNumber" => 2, 5, 29, 20;
+        CRL_REASONS = "id-ce-cRLReasons" => 2, 5, 29, 21;
+        INVALIDITY_DATE = "id-ce-invalidityDate" => 2, 5, 29, 24;
+        DELTA_CRL_INDICATOR = "id-ce-deltaCRLIndicator" => 2, 5, 29, 27;
+        ISSUING_DISTRIBUTION_POINT = "id-ce-issuingDistributionPoint" => 2, 5, 29, 28;
+        CERTIFICATE_ISSUER = "id-ce-certificateIssuer" => 2, 5, 29, 29;
+        NAME_CONSTRAINTS = "id-ce-nameConstraints" => 2, 5, 29, 30;
+        CRL_DISTRIBUTION_POINTS = "id-ce-cRLDistributionPoints" => 2, 5, 29, 31;
+        CERTIFICATE_POLICIES = "id-ce-certificatePolicies" => 2, 5, 29, 32;
+        ANY_POLICY = "anyPolicy" => 2, 5, 29, 32, 0;
+        POLICY_MAPPINGS = "id-ce-policyMappings" => 2, 5, 29, 33;
+        AUTHORITY_KEY_IDENTIFIER = "id-ce-authorityKeyIdentifier" => 2, 5, 29, 35;
+        POLICY_CONSTRAINTS = "id-ce-policyConstraints" => 2, 5, 29, 36;
+        EXT_KEY_USAGE = "id-ce-extKeyUsage" => 2, 5, 29, 37;
+        ANY_EXTENDED_KEY_USAGE = "anyExtendedKeyUsage" => 2, 5, 29, 37, 0;
+        FRESHEST_CRL = "id-ce-freshestCRL" => 2, 5, 29, 46;
+        INHIBIT_ANY_POLICY = "id-ce-inhibitAnyPolicy" => 2, 5, 29, 54;
+    }
+
+    /// PKIX private certificate extensions (RFC 5280), under
+    /// `1.3.6.1.5.5.7.1`.
+    pkix_pe {
+        AUTHORITY_INFO_ACCESS = "id-pe-authorityInfoAccess" => 1, 3, 6, 1, 5, 5, 7, 1, 1;
+        SUBJECT_INFO_ACCESS = "id-pe-subjectInfoAccess" => 1, 3, 6, 1, 5, 5, 7, 1, 11;
+    }
+
+    /// PKIX extended key purposes (RFC 5280), under `1.3.6.1.5.5.7.3`.
+    pkix_kp {
+        SERVER_AUTH = "id-kp-serverAuth" => 1, 3, 6, 1, 5, 5, 7, 3, 1;
+        CLIENT_AUTH = "id-kp-clientAuth" => 1, 3, 6, 1, 5, 5, 7, 3, 2;
+        CODE_SIGNING = "id-kp-codeSigning" => 1, 3, 6, 1, 5, 5, 7, 3, 3;
+        EMAIL_PROTECTION = "id-kp-emailProtection" => 1, 3, 6, 1, 5, 5, 7, 3, 4;
+        TIME_STAMPING = "id-kp-timeStamping" => 1, 3, 6, 1, 5, 5, 7, 3, 8;
+        OCSP_SIGNING = "id-kp-OCSPSigning" => 1, 3, 6, 1, 5, 5, 7, 3, 9;
+    }
+
+    /// PKIX access descriptors (RFC 5280), under `1.3.6.1.5.5.7.48`.
+    pkix_ad {
+        OCSP = "id-ad-ocsp" => 1, 3, 6, 1, 5, 5, 7, 48, 1;
+        CA_ISSUERS = "id-ad-caIssuers" => 1, 3, 6, 1, 5, 5, 7, 48, 2;
+        TIME_STAMPING = "id-ad-timeStamping" => 1, 3, 6, 1, 5, 5, 7, 48, 3;
+        CA_REPOSITORY = "id-ad-caRepository" => 1, 3, 6, 1, 5, 5, 7, 48, 5;
+    }
+
+    /// X.520 directory attribute types, as used in distinguished names and
+    /// LDAP, under `2.5.4`.
+    x520 {
+        OBJECT_CLASS = "id-at-objectClass" => 2, 5, 4, 0;
+        ALIASED_ENTRY_NAME = "id-at-aliasedEntryName" => 2, 5, 4, 1;
+        COMMON_NAME = "id-at-commonName" => 2, 5, 4, 3;
+        SURNAME = "id-at-surname" => 2, 5, 4, 4;
+        SERIAL_NUMBER = "id-at-serialNumber" => 2, 5, 4, 5;
+        COUNTRY_NAME = "id-at-countryName" => 2, 5, 4, 6;
+        LOCALITY_NAME = "id-at-localityName" => 2, 5, 4, 7;
+        STATE_OR_PROVINCE_NAME = "id-at-stateOrProvinceName" => 2, 5, 4, 8;
+        STREET_ADDRESS = "id-at-streetAddress" => 2, 5, 4, 9;
+        ORGANIZATION_NAME = "id-at-organizationName" => 2, 5, 4, 10;
+        ORGANIZATIONAL_UNIT_NAME = "id-at-organizationalUnitName" => 2, 5, 4, 11;
+        TITLE = "id-at-title" => 2, 5, 4, 12;
+        DESCRIPTION = "id-at-description" => 2, 5, 4, 13;
+        POSTAL_ADDRESS = "id-at-postalAddress" => 2, 5, 4, 16;
+        POSTAL_CODE = "id-at-postalCode" => 2, 5, 4, 17;
+        TELEPHONE_NUMBER = "id-at-telephoneNumber" => 2, 5, 4, 20;
+        NAME = "id-at-name" => 2, 5, 4, 41;
+        GIVEN_NAME = "id-at-givenName" => 2, 5, 4, 42;
+        INITIALS = "id-at-initials" => 2, 5, 4, 43;
+        GENERATION_QUALIFIER = "id-at-generationQualifier" => 2, 5, 4, 44;
+        UNIQUE_IDENTIFIER = "id-at-uniqueIdentifier" => 2, 5, 4, 45;
+        DN_QUALIFIER = "id-at-dnQualifier" => 2, 5, 4, 46;
+        PSEUDONYM = "id-at-pseudonym" => 2, 5, 4, 65;
+    }
+
+    /// COSINE pilot attribute types (RFC 4519), under
+    /// `0.9.2342.19200300.100.1`.
+    pilot {
+        UID = "uid" => 0, 9, 2342, 19200300, 100, 1, 1;
+        MAIL = "mail" => 0, 9, 2342, 19200300, 100, 1, 3;
+        DOMAIN_COMPONENT = "domainComponent" => 0, 9, 2342, 19200300, 100, 1, 25;
+    }
+
+    /// The Internet subtree (RFC 1155, RFC 2578), under `1.3.6.1`.
+    internet {
+        INTERNET = "internet" => 1, 3, 6, 1;
+        DIRECTORY = "directory" => 1, 3, 6, 1, 1;
+        MGMT = "mgmt" => 1, 3, 6, 1, 2;
+        MIB2 = "mib-2" => 1, 3, 6, 1, 2, 1;
+        EXPERIMENTAL = "experimental" => 1, 3, 6, 1, 3;
+        PRIVATE = "private" => 1, 3, 6, 1, 4;
+        ENTERPRISES = "enterprises" => 1, 3, 6, 1, 4, 1;
+        SECURITY = "security" => 1, 3, 6, 1, 5;
+        SNMPV2 = "snmpV2" => 1, 3, 6, 1, 6;
+    }
+
+    /// SNMP MIB-II groups and objects (RFC 1213), under `1.3.6.1.2.1`.
+    mib2 {
+        SYSTEM = "system" => 1, 3, 6, 1, 2, 1, 1;
+        SYS_DESCR = "sysDescr" => 1, 3, 6, 1, 2, 1, 1, 1;
+        SYS_OBJECT_ID = "sysObjectID" => 1, 3, 6, 1, 2, 1, 1, 2;
+        SYS_UP_TIME = "sysUpTime" => 1, 3, 6, 1, 2, 1, 1, 3;
+        SYS_CONTACT = "sysContact" => 1, 3, 6, 1, 2, 1, 1, 4;
+        SYS_NAME = "sysName" => 1, 3, 6, 1, 2, 1, 1, 5;
+        SYS_LOCATION = "sysLocation" => 1, 3, 6, 1, 2, 1, 1, 6;
+        SYS_SERVICES = "sysServices" => 1, 3, 6, 1, 2, 1, 1, 7;
+        INTERFACES = "interfaces" => 1, 3, 6, 1, 2, 1, 2;
+        IF_NUMBER = "ifNumber" => 1, 3, 6, 1, 2, 1, 2, 1;
+        IF_TABLE = "ifTable" => 1, 3, 6, 1, 2, 1, 2, 2;
+        AT = "at" => 1, 3, 6, 1, 2, 1, 3;
+        IP = "ip" => 1, 3, 6, 1, 2, 1, 4;
+        ICMP = "icmp" => 1, 3, 6, 1, 2, 1, 5;
+        TCP = "tcp" => 1, 3, 6, 1, 2, 1, 6;
+        UDP = "udp" => 1, 3, 6, 1, 2, 1, 7;
+        TRANSMISSION = "transmission" => 1, 3, 6, 1, 2, 1, 10;
+        SNMP = "snmp" => 1, 3, 6, 1, 2, 1, 11;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ObjectIdentifier;
+    use alloc::string::ToString;
+
+    #[test]
+    fn dotted_forms() {
+        for (oid, dotted) in [
+            (pkcs1::RSA_ENCRYPTION, "1.2.840.113549.1.1.1"),
+            (pkcs1::SHA256_WITH_RSA_ENCRYPTION, "1.2.840.113549.1.1.11"),
+            (pkcs9::EMAIL_ADDRESS, "1.2.840.113549.1.9.1"),
+            (ansi_x962::EC_PUBLIC_KEY, "1.2.840.10045.2.1"),
+            (ansi_x962::ECDSA_WITH_SHA384, "1.2.840.10045.4.3.3"),
+            (secg::SECP384R1, "1.3.132.0.34"),
+            (rfc8410::ED25519, "1.3.101.112"),
+            (nist_hash::SHA3_256, "2.16.840.1.101.3.4.2.8"),
+            (nist_aes::AES256_GCM, "2.16.840.1.101.3.4.1.46"),
+            (x509_ce::KEY_USAGE, "2.5.29.15"),
+            (x509_ce::ANY_POLICY, "2.5.29.32.0"),
+            (pkix_kp::SERVER_AUTH, "1.3.6.1.5.5.7.3.1"),
+            (pkix_ad::OCSP, "1.3.6.1.5.5.7.48.1"),
+            (x520::COMMON_NAME, "2.5.4.3"),
+            (pilot::DOMAIN_COMPONENT, "0.9.2342.19200300.100.1.25"),
+            (internet::ENTERPRISES, "1.3.6.1.4.1"),
+            (mib2::SYSTEM, "1.3.6.1.2.1.1"),
+            (mib2::SYS_UP_TIME, "1.3.6.1.2.1.1.3"),
+        ] {
+            assert_eq!(dotted, oid.to_string());
+            assert_eq!(oid, &*dotted.parse::<ObjectIdentifier>().unwrap());
+        }
+    }
+
+    #[test]
+    fn matches_existing_constants() {
+        assert_eq!(
+            pkcs1::RSA_ENCRYPTION,
+            Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA
+        );
+        assert_eq!(
+            ansi_x962::EC_PUBLIC_KEY,
+            Oid::ISO_MEMBER_BODY_US_ANSI_X962_KEY_TYPE_EC_PUBLIC_KEY
+        );
+        assert_eq!(
+            nist_hash::SHA512,
+            Oid::JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_SHA512
+        );
+        assert_eq!(
+            pilot::DOMAIN_COMPONENT,
+            Oid::ITU_T_DATA_PSS_UCL_PILOT_ATTRIBUTE_TYPE_DOMAIN_COMPONENT
+        );
+    }
+
+    #[test]
+    fn decoded() {
+        for (oid, encoded) in [
+            (
+                pkcs1::RSA_ENCRYPTION,
+                &[
+                    0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01,
+                ][..],
+            ),
+            (
+                ansi_x962::EC_PUBLIC_KEY,
+                &[0x06, 0x07, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01],
+            ),
+            (
+                nist_hash::SHA256,
+                &[
+                    0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+                ],
+            ),
+            (x509_ce::KEY_USAGE, &[0x06, 0x03, 0x55, 0x1D, 0x0F]),
+            (x520::COMMON_NAME, &[0x06, 0x03, 0x55, 0x04, 0x03]),
+            (
+                mib2::SYSTEM,
+                &[0x06, 0x06, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x01],
+            ),
+        ] {
+            let decoded: ObjectIdentifier = crate::der::decode(encoded).unwrap();
+            assert_eq!(oid, decoded);
+            assert_eq!(encoded, crate::der::encode(&oid.to_owned()).unwrap());
+        }
+    }
+}
@@ -1,11 +1,19 @@
 use super::{
-    AsnType, BitString, GeneralizedTime, InstanceOf, Integer, ObjectIdentifier, OctetString,
-    UniversalString, UtcTime, VisibleString,
+    any::write_hex, Any, AsnType, BitString, DateTime, EmbeddedPdv, GeneralizedTime, InstanceOf,
+    Integer, ObjectIdentifier, OctetString, Tag, UniversalString, UtcTime, Utf8String,
+    VisibleString,
 };
 use crate::{Decode, Encode};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
 
 /// An "open" type representing any valid ASN.1 type.
-#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode)]
+///
+/// Equality, ordering, and hashing are structural: they compare the
+/// variants and their decoded values, not an encoding. Values decoded from
+/// different BER encodings of the same primitive value are equal, but the
+/// components of a `Set` are compared in the order they were encoded, and
+/// `Unknown` values compare their encoded octets.
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
 #[rasn(crate_root = "crate")]
 #[rasn(choice)]
 pub enum Open {
@@ -25,13 +33,486 @@ pub enum Open {
     ObjectIdentifier(ObjectIdentifier),
     /// A octet string value.
     OctetString(OctetString),
+    /// A sequence value, containing its components in order.
+    Sequence(Vec<Open>),
+    /// A set value, containing its components in the order they were encoded.
+    #[rasn(tag(universal, 17))]
+    Set(Vec<Open>),
     // PrintableString(PrintableString),
     /// A universal string value.
     UniversalString(UniversalString),
     /// A utc time value.
     UtcTime(UtcTime),
+    /// A UTF-8 string value.
+    Utf8String(Utf8String),
     /// A visible string value.
     VisibleString(VisibleString),
     /// An "instance of" value.
-    InstanceOf(alloc::boxed::Box<InstanceOf<Open>>),
+    InstanceOf(Box<InstanceOf<Open>>),
+    /// An embedded PDV value.
+    EmbeddedPdv(Box<EmbeddedPdv>),
+    /// A value of any other type, holding its complete encoding so that it
+    /// is re-encoded exactly as it was received.
+    #[rasn(unknown)]
+    Unknown(Any),
+}
+
+impl Open {
+    /// Returns the tag of the value currently held.
+    #[must_use]
+    pub fn tag(&self) -> Tag {
+        match self {
+            Self::BitString(_) => Tag::BIT_STRING,
+            Self::Bool(_) => Tag::BOOL,
+            Self::GeneralizedTime(_) => Tag::GENERALIZED_TIME,
+            Self::Integer(_) => Tag::INTEGER,
+            Self::Null => Tag::NULL,
+            Self::ObjectIdentifier(_) => Tag::OBJECT_IDENTIFIER,
+            Self::OctetString(_) => Tag::OCTET_STRING,
+            Self::Sequence(_) => Tag::SEQUENCE,
+            Self::Set(_) => Tag::SET,
+            Self::UniversalString(_) => Tag::UNIVERSAL_STRING,
+            Self::UtcTime(_) => Tag::UTC_TIME,
+            Self::Utf8String(_) => Tag::UTF8_STRING,
+            Self::VisibleString(_) => Tag::VISIBLE_STRING,
+            Self::InstanceOf(_) => Tag::EXTERNAL,
+            Self::EmbeddedPdv(_) => Tag::EMBEDDED_PDV,
+            Self::Unknown(value) => value.tag().unwrap_or(Tag::EOC),
+        }
+    }
+
+    /// Returns whether the value is `NULL`.
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns the components if the value is a `SEQUENCE`.
+    #[must_use]
+    pub fn as_sequence(&self) -> Option<&[Open]> {
+        match self {
+            Self::Sequence(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the components if the value is a `SET`.
+    #[must_use]
+    pub fn as_set(&self) -> Option<&[Open]> {
+        match self {
+            Self::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Open {
+    /// Formats the value on a single line, with its type and value in ASN.1
+    /// value notation where possible, and octets in bounded hexadecimal.
+    /// ```
+    /// use rasn::types::{Integer, Open};
+    ///
+    /// let value = Open::Sequence(vec![Open::Integer(Integer::from(5)), Open::Null]);
+    /// assert_eq!(value.to_string(), "SEQUENCE { INTEGER 5, NULL }");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BitString(value) => {
+                write!(f, "BIT STRING {} bits", value.len())?;
+                write_hex(f, value.as_raw_slice())
+            }
+            Self::Bool(true) => f.write_str("BOOLEAN TRUE"),
+            Self::Bool(false) => f.write_str("BOOLEAN FALSE"),
+            Self::GeneralizedTime(value) => write!(
+                f,
+                "GeneralizedTime {}",
+                DateTime::from(*value).to_generalized_time_string(true)
+            ),
+            Self::Integer(value) => write!(f, "INTEGER {value}"),
+            Self::Null => f.write_str("NULL"),
+            Self::ObjectIdentifier(value) => write!(f, "OBJECT IDENTIFIER {value}"),
+            Self::OctetString(value) => {
+                write!(f, "OCTET STRING len {}", value.len())?;
+                write_hex(f, value)
+            }
+            Self::Sequence(values) => write_components(f, "SEQUENCE", values),
+            Self::Set(values) => write_components(f, "SET", values),
+            Self::UniversalString(value) => write!(f, "UniversalString {:?}", value.value),
+            Self::UtcTime(value) => {
+                write!(f, "UTCTime {}", DateTime::from(*value).to_utc_time_string())
+            }
+            Self::Utf8String(value) => write!(f, "UTF8String {value:?}"),
+            Self::VisibleString(value) => write!(f, "VisibleString {:?}", value.to_string()),
+            Self::InstanceOf(value) => {
+                write!(f, "INSTANCE OF {} {}", value.type_id, value.value)
+            }
+            Self::EmbeddedPdv(value) => {
+                write!(f, "EMBEDDED PDV len {}", value.data_value.len())?;
+                write_hex(f, &value.data_value)
+            }
+            Self::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+fn write_components(
+    f: &mut core::fmt::Formatter<'_>,
+    name: &str,
+    values: &[Open],
+) -> core::fmt::Result {
+    f.write_str(name)?;
+    let mut separator = " { ";
+    for value in values {
+        write!(f, "{separator}{value}")?;
+        separator = ", ";
+    }
+
+    f.write_str(if values.is_empty() { " {}" } else { " }" })
+}
+
+/// The error returned when converting an [`Open`] value into a type it
+/// doesn't hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromOpenError {
+    expected: Tag,
+    original: Open,
+}
+
+impl TryFromOpenError {
+    fn new(expected: Tag, original: Open) -> Self {
+        Self { expected, original }
+    }
+
+    /// The tag of the type that was requested.
+    #[must_use]
+    pub fn expected(&self) -> Tag {
+        self.expected
+    }
+
+    /// The tag of the value that was actually present.
+    #[must_use]
+    pub fn actual(&self) -> Tag {
+        self.original.tag()
+    }
+
+    /// Returns the value that failed to convert.
+    #[must_use]
+    pub fn into_original(self) -> Open {
+        self.original
+    }
+}
+
+impl core::fmt::Display for TryFromOpenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected open value with tag {}, found {}",
+            self.expected,
+            self.actual()
+        )
+    }
+}
+
+impl core::error::Error for TryFromOpenError {}
+
+/// The error returned when converting an [`Open`] value that isn't
+/// [`Open::Unknown`] into an [`Any`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotUnknownError {
+    original: Open,
+}
+
+impl NotUnknownError {
+    /// The tag of the value that was actually present.
+    #[must_use]
+    pub fn actual(&self) -> Tag {
+        self.original.tag()
+    }
+
+    /// Returns the value that failed to convert.
+    #[must_use]
+    pub fn into_original(self) -> Open {
+        self.original
+    }
+}
+
+impl core::fmt::Display for NotUnknownError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected unknown open value, found {}", self.actual())
+    }
+}
+
+impl core::error::Error for NotUnknownError {}
+
+macro_rules! impl_open_conversions {
+    ($($variant:ident($typ:ty) => $accessor:ident, $tag:ident;)*) => {
+        $(
+            impl From<$typ> for Open {
+                fn from(value: $typ) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            impl TryFrom<Open> for $typ {
+                type Error = TryFromOpenError;
+
+                fn try_from(value: Open) -> Result<Self, Self::Error> {
+                    match value {
+                        Open::$variant(value) => Ok(value),
+                        other => Err(TryFromOpenError::new(Tag::$tag, other)),
+                    }
+                }
+            }
+
+            impl Open {
+                #[doc = concat!("Returns a reference to the value if it is a `", stringify!($variant), "`.")]
+                #[must_use]
+                pub fn $accessor(&self) -> Option<&$typ> {
+                    match self {
+                        Self::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_open_conversions! {
+    BitString(BitString) => as_bit_string, BIT_STRING;
+    Bool(bool) => as_bool, BOOL;
+    GeneralizedTime(GeneralizedTime) => as_generalized_time, GENERALIZED_TIME;
+    Integer(Integer) => as_integer, INTEGER;
+    ObjectIdentifier(ObjectIdentifier) => as_object_identifier, OBJECT_IDENTIFIER;
+    OctetString(OctetString) => as_octet_string, OCTET_STRING;
+    UniversalString(UniversalString) => as_universal_string, UNIVERSAL_STRING;
+    UtcTime(UtcTime) => as_utc_time, UTC_TIME;
+    Utf8String(Utf8String) => as_utf8_string, UTF8_STRING;
+    VisibleString(VisibleString) => as_visible_string, VISIBLE_STRING;
+    InstanceOf(Box<InstanceOf<Open>>) => as_instance_of, EXTERNAL;
+    EmbeddedPdv(Box<EmbeddedPdv>) => as_embedded_pdv, EMBEDDED_PDV;
+}
+
+impl From<Any> for Open {
+    /// Decodes a value whose tag belongs to one of `Open`'s variants into that
+    /// variant, and keeps any other value as `Unknown`.
+    fn from(value: Any) -> Self {
+        match crate::ber::decode::<Self>(value.as_bytes()) {
+            Ok(Self::Unknown(_)) | Err(_) => Self::Unknown(value),
+            Ok(open) => open,
+        }
+    }
+}
+
+impl TryFrom<Open> for Any {
+    type Error = NotUnknownError;
+
+    fn try_from(value: Open) -> Result<Self, Self::Error> {
+        match value {
+            Open::Unknown(value) => Ok(value),
+            original => Err(NotUnknownError { original }),
+        }
+    }
+}
+
+impl Open {
+    /// Returns a reference to the value if it is an `Unknown`.
+    #[must_use]
+    pub fn as_unknown(&self) -> Option<&Any> {
+        match self {
+            Self::Unknown(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<()> for Open {
+    fn from((): ()) -> Self {
+        Self::Null
+    }
+}
+
+impl TryFrom<Open> for () {
+    type Error = TryFromOpenError;
+
+    fn try_from(value: Open) -> Result<Self, Self::Error> {
+        match value {
+            Open::Null => Ok(()),
+            other => Err(TryFromOpenError::new(Tag::NULL, other)),
+        }
+    }
+}
+
+impl From<InstanceOf<Open>> for Open {
+    fn from(value: InstanceOf<Open>) -> Self {
+        Self::InstanceOf(Box::new(value))
+    }
+}
+
+impl From<EmbeddedPdv> for Open {
+    fn from(value: EmbeddedPdv) -> Self {
+        Self::EmbeddedPdv(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn display() {
+        let value = Open::Sequence(vec![
+            Open::ObjectIdentifier(ObjectIdentifier::new(vec![2, 5, 29, 19]).unwrap()),
+            Open::Bool(true),
+            Open::OctetString(OctetString::from(vec![0x30, 0x03, 0x01, 0x01, 0xFF])),
+            Open::Utf8String("a \"b\"\n".into()),
+            Open::Set(vec![]),
+            Open::Unknown(Any::new(vec![0xA3, 0x03, 0x02, 0x01, 0x05])),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            "SEQUENCE { OBJECT IDENTIFIER 2.5.29.19, BOOLEAN TRUE, \
+             OCTET STRING len 5: 30 03 01 01 ff, UTF8String \"a \\\"b\\\"\\n\", SET {}, \
+             [3] (constructed) len 3: 02 01 05 }"
+        );
+        assert_eq!(
+            format!("{:?}", Open::Unknown(Any::new(vec![0x05, 0x00]))),
+            "Unknown(Any([UNIVERSAL 5] len 0))"
+        );
+    }
+
+    #[test]
+    fn map_keys() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let key = || {
+            Open::Sequence(vec![
+                Open::ObjectIdentifier(ObjectIdentifier::new(vec![2, 5, 29, 19]).unwrap()),
+                Open::Integer(300.into()),
+            ])
+        };
+        let map = BTreeMap::from([(key(), 1), (Open::Null, 2)]);
+        let set: HashSet<_> = map.keys().cloned().collect();
+
+        assert_eq!(Some(&1), map.get(&key()));
+        assert!(set.contains(&key()));
+        assert!(set.contains(&Open::Null));
+
+        // A non-minimal BER length decodes to an equal value.
+        let decoded: Open = crate::ber::decode(&[0x05, 0x81, 0x00]).unwrap();
+        assert_eq!(Some(&2), map.get(&decoded));
+        assert!(set.contains(&decoded));
+
+        // Sets are compared in the order they were encoded.
+        let set_of = |values: [bool; 2]| Open::Set(values.map(Open::Bool).to_vec());
+        assert_ne!(set_of([true, false]), set_of([false, true]));
+        assert!(!set.contains(&Open::Unknown(Any::new(vec![0x05, 0x00]))));
+    }
+
+    #[test]
+    fn conversions() {
+        let value = Open::from(true);
+        assert_eq!(value.as_bool(), Some(&true));
+        assert_eq!(value.as_integer(), None);
+        assert!(bool::try_from(value).unwrap());
+
+        let value = Open::from(Integer::from(5));
+        assert_eq!(value.as_integer(), Some(&Integer::from(5)));
+        assert_eq!(Integer::try_from(value).unwrap(), 5.into());
+
+        let octets = OctetString::from_static(&[1, 2, 3]);
+        let value = Open::from(octets.clone());
+        assert_eq!(value.as_octet_string(), Some(&octets));
+        assert_eq!(OctetString::try_from(value).unwrap(), octets);
+
+        let bits = BitString::from_slice(&[0xF0]);
+        let value = Open::from(bits.clone());
+        assert_eq!(value.as_bit_string(), Some(&bits));
+        assert_eq!(BitString::try_from(value).unwrap(), bits);
+
+        let oid = ObjectIdentifier::from(crate::types::Oid::ISO_MEMBER_BODY);
+        let value = Open::from(oid.clone());
+        assert_eq!(value.as_object_identifier(), Some(&oid));
+        assert_eq!(ObjectIdentifier::try_from(value).unwrap(), oid);
+
+        let string = VisibleString::try_from("rasn").unwrap();
+        let value = Open::from(string.clone());
+        assert_eq!(value.as_visible_string(), Some(&string));
+        assert_eq!(VisibleString::try_from(value).unwrap(), string);
+
+        let string = UniversalString::new("rasn".into());
+        let value = Open::from(string.clone());
+        assert_eq!(value.as_universal_string(), Some(&string));
+        assert_eq!(UniversalString::try_from(value).unwrap(), string);
+
+        let string = Utf8String::from("rasn");
+        let value = Open::from(string.clone());
+        assert_eq!(value.as_utf8_string(), Some(&string));
+        assert_eq!(value.tag(), Tag::UTF8_STRING);
+        assert_eq!(Utf8String::try_from(value).unwrap(), string);
+
+        let time = crate::types::DateTime {
+            year: 1970,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanos: 0,
+            offset_minutes: 0,
+        };
+        let utc_time = UtcTime::try_from(time).unwrap();
+        let value = Open::from(utc_time);
+        assert_eq!(value.as_utc_time(), Some(&utc_time));
+        assert_eq!(UtcTime::try_from(value).unwrap(), utc_time);
+
+        let time = GeneralizedTime::try_from(time).unwrap();
+        let value = Open::from(time);
+        assert_eq!(value.as_generalized_time(), Some(&time));
+        assert_eq!(GeneralizedTime::try_from(value).unwrap(), time);
+
+        let value = Open::from(());
+        assert!(value.is_null());
+        <()>::try_from(value).unwrap();
+
+        let value = Open::Sequence(vec![Open::Null]);
+        assert_eq!(value.as_sequence(), Some(&[Open::Null][..]));
+        assert_eq!(value.as_set(), None);
+        let value = Open::Set(vec![Open::Null]);
+        assert_eq!(value.as_set(), Some(&[Open::Null][..]));
+        assert_eq!(value.as_sequence(), None);
+
+        let value = Open::from(Any::new(vec![0x01, 0x01, 0xFF]));
+        assert_eq!(value, Open::Bool(true));
+
+        let any = Any::new(vec![0xA3, 0x03, 0x02, 0x01, 0x05]);
+        let value = Open::from(any.clone());
+        assert_eq!(value.as_unknown(), Some(&any));
+        assert_eq!(Any::try_from(value).unwrap(), any);
+    }
+
+    #[test]
+    fn mismatched_conversion() {
+        let error = bool::try_from(Open::from(Integer::from(1))).unwrap_err();
+        assert_eq!(error.expected(), Tag::BOOL);
+        assert_eq!(error.actual(), Tag::INTEGER);
+        assert_eq!(
+            error.to_string(),
+            "expected open value with tag Universal 1, found Universal 2"
+        );
+        assert_eq!(error.into_original(), Open::Integer(1.into()));
+
+        let error = <()>::try_from(Open::Sequence(vec![])).unwrap_err();
+        assert_eq!(error.actual(), Tag::SEQUENCE);
+
+        let error = Any::try_from(Open::Bool(true)).unwrap_err();
+        assert_eq!(error.actual(), Tag::BOOL);
+        assert_eq!(
+            error.to_string(),
+            "expected unknown open value, found Universal 1"
+        );
+        assert_eq!(error.into_original(), Open::Bool(true));
+    }
 }
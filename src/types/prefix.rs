@@ -9,6 +9,23 @@ pub struct Explicit<T, V> {
 }
 
 /// A newtype wrapper that will implicitly tag its value with `T`'s tag.
+///
+/// `T` is a marker type whose [`AsnType::TAG`] is the tag to use, so tags are
+/// chosen with plain types rather than const generic parameters.
+/// ```
+/// use rasn::{types::{Implicit, Tag, Utf8String}, AsnType};
+///
+/// struct Application5;
+///
+/// impl AsnType for Application5 {
+///     const TAG: Tag = Tag::new_application(5);
+/// }
+///
+/// type Name = Implicit<Application5, Utf8String>;
+///
+/// let name = Name::new("rasn".into());
+/// assert_eq!(rasn::der::encode(&name).unwrap(), b"\x45\x04rasn");
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Implicit<T, V> {
     _tag: core::marker::PhantomData<T>,
@@ -22,12 +39,23 @@ macro_rules! tag_kind {
 
             impl<T, V> $name<T, V>{
                 /// Create a wrapper from `value`.
-                pub fn new(value: V) -> Self {
+                pub const fn new(value: V) -> Self {
                     Self {
                         value,
                         _tag: core::marker::PhantomData,
                     }
                 }
+
+                /// Returns the inner value.
+                pub fn into_inner(self) -> V {
+                    self.value
+                }
+            }
+
+            impl<T, V: Default> Default for $name<T, V> {
+                fn default() -> Self {
+                    Self::new(V::default())
+                }
             }
 
             impl<T, V> From<V> for $name<T, V> {
@@ -49,6 +77,36 @@ macro_rules! tag_kind {
                     &mut self.value
                 }
             }
+
+            impl<T, V> AsRef<V> for $name<T, V> {
+                fn as_ref(&self) -> &V {
+                    &self.value
+                }
+            }
+
+            impl<T, V> AsMut<V> for $name<T, V> {
+                fn as_mut(&mut self) -> &mut V {
+                    &mut self.value
+                }
+            }
+
+            impl<T, V: PartialEq> PartialEq<V> for $name<T, V> {
+                fn eq(&self, other: &V) -> bool {
+                    self.value == *other
+                }
+            }
+
+            impl<T, V: AsRef<str>> PartialEq<str> for $name<T, V> {
+                fn eq(&self, other: &str) -> bool {
+                    self.value.as_ref() == other
+                }
+            }
+
+            impl<T, V: core::fmt::Display> core::fmt::Display for $name<T, V> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.value.fmt(f)
+                }
+            }
         )+
     }
 }
@@ -62,3 +120,28 @@ impl<T: AsnType, V> AsnType for Implicit<T, V> {
 impl<T: AsnType, V> AsnType for Explicit<T, V> {
     const TAG: Tag = T::TAG;
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use crate::types::{Explicit, Implicit, UniversalString, Utf8String};
+
+    #[test]
+    fn wrapper_ergonomics() {
+        const NAME: Implicit<crate::types::Integer, u8> = Implicit::new(5);
+        assert_eq!(5, NAME.into_inner());
+
+        let mut name = UniversalString::new(String::from("rasn"));
+        assert!(name == *"rasn");
+        assert!(name == Utf8String::from("rasn"));
+        assert_eq!("rasn", name.to_string());
+        assert_eq!("rasn", AsRef::<Utf8String>::as_ref(&name));
+
+        name.as_mut().push_str("!");
+        assert_eq!(String::from("rasn!"), name.into_inner());
+
+        assert_eq!(String::new(), UniversalString::default().value);
+        assert!(Explicit::<crate::types::Integer, u8>::new(7) == 7);
+    }
+}
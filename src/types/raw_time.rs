@@ -0,0 +1,127 @@
+//! `UTCTime` and `GeneralizedTime` values that keep the contents they were
+//! decoded from, so that they can be re-encoded byte for byte.
+
+use alloc::string::String;
+
+use super::{DateTime, GeneralizedTime, UtcTime};
+
+/// A `UTCTime` that keeps the contents it was decoded from, e.g.
+/// `1701020304+0100`, alongside the parsed [`UtcTime`].
+///
+/// Unlike [`UtcTime`], which is always encoded in the canonical form, BER,
+/// CER, and DER emit the stored contents unchanged. This allows a structure
+/// to be re-encoded exactly as it was received, such as the signed part of a
+/// certificate whose signature is to be verified. CER and DER still only
+/// decode contents in the canonical form, and other codecs decode and encode
+/// the parsed value as usual.
+///
+/// ```
+/// use rasn::types::RawUtcTime;
+///
+/// let encoded = b"\x17\x0F1701020304+0100";
+/// let time: RawUtcTime = rasn::ber::decode(encoded).unwrap();
+/// assert_eq!(time.as_str(), "1701020304+0100");
+/// assert_eq!(rasn::ber::encode(&time).unwrap(), encoded);
+/// assert_eq!(&rasn::ber::encode(&time.time()).unwrap()[2..], b"170102020400Z");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RawUtcTime {
+    contents: String,
+    time: UtcTime,
+}
+
+impl RawUtcTime {
+    /// Creates a new value from `time`, with contents in the canonical form.
+    #[must_use]
+    pub fn new(time: UtcTime) -> Self {
+        Self::from_parts(DateTime::from(time).to_utc_time_string(), time)
+    }
+
+    pub(crate) fn from_parts(contents: String, time: UtcTime) -> Self {
+        Self { contents, time }
+    }
+
+    /// The parsed time.
+    #[must_use]
+    pub fn time(&self) -> UtcTime {
+        self.time
+    }
+
+    /// The contents the time was decoded from.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.contents
+    }
+}
+
+impl From<UtcTime> for RawUtcTime {
+    fn from(time: UtcTime) -> Self {
+        Self::new(time)
+    }
+}
+
+impl From<RawUtcTime> for UtcTime {
+    fn from(value: RawUtcTime) -> Self {
+        value.time
+    }
+}
+
+/// A `GeneralizedTime` that keeps the contents it was decoded from, e.g.
+/// `20170102030405,50+0100`, alongside the parsed [`GeneralizedTime`].
+///
+/// Unlike [`GeneralizedTime`], which is always encoded in the canonical
+/// form, BER, CER, and DER emit the stored contents unchanged, preserving
+/// whether seconds, a fraction, or an offset were present. CER and DER still
+/// only decode contents in the canonical form, and other codecs decode and
+/// encode the parsed value as usual.
+///
+/// ```
+/// use rasn::types::RawGeneralizedTime;
+///
+/// let encoded = b"\x18\x1620170102030405,50+0100";
+/// let time: RawGeneralizedTime = rasn::ber::decode(encoded).unwrap();
+/// assert_eq!(time.as_str(), "20170102030405,50+0100");
+/// assert_eq!(rasn::ber::encode(&time).unwrap(), encoded);
+/// assert_eq!(&rasn::ber::encode(&time.time()).unwrap()[2..], b"20170102020405.5Z");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RawGeneralizedTime {
+    contents: String,
+    time: GeneralizedTime,
+}
+
+impl RawGeneralizedTime {
+    /// Creates a new value from `time`, with contents in the canonical form.
+    #[must_use]
+    pub fn new(time: GeneralizedTime) -> Self {
+        Self::from_parts(DateTime::from(time).to_generalized_time_string(false), time)
+    }
+
+    pub(crate) fn from_parts(contents: String, time: GeneralizedTime) -> Self {
+        Self { contents, time }
+    }
+
+    /// The parsed time.
+    #[must_use]
+    pub fn time(&self) -> GeneralizedTime {
+        self.time
+    }
+
+    /// The contents the time was decoded from.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.contents
+    }
+}
+
+impl From<GeneralizedTime> for RawGeneralizedTime {
+    fn from(time: GeneralizedTime) -> Self {
+        Self::new(time)
+    }
+}
+
+impl From<RawGeneralizedTime> for GeneralizedTime {
+    fn from(value: RawGeneralizedTime) -> Self {
+        value.time
+    }
+}
@@ -0,0 +1,241 @@
+//! `serde` support for rasn's own types, enabled with the `serde` feature.
+//!
+//! Human-readable formats use the same representations as JER, e.g. octet
+//! strings are written as hex strings, while other formats use raw bytes.
+//! `BitString` and the time types get their implementations from `bitvec` and
+//! `chrono`, so the time types only implement them with the `chrono` feature.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use num_bigint::BigInt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Any, Integer, ObjectIdentifier, OctetString};
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&bytes.iter().fold(String::new(), |mut acc, byte| {
+            acc.push_str(&alloc::format!("{byte:02X}"));
+            acc
+        }))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    struct BytesVisitor;
+
+    impl<'de> de::Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a hex string or bytes")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            crate::jer::de::bytes_from_hexstring(value)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            Ok(value.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(value)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor)
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+/// Serialized as a hex string, or as bytes in binary formats.
+impl Serialize for OctetString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OctetString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer).map(Self::from)
+    }
+}
+
+/// Serialized as a hex string of the complete encoding, or as bytes in binary
+/// formats.
+impl Serialize for Any {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Any {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer).map(Self::new)
+    }
+}
+
+/// Serialized as a dotted string, e.g. `"1.2.840.113549"`.
+impl Serialize for ObjectIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OidVisitor;
+
+        impl de::Visitor<'_> for OidVisitor {
+            type Value = ObjectIdentifier;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a dotted object identifier string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(OidVisitor)
+    }
+}
+
+/// Serialized as a number in human-readable formats when it fits in an `i64`,
+/// and as a decimal string otherwise.
+impl Serialize for Integer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match i64::try_from(self) {
+            Ok(value) if serializer.is_human_readable() => serializer.serialize_i64(value),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Integer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IntegerVisitor;
+
+        impl de::Visitor<'_> for IntegerVisitor {
+            type Value = Integer;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an integer or a decimal integer string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(value.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(value.into())
+            }
+
+            fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+                Ok(value.into())
+            }
+
+            fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+                Ok(value.into())
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value
+                    .parse::<BigInt>()
+                    .map(Integer::from)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IntegerVisitor)
+        } else {
+            deserializer.deserialize_str(IntegerVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BitString;
+    #[cfg(feature = "chrono")]
+    use crate::types::{GeneralizedTime, UtcTime};
+    use alloc::vec;
+
+    fn round_trip<T>(value: &T, expected: &str)
+    where
+        T: Serialize + for<'de> Deserialize<'de> + PartialEq + fmt::Debug,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(expected, json);
+        assert_eq!(*value, serde_json::from_str::<T>(&json).unwrap());
+    }
+
+    #[test]
+    fn octet_string() {
+        round_trip(&OctetString::from_static(&[0xDE, 0xAD, 0x01]), "\"DEAD01\"");
+        round_trip(&OctetString::default(), "\"\"");
+        assert!(serde_json::from_str::<OctetString>("\"ABC\"").is_err());
+        assert!(serde_json::from_str::<OctetString>("\"XY\"").is_err());
+    }
+
+    #[test]
+    fn any() {
+        round_trip(&Any::new(vec![0x05, 0x00]), "\"0500\"");
+    }
+
+    #[test]
+    fn object_identifier() {
+        let oid = ObjectIdentifier::new(vec![1, 2, 840, 113_549]).unwrap();
+        round_trip(&oid, "\"1.2.840.113549\"");
+        assert!(serde_json::from_str::<ObjectIdentifier>("\"1.x\"").is_err());
+    }
+
+    #[test]
+    fn integer() {
+        round_trip(&Integer::from(-42), "-42");
+        round_trip(
+            &Integer::from(u128::MAX),
+            &alloc::format!("\"{}\"", u128::MAX),
+        );
+        assert_eq!(
+            Integer::from(u64::MAX),
+            serde_json::from_str::<Integer>(&u64::MAX.to_string()).unwrap()
+        );
+    }
+
+    #[test]
+    fn foreign_types() {
+        let bits = BitString::from_slice(&[0xA5]);
+        assert_eq!(
+            bits,
+            serde_json::from_str::<BitString>(&serde_json::to_string(&bits).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono_types() {
+        let time: UtcTime = chrono::DateTime::from_timestamp(0, 0).unwrap();
+        round_trip(&time, "\"1970-01-01T00:00:00Z\"");
+        let time = GeneralizedTime::from(time);
+        round_trip(&time, "\"1970-01-01T00:00:00+00:00\"");
+    }
+}
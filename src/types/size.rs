@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+
+use super::{
+    constraints, strings::StaticPermittedAlphabet, AsnType, BitString, BmpString, Constraints,
+    Extensible, GeneralString, GraphicString, Ia5String, Implicit, NumericString, OctetString,
+    PrintableString, SetOf, SetVec, Tag, TeletexString, Utf8String, VisibleString,
+};
+
+/// A value whose size can be constrained with `SIZE`, e.g. the number of
+/// octets in an `OCTET STRING`, or the number of elements in a `SEQUENCE OF`.
+pub trait Length {
+    /// The size of the value as measured by its `SIZE` constraint.
+    fn length(&self) -> usize;
+}
+
+impl Length for OctetString {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for BitString {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for Utf8String {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for SetOf<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for SetVec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+macro_rules! impl_restricted_string_length {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl Length for $name {
+                fn length(&self) -> usize {
+                    StaticPermittedAlphabet::len(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_restricted_string_length!(
+    BmpString,
+    GeneralString,
+    GraphicString,
+    Ia5String,
+    NumericString,
+    PrintableString,
+    TeletexString,
+    VisibleString,
+);
+
+impl<T, V: Length> Length for Implicit<T, V> {
+    fn length(&self) -> usize {
+        self.value.length()
+    }
+}
+
+/// A value of `T` with a size between `MIN` and `MAX` inclusive, i.e.
+/// `T (SIZE(MIN..MAX))`. The size is checked when creating this type and when
+/// decoding it.
+/// ```
+/// use rasn::types::{Ia5String, OctetString, SequenceOf, SizeConstrained};
+///
+/// type Nonce = SizeConstrained<OctetString, 8, 8>;
+/// type Names = SizeConstrained<SequenceOf<Ia5String>, 1, 10>;
+///
+/// assert_eq!((Names::MIN, Names::MAX), (1, 10));
+/// assert!(Nonce::new(OctetString::from_static(&[0; 8])).is_ok());
+/// assert!(Nonce::new(OctetString::from_static(&[0; 7])).is_err());
+/// assert!(Names::new(Vec::new()).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SizeConstrained<T, const MIN: usize, const MAX: usize>(pub(crate) T);
+
+impl<T: Length, const MIN: usize, const MAX: usize> SizeConstrained<T, MIN, MAX> {
+    /// The smallest permitted size.
+    pub const MIN: usize = MIN;
+    /// The largest permitted size.
+    pub const MAX: usize = MAX;
+
+    /// Creates a new value from `value`, returning an error if its size is
+    /// outside of the permitted range.
+    pub fn new(value: T) -> Result<Self, SizeConstraintError> {
+        let size = value.length();
+
+        if (MIN..=MAX).contains(&size) {
+            Ok(Self(value))
+        } else {
+            Err(SizeConstraintError {
+                size,
+                min: MIN,
+                max: MAX,
+            })
+        }
+    }
+
+    /// Returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> core::ops::Deref for SizeConstrained<T, MIN, MAX> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: AsnType, const MIN: usize, const MAX: usize> AsnType for SizeConstrained<T, MIN, MAX> {
+    const TAG: Tag = T::TAG;
+    const TAG_TREE: super::TagTree = T::TAG_TREE;
+    const CONSTRAINTS: Constraints =
+        T::CONSTRAINTS.intersect(Constraints::new(&[constraints::Constraint::Size(
+            Extensible::new(constraints::Size::new(constraints::Bounded::const_new(
+                MIN, MAX,
+            ))),
+        )]));
+    const IDENTIFIER: super::Identifier = T::IDENTIFIER;
+}
+
+/// The error returned when creating a [`SizeConstrained`] value with a size
+/// outside of the permitted range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SizeConstraintError {
+    /// The size of the value.
+    pub size: usize,
+    /// The smallest permitted size.
+    pub min: usize,
+    /// The largest permitted size.
+    pub max: usize,
+}
+
+impl core::fmt::Display for SizeConstraintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "size {} is outside of the permitted range {}..={}",
+            self.size, self.min, self.max
+        )
+    }
+}
+
+impl core::error::Error for SizeConstraintError {}
@@ -22,7 +22,7 @@ pub use {
     graphic::GraphicString,
     ia5::Ia5String,
     numeric::NumericString,
-    octet::{FixedOctetString, OctetString},
+    octet::{FixedOctetString, OctetSlice, OctetString, OctetVec},
     printable::PrintableString,
     teletex::TeletexString,
     visible::VisibleString,
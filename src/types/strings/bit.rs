@@ -89,6 +89,35 @@ impl Encode for BitStr {
     }
 }
 
+impl AsnType for &'_ BitStr {
+    const TAG: Tag = Tag::BIT_STRING;
+    const IDENTIFIER: Identifier = Identifier::BIT_STRING;
+}
+
+/// Bit slices can be encoded directly, even when they don't start on an octet
+/// boundary, in which case the bits are realigned.
+/// ```
+/// use bitvec::prelude::*;
+///
+/// let bits = bits![u8, Msb0; 0, 0, 0, 1, 0, 1, 1, 0, 1, 1];
+/// let encoded = rasn::der::encode(&&bits[3..]).unwrap();
+///
+/// assert_eq!(&[0x03, 0x02, 0x01, 0xB6], &*encoded);
+/// ```
+impl Encode for &'_ BitStr {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_bit_string(tag, constraints, self, identifier)
+            .map(drop)
+    }
+}
+
 impl<const N: usize> AsnType for FixedBitString<N> {
     const TAG: Tag = Tag::BIT_STRING;
     const CONSTRAINTS: Constraints = constraints!(size_constraint!(N));
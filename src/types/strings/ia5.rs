@@ -26,6 +26,13 @@ impl Ia5String {
     pub fn as_iso646_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Returns the string as a `str`, which is possible because every
+    /// permitted character is ASCII.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).expect("Ia5String is always ASCII")
+    }
 }
 
 impl core::fmt::Display for Ia5String {
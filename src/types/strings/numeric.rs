@@ -27,6 +27,13 @@ impl NumericString {
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Returns the string as a `str`, which is possible because every
+    /// permitted character is ASCII.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).expect("NumericString is always ASCII")
+    }
 }
 
 impl StaticPermittedAlphabet for NumericString {
@@ -54,6 +61,12 @@ impl StaticPermittedAlphabet for NumericString {
     }
 }
 
+impl core::fmt::Display for NumericString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl AsnType for NumericString {
     const TAG: Tag = Tag::NUMERIC_STRING;
     const IDENTIFIER: Identifier = Identifier::NUMERIC_STRING;
@@ -82,3 +95,9 @@ impl Decode for NumericString {
         decoder.decode_numeric_string(tag, constraints)
     }
 }
+
+impl From<NumericString> for alloc::string::String {
+    fn from(value: NumericString) -> Self {
+        value.as_str().into()
+    }
+}
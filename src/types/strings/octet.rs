@@ -77,6 +77,15 @@ impl From<Vec<u8>> for OctetString {
     }
 }
 
+/// Wraps `value` without copying, `OctetString`s decoded with
+/// [`crate::ber::decode_bytes`] share the input's storage in the same way.
+#[cfg(not(feature = "arc-slice"))]
+impl From<bytes::Bytes> for OctetString {
+    fn from(value: bytes::Bytes) -> Self {
+        Self(value)
+    }
+}
+
 impl From<&[u8]> for OctetString {
     fn from(value: &[u8]) -> Self {
         cfg_if::cfg_if! {
@@ -219,3 +228,126 @@ impl<const N: usize> Encode for FixedOctetString<N> {
             .map(drop)
     }
 }
+
+/// An `OCTET STRING` backed by a `Vec<u8>`.
+///
+/// `Vec<u8>` itself represents a `SEQUENCE OF INTEGER`, so this wrapper is
+/// needed for types that hold their bytes in a `Vec<u8>` rather than an
+/// [`OctetString`]. Both produce identical encodings.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct OctetVec(pub Vec<u8>);
+
+impl From<Vec<u8>> for OctetVec {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for OctetVec {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<OctetVec> for Vec<u8> {
+    fn from(value: OctetVec) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<[u8]> for OctetVec {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for OctetVec {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for OctetVec {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsnType for OctetVec {
+    const TAG: Tag = Tag::OCTET_STRING;
+    const IDENTIFIER: Identifier = Identifier::OCTET_STRING;
+}
+
+impl Decode for OctetVec {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder
+            .decode_octet_string::<Vec<u8>>(tag, constraints)
+            .map(Self)
+    }
+}
+
+impl Encode for OctetVec {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(tag, constraints, &self.0, identifier)
+            .map(drop)
+    }
+}
+
+/// A borrowed `OCTET STRING`, for encoding bytes held in a `&[u8]`, which
+/// itself represents a `SEQUENCE OF INTEGER`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct OctetSlice<'a>(pub &'a [u8]);
+
+impl<'a> From<&'a [u8]> for OctetSlice<'a> {
+    fn from(value: &'a [u8]) -> Self {
+        Self(value)
+    }
+}
+
+impl AsRef<[u8]> for OctetSlice<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl core::ops::Deref for OctetSlice<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl AsnType for OctetSlice<'_> {
+    const TAG: Tag = Tag::OCTET_STRING;
+    const IDENTIFIER: Identifier = Identifier::OCTET_STRING;
+}
+
+impl Encode for OctetSlice<'_> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(tag, constraints, self.0, identifier)
+            .map(drop)
+    }
+}
@@ -31,6 +31,13 @@ impl PrintableString {
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Returns the string as a `str`, which is possible because every
+    /// permitted character is ASCII.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).expect("PrintableString is always ASCII")
+    }
 }
 
 impl StaticPermittedAlphabet for PrintableString {
@@ -64,6 +71,12 @@ impl StaticPermittedAlphabet for PrintableString {
     }
 }
 
+impl core::fmt::Display for PrintableString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl AsnType for PrintableString {
     const TAG: Tag = Tag::PRINTABLE_STRING;
     const IDENTIFIER: Identifier = Identifier::PRINTABLE_STRING;
@@ -92,3 +105,9 @@ impl Decode for PrintableString {
         decoder.decode_printable_string(tag, constraints)
     }
 }
+
+impl From<PrintableString> for alloc::string::String {
+    fn from(value: PrintableString) -> Self {
+        value.as_str().into()
+    }
+}
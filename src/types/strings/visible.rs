@@ -35,6 +35,13 @@ impl VisibleString {
     pub fn as_iso646_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Returns the string as a `str`, which is possible because every
+    /// permitted character is ASCII.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).expect("VisibleString is always ASCII")
+    }
 }
 
 impl StaticPermittedAlphabet for VisibleString {
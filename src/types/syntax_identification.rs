@@ -0,0 +1,53 @@
+use super::{Integer, ObjectIdentifier};
+use crate::{AsnType, Decode, Encode};
+
+/// The identification of the abstract and transfer syntax of an
+/// [`EmbeddedPdv`][super::EmbeddedPdv] or
+/// [`CharacterString`][super::CharacterString] value.
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(choice)]
+pub enum SyntaxIdentification {
+    /// Separate object identifiers for the abstract and transfer syntax.
+    #[rasn(tag(0))]
+    Syntaxes(Syntaxes),
+    /// A single object identifier for both the abstract and transfer syntax.
+    #[rasn(tag(1))]
+    Syntax(ObjectIdentifier),
+    /// A presentation context negotiated by the presentation layer.
+    #[rasn(tag(2))]
+    PresentationContextId(Integer),
+    /// A presentation context being negotiated, and its transfer syntax.
+    #[rasn(tag(3))]
+    ContextNegotiation(ContextNegotiation),
+    /// The transfer syntax, with the abstract syntax known to both parties.
+    #[rasn(tag(4))]
+    TransferSyntax(ObjectIdentifier),
+    /// Both syntaxes are known to both parties.
+    #[rasn(tag(5))]
+    Fixed,
+}
+
+/// The abstract and transfer syntax of a [`SyntaxIdentification`].
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+pub struct Syntaxes {
+    /// The abstract syntax.
+    #[rasn(tag(0))]
+    pub r#abstract: ObjectIdentifier,
+    /// The transfer syntax.
+    #[rasn(tag(1))]
+    pub transfer: ObjectIdentifier,
+}
+
+/// A presentation context being negotiated in a [`SyntaxIdentification`].
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+pub struct ContextNegotiation {
+    /// The presentation context identifier.
+    #[rasn(tag(0))]
+    pub presentation_context_id: Integer,
+    /// The transfer syntax.
+    #[rasn(tag(1))]
+    pub transfer_syntax: ObjectIdentifier,
+}
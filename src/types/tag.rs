@@ -37,8 +37,26 @@ impl Class {
 
     /// Returns whether the given class is universal.
     #[must_use]
-    pub fn is_universal(self) -> bool {
-        self == Class::Universal
+    pub const fn is_universal(self) -> bool {
+        matches!(self, Class::Universal)
+    }
+
+    /// Returns whether the given class is application specific.
+    #[must_use]
+    pub const fn is_application(self) -> bool {
+        matches!(self, Class::Application)
+    }
+
+    /// Returns whether the given class is context specific.
+    #[must_use]
+    pub const fn is_context(self) -> bool {
+        matches!(self, Class::Context)
+    }
+
+    /// Returns whether the given class is private.
+    #[must_use]
+    pub const fn is_private(self) -> bool {
+        matches!(self, Class::Private)
     }
 }
 
@@ -139,6 +157,7 @@ consts! {
     EMBEDDED_PDV = 11,
     UTF8_STRING = 12,
     RELATIVE_OID = 13,
+    TIME = 14,
     SEQUENCE = 16,
     SET = 17,
     NUMERIC_STRING = 18,
@@ -154,7 +173,12 @@ consts! {
     UNIVERSAL_STRING = 28,
     CHARACTER_STRING = 29,
     BMP_STRING = 30,
-    DATE = 31
+    DATE = 31,
+    TIME_OF_DAY = 32,
+    DATE_TIME = 33,
+    DURATION = 34,
+    OID_IRI = 35,
+    RELATIVE_OID_IRI = 36
 }
 
 impl Tag {
@@ -185,6 +209,51 @@ impl Tag {
         Self::new(Class::Private, value)
     }
 
+    /// Create a new `APPLICATION` tag from `value`, the same as
+    /// [`Tag::new_application`].
+    #[must_use]
+    pub const fn application(value: u32) -> Self {
+        Self::new_application(value)
+    }
+
+    /// Create a new `CONTEXT` tag from `value`, the same as
+    /// [`Tag::new_context`].
+    #[must_use]
+    pub const fn context(value: u32) -> Self {
+        Self::new_context(value)
+    }
+
+    /// Create a new `PRIVATE` tag from `value`, the same as
+    /// [`Tag::new_private`].
+    #[must_use]
+    pub const fn private(value: u32) -> Self {
+        Self::new_private(value)
+    }
+
+    /// Returns whether the tag is in the `UNIVERSAL` class.
+    #[must_use]
+    pub const fn is_universal(&self) -> bool {
+        self.class.is_universal()
+    }
+
+    /// Returns whether the tag is in the `APPLICATION` class.
+    #[must_use]
+    pub const fn is_application(&self) -> bool {
+        self.class.is_application()
+    }
+
+    /// Returns whether the tag is in the `CONTEXT` class.
+    #[must_use]
+    pub const fn is_context(&self) -> bool {
+        self.class.is_context()
+    }
+
+    /// Returns whether the tag is in the `PRIVATE` class.
+    #[must_use]
+    pub const fn is_private(&self) -> bool {
+        self.class.is_private()
+    }
+
     /// Set the value of the tag.
     #[allow(clippy::return_self_not_must_use, clippy::must_use_candidate)]
     pub fn set_value(mut self, value: u32) -> Self {
@@ -201,7 +270,7 @@ impl Tag {
     #[doc(hidden)]
     #[must_use]
     pub const fn const_less_than(self, rhs: Self) -> bool {
-        (self.class as u8) < (rhs.class as u8) && self.value < rhs.value
+        matches!(self.const_cmp(&rhs), core::cmp::Ordering::Less)
     }
 
     /// Returns whether `Tag` is defined as `Tag::EOC`, and thus is an invalid
@@ -332,6 +401,24 @@ impl TagTree {
         }
     }
 
+    /// Whether any `Leaf` in `self` matches any `Leaf` in `other`.
+    pub(crate) const fn overlaps(&self, other: &TagTree) -> bool {
+        match self {
+            TagTree::Leaf(tag) => Self::tag_contains(tag, core::slice::from_ref(other)),
+            TagTree::Choice(tree) => {
+                let mut index = 0;
+                while index < tree.len() {
+                    if tree[index].overlaps(other) {
+                        return true;
+                    }
+
+                    index += 1;
+                }
+                false
+            }
+        }
+    }
+
     /// Whether `needle` matches any `Leaf`s in `nodes`.
     #[must_use]
     pub const fn tag_contains(needle: &Tag, nodes: &[TagTree]) -> bool {
@@ -450,4 +537,66 @@ mod tests {
 
         assert_eq!(tags, expected);
     }
+
+    #[test]
+    fn smallest_tag() {
+        let tree = TagTree::Choice(&[
+            TagTree::Leaf(Tag::new(Class::Context, 0)),
+            TagTree::Leaf(Tag::new(Class::Application, 5)),
+            TagTree::Choice(&[TagTree::Leaf(Tag::new(Class::Application, 3))]),
+        ]);
+
+        assert_eq!(Tag::new(Class::Application, 3), tree.smallest_tag());
+        assert!(Tag::new(Class::Universal, 30).const_less_than(Tag::new(Class::Context, 1)));
+        assert!(!Tag::new(Class::Context, 1).const_less_than(Tag::new(Class::Universal, 30)));
+    }
+
+    #[test]
+    fn universal_tag_numbers() {
+        let tags = [
+            (Tag::ENUMERATED, 10),
+            (Tag::EMBEDDED_PDV, 11),
+            (Tag::RELATIVE_OID, 13),
+            (Tag::TIME, 14),
+            (Tag::DATE, 31),
+            (Tag::TIME_OF_DAY, 32),
+            (Tag::DATE_TIME, 33),
+            (Tag::DURATION, 34),
+            (Tag::OID_IRI, 35),
+            (Tag::RELATIVE_OID_IRI, 36),
+        ];
+
+        for (tag, value) in tags {
+            assert_eq!(Tag::new(Class::Universal, value), tag);
+        }
+    }
+
+    #[test]
+    fn class_predicates() {
+        assert!(Tag::BOOL.is_universal());
+        assert!(Tag::new_application(1).is_application());
+        assert!(Tag::new_context(1).is_context());
+        assert!(Tag::new_private(1).is_private());
+        assert!(!Tag::new_context(1).is_universal());
+        assert!(!Tag::BOOL.is_context());
+    }
+
+    #[test]
+    fn class_constructors() {
+        const CONTEXT: Tag = Tag::context(3);
+
+        assert_eq!(Tag::new(Class::Context, 3), CONTEXT);
+        assert_eq!(Tag::new(Class::Application, 3), Tag::application(3));
+        assert_eq!(Tag::new(Class::Private, 3), Tag::private(3));
+        assert_eq!(crate::Class::Context, CONTEXT.class);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("Universal 1", Tag::BOOL.to_string());
+        assert_eq!("Application 7", Tag::new_application(7).to_string());
+        assert_eq!("Context 1000", Tag::new_context(1000).to_string());
+        assert_eq!("Private 0", Tag::new_private(0).to_string());
+        assert_eq!("context", Class::Context.to_string());
+    }
 }
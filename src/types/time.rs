@@ -0,0 +1,626 @@
+//! A built-in date and time representation, along with the parser and
+//! formatter for the contents of `UTCTime` and `GeneralizedTime` values that
+//! every codec shares, whether or not the `chrono` feature is enabled.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_MINUTE: u64 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MINUTE;
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// A calendar date and time of day, with its offset from UTC.
+///
+/// This is the [`GeneralizedTime`](super::GeneralizedTime) type when the
+/// `chrono` feature is disabled, and converts to and from
+/// `chrono::DateTime` when it is enabled. Fields aren't checked when
+/// constructed directly, out of range values are rejected when converting to
+/// `chrono` types. Like `chrono::DateTime`, values compare as instants, so the
+/// same time at different offsets is equal.
+/// ```
+/// use rasn::types::DateTime;
+///
+/// let time = DateTime {
+///     year: 2024,
+///     month: 1,
+///     day: 1,
+///     hour: 1,
+///     minute: 30,
+///     second: 0,
+///     nanos: 0,
+///     offset_minutes: 120,
+/// };
+/// let utc = time.to_utc();
+///
+/// assert_eq!((2023, 12, 31), (utc.year, utc.month, utc.day));
+/// assert_eq!((23, 30, 0), (utc.hour, utc.minute, utc.offset_minutes));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DateTime {
+    /// The year, e.g. `2024`.
+    pub year: i32,
+    /// The month of the year, from `1` to `12`.
+    pub month: u8,
+    /// The day of the month, starting at `1`.
+    pub day: u8,
+    /// The hour of the day, from `0` to `23`.
+    pub hour: u8,
+    /// The minute of the hour, from `0` to `59`.
+    pub minute: u8,
+    /// The second of the minute, from `0` to `59`.
+    pub second: u8,
+    /// The fraction of the second, in nanoseconds.
+    pub nanos: u32,
+    /// The offset of the other fields from UTC, in minutes, e.g. `120` for
+    /// `+02:00`.
+    pub offset_minutes: i16,
+}
+
+impl DateTime {
+    /// Returns the same instant in UTC.
+    #[must_use]
+    pub fn to_utc(self) -> Self {
+        self.with_offset(0)
+    }
+
+    /// Returns the same instant, with its fields in local time at
+    /// `offset_minutes` from UTC.
+    #[must_use]
+    pub fn with_offset(self, offset_minutes: i16) -> Self {
+        if self.offset_minutes == offset_minutes {
+            return self;
+        }
+
+        let minutes = days_from_civil(self.year, self.month, self.day) * MINUTES_PER_DAY
+            + i64::from(self.hour) * 60
+            + i64::from(self.minute)
+            - i64::from(self.offset_minutes)
+            + i64::from(offset_minutes);
+        let (year, month, day) = civil_from_days(minutes.div_euclid(MINUTES_PER_DAY));
+        let minutes = minutes.rem_euclid(MINUTES_PER_DAY);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+            offset_minutes,
+            ..self
+        }
+    }
+
+    /// The fields of the value in UTC, from the most to the least significant.
+    fn instant(self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        let utc = self.to_utc();
+        (
+            utc.year, utc.month, utc.day, utc.hour, utc.minute, utc.second, utc.nanos,
+        )
+    }
+
+    /// Parses the contents of a `GeneralizedTime` in any of the forms X.680
+    /// 46 allows: `YYYYMMDDHH[MM[SS]]`, with an optional fraction of the last
+    /// element separated by `.` or `,`, followed by `Z`, an offset like
+    /// `+HHMM`, or nothing for local time, which is read as UTC. The hour is
+    /// required, so a date alone, `YYYYMMDD`, is rejected.
+    pub(crate) fn parse_generalized_time(string: &str) -> Option<Self> {
+        let (string, offset_minutes) = split_zone(string.as_bytes())?;
+        let (digits, fraction) = match string.iter().position(|&byte| byte == b'.' || byte == b',')
+        {
+            Some(index) => (&string[..index], Some(&string[index + 1..])),
+            None => (string, None),
+        };
+        let year = digits.get(..4).and_then(number)?;
+
+        Self::from_fields(
+            i32::from(year),
+            &digits[4..],
+            fraction,
+            offset_minutes.unwrap_or(0),
+        )
+    }
+
+    /// Parses the contents of a `GeneralizedTime` in the only form CER and
+    /// DER allow (X.690 11.7): UTC with seconds, and an optional fraction
+    /// separated by `.`.
+    pub(crate) fn parse_canonical_generalized_time(string: &str) -> Option<Self> {
+        if Self::non_canonical_generalized_time(string).is_some() {
+            return None;
+        }
+
+        Self::parse_generalized_time(string)
+    }
+
+    /// Returns the rule of X.690 11.7 broken by the contents of a
+    /// `GeneralizedTime`, if it isn't in the form CER and DER allow.
+    pub(crate) fn non_canonical_generalized_time(string: &str) -> Option<&'static str> {
+        let Some(local) = string.strip_suffix('Z') else {
+            return Some("the time must be UTC and end with `Z` (X.690 11.7.1)");
+        };
+        if local.contains(',') {
+            return Some("the decimal separator must be `.` (X.690 11.7.4)");
+        }
+
+        let bytes = local.as_bytes();
+        let has_seconds = bytes.len() >= 14
+            && bytes[..14].iter().all(u8::is_ascii_digit)
+            && matches!(bytes.get(14), None | Some(b'.'));
+        if !has_seconds {
+            return Some("the minutes and seconds must be present (X.690 11.7.2)");
+        }
+        if local.ends_with('0') && local.contains('.') {
+            return Some("the fraction must not end with `0` (X.690 11.7.3)");
+        }
+
+        None
+    }
+
+    /// Returns the rule of X.690 11.8 broken by the contents of a `UTCTime`,
+    /// if it isn't in the form CER and DER allow.
+    pub(crate) fn non_canonical_utc_time(string: &str) -> Option<&'static str> {
+        let Some(local) = string.strip_suffix('Z') else {
+            return Some("the time must be UTC and end with `Z` (X.690 11.8.1)");
+        };
+        if local.len() == 10 {
+            return Some("the seconds must be present (X.690 11.8.2)");
+        }
+
+        None
+    }
+
+    /// Parses the contents of a `UTCTime`, `YYMMDDHHMM[SS]` followed by `Z`
+    /// or an offset like `+HHMM`. Two digit years stand for 1950 to 2049
+    /// (RFC 5280 4.1.2.5.1), e.g. `50` is 1950 rather than 2050.
+    pub(crate) fn parse_utc_time(string: &str) -> Option<Self> {
+        let (string, offset_minutes) = split_zone(string.as_bytes())?;
+        if !matches!(string.len(), 10 | 12) {
+            return None;
+        }
+        let year = number(&string[..2])?;
+        let century = if year < 50 { 2000 } else { 1900 };
+
+        Self::from_fields(
+            century + i32::from(year),
+            &string[2..],
+            None,
+            offset_minutes?,
+        )
+    }
+
+    /// Parses the contents of a `UTCTime` in the only form CER and DER allow
+    /// (X.690 11.8): UTC with seconds.
+    pub(crate) fn parse_canonical_utc_time(string: &str) -> Option<Self> {
+        if string.len() != 13 || !string.ends_with('Z') {
+            return None;
+        }
+
+        Self::parse_utc_time(string)
+    }
+
+    /// Formats the contents of a `GeneralizedTime`, with seconds always
+    /// present, and a fraction without trailing zeros only when it is
+    /// non-zero. The value is converted to UTC and ends with `Z` as CER and
+    /// DER require, unless `keep_offset` is set and the offset is non-zero.
+    pub(crate) fn to_generalized_time_string(self, keep_offset: bool) -> String {
+        let value = if keep_offset { self } else { self.to_utc() };
+        let mut string = String::new();
+        let _ = write!(
+            string,
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            value.year, value.month, value.day, value.hour, value.minute, value.second
+        );
+        if value.nanos > 0 {
+            let _ = write!(string, ".{:09}", value.nanos);
+            while string.ends_with('0') {
+                string.pop();
+            }
+        }
+        value.push_zone(&mut string);
+        string
+    }
+
+    /// Formats the contents of a `UTCTime` in UTC, as CER and DER require.
+    /// Years outside of 1950 to 2049 can't be told apart from those in it.
+    pub(crate) fn to_utc_time_string(self) -> String {
+        let value = self.to_utc();
+        let mut string = String::new();
+        let _ = write!(
+            string,
+            "{:02}{:02}{:02}{:02}{:02}{:02}Z",
+            value.year.rem_euclid(100),
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second
+        );
+        string
+    }
+
+    /// Builds a value from the `MMDDHH[MM[SS]]` digits following the year,
+    /// and an optional fraction of the last of them.
+    fn from_fields(
+        year: i32,
+        digits: &[u8],
+        fraction: Option<&[u8]>,
+        offset_minutes: i16,
+    ) -> Option<Self> {
+        let unit = match digits.len() {
+            6 => NANOS_PER_HOUR,
+            8 => NANOS_PER_MINUTE,
+            10 => NANOS_PER_SECOND,
+            _ => return None,
+        };
+        // Elements past the end of `digits` are left out, and so zero.
+        let field = |index: usize| match digits.get(index..index + 2) {
+            Some(field) => number(field).map(|value| value as u8),
+            None => Some(0),
+        };
+        let mut value = Self {
+            year,
+            month: field(0)?,
+            day: field(2)?,
+            hour: field(4)?,
+            minute: field(6)?,
+            second: field(8)?,
+            nanos: 0,
+            offset_minutes,
+        };
+
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || !fraction.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            // Thirteen digits are enough for nanoseconds of an hour.
+            let (numerator, denominator) =
+                fraction
+                    .iter()
+                    .take(13)
+                    .fold((0_u64, 1_u64), |(numerator, denominator), digit| {
+                        (numerator * 10 + u64::from(digit - b'0'), denominator * 10)
+                    });
+            let nanos = (u128::from(numerator) * u128::from(unit) / u128::from(denominator)) as u64;
+            value.minute += (nanos / NANOS_PER_MINUTE) as u8;
+            value.second += (nanos / NANOS_PER_SECOND % 60) as u8;
+            value.nanos = (nanos % NANOS_PER_SECOND) as u32;
+        }
+
+        value.is_valid().then_some(value)
+    }
+
+    fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60
+            && u64::from(self.nanos) < NANOS_PER_SECOND
+            && self.offset_minutes.unsigned_abs() < 24 * 60
+    }
+
+    fn push_zone(self, string: &mut String) {
+        if self.offset_minutes == 0 {
+            string.push('Z');
+        } else {
+            let sign = if self.offset_minutes < 0 { '-' } else { '+' };
+            let offset = self.offset_minutes.unsigned_abs();
+            let _ = write!(string, "{sign}{:02}{:02}", offset / 60, offset % 60);
+        }
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+impl core::hash::Hash for DateTime {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
+}
+
+/// A `UTCTime` value, which is always in UTC. This is the
+/// [`UtcTime`](super::UtcTime) type when the `chrono` feature is disabled.
+#[cfg(not(feature = "chrono"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcDateTime(DateTime);
+
+#[cfg(not(feature = "chrono"))]
+impl UtcDateTime {
+    /// Returns the date and time, with an offset of zero.
+    #[must_use]
+    pub fn date_time(self) -> DateTime {
+        self.0
+    }
+}
+
+#[cfg(not(feature = "chrono"))]
+impl From<DateTime> for UtcDateTime {
+    fn from(value: DateTime) -> Self {
+        Self(value.to_utc())
+    }
+}
+
+#[cfg(not(feature = "chrono"))]
+impl From<UtcDateTime> for DateTime {
+    fn from(value: UtcDateTime) -> Self {
+        value.0
+    }
+}
+
+/// The error returned when a [`DateTime`] has fields out of range for the
+/// type it is converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDateTime;
+
+impl core::fmt::Display for InvalidDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("date or time fields are out of range")
+    }
+}
+
+impl core::error::Error for InvalidDateTime {}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::FixedOffset>> for DateTime {
+    fn from(value: chrono::DateTime<chrono::FixedOffset>) -> Self {
+        use chrono::{Datelike, Timelike};
+
+        let local = value.naive_local();
+        Self {
+            year: local.year(),
+            month: local.month() as u8,
+            day: local.day() as u8,
+            hour: local.hour() as u8,
+            minute: local.minute() as u8,
+            second: local.second() as u8,
+            nanos: local.nanosecond() % NANOS_PER_SECOND as u32,
+            offset_minutes: (value.offset().local_minus_utc() / 60) as i16,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::from(value.fixed_offset())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = InvalidDateTime;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        let local =
+            chrono::NaiveDate::from_ymd_opt(value.year, value.month.into(), value.day.into())
+                .and_then(|date| {
+                    date.and_hms_nano_opt(
+                        value.hour.into(),
+                        value.minute.into(),
+                        value.second.into(),
+                        value.nanos,
+                    )
+                });
+        let offset = chrono::FixedOffset::east_opt(i32::from(value.offset_minutes) * 60);
+
+        local
+            .zip(offset)
+            .and_then(|(local, offset)| local.and_local_timezone(offset).single())
+            .ok_or(InvalidDateTime)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::Utc> {
+    type Error = InvalidDateTime;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        chrono::DateTime::<chrono::FixedOffset>::try_from(value)
+            .map(|value| value.with_timezone(&chrono::Utc))
+    }
+}
+
+/// Splits a trailing `Z` or `+HHMM`/`-HHMM` off `string`, along with its
+/// offset from UTC in minutes, or `None` for local time.
+fn split_zone(string: &[u8]) -> Option<(&[u8], Option<i16>)> {
+    if let Some(rest) = string.strip_suffix(b"Z") {
+        return Some((rest, Some(0)));
+    }
+    let Some(index) = string.len().checked_sub(5) else {
+        return Some((string, None));
+    };
+    let sign = match string[index] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return Some((string, None)),
+    };
+    let hours = number(&string[index + 1..index + 3])?;
+    let minutes = number(&string[index + 3..])?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some((&string[..index], Some(sign * (hours * 60 + minutes) as i16)))
+}
+
+/// Parses up to four ASCII digits.
+fn number(digits: &[u8]) -> Option<u16> {
+    if digits.is_empty() || digits.len() > 4 || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    Some(
+        digits
+            .iter()
+            .fold(0, |value, digit| value * 10 + u16::from(digit - b'0')),
+    )
+}
+
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as i32, month as u8, day as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos: 0,
+            offset_minutes: 0,
+        }
+    }
+
+    #[test]
+    fn generalized_time_forms() {
+        let parse = DateTime::parse_generalized_time;
+
+        assert_eq!(Some(time(2024, 2, 29, 13, 0, 0)), parse("2024022913Z"));
+        assert_eq!(Some(time(2024, 2, 29, 13, 30, 0)), parse("2024022913.5"));
+        assert_eq!(Some(time(2024, 2, 29, 13, 1, 30)), parse("202402291301,5"));
+        assert_eq!(
+            Some(DateTime {
+                nanos: 250_000_000,
+                offset_minutes: -330,
+                ..time(2024, 2, 29, 13, 1, 2)
+            }),
+            parse("20240229130102.25-0530")
+        );
+        assert_eq!(
+            Some((13, -330)),
+            parse("20240229130102.25-0530").map(|value| (value.hour, value.offset_minutes))
+        );
+        assert_eq!(None, parse("2023022913Z"));
+        assert_eq!(None, parse("20240229"));
+        assert_eq!(None, parse("20240229Z"));
+        assert_eq!(None, parse("20240229.5Z"));
+        assert_eq!(None, parse("20240229130102."));
+        assert_eq!(None, parse("20240229130160Z"));
+        assert_eq!(None, parse("2024022913010Z"));
+        assert_eq!(None, parse("20240229130102+2400"));
+
+        let canonical = DateTime::parse_canonical_generalized_time;
+        assert!(canonical("20240229130102Z").is_some());
+        assert!(canonical("20240229130102.25Z").is_some());
+        assert!(canonical("20240229130102.250Z").is_none());
+        assert!(canonical("20240229130102.0Z").is_none());
+        assert!(canonical("20240229130102,25Z").is_none());
+        assert!(canonical("202402291301Z").is_none());
+        assert!(canonical("20240229130102").is_none());
+        assert!(canonical("20240229130102+0100").is_none());
+    }
+
+    #[test]
+    fn utc_time_forms() {
+        let parse = DateTime::parse_utc_time;
+
+        assert_eq!(Some(time(1950, 1, 2, 3, 4, 0)), parse("5001020304Z"));
+        assert_eq!(Some(time(2049, 1, 2, 3, 4, 5)), parse("490102030405Z"));
+        assert_eq!(
+            Some(DateTime {
+                offset_minutes: -300,
+                ..time(1982, 1, 2, 7, 0, 0)
+            }),
+            parse("820102070000-0500")
+        );
+        assert_eq!(
+            Some(-300),
+            parse("820102070000-0500").map(|value| value.offset_minutes)
+        );
+        assert_eq!(None, parse("820102070000"));
+        assert_eq!(None, parse("8201020700.5Z"));
+
+        assert!(DateTime::parse_canonical_utc_time("820102070000Z").is_some());
+        assert!(DateTime::parse_canonical_utc_time("8201020700Z").is_none());
+    }
+
+    #[test]
+    fn formatting() {
+        let value = DateTime {
+            nanos: 120_000_000,
+            offset_minutes: 90,
+            ..time(2024, 1, 1, 0, 30, 0)
+        };
+
+        assert_eq!(
+            "20231231230000.12Z",
+            value.to_generalized_time_string(false)
+        );
+        assert_eq!(
+            "20240101003000.12+0130",
+            value.to_generalized_time_string(true)
+        );
+        assert_eq!("231231230000Z", value.to_utc_time_string());
+        assert_eq!(value, value.to_utc());
+        assert_eq!(
+            value.to_generalized_time_string(true),
+            value
+                .to_utc()
+                .with_offset(90)
+                .to_generalized_time_string(true)
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! `uuid::Uuid` as an `OCTET STRING (SIZE(16))`, enabled with the `uuid`
+//! feature.
+
+use uuid::Uuid;
+
+use super::{AsnType, Constraints, FixedOctetString, Identifier, ObjectIdentifier, Oid, Tag};
+use crate::{Decode, Decoder, Encode, Encoder};
+
+/// Encoded as the 16 octets of the UUID in big-endian order.
+/// ```
+/// use uuid::Uuid;
+///
+/// let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+/// let encoded = rasn::der::encode(&uuid).unwrap();
+///
+/// assert_eq!(&[0x04, 0x10, 0x67, 0xE5], &encoded[..4]);
+/// assert_eq!(uuid, rasn::der::decode::<Uuid>(&encoded).unwrap());
+/// ```
+impl AsnType for Uuid {
+    const TAG: Tag = FixedOctetString::<16>::TAG;
+    const CONSTRAINTS: Constraints = FixedOctetString::<16>::CONSTRAINTS;
+    const IDENTIFIER: Identifier = FixedOctetString::<16>::IDENTIFIER;
+}
+
+impl Decode for Uuid {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        FixedOctetString::<16>::decode_with_tag_and_constraints(decoder, tag, constraints)
+            .map(|bytes| Self::from_bytes(*bytes))
+    }
+}
+
+impl Encode for Uuid {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(
+                tag,
+                constraints,
+                self.as_bytes(),
+                identifier.or(Self::IDENTIFIER),
+            )
+            .map(drop)
+    }
+}
+
+impl ObjectIdentifier {
+    /// Creates the object identifier `2.25.<uuid>`, which names `uuid` as a
+    /// single integer arc under the UUID arc (ITU-T X.667).
+    /// ```
+    /// use rasn::types::ObjectIdentifier;
+    /// use uuid::Uuid;
+    ///
+    /// let uuid = Uuid::from_u128(0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6);
+    /// let oid = ObjectIdentifier::from_uuid(uuid);
+    ///
+    /// assert_eq!(oid.to_string(), "2.25.329800735698586629295641978511506172918");
+    /// assert_eq!(oid.to_uuid(), Some(uuid));
+    /// ```
+    #[must_use]
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Oid::JOINT_ISO_ITU_T_UUID
+            .child(uuid.as_u128())
+            .expect("arcs under `2` are unbounded")
+    }
+}
+
+impl Oid {
+    /// Returns the UUID named by an object identifier of the form
+    /// `2.25.<uuid>`, or `None` for any other object identifier.
+    #[must_use]
+    pub fn to_uuid(&self) -> Option<Uuid> {
+        match **self {
+            [2, 25, arc] => Some(Uuid::from_u128(arc)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::DecodeErrorKind, types::OctetString};
+
+    #[test]
+    fn round_trip() {
+        let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+
+        macro_rules! round_trip {
+            ($($codec:ident),+ $(,)?) => {
+                $(
+                    let encoded = crate::$codec::encode(&uuid).unwrap();
+                    assert_eq!(uuid, crate::$codec::decode::<Uuid>(&encoded).unwrap());
+                )+
+            }
+        }
+
+        round_trip!(ber, der, cer, oer, coer, uper, aper, jer);
+        assert_eq!(
+            crate::der::encode(&OctetString::from_static(uuid.as_bytes())).unwrap(),
+            crate::der::encode(&uuid).unwrap()
+        );
+    }
+
+    #[test]
+    fn wrong_length() {
+        let error = crate::ber::decode::<Uuid>(&[0x04, 0x03, 0x01, 0x02, 0x03]).unwrap_err();
+
+        assert!(matches!(
+            *error.kind,
+            DecodeErrorKind::FixedStringConversionFailed {
+                actual: 3,
+                expected: 16,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn object_identifier() {
+        let uuid = Uuid::from_u128(u128::MAX);
+        let oid = ObjectIdentifier::from_uuid(uuid);
+        assert_eq!(oid.parent(), Some(Oid::JOINT_ISO_ITU_T_UUID));
+        assert_eq!(oid.to_uuid(), Some(uuid));
+
+        let encoded = crate::der::encode(&oid).unwrap();
+        let decoded = crate::der::decode::<ObjectIdentifier>(&encoded).unwrap();
+        assert_eq!(decoded.to_uuid(), Some(uuid));
+
+        assert_eq!(Oid::JOINT_ISO_ITU_T_UUID.to_uuid(), None);
+        assert_eq!(Oid::new(&[2, 26, 1]).unwrap().to_uuid(), None);
+        assert_eq!(Oid::new(&[2, 25, 1, 1]).unwrap().to_uuid(), None);
+    }
+}
@@ -941,6 +941,7 @@ impl crate::Decoder for Decoder {
         self.decode_optional()
     }
 
+    #[cfg(feature = "chrono")]
     fn decode_date(&mut self, _tag: Tag) -> Result<Date, Self::Error> {
         decode_time!(
             self,
@@ -999,7 +1000,7 @@ fn parse_octetstring_value(val: &str) -> Result<alloc::vec::Vec<u8>, DecodeError
 fn parse_object_identifier(val: &str) -> Result<ObjectIdentifier, DecodeError> {
     let arcs = val
         .split('.')
-        .try_fold(alloc::vec::Vec::<u32>::new(), |mut acc, curr| {
+        .try_fold(alloc::vec::Vec::<u128>::new(), |mut acc, curr| {
             curr.parse()
                 .map(|i| {
                     acc.push(i);
@@ -1395,6 +1396,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
     fn generalized_time() {
         let mut decoder =
             Decoder::new(r#"<TimeType>20001231235959.999+0000</TimeType>"#.as_bytes()).unwrap();
@@ -1412,6 +1414,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
     fn utc_time() {
         let mut decoder = Decoder::new(r#"<TimeType>991231235900Z</TimeType>"#.as_bytes()).unwrap();
 
@@ -7,7 +7,7 @@ use crate::{
         vec::Vec,
     },
     types::{
-        fields::Fields, Any, BitStr, BmpString, Constraints, Date, Enumerated, GeneralString,
+        fields::Fields, Any, BitStr, BmpString, Constraints, Enumerated, GeneralString,
         GeneralizedTime, GraphicString, Ia5String, Identifier, IntegerType, NumericString,
         OctetString, Oid, PrintableString, RealType, SetOf, Tag, TeletexString, UtcTime,
         VisibleString,
@@ -221,7 +221,7 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_object_identifier(
         &mut self,
         _tag: Tag,
-        value: &[u32],
+        value: &[u128],
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         wrap_in_tags!(
@@ -721,6 +721,7 @@ impl crate::Encoder<'_> for Encoder {
         )
     }
 
+    #[cfg(feature = "chrono")]
     fn encode_date(
         &mut self,
         _tag: Tag,
@@ -729,7 +730,7 @@ impl crate::Encoder<'_> for Encoder {
     ) -> Result<Self::Ok, Self::Error> {
         wrap_in_tags!(
             self,
-            Cow::Borrowed(identifier.or(Date::IDENTIFIER).unwrap()),
+            Cow::Borrowed(identifier.or(crate::types::Date::IDENTIFIER).unwrap()),
             write_date,
             value
         )
@@ -747,7 +748,8 @@ impl Encoder {
         }
     }
 
-    fn write_date(&mut self, value: &Date) -> Result<(), EncodeError> {
+    #[cfg(feature = "chrono")]
+    fn write_date(&mut self, value: &crate::types::Date) -> Result<(), EncodeError> {
         self.write(XmlEvent::Characters(&value.format("%Y%m%d").to_string()))
     }
 
@@ -789,7 +791,7 @@ impl Encoder {
         }
     }
 
-    fn write_object_identifier(&mut self, value: &[u32]) -> Result<(), EncodeError> {
+    fn write_object_identifier(&mut self, value: &[u128]) -> Result<(), EncodeError> {
         self.write(XmlEvent::Characters(
             &value
                 .iter()
@@ -839,9 +841,10 @@ impl Encoder {
 
     fn write_utc_time(&mut self, value: &UtcTime) -> Result<(), EncodeError> {
         self.write(XmlEvent::Characters(
-            &String::from_utf8(
-                crate::ber::enc::Encoder::datetime_to_canonical_utc_time_bytes(value),
-            )
+            &String::from_utf8(crate::ber::enc::Encoder::checked_utc_time_bytes(
+                value,
+                crate::Codec::Xer,
+            )?)
             .map_err(|e| XerEncodeErrorKind::XmlEncodingError {
                 upstream: e.to_string(),
             })?,
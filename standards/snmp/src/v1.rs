@@ -88,7 +88,7 @@ mod tests {
     use rasn::types::ObjectIdentifier;
     use smi::v1::{Gauge, IpAddress, NetworkAddress, TimeTicks};
 
-    fn string_oid(oid: impl AsRef<[u32]>) -> String {
+    fn string_oid(oid: impl AsRef<[u128]>) -> String {
         oid.as_ref()
             .iter()
             .map(ToString::to_string)
@@ -279,4 +279,42 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn application_syntax_var_bind() {
+        use smi::{
+            v1::Counter,
+            v2::{ApplicationSyntax, Counter32, Counter64},
+        };
+
+        let var_bind = VarBind {
+            name: ObjectIdentifier::new_unchecked(vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 10, 1].into()),
+            value: VarBindValue::Value(ObjectSyntax::ApplicationWide(ApplicationSyntax::Counter(
+                Counter(300),
+            ))),
+        };
+        let data = vec![
+            // SEQUENCE
+            0x30, 0x10, // OBJECT IDENTIFIER
+            0x06, 0x0a, 0x2b, 0x06, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x0a, 0x01,
+            // [APPLICATION 1] Counter32
+            0x41, 0x02, 0x01, 0x2c,
+        ];
+
+        assert_eq!(data, rasn::ber::encode(&var_bind).unwrap());
+        assert_eq!(var_bind, rasn::ber::decode::<VarBind>(&data).unwrap());
+
+        // Counter32 values must fit in 32 bits.
+        assert!(
+            rasn::ber::decode::<Counter32>(&[0x41, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00]).is_err()
+        );
+
+        let big_counter = ApplicationSyntax::BigCounter(Counter64(u64::MAX));
+        let data = rasn::ber::encode(&big_counter).unwrap();
+        assert_eq!(
+            vec![0x46, 0x09, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+            data
+        );
+        assert_eq!(big_counter, rasn::ber::decode(&data).unwrap());
+    }
 }
@@ -0,0 +1,129 @@
+//! Round-tripping an SNMPv2c GetResponse whose variable bindings use each of
+//! the SMI application types.
+
+use pretty_assertions::assert_eq;
+use rasn::prelude::*;
+use rasn_snmp::{
+    v2::{GetNextRequest, Pdu, Pdus, Response, VarBind, VarBindValue},
+    v2c::Message,
+};
+use smi::{
+    v1::{Counter, Gauge, IpAddress, TimeTicks},
+    v2::{ApplicationSyntax, Counter64, ObjectSyntax, SimpleSyntax},
+};
+
+/// The response from a router to a GET of sysDescr.0, sysObjectID.0,
+/// sysUpTime.0, ifInOctets.2, ifSpeed.2, ipAdEntAddr.192.168.1.1,
+/// ifHCInOctets.2 and sysORDescr.1, the last of which doesn't exist.
+const GET_RESPONSE: &[u8] = &[
+    0x30, 0x81, 0xD1, 0x02, 0x01, 0x01, 0x04, 0x06, 0x70, 0x75, 0x62, 0x6C, 0x69, 0x63, 0xA2, 0x81,
+    0xC3, 0x02, 0x04, 0x2C, 0x4A, 0x6F, 0x1D, 0x02, 0x01, 0x00, 0x02, 0x01, 0x00, 0x30, 0x81, 0xB4,
+    0x30, 0x1D, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x01, 0x01, 0x00, 0x04, 0x11, 0x4C, 0x69,
+    0x6E, 0x75, 0x78, 0x20, 0x72, 0x6F, 0x75, 0x74, 0x65, 0x72, 0x20, 0x35, 0x2E, 0x31, 0x35, 0x30,
+    0x16, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x01, 0x02, 0x00, 0x06, 0x0A, 0x2B, 0x06, 0x01,
+    0x04, 0x01, 0xBF, 0x08, 0x03, 0x02, 0x0A, 0x30, 0x0F, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x02, 0x01,
+    0x01, 0x03, 0x00, 0x43, 0x03, 0x01, 0xE2, 0x40, 0x30, 0x13, 0x06, 0x0A, 0x2B, 0x06, 0x01, 0x02,
+    0x01, 0x02, 0x02, 0x01, 0x0A, 0x02, 0x41, 0x05, 0x00, 0xB2, 0xD0, 0x5E, 0x00, 0x30, 0x12, 0x06,
+    0x0A, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x05, 0x02, 0x42, 0x04, 0x3B, 0x9A, 0xCA,
+    0x00, 0x30, 0x17, 0x06, 0x0F, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x04, 0x14, 0x01, 0x01, 0x81, 0x40,
+    0x81, 0x28, 0x01, 0x01, 0x40, 0x04, 0xC0, 0xA8, 0x01, 0x01, 0x30, 0x18, 0x06, 0x0B, 0x2B, 0x06,
+    0x01, 0x02, 0x01, 0x1F, 0x01, 0x01, 0x01, 0x06, 0x02, 0x46, 0x09, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x30, 0x0E, 0x06, 0x0A, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x01, 0x09, 0x01,
+    0x02, 0x01, 0x81, 0x00,
+];
+
+fn oid(arcs: &[u128]) -> ObjectIdentifier {
+    ObjectIdentifier::new(arcs.to_vec()).unwrap()
+}
+
+fn var_binds() -> Vec<VarBind> {
+    let value = |syntax| VarBindValue::Value(ObjectSyntax::ApplicationWide(syntax));
+
+    vec![
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 1, 1, 0]),
+            value: VarBindValue::Value(ObjectSyntax::Simple(SimpleSyntax::String(
+                OctetString::from_static(b"Linux router 5.15"),
+            ))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 1, 2, 0]),
+            value: VarBindValue::Value(ObjectSyntax::Simple(SimpleSyntax::ObjectId(oid(&[
+                1, 3, 6, 1, 4, 1, 8072, 3, 2, 10,
+            ])))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 1, 3, 0]),
+            value: value(ApplicationSyntax::Ticks(TimeTicks(123_456))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 2, 2, 1, 10, 2]),
+            value: value(ApplicationSyntax::Counter(Counter(3_000_000_000))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 2, 2, 1, 5, 2]),
+            value: value(ApplicationSyntax::Unsigned(Gauge(1_000_000_000))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 4, 20, 1, 1, 192, 168, 1, 1]),
+            value: value(ApplicationSyntax::Address(IpAddress(
+                [192, 168, 1, 1].into(),
+            ))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 6, 2]),
+            value: value(ApplicationSyntax::BigCounter(Counter64((1 << 63) + 5))),
+        },
+        VarBind {
+            name: oid(&[1, 3, 6, 1, 2, 1, 1, 9, 1, 2, 1]),
+            value: VarBindValue::NoSuchInstance,
+        },
+    ]
+}
+
+#[test]
+fn get_response() {
+    let message = Message {
+        version: 1.into(),
+        community: OctetString::from_static(b"public"),
+        data: Pdus::Response(Response(Pdu {
+            request_id: 0x2C4A_6F1D,
+            error_status: Pdu::ERROR_STATUS_NO_ERROR,
+            error_index: 0,
+            variable_bindings: var_binds(),
+        })),
+    };
+
+    assert_eq!(message, rasn::ber::decode(GET_RESPONSE).unwrap());
+    assert_eq!(GET_RESPONSE, &*rasn::ber::encode(&message).unwrap());
+}
+
+/// The same PDUs, with the IMPLICIT context tags given on the CHOICE variants
+/// instead of on newtypes of `Pdu`.
+#[test]
+fn variant_tagged_pdus() {
+    #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+    #[rasn(choice)]
+    enum VariantPdus {
+        #[rasn(tag(0))]
+        GetRequest(Pdu),
+        #[rasn(tag(1))]
+        GetNextRequest(Pdu),
+        #[rasn(tag(2))]
+        Response(Pdu),
+    }
+
+    let message: Message<VariantPdus> = rasn::ber::decode(GET_RESPONSE).unwrap();
+    let VariantPdus::Response(pdu) = &message.data else {
+        panic!("expected a Response, got {:?}", message.data);
+    };
+
+    assert_eq!(var_binds(), pdu.variable_bindings);
+    assert_eq!(GET_RESPONSE, &*rasn::ber::encode(&message).unwrap());
+
+    // Each alternative is the same SEQUENCE, distinguished only by its tag.
+    assert_eq!(
+        rasn::ber::encode(&Pdus::GetNextRequest(GetNextRequest(pdu.clone()))).unwrap(),
+        rasn::ber::encode(&VariantPdus::GetNextRequest(pdu.clone())).unwrap()
+    );
+}
@@ -0,0 +1,103 @@
+//! Checks that encoding nested values writes large contents straight into the
+//! output, rather than into a temporary buffer for each level of nesting.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rasn::prelude::*;
+
+const PAYLOAD_LEN: usize = 1 << 20;
+
+static LARGE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts allocations that are large enough to hold the payload.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() >= PAYLOAD_LEN {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size >= PAYLOAD_LEN {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct Level1 {
+    inner: Level2,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct Level2 {
+    inner: Level3,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct Level3 {
+    #[rasn(tag(explicit(0)))]
+    inner: Level4,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct Level4 {
+    inner: Vec<Level5>,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct Level5 {
+    id: u8,
+    payload: OctetString,
+}
+
+#[test]
+fn nested_encoding_does_not_copy_payload() {
+    let value = Level1 {
+        inner: Level2 {
+            inner: Level3 {
+                inner: Level4 {
+                    inner: vec![Level5 {
+                        id: 1,
+                        payload: OctetString::from(vec![0xAB; PAYLOAD_LEN]),
+                    }],
+                },
+            },
+        },
+    };
+
+    let mut buffer = Vec::with_capacity(PAYLOAD_LEN + 64);
+    let before = LARGE_ALLOCATIONS.load(Ordering::SeqCst);
+    rasn::ber::encode_buf(&value, &mut buffer).unwrap();
+    assert_eq!(before, LARGE_ALLOCATIONS.load(Ordering::SeqCst));
+
+    // Each level needs a four octet length for its contents.
+    assert_eq!(
+        &[
+            0x30, 0x83, 0x10, 0x00, 0x26, // Level1
+            0x30, 0x83, 0x10, 0x00, 0x21, // Level2
+            0x30, 0x83, 0x10, 0x00, 0x1C, // Level3
+            0xA0, 0x83, 0x10, 0x00, 0x17, // explicit tag
+            0x30, 0x83, 0x10, 0x00, 0x12, // Level4
+            0x30, 0x83, 0x10, 0x00, 0x0D, // SEQUENCE OF
+            0x30, 0x83, 0x10, 0x00, 0x08, // Level5
+            0x02, 0x01, 0x01, // id
+            0x04, 0x83, 0x10, 0x00, 0x00, // payload
+        ][..],
+        &buffer[..43]
+    );
+    assert_eq!(43 + PAYLOAD_LEN, buffer.len());
+    assert_eq!(value, rasn::der::decode::<Level1>(&buffer).unwrap());
+}
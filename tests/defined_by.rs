@@ -0,0 +1,115 @@
+use rasn::prelude::*;
+
+const BASIC_CONSTRAINTS: &Oid = rasn::oid!("2.5.29.19");
+const KEY_USAGE: &Oid = rasn::oid!("2.5.29.15");
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct BasicConstraints {
+    #[rasn(default)]
+    ca: bool,
+    path_len_constraint: Option<u32>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(delegate)]
+struct KeyUsage(BitString);
+
+rasn::defined_by! {
+    #[derive(Clone, Debug, PartialEq)]
+    enum ExtensionValue {
+        BasicConstraints(BasicConstraints) = BASIC_CONSTRAINTS,
+        KeyUsage(KeyUsage) = KEY_USAGE,
+    }
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Extension {
+    extn_id: ObjectIdentifier,
+    #[rasn(default)]
+    critical: bool,
+    #[rasn(defined_by = "extn_id")]
+    extn_value: ExtensionValue,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct AlgorithmIdentifier {
+    algorithm: ObjectIdentifier,
+    #[rasn(defined_by = "algorithm")]
+    parameters: Option<ExtensionValue>,
+}
+
+#[test]
+fn known_values() {
+    let encoded = &[
+        0x30, 0x10, 0x06, 0x03, 0x55, 0x1D, 0x13, 0x01, 0x01, 0xFF, 0x30, 0x06, 0x01, 0x01, 0xFF,
+        0x02, 0x01, 0x00,
+    ][..];
+    let extension = Extension {
+        extn_id: BASIC_CONSTRAINTS.to_owned(),
+        critical: true,
+        extn_value: ExtensionValue::BasicConstraints(BasicConstraints {
+            ca: true,
+            path_len_constraint: Some(0),
+        }),
+    };
+    assert_eq!(extension, rasn::der::decode(encoded).unwrap());
+    assert_eq!(encoded, rasn::der::encode(&extension).unwrap());
+    assert_eq!(Some(BASIC_CONSTRAINTS), extension.extn_value.oid());
+
+    // keyCertSign and cRLSign.
+    let encoded = &[
+        0x30, 0x09, 0x06, 0x03, 0x55, 0x1D, 0x0F, 0x03, 0x02, 0x01, 0x06,
+    ][..];
+    let extension: Extension = rasn::der::decode(encoded).unwrap();
+    let ExtensionValue::KeyUsage(KeyUsage(bits)) = &extension.extn_value else {
+        panic!("expected KeyUsage, got {:?}", extension.extn_value);
+    };
+    assert_eq!(7, bits.len());
+    assert!(bits[5] && bits[6]);
+    assert!(!extension.critical);
+    assert_eq!(encoded, rasn::der::encode(&extension).unwrap());
+}
+
+#[test]
+fn unknown_values_are_kept() {
+    // subjectKeyIdentifier isn't in ExtensionValue.
+    let encoded = &[
+        0x30, 0x09, 0x06, 0x03, 0x55, 0x1D, 0x0E, 0x04, 0x02, 0xCA, 0xFE,
+    ][..];
+    let extension: Extension = rasn::der::decode(encoded).unwrap();
+
+    assert_eq!(
+        ExtensionValue::Unknown(Any::new(vec![0x04, 0x02, 0xCA, 0xFE])),
+        extension.extn_value
+    );
+    assert_eq!(None, extension.extn_value.oid());
+    assert_eq!(encoded, rasn::der::encode(&extension).unwrap());
+}
+
+#[test]
+fn mismatched_values_are_errors() {
+    // A basicConstraints extension holding a BIT STRING.
+    let encoded = &[
+        0x30, 0x09, 0x06, 0x03, 0x55, 0x1D, 0x13, 0x03, 0x02, 0x01, 0x06,
+    ][..];
+
+    assert!(rasn::der::decode::<Extension>(encoded).is_err());
+}
+
+#[test]
+fn optional_values() {
+    let absent = &[0x30, 0x05, 0x06, 0x03, 0x55, 0x1D, 0x0F][..];
+    let identifier: AlgorithmIdentifier = rasn::der::decode(absent).unwrap();
+    assert_eq!(None, identifier.parameters);
+    assert_eq!(absent, rasn::der::encode(&identifier).unwrap());
+
+    let present = &[
+        0x30, 0x09, 0x06, 0x03, 0x55, 0x1D, 0x0F, 0x03, 0x02, 0x01, 0x06,
+    ][..];
+    let identifier: AlgorithmIdentifier = rasn::der::decode(present).unwrap();
+    assert!(matches!(
+        identifier.parameters,
+        Some(ExtensionValue::KeyUsage(_))
+    ));
+    assert_eq!(present, rasn::der::encode(&identifier).unwrap());
+}
@@ -21,6 +21,52 @@ fn enumerated() {
     assert_eq!(drei, ber::decode(&ber::encode(&drei).unwrap()).unwrap());
 }
 
+#[test]
+fn enumerated_explicit_discriminants() {
+    #[derive(AsnType, Clone, Copy, Debug, Encode, Decode, PartialEq)]
+    #[rasn(enumerated)]
+    enum Status {
+        Other = 0,
+        Unknown = -1,
+        Reserved = 255,
+    }
+
+    let cases: [(Status, &[u8]); 3] = [
+        (Status::Other, &[0x0A, 0x01, 0x00]),
+        (Status::Unknown, &[0x0A, 0x01, 0xFF]),
+        (Status::Reserved, &[0x0A, 0x02, 0x00, 0xFF]),
+    ];
+
+    for (status, encoded) in cases {
+        assert_eq!(encoded, ber::encode(&status).unwrap());
+        assert_eq!(encoded, der::encode(&status).unwrap());
+        assert_eq!(status, ber::decode(encoded).unwrap());
+        assert_eq!(
+            status,
+            uper::decode(&uper::encode(&status).unwrap()).unwrap()
+        );
+        assert_eq!(status, oer::decode(&oer::encode(&status).unwrap()).unwrap());
+    }
+
+    // `-1` with a redundant sign octet is still `Unknown` in BER.
+    assert_eq!(
+        Status::Unknown,
+        ber::decode(&[0x0A, 0x02, 0xFF, 0xFF]).unwrap()
+    );
+
+    for (encoded, value) in [(&[0x0A, 0x01, 0x07][..], 7), (&[0x0A, 0x01, 0xFE], -2)] {
+        let error = ber::decode::<Status>(encoded).unwrap_err();
+        assert!(
+            matches!(
+                *error.kind,
+                rasn::error::DecodeErrorKind::DiscriminantValueNotFound { discriminant }
+                    if discriminant == value
+            ),
+            "{error}"
+        );
+    }
+}
+
 #[test]
 fn choice() {
     #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
@@ -64,6 +110,140 @@ fn choice() {
     assert_eq!(bar, ber::decode(&ber::encode(&bar).unwrap()).unwrap());
 }
 
+#[test]
+fn choice_with_same_inner_type() {
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    #[rasn(choice)]
+    enum Name {
+        Plain(Utf8String),
+        #[rasn(tag(0))]
+        Implicit(Utf8String),
+        #[rasn(tag(explicit(1)))]
+        Explicit(Utf8String),
+    }
+
+    for name in [
+        Name::Plain("a".into()),
+        Name::Implicit("b".into()),
+        Name::Explicit("c".into()),
+    ] {
+        assert_eq!(name, ber::decode(&ber::encode(&name).unwrap()).unwrap());
+        assert_eq!(name, der::decode(&der::encode(&name).unwrap()).unwrap());
+    }
+
+    assert_eq!(
+        &[0x80, 0x01, b'b'][..],
+        ber::encode(&Name::Implicit("b".into())).unwrap()
+    );
+    assert_eq!(
+        &[0xA1, 0x03, 0x0C, 0x01, b'c'][..],
+        ber::encode(&Name::Explicit("c".into())).unwrap()
+    );
+}
+
+#[test]
+fn choice_with_mixed_variant_tags() {
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    struct Certificate {
+        serial_number: u8,
+    }
+
+    /// Shaped like `CertificateChoices` from RFC 5652, where one alternative
+    /// is distinguished by its universal tag and the others by their own.
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    #[rasn(choice)]
+    enum CertificateChoices {
+        Certificate(Certificate),
+        #[rasn(tag(0))]
+        ExtendedCertificate(Certificate),
+        #[rasn(tag(explicit(2)))]
+        V2AttrCert(Certificate),
+        #[rasn(tag(application, 3))]
+        Other(Certificate),
+    }
+
+    let certificate = || Certificate { serial_number: 5 };
+    let cases: [(CertificateChoices, &[u8]); 4] = [
+        (
+            CertificateChoices::Certificate(certificate()),
+            &[0x30, 0x03, 0x02, 0x01, 0x05],
+        ),
+        (
+            CertificateChoices::ExtendedCertificate(certificate()),
+            &[0xA0, 0x03, 0x02, 0x01, 0x05],
+        ),
+        (
+            CertificateChoices::V2AttrCert(certificate()),
+            &[0xA2, 0x05, 0x30, 0x03, 0x02, 0x01, 0x05],
+        ),
+        (
+            CertificateChoices::Other(certificate()),
+            &[0x63, 0x03, 0x02, 0x01, 0x05],
+        ),
+    ];
+
+    for (value, encoded) in cases {
+        assert_eq!(encoded, ber::encode(&value).unwrap());
+        assert_eq!(value, ber::decode(encoded).unwrap());
+    }
+
+    // `[1]` isn't one of the alternatives.
+    assert!(ber::decode::<CertificateChoices>(&[0xA1, 0x03, 0x02, 0x01, 0x05]).is_err());
+    // `[2]` is EXPLICIT, so the certificate must be wrapped.
+    assert!(ber::decode::<CertificateChoices>(&[0xA2, 0x03, 0x02, 0x01, 0x05]).is_err());
+}
+
+#[test]
+fn recursive_choice() {
+    use std::{rc::Rc, sync::Arc};
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    #[rasn(choice)]
+    enum Filter {
+        #[rasn(tag(0))]
+        And(Vec<Filter>),
+        #[rasn(tag(explicit(2)))]
+        Not(Box<Filter>),
+        #[rasn(tag(7))]
+        Present(OctetString),
+    }
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    struct Shared {
+        filter: Arc<Filter>,
+        count: Rc<Integer>,
+    }
+
+    let present = Filter::Present(OctetString::from_static(b"cn"));
+    let filter = Filter::Not(Box::new(Filter::Not(Box::new(Filter::Not(Box::new(
+        present.clone(),
+    ))))));
+    let encoded = &[0xA2, 0x08, 0xA2, 0x06, 0xA2, 0x04, 0x87, 0x02, b'c', b'n'];
+
+    assert_eq!(&encoded[..], ber::encode(&filter).unwrap());
+    assert_eq!(filter, ber::decode(encoded).unwrap());
+    assert_eq!(filter, der::decode(encoded).unwrap());
+
+    let and = Filter::And(vec![present, filter]);
+    assert_eq!(and, ber::decode(&ber::encode(&and).unwrap()).unwrap());
+
+    let shared = Shared {
+        filter: Arc::new(and),
+        count: Rc::new(3.into()),
+    };
+    assert_eq!(shared, ber::decode(&ber::encode(&shared).unwrap()).unwrap());
+    assert_eq!(
+        ber::encode(&Filter::Not(Box::new(Filter::Present(
+            OctetString::default()
+        ))))
+        .unwrap(),
+        ber::encode(&Arc::new(Filter::Not(Box::new(Filter::Present(
+            OctetString::default()
+        )))))
+        .unwrap()
+    );
+}
+
 #[test]
 fn sequence() {
     #[derive(AsnType, Debug, Default, Decode, Encode, PartialEq)]
@@ -283,3 +463,427 @@ fn test_constraint_values() {
     let encoded = rasn::uper::encode(&my_struct);
     assert!(encoded.is_ok());
 }
+
+#[test]
+fn custom_field_codec() {
+    use rasn::{de::Error as _, enc::Error as _};
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Inner {
+        id: Integer,
+        flag: bool,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Outer {
+        version: Integer,
+        /// Carried as the DER encoding of `Inner` inside an OCTET STRING.
+        #[rasn(tag(universal, 4))]
+        #[rasn(decode_with = "decode_nested", encode_with = "encode_nested")]
+        inner: Inner,
+    }
+
+    fn decode_nested<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Inner, D::Error> {
+        assert_eq!(tag, Tag::OCTET_STRING);
+        let bytes = OctetString::decode_with_tag(decoder, tag)?;
+        der::decode(&bytes).map_err(|error| D::Error::custom(error, decoder.codec()))
+    }
+
+    fn encode_nested<'e, E: Encoder<'e>>(
+        value: &Inner,
+        encoder: &mut E,
+        tag: Tag,
+    ) -> Result<(), E::Error> {
+        let bytes = der::encode(value).map_err(|error| E::Error::custom(error, encoder.codec()))?;
+        OctetString::from(bytes).encode_with_tag(encoder, tag)
+    }
+
+    let value = Outer {
+        version: 1.into(),
+        inner: Inner {
+            id: 5.into(),
+            flag: true,
+        },
+    };
+    let encoded = &[
+        0x30, 0x0D, 0x02, 0x01, 0x01, 0x04, 0x08, 0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF,
+    ][..];
+
+    assert_eq!(encoded, &*ber::encode(&value).unwrap());
+    assert_eq!(value, ber::decode(encoded).unwrap());
+
+    // The nested value is still validated.
+    let invalid = &[0x30, 0x07, 0x02, 0x01, 0x01, 0x04, 0x02, 0x05, 0x00][..];
+    assert!(ber::decode::<Outer>(invalid).is_err());
+}
+
+#[test]
+fn unknown_choice_alternative() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(choice, extensible)]
+    enum Message {
+        #[rasn(tag(0))]
+        Ping(Integer),
+        #[rasn(tag(1))]
+        Text(Utf8String),
+        #[rasn(unknown)]
+        Unknown(Any),
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Envelope {
+        message: Message,
+        id: Integer,
+    }
+
+    let ping = &[0x80, 0x01, 0x05][..];
+    assert_eq!(Message::Ping(5.into()), ber::decode(ping).unwrap());
+    assert_eq!(ping, &*ber::encode(&Message::Ping(5.into())).unwrap());
+
+    // An alternative added in a later version, `[5] SEQUENCE { INTEGER }`.
+    let unknown = &[0xA5, 0x03, 0x02, 0x01, 0x07][..];
+    let message = ber::decode::<Message>(unknown).unwrap();
+    assert_eq!(message, Message::Unknown(Any::new(unknown.to_vec())));
+    assert_eq!(unknown, &*ber::encode(&message).unwrap());
+
+    let envelope = &[0x30, 0x08, 0xA5, 0x03, 0x02, 0x01, 0x07, 0x02, 0x01, 0x01][..];
+    let decoded = ber::decode::<Envelope>(envelope).unwrap();
+    assert_eq!(decoded.message, message);
+    assert_eq!(decoded.id, 1.into());
+    assert_eq!(envelope, &*ber::encode(&decoded).unwrap());
+}
+
+#[test]
+fn sequence_remainder() {
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+    struct Record {
+        id: Integer,
+        #[rasn(tag(0))]
+        note: Option<Utf8String>,
+        #[rasn(remainder)]
+        rest: SequenceOf<Any>,
+    }
+
+    // Two components added in a later version, `[1] BOOLEAN` and `OCTET STRING`.
+    let encoded = &[
+        0x30, 0x0D, 0x02, 0x01, 0x01, 0x80, 0x02, 0x68, 0x69, 0x81, 0x01, 0xFF, 0x04, 0x01, 0xAA,
+    ][..];
+    let record = ber::decode::<Record>(encoded).unwrap();
+    assert_eq!(record.id, 1.into());
+    assert_eq!(record.note.as_deref(), Some("hi"));
+    assert_eq!(
+        record.rest,
+        [
+            Any::new(vec![0x81, 0x01, 0xFF]),
+            Any::new(vec![0x04, 0x01, 0xAA])
+        ]
+    );
+    assert_eq!(encoded, &*ber::encode(&record).unwrap());
+
+    let encoded = &[
+        0x30, 0x09, 0x02, 0x01, 0x01, 0x81, 0x01, 0xFF, 0x04, 0x01, 0xAA,
+    ][..];
+    let record = ber::decode::<Record>(encoded).unwrap();
+    assert_eq!(record.note, None);
+    assert_eq!(record.rest.len(), 2);
+    assert_eq!(encoded, &*ber::encode(&record).unwrap());
+
+    let encoded = &[0x30, 0x03, 0x02, 0x01, 0x01][..];
+    let record = ber::decode::<Record>(encoded).unwrap();
+    assert!(record.rest.is_empty());
+    assert_eq!(encoded, &*ber::encode(&record).unwrap());
+
+    // Other codecs can't delimit unknown components, so they skip them on
+    // both sides.
+    let with_rest = Record {
+        rest: vec![Any::new(vec![0x04, 0x01, 0xAA])],
+        ..record.clone()
+    };
+    assert_eq!(
+        uper::encode(&record).unwrap(),
+        uper::encode(&with_rest).unwrap()
+    );
+    assert_eq!(
+        record,
+        uper::decode(&uper::encode(&with_rest).unwrap()).unwrap()
+    );
+    assert_eq!(
+        record,
+        oer::decode(&oer::encode(&with_rest).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn named_bits() {
+    #[derive(AsnType, Clone, Copy, Debug, Default, Decode, Encode, PartialEq)]
+    #[rasn(bit_string)]
+    struct KeyUsage {
+        digital_signature: bool,
+        non_repudiation: bool,
+        key_encipherment: bool,
+        data_encipherment: bool,
+        key_agreement: bool,
+        key_cert_sign: bool,
+        crl_sign: bool,
+        encipher_only: bool,
+        decipher_only: bool,
+    }
+
+    let usage = KeyUsage {
+        digital_signature: true,
+        key_cert_sign: true,
+        ..Default::default()
+    };
+    let encoded = &[0x03, 0x02, 0x02, 0x84][..];
+    assert_eq!(encoded, &*der::encode(&usage).unwrap());
+    assert_eq!(encoded, &*ber::encode(&usage).unwrap());
+    assert_eq!(usage, der::decode(encoded).unwrap());
+
+    let usage = KeyUsage {
+        decipher_only: true,
+        ..Default::default()
+    };
+    let encoded = &[0x03, 0x03, 0x07, 0x00, 0x80][..];
+    assert_eq!(encoded, &*der::encode(&usage).unwrap());
+    assert_eq!(usage, der::decode(encoded).unwrap());
+
+    let encoded = &[0x03, 0x01, 0x00][..];
+    assert_eq!(encoded, &*der::encode(&KeyUsage::default()).unwrap());
+    assert_eq!(KeyUsage::default(), der::decode(encoded).unwrap());
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(bit_string, tag(context, 1))]
+    struct Flags {
+        #[rasn(bit = 3)]
+        ready: bool,
+        #[rasn(bit = 1)]
+        busy: bool,
+    }
+
+    let flags = Flags {
+        ready: true,
+        busy: false,
+    };
+    let encoded = &[0x81, 0x02, 0x04, 0x10][..];
+    assert_eq!(encoded, &*ber::encode(&flags).unwrap());
+    assert_eq!(flags, ber::decode(encoded).unwrap());
+    // Unnamed bits are ignored.
+    assert_eq!(flags, ber::decode(&[0x81, 0x02, 0x00, 0xB1]).unwrap());
+}
+
+#[test]
+fn tuple_struct_sequence() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Point(Integer, Integer);
+
+    let point = Point(1.into(), (-1).into());
+    let encoded = &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0xFF][..];
+    assert_eq!(encoded, &*der::encode(&point).unwrap());
+    assert_eq!(point, der::decode(encoded).unwrap());
+    assert_eq!(point, uper::decode(&uper::encode(&point).unwrap()).unwrap());
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Labelled(
+        Point,
+        #[rasn(tag(0))] bool,
+        #[rasn(tag(1))] Option<Utf8String>,
+    );
+
+    let labelled = Labelled(point, true, Some("origin".into()));
+    let unlabelled = Labelled(Point(0.into(), 0.into()), false, None);
+    for value in [labelled, unlabelled] {
+        assert_eq!(value, der::decode(&der::encode(&value).unwrap()).unwrap());
+        assert_eq!(value, oer::decode(&oer::encode(&value).unwrap()).unwrap());
+    }
+
+    let encoded = &[
+        0x30, 0x0B, 0x30, 0x06, 0x02, 0x01, 0x00, 0x02, 0x01, 0x00, 0x80, 0x01, 0x00,
+    ];
+    assert_eq!(
+        Labelled(Point(0.into(), 0.into()), false, None),
+        der::decode(encoded).unwrap()
+    );
+
+    // Fields are named by their index in error paths.
+    let error = der::decode::<Point>(&[0x30, 0x05, 0x02, 0x01, 0x01, 0x05, 0x00]).unwrap_err();
+    assert_eq!(Some("Point.1".into()), error.field_path());
+}
+
+#[test]
+fn unit_struct_null() {
+    #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+    struct NoParameters;
+
+    let encoded = &[0x05, 0x00][..];
+    assert_eq!(encoded, &*der::encode(&NoParameters).unwrap());
+    assert_eq!(NoParameters, der::decode(encoded).unwrap());
+    assert_eq!(
+        NoParameters,
+        uper::decode(&uper::encode(&NoParameters).unwrap()).unwrap()
+    );
+    assert!(der::decode::<NoParameters>(&[0x05, 0x01, 0x00]).is_err());
+    assert!(der::decode::<NoParameters>(&[0x04, 0x00]).is_err());
+
+    #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+    #[rasn(tag(context, 3))]
+    struct Absent;
+
+    let encoded = &[0x83, 0x00][..];
+    assert_eq!(encoded, &*der::encode(&Absent).unwrap());
+    assert_eq!(Absent, der::decode(encoded).unwrap());
+    assert!(der::decode::<Absent>(&[0x05, 0x00]).is_err());
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct AlgorithmIdentifier {
+        algorithm: ObjectIdentifier,
+        parameters: Option<NoParameters>,
+    }
+
+    let identifier = AlgorithmIdentifier {
+        algorithm: ObjectIdentifier::new(vec![1, 2, 840, 113_549, 1, 1, 1]).unwrap(),
+        parameters: Some(NoParameters),
+    };
+    let encoded = der::encode(&identifier).unwrap();
+    assert_eq!(&[0x05, 0x00], &encoded[encoded.len() - 2..]);
+    assert_eq!(identifier, der::decode(&encoded).unwrap());
+}
+
+#[test]
+fn optional_unit_null() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct AlgorithmIdentifier {
+        algorithm: ObjectIdentifier,
+        parameters: Option<()>,
+    }
+
+    let algorithm = ObjectIdentifier::new(vec![1, 2, 840, 113_549, 1, 1, 11]).unwrap();
+    let absent = AlgorithmIdentifier {
+        algorithm: algorithm.clone(),
+        parameters: None,
+    };
+    let null = AlgorithmIdentifier {
+        algorithm,
+        parameters: Some(()),
+    };
+
+    let oid = &[
+        0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B,
+    ][..];
+    let absent_encoded = [&[0x30, 0x0B][..], oid].concat();
+    let null_encoded = [&[0x30, 0x0D][..], oid, &[0x05, 0x00]].concat();
+
+    assert_eq!(absent_encoded, der::encode(&absent).unwrap());
+    assert_eq!(null_encoded, der::encode(&null).unwrap());
+    assert_eq!(absent, der::decode(&absent_encoded).unwrap());
+    assert_eq!(null, der::decode(&null_encoded).unwrap());
+
+    let invalid = [&[0x30, 0x0E][..], oid, &[0x05, 0x01, 0x00]].concat();
+    assert!(der::decode::<AlgorithmIdentifier>(&invalid).is_err());
+
+    for value in [&absent, &null] {
+        assert_eq!(value, &uper::decode(&uper::encode(value).unwrap()).unwrap());
+        assert_eq!(value, &oer::decode(&oer::encode(value).unwrap()).unwrap());
+    }
+}
+
+#[test]
+fn borrowed_fields() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Header<'a> {
+        magic: OctetSlice<'a>,
+        name: &'a str,
+        #[rasn(tag(0))]
+        comment: Option<&'a str>,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct OwnedHeader {
+        magic: OctetString,
+        name: Utf8String,
+        #[rasn(tag(0))]
+        comment: Option<Utf8String>,
+    }
+
+    let input = vec![0x7F, b'E', b'L', b'F'];
+    let name = String::from("header");
+    let header = Header {
+        magic: OctetSlice(&input),
+        name: &name,
+        comment: Some("x"),
+    };
+    let encoded = &[
+        0x30, 0x11, 0x04, 0x04, 0x7F, b'E', b'L', b'F', 0x0C, 0x06, b'h', b'e', b'a', b'd', b'e',
+        b'r', 0x80, 0x01, b'x',
+    ][..];
+
+    assert_eq!(encoded, ber::encode(&header).unwrap());
+    assert_eq!(encoded, der::encode(&header).unwrap());
+    assert_eq!(header, ber::decode_borrowed(encoded).unwrap());
+    assert_eq!(header, der::decode_borrowed(encoded).unwrap());
+    assert_eq!(
+        OwnedHeader {
+            magic: OctetString::from(input.clone()),
+            name: name.clone(),
+            comment: Some("x".into()),
+        },
+        ber::decode(encoded).unwrap()
+    );
+
+    let header = Header {
+        comment: None,
+        ..header
+    };
+    let owned = OwnedHeader {
+        magic: OctetString::from(input.clone()),
+        name: name.clone(),
+        comment: None,
+    };
+    assert_eq!(ber::encode(&owned).unwrap(), ber::encode(&header).unwrap());
+    assert_eq!(
+        uper::encode(&owned).unwrap(),
+        uper::encode(&header).unwrap()
+    );
+    assert_eq!(
+        header,
+        ber::decode_borrowed(&ber::encode(&owned).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn borrowed_fields_point_into_input() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Entry<'a> {
+        id: Integer,
+        name: &'a str,
+        #[rasn(tag(explicit(0)))]
+        data: Option<OctetSlice<'a>>,
+        #[rasn(tag(1))]
+        label: Option<&'a str>,
+    }
+
+    let encoded = &[
+        0x30, 0x0E, 0x02, 0x01, 0x07, 0x0C, 0x03, b'k', b'e', b'y', 0xA0, 0x04, 0x04, 0x02, 0x01,
+        0x02,
+    ][..];
+    let entry: Entry = ber::decode_borrowed(encoded).unwrap();
+
+    assert_eq!(
+        Entry {
+            id: 7.into(),
+            name: "key",
+            data: Some(OctetSlice(&[1, 2])),
+            label: None,
+        },
+        entry
+    );
+    assert_eq!(encoded[7..10].as_ptr(), entry.name.as_ptr());
+    assert_eq!(encoded[14..].as_ptr(), entry.data.unwrap().as_ptr());
+    assert_eq!(encoded, ber::encode(&entry).unwrap());
+
+    // Borrowed values can't be split across segments, so the constructed
+    // form of a borrowed field is rejected.
+    let constructed = &[
+        0x30, 0x0C, 0x02, 0x01, 0x07, 0x2C, 0x80, 0x0C, 0x03, b'k', b'e', b'y', 0x00, 0x00,
+    ][..];
+    assert!(ber::decode_borrowed::<Entry>(constructed).is_err());
+}
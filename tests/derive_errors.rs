@@ -0,0 +1,8 @@
+//! Checks the compile errors reported for invalid derive attributes, ambiguous
+//! tags and invalid `oid!` arcs.
+
+#[test]
+fn derive_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -150,3 +150,48 @@ fn works() {
     assert_eq!(inline_choice, rasn::der::decode(EXPECTED).unwrap());
     assert_eq!(wrapped_choice, rasn::der::decode(EXPECTED).unwrap());
 }
+
+#[derive(AsnType, Decode, Debug, Encode, PartialEq)]
+pub struct KdcReq {
+    pvno: u8,
+}
+
+#[derive(AsnType, Decode, Debug, Encode, PartialEq)]
+#[rasn(tag(explicit(application, 10)))]
+pub struct AsReq {
+    pvno: u8,
+}
+
+#[derive(AsnType, Decode, Debug, Encode, PartialEq)]
+#[rasn(tag(explicit(application, 10)), delegate)]
+pub struct DelegateAsReq(pub KdcReq);
+
+#[derive(AsnType, Decode, Debug, Encode, PartialEq)]
+#[rasn(tag(application, 10))]
+pub struct ImplicitAsReq {
+    pvno: u8,
+}
+
+#[test]
+fn application_tagged_top_level_sequence() {
+    const EXPLICIT: &[u8] = &[0x6A, 0x05, 0x30, 0x03, 0x02, 0x01, 0x05];
+    const IMPLICIT: &[u8] = &[0x6A, 0x03, 0x02, 0x01, 0x05];
+
+    assert!(Tag::const_eq(AsReq::TAG, &Tag::new(Class::Application, 10)));
+    assert!(Tag::const_eq(
+        ImplicitAsReq::TAG,
+        &Tag::new(Class::Application, 10)
+    ));
+
+    let as_req: AsReq = rasn::der::decode(EXPLICIT).unwrap();
+    assert_eq!(as_req, AsReq { pvno: 5 });
+    assert_eq!(rasn::der::encode(&as_req).unwrap(), EXPLICIT);
+
+    let delegate: DelegateAsReq = rasn::der::decode(EXPLICIT).unwrap();
+    assert_eq!(delegate, DelegateAsReq(KdcReq { pvno: 5 }));
+    assert_eq!(rasn::der::encode(&delegate).unwrap(), EXPLICIT);
+
+    let implicit: ImplicitAsReq = rasn::der::decode(IMPLICIT).unwrap();
+    assert_eq!(implicit, ImplicitAsReq { pvno: 5 });
+    assert_eq!(rasn::der::encode(&implicit).unwrap(), IMPLICIT);
+}
@@ -0,0 +1,141 @@
+use rasn::prelude::*;
+
+// KDC-REQ-BODY style component groups, with OPTIONAL components on both
+// sides of the flattened boundary.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Times {
+    #[rasn(tag(explicit(0)))]
+    from: Option<u32>,
+    #[rasn(tag(explicit(1)))]
+    till: u32,
+    #[rasn(tag(explicit(2)))]
+    rtime: Option<u32>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Request {
+    #[rasn(tag(explicit(3)))]
+    options: Option<bool>,
+    #[rasn(flatten)]
+    times: Times,
+    #[rasn(tag(explicit(4)))]
+    nonce: Option<u32>,
+    realm: Utf8String,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct MergedRequest {
+    #[rasn(tag(explicit(3)))]
+    options: Option<bool>,
+    #[rasn(tag(explicit(0)))]
+    from: Option<u32>,
+    #[rasn(tag(explicit(1)))]
+    till: u32,
+    #[rasn(tag(explicit(2)))]
+    rtime: Option<u32>,
+    #[rasn(tag(explicit(4)))]
+    nonce: Option<u32>,
+    realm: Utf8String,
+}
+
+impl From<Request> for MergedRequest {
+    fn from(request: Request) -> Self {
+        Self {
+            options: request.options,
+            from: request.times.from,
+            till: request.times.till,
+            rtime: request.times.rtime,
+            nonce: request.nonce,
+            realm: request.realm,
+        }
+    }
+}
+
+// Flattening a type that itself flattens another.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Renewal {
+    #[rasn(flatten)]
+    request: Request,
+    renewable: bool,
+}
+
+fn requests() -> Vec<Request> {
+    vec![
+        Request {
+            options: Some(true),
+            times: Times {
+                from: Some(1),
+                till: 2,
+                rtime: Some(3),
+            },
+            nonce: Some(4),
+            realm: "EXAMPLE.COM".into(),
+        },
+        Request {
+            options: None,
+            times: Times {
+                from: None,
+                till: 2,
+                rtime: None,
+            },
+            nonce: None,
+            realm: "EXAMPLE.COM".into(),
+        },
+        Request {
+            options: None,
+            times: Times {
+                from: Some(1),
+                till: 2,
+                rtime: None,
+            },
+            nonce: Some(4),
+            realm: "EXAMPLE.COM".into(),
+        },
+    ]
+}
+
+macro_rules! assert_same_encoding {
+    ($($codec:ident),+) => {
+        for request in requests() {
+            let merged = MergedRequest::from(request.clone());
+            $(
+                let encoded = rasn::$codec::encode(&request).unwrap();
+                assert_eq!(
+                    rasn::$codec::encode(&merged).unwrap(),
+                    encoded,
+                    "{} encoding of {request:?}",
+                    stringify!($codec)
+                );
+                assert_eq!(request, rasn::$codec::decode(&encoded).unwrap());
+            )+
+        }
+    };
+}
+
+#[test]
+fn flattened_matches_merged() {
+    assert_same_encoding!(ber, cer, der, uper, aper, oer, coer);
+}
+
+#[test]
+fn nested_flatten() {
+    let request = requests().remove(1);
+    let renewal = Renewal {
+        request: request.clone(),
+        renewable: true,
+    };
+
+    let mut expected = rasn::der::encode(&request).unwrap();
+    expected.extend_from_slice(&[0x01, 0x01, 0xFF]);
+    expected[1] += 3;
+    let encoded = rasn::der::encode(&renewal).unwrap();
+    assert_eq!(expected, encoded);
+    assert_eq!(renewal, rasn::der::decode(&encoded).unwrap());
+
+    assert_eq!(
+        renewal,
+        rasn::uper::decode(&rasn::uper::encode(&renewal).unwrap()).unwrap()
+    );
+    assert_eq!(6, <Request as Components>::COMPONENTS.len());
+    assert_eq!(7, <Renewal as Components>::COMPONENTS.len());
+}
@@ -0,0 +1,100 @@
+//! Decodes into fixed capacity and caller provided storage. Also run with
+//! `cargo test --no-default-features --features heapless,bytes --test heapless`,
+//! so that none of this depends on the default features besides `bytes`, which
+//! `OctetString` needs.
+
+use rasn::{
+    ber::de::{Decoder, DecoderOptions},
+    error::{BerDecodeErrorKind, CodecDecodeError, DecodeErrorKind},
+    prelude::*,
+};
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct Device {
+    id: u32,
+    ports: heapless::Vec<u16, 4>,
+    name: heapless::String<8>,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+struct UnboundedDevice {
+    id: u32,
+    ports: SequenceOf<u16>,
+    name: Utf8String,
+}
+
+#[test]
+fn fixed_capacity_sequence_of() {
+    let device = Device {
+        id: 7,
+        ports: heapless::Vec::from_slice(&[80, 443]).unwrap(),
+        name: heapless::String::try_from("sensor").unwrap(),
+    };
+    let encoded = rasn::der::encode(&device).unwrap();
+    assert_eq!(device, rasn::der::decode(&encoded).unwrap());
+
+    let device = UnboundedDevice {
+        id: 7,
+        ports: vec![20, 21, 22, 80, 443],
+        name: "sensor".into(),
+    };
+    let encoded = rasn::der::encode(&device).unwrap();
+    let error = rasn::der::decode::<Device>(&encoded).unwrap_err();
+    assert!(matches!(
+        *error.root_cause().kind,
+        DecodeErrorKind::SizeConstraintNotSatisfied { size: Some(5), .. }
+    ));
+}
+
+#[test]
+fn octet_string_into_buffer() {
+    let mut buffer = [0; 4];
+
+    let mut decoder = Decoder::new(&[0x04, 0x03, 1, 2, 3], DecoderOptions::der());
+    let len = decoder
+        .decode_octet_string_into(Tag::OCTET_STRING, &mut buffer)
+        .unwrap();
+    assert_eq!(&[1, 2, 3], &buffer[..len]);
+
+    // Constructed segments are joined in the buffer.
+    let data = &[0x24, 0x80, 0x04, 0x02, 4, 5, 0x04, 0x02, 6, 7, 0x00, 0x00];
+    let mut decoder = Decoder::new(data, DecoderOptions::ber());
+    let len = decoder
+        .decode_octet_string_into(Tag::OCTET_STRING, &mut buffer)
+        .unwrap();
+    assert_eq!(&[4, 5, 6, 7], &buffer[..len]);
+    assert!(decoder.remaining().is_empty());
+
+    let mut decoder = Decoder::new(&[0x04, 0x05, 1, 2, 3, 4, 5], DecoderOptions::der());
+    let error = decoder
+        .decode_octet_string_into(Tag::OCTET_STRING, &mut buffer)
+        .unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        DecodeErrorKind::SizeConstraintNotSatisfied { .. }
+    ));
+}
+
+#[test]
+fn object_identifier_into_arcs() {
+    let data = &[0x06, 0x03, 0x2B, 0x06, 0x01];
+
+    let mut arcs = [0; 4];
+    let mut decoder = Decoder::new(data, DecoderOptions::der());
+    let len = decoder
+        .decode_object_identifier_into(Tag::OBJECT_IDENTIFIER, &mut arcs)
+        .unwrap();
+    assert_eq!(&[1, 3, 6, 1], &arcs[..len]);
+
+    let mut arcs = [0; 3];
+    let mut decoder = Decoder::new(data, DecoderOptions::der());
+    let error = decoder
+        .decode_object_identifier_into(Tag::OBJECT_IDENTIFIER, &mut arcs)
+        .unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        DecodeErrorKind::CodecSpecific {
+            inner: CodecDecodeError::Ber(BerDecodeErrorKind::TooManyOidArcs { limit: 3 })
+        }
+    ));
+}
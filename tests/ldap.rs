@@ -0,0 +1,188 @@
+//! The shape of an LDAP message (RFC 4511), where the protocol operation is a
+//! CHOICE of IMPLICIT `APPLICATION` tagged SEQUENCEs and search filters are a
+//! recursive CHOICE. The tags are given on the CHOICE variants rather than on
+//! the types, unlike in `rasn-ldap`.
+
+use rasn::prelude::*;
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct LdapMessage {
+    message_id: u32,
+    protocol_op: ProtocolOp,
+    #[rasn(tag(0))]
+    controls: Option<Vec<OctetString>>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(choice)]
+enum ProtocolOp {
+    #[rasn(tag(application, 0))]
+    BindRequest(BindRequest),
+    #[rasn(tag(application, 1))]
+    BindResponse(LdapResult),
+    #[rasn(tag(application, 3))]
+    SearchRequest(SearchRequest),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct BindRequest {
+    version: u8,
+    name: Utf8String,
+    authentication: AuthenticationChoice,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(choice)]
+enum AuthenticationChoice {
+    #[rasn(tag(0))]
+    Simple(OctetString),
+    #[rasn(tag(3))]
+    Sasl(OctetString),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct LdapResult {
+    result_code: ResultCode,
+    matched_dn: OctetString,
+    diagnostic_message: OctetString,
+}
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+#[rasn(enumerated)]
+enum ResultCode {
+    Success = 0,
+    OperationsError = 1,
+    ProtocolError = 2,
+    InvalidCredentials = 49,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct SearchRequest {
+    base_object: OctetString,
+    scope: Scope,
+    deref_aliases: DerefAliases,
+    size_limit: u32,
+    time_limit: u32,
+    types_only: bool,
+    filter: Filter,
+    attributes: Vec<OctetString>,
+}
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+#[rasn(enumerated)]
+enum Scope {
+    BaseObject = 0,
+    SingleLevel = 1,
+    WholeSubtree = 2,
+}
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+#[rasn(enumerated)]
+enum DerefAliases {
+    NeverDerefAliases = 0,
+    DerefInSearching = 1,
+    DerefFindingBaseObj = 2,
+    DerefAlways = 3,
+}
+
+/// The `[2]` tag of `not` is EXPLICIT, as a tag on a CHOICE always is.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+enum Filter {
+    #[rasn(tag(0))]
+    And(SetOf<Filter>),
+    #[rasn(tag(1))]
+    Or(SetOf<Filter>),
+    #[rasn(tag(2))]
+    Not(Box<Filter>),
+    #[rasn(tag(3))]
+    EqualityMatch(AttributeValueAssertion),
+    #[rasn(tag(7))]
+    Present(OctetString),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+struct AttributeValueAssertion {
+    attribute_desc: OctetString,
+    assertion_value: OctetString,
+}
+
+#[test]
+fn bind_request() {
+    // A simple bind as "cn=admin,dc=example,dc=com" with the password "secret".
+    let encoded = &[
+        0x30, 0x2C, 0x02, 0x01, 0x01, 0x60, 0x27, 0x02, 0x01, 0x03, 0x04, 0x1A, 0x63, 0x6E, 0x3D,
+        0x61, 0x64, 0x6D, 0x69, 0x6E, 0x2C, 0x64, 0x63, 0x3D, 0x65, 0x78, 0x61, 0x6D, 0x70, 0x6C,
+        0x65, 0x2C, 0x64, 0x63, 0x3D, 0x63, 0x6F, 0x6D, 0x80, 0x06, 0x73, 0x65, 0x63, 0x72, 0x65,
+        0x74,
+    ];
+    let message = LdapMessage {
+        message_id: 1,
+        protocol_op: ProtocolOp::BindRequest(BindRequest {
+            version: 3,
+            name: "cn=admin,dc=example,dc=com".into(),
+            authentication: AuthenticationChoice::Simple(OctetString::from_static(b"secret")),
+        }),
+        controls: None,
+    };
+
+    assert_eq!(message, rasn::ber::decode(encoded).unwrap());
+    assert_eq!(&encoded[..], rasn::ber::encode(&message).unwrap());
+
+    let response = &[
+        0x30, 0x0C, 0x02, 0x01, 0x01, 0x61, 0x07, 0x0A, 0x01, 0x00, 0x04, 0x00, 0x04, 0x00,
+    ];
+    let message = LdapMessage {
+        message_id: 1,
+        protocol_op: ProtocolOp::BindResponse(LdapResult {
+            result_code: ResultCode::Success,
+            matched_dn: OctetString::new(),
+            diagnostic_message: OctetString::new(),
+        }),
+        controls: None,
+    };
+
+    assert_eq!(message, rasn::ber::decode(response).unwrap());
+    assert_eq!(&response[..], rasn::ber::encode(&message).unwrap());
+}
+
+#[test]
+fn search_request() {
+    // A search of "dc=example,dc=com" with the filter
+    // "(&(!(mail=*))(objectClass=person))", returning "cn" and "mail".
+    let encoded = &[
+        0x30, 0x54, 0x02, 0x01, 0x02, 0x63, 0x4F, 0x04, 0x11, 0x64, 0x63, 0x3D, 0x65, 0x78, 0x61,
+        0x6D, 0x70, 0x6C, 0x65, 0x2C, 0x64, 0x63, 0x3D, 0x63, 0x6F, 0x6D, 0x0A, 0x01, 0x02, 0x0A,
+        0x01, 0x00, 0x02, 0x01, 0x00, 0x02, 0x01, 0x00, 0x01, 0x01, 0x00, 0xA0, 0x1F, 0xA2, 0x06,
+        0x87, 0x04, 0x6D, 0x61, 0x69, 0x6C, 0xA3, 0x15, 0x04, 0x0B, 0x6F, 0x62, 0x6A, 0x65, 0x63,
+        0x74, 0x43, 0x6C, 0x61, 0x73, 0x73, 0x04, 0x06, 0x70, 0x65, 0x72, 0x73, 0x6F, 0x6E, 0x30,
+        0x0A, 0x04, 0x02, 0x63, 0x6E, 0x04, 0x04, 0x6D, 0x61, 0x69, 0x6C,
+    ];
+    let filter = Filter::And(SetOf::from_vec(vec![
+        Filter::Not(Box::new(Filter::Present(OctetString::from_static(b"mail")))),
+        Filter::EqualityMatch(AttributeValueAssertion {
+            attribute_desc: OctetString::from_static(b"objectClass"),
+            assertion_value: OctetString::from_static(b"person"),
+        }),
+    ]));
+    let message = LdapMessage {
+        message_id: 2,
+        protocol_op: ProtocolOp::SearchRequest(SearchRequest {
+            base_object: OctetString::from_static(b"dc=example,dc=com"),
+            scope: Scope::WholeSubtree,
+            deref_aliases: DerefAliases::NeverDerefAliases,
+            size_limit: 0,
+            time_limit: 0,
+            types_only: false,
+            filter,
+            attributes: vec![
+                OctetString::from_static(b"cn"),
+                OctetString::from_static(b"mail"),
+            ],
+        }),
+        controls: None,
+    };
+
+    assert_eq!(message, rasn::ber::decode(encoded).unwrap());
+    assert_eq!(&encoded[..], rasn::ber::encode(&message).unwrap());
+}
@@ -43,3 +43,55 @@ fn decode() {
             .unwrap(),
     );
 }
+
+/// Fields declared out of tag order, with `OPTIONAL` and `DEFAULT` fields.
+#[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+#[rasn(set)]
+#[non_exhaustive]
+struct Options {
+    #[rasn(tag(2))]
+    name: Utf8String,
+    #[rasn(tag(0), default)]
+    version: u8,
+    #[rasn(tag(1))]
+    comment: Option<Utf8String>,
+}
+
+#[test]
+fn optional_and_default_components() {
+    let options = Options {
+        name: "a".into(),
+        version: 2,
+        comment: Some("b".into()),
+    };
+    // Components are encoded in tag order rather than declaration order.
+    let canonical = &[
+        0x31, 0x09, 0x80, 0x01, 0x02, 0x81, 0x01, b'b', 0x82, 0x01, b'a',
+    ][..];
+    let reordered = &[
+        0x31, 0x09, 0x82, 0x01, b'a', 0x81, 0x01, b'b', 0x80, 0x01, 0x02,
+    ][..];
+
+    assert_eq!(canonical, rasn::der::encode(&options).unwrap());
+    for encoded in [canonical, reordered] {
+        let decoded: Options = rasn::ber::decode(encoded).unwrap();
+        assert_eq!(options, decoded);
+        assert_eq!(canonical, rasn::der::encode(&decoded).unwrap());
+    }
+
+    let defaults = Options {
+        name: "a".into(),
+        version: 0,
+        comment: None,
+    };
+    let encoded = &[0x31, 0x03, 0x82, 0x01, b'a'][..];
+    assert_eq!(encoded, rasn::der::encode(&defaults).unwrap());
+    assert_eq!(defaults, rasn::ber::decode(encoded).unwrap());
+    assert_eq!(
+        defaults,
+        rasn::ber::decode(&[0x31, 0x06, 0x80, 0x01, 0x00, 0x82, 0x01, b'a']).unwrap()
+    );
+
+    // The required `name` is missing.
+    assert!(rasn::ber::decode::<Options>(&[0x31, 0x03, 0x81, 0x01, b'b']).is_err());
+}
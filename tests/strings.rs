@@ -456,3 +456,44 @@ fn test_per_encode_utf8_string() {
         assert_eq!(case, uper::decode::<Utf8String>(&buf_expected).unwrap());
     }
 }
+
+#[test]
+fn restricted_strings_as_str() {
+    let printable = PrintableString::try_from("Example Org. (Test)").unwrap();
+    assert_eq!("Example Org. (Test)", printable.as_str());
+    assert_eq!("Example Org. (Test)", printable.to_string());
+    assert_eq!(
+        String::from("Example Org. (Test)"),
+        String::from(printable.clone())
+    );
+    assert_eq!(
+        &[0x13, 0x13][..],
+        &ber::encode(&printable).unwrap()[..2],
+        "encoded with the PrintableString tag"
+    );
+    assert_eq!(
+        printable,
+        ber::decode(&ber::encode(&printable).unwrap()).unwrap()
+    );
+
+    let numeric = NumericString::try_from(String::from("0123 456")).unwrap();
+    assert_eq!("0123 456", numeric.as_str());
+    assert_eq!("0123 456", numeric.to_string());
+    assert_eq!(String::from("0123 456"), String::from(numeric.clone()));
+    assert_eq!(
+        vec![0x12, 0x08, b'0', b'1', b'2', b'3', b' ', b'4', b'5', b'6'],
+        ber::encode(&numeric).unwrap()
+    );
+
+    let ia5 = Ia5String::try_from("user@example.com").unwrap();
+    assert_eq!("user@example.com", ia5.as_str());
+    let visible = VisibleString::try_from("~visible~").unwrap();
+    assert_eq!("~visible~", visible.as_str());
+
+    // Characters outside of each alphabet are rejected.
+    assert!(PrintableString::try_from("user@example.com").is_err());
+    assert!(PrintableString::try_from(String::from("a*b")).is_err());
+    assert!(NumericString::try_from("12a").is_err());
+    assert!(Ia5String::try_from("caf\u{e9}").is_err());
+    assert!(VisibleString::try_from("tab\t").is_err());
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "chrono")]
+
 use std::str::FromStr;
 
 use rasn::types::*;
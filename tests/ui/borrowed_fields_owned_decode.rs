@@ -0,0 +1,12 @@
+use rasn::prelude::*;
+
+// Structs with borrowed fields implement `DecodeBorrowed` rather than
+// `Decode`, so they can only be decoded with `decode_borrowed`.
+#[derive(AsnType, Decode)]
+struct Header<'a> {
+    name: &'a str,
+}
+
+fn main() {
+    let _ = rasn::ber::decode::<Header>(&[0x30, 0x00]);
+}
@@ -0,0 +1,9 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+#[rasn(choice)]
+struct Choice {
+    flag: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rasn::prelude::*;
+
+#[derive(AsnType)]
+#[rasn(choice)]
+enum Name {
+    First(Utf8String),
+    Second(Utf8String),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+#[rasn(delegate)]
+struct Wrapper(bool, u8);
+
+fn main() {}
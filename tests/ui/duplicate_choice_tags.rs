@@ -0,0 +1,13 @@
+use rasn::{types::OctetString, AsnType};
+
+#[derive(AsnType)]
+#[rasn(choice)]
+enum Choice {
+    Plain(bool),
+    #[rasn(tag(0))]
+    Implicit(OctetString),
+    #[rasn(tag(explicit(0)))]
+    Explicit(OctetString),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+struct Record {
+    #[rasn(tag(0), tag(1))]
+    flag: bool,
+}
+
+fn main() {}
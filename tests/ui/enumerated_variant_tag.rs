@@ -0,0 +1,11 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+#[rasn(enumerated)]
+enum Color {
+    Red,
+    #[rasn(tag(1))]
+    Green,
+}
+
+fn main() {}
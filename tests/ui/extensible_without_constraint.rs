@@ -0,0 +1,9 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+struct Record {
+    #[rasn(size(extensible))]
+    items: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use rasn::prelude::*;
+
+#[derive(AsnType, Decode, Encode)]
+struct Validity {
+    not_before: Integer,
+    not_after: Integer,
+}
+
+// `serial` is OPTIONAL and followed by the flattened `not_before`, which is
+// also an INTEGER.
+#[derive(AsnType, Decode, Encode)]
+struct Ticket {
+    serial: Option<Integer>,
+    #[rasn(flatten)]
+    validity: Validity,
+}
+
+fn main() {}
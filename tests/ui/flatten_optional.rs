@@ -0,0 +1,15 @@
+use rasn::prelude::*;
+
+#[derive(AsnType, Decode, Encode)]
+struct Validity {
+    not_before: Integer,
+    not_after: Integer,
+}
+
+#[derive(AsnType)]
+struct Ticket {
+    #[rasn(flatten)]
+    validity: Option<Validity>,
+}
+
+fn main() {}
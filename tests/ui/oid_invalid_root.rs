@@ -0,0 +1,5 @@
+const INVALID: &rasn::types::Oid = rasn::oid!(7.1);
+
+fn main() {
+    let _ = INVALID;
+}
@@ -0,0 +1,9 @@
+use rasn::prelude::*;
+
+#[derive(AsnType)]
+struct Record {
+    nickname: Option<Utf8String>,
+    name: Utf8String,
+}
+
+fn main() {}
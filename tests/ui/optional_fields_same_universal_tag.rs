@@ -0,0 +1,11 @@
+use rasn::prelude::*;
+
+// `u8` and `Integer` are both `INTEGER`s, which the derive can only see once
+// their tags are evaluated.
+#[derive(AsnType)]
+struct Record {
+    age: Option<u8>,
+    id: Option<Integer>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+#[rasn(choise)]
+enum Choice {
+    Flag(bool),
+}
+
+fn main() {}
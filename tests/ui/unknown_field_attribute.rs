@@ -0,0 +1,9 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+struct Record {
+    #[rasn(defualt)]
+    flag: bool,
+}
+
+fn main() {}
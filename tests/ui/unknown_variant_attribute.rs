@@ -0,0 +1,10 @@
+use rasn::AsnType;
+
+#[derive(AsnType)]
+#[rasn(choice)]
+enum Choice {
+    #[rasn(tga(0))]
+    Flag(bool),
+}
+
+fn main() {}
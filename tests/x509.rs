@@ -0,0 +1,152 @@
+//! Decoding and re-encoding a complete X.509 certificate (RFC 5280), using
+//! types defined here rather than those in `rasn-pkix`.
+
+use rasn::prelude::*;
+use rasn::types::Unsigned;
+
+/// A self-signed P-256 certificate with a 16 octet serial number whose top
+/// bit is set, a `notBefore` in `UTCTime`, a `notAfter` in `GeneralizedTime`
+/// and four extensions, two of them critical.
+const SELF_SIGNED: &[u8] = include_bytes!("data/self-signed.der");
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Certificate {
+    tbs_certificate: TbsCertificate,
+    signature_algorithm: AlgorithmIdentifier,
+    signature_value: BitString,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct TbsCertificate {
+    #[rasn(tag(explicit(0)), default)]
+    version: Version,
+    serial_number: Unsigned,
+    signature: AlgorithmIdentifier,
+    issuer: Name,
+    validity: Validity,
+    subject: Name,
+    subject_public_key_info: SubjectPublicKeyInfo,
+    #[rasn(tag(1))]
+    issuer_unique_id: Option<BitString>,
+    #[rasn(tag(2))]
+    subject_unique_id: Option<BitString>,
+    #[rasn(tag(explicit(3)))]
+    extensions: Option<Vec<Extension>>,
+}
+
+/// `Version ::= INTEGER { v1(0), v2(1), v3(2) }`, where the default is v1.
+#[derive(AsnType, Clone, Copy, Debug, Decode, Default, Encode, PartialEq)]
+#[rasn(delegate)]
+struct Version(u8);
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct AlgorithmIdentifier {
+    algorithm: ObjectIdentifier,
+    parameters: Option<Any>,
+}
+
+type Name = Vec<SetOf<AttributeTypeAndValue>>;
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+struct AttributeTypeAndValue {
+    r#type: ObjectIdentifier,
+    value: Any,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Validity {
+    not_before: Time,
+    not_after: Time,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(choice)]
+enum Time {
+    Utc(UtcTime),
+    General(GeneralizedTime),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct SubjectPublicKeyInfo {
+    algorithm: AlgorithmIdentifier,
+    subject_public_key: BitString,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Extension {
+    extn_id: ObjectIdentifier,
+    #[rasn(default)]
+    critical: bool,
+    extn_value: OctetString,
+}
+
+#[test]
+fn self_signed_round_trip() {
+    let certificate: Certificate = rasn::der::decode(SELF_SIGNED).unwrap();
+    let tbs = &certificate.tbs_certificate;
+
+    assert_eq!(Version(2), tbs.version);
+    assert_eq!(
+        Unsigned::from_be_bytes(&[
+            0x8C, 0x5F, 0x3A, 0x2B, 0x1E, 0x0D, 0x9C, 0x8B, 0x7A, 0x6F, 0x5E, 0x4D, 0x3C, 0x2B,
+            0x1A, 0x09
+        ]),
+        tbs.serial_number
+    );
+
+    // ecdsa-with-SHA256 has no parameters, id-ecPublicKey names its curve.
+    let ecdsa_with_sha256 = Oid::const_new(&[1, 2, 840, 10045, 4, 3, 2]);
+    assert_eq!(ecdsa_with_sha256, &*tbs.signature.algorithm);
+    assert_eq!(None, tbs.signature.parameters);
+    assert_eq!(tbs.signature, certificate.signature_algorithm);
+    assert_eq!(
+        // prime256v1
+        Some(&[0x06, 0x08, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07][..]),
+        tbs.subject_public_key_info
+            .algorithm
+            .parameters
+            .as_ref()
+            .map(Any::as_bytes)
+    );
+    assert_eq!(65 * 8, tbs.subject_public_key_info.subject_public_key.len());
+
+    assert_eq!(tbs.issuer, tbs.subject);
+    assert_eq!(3, tbs.subject.len());
+    assert!(matches!(tbs.validity.not_before, Time::Utc(_)));
+    assert!(matches!(tbs.validity.not_after, Time::General(_)));
+    assert_eq!(None, tbs.issuer_unique_id);
+    assert_eq!(None, tbs.subject_unique_id);
+
+    let extensions = tbs.extensions.as_ref().unwrap();
+    assert_eq!(
+        [false, false, true, true],
+        [0, 1, 2, 3].map(|i| extensions[i].critical)
+    );
+    // basicConstraints: cA TRUE.
+    assert_eq!(&[0x30, 0x03, 0x01, 0x01, 0xFF], &*extensions[2].extn_value);
+
+    assert_eq!(SELF_SIGNED, &*rasn::der::encode(&certificate).unwrap());
+}
+
+#[test]
+fn defaults_are_omitted() {
+    let mut certificate: Certificate = rasn::der::decode(SELF_SIGNED).unwrap();
+    let tbs = &mut certificate.tbs_certificate;
+    tbs.version = Version::default();
+    tbs.extensions = None;
+    let der = rasn::der::encode(&*tbs).unwrap();
+
+    // A v1 certificate starts directly with its serial number.
+    assert_eq!(&[0x02, 0x11, 0x00, 0x8C], &der[4..8]);
+    assert_eq!(*tbs, rasn::der::decode(&der).unwrap());
+
+    let extension = Extension {
+        extn_id: Oid::const_new(&[2, 5, 29, 14]).to_owned(),
+        critical: false,
+        extn_value: OctetString::from(vec![0x04, 0x00]),
+    };
+    assert_eq!(
+        &[0x30, 0x09, 0x06, 0x03, 0x55, 0x1D, 0x0E, 0x04, 0x02, 0x04, 0x00],
+        &*rasn::der::encode(&extension).unwrap()
+    );
+}